@@ -222,7 +222,7 @@ fn table_size(cli: &Cli, query: &str) -> usize {
 /// # Panics
 /// * The table reference cannot be parsed from the string.
 /// * The columns generated from `generate_random_columns` lead to a failure in `insert_table`.
-/// * The query string cannot be parsed into a `QueryExpr`.
+/// * The query string cannot be parsed or planned into `DynProofPlan`s.
 /// * The creation of the `VerifiableQueryResult` fails due to invalid proof expressions.
 /// * If the verification of the `VerifiableQueryResult` fails.
 fn bench_by_schema<CP: CommitmentEvaluationProof>(
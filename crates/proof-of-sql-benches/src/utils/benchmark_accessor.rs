@@ -107,4 +107,8 @@ impl<C: Commitment> SchemaAccessor for BenchmarkAccessor<'_, C> {
     fn lookup_schema(&self, table_ref: &TableRef) -> Vec<(Ident, ColumnType)> {
         self.table_schemas.get(&table_ref).unwrap().clone()
     }
+
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.table_schemas.contains_key(table_ref)
+    }
 }
@@ -87,7 +87,8 @@ fn main() {
     let filename = "crates/proof-of-sql-planner/examples/albums/albums.csv";
     let schema = get_posql_compatible_schema(&SchemaRef::new(
         infer_schema_from_files(&[filename.to_string()], b',', None, true).unwrap(),
-    ));
+    ))
+    .unwrap();
     let albums_batch = ReaderBuilder::new(schema)
         .with_header(true)
         .build(File::open(filename).unwrap())
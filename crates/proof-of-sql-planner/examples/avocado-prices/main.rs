@@ -36,7 +36,7 @@ use std::{fs::File, time::Instant};
 // max_nu = 10 => max table size is 0.5 million rows
 // max_nu = 15 => max table size is 0.5 billion rows
 // max_nu = 20 => max table size is 0.5 trillion rows
-// Note: we will eventually load these from a file.
+// Note: in production, these should be loaded with `PublicParameters::load_from_file`.
 const DORY_SETUP_MAX_NU: usize = 8;
 // This should be a "nothing-up-my-sleeve" phrase or number.
 const DORY_SEED: [u8; 32] = *b"len 32 rng seed - Space and Time";
@@ -94,7 +94,8 @@ fn main() {
     let filename = "crates/proof-of-sql-planner/examples/avocado-prices/avocado-prices.csv";
     let schema = get_posql_compatible_schema(&SchemaRef::new(
         infer_schema_from_files(&[filename.to_string()], b',', None, true).unwrap(),
-    ));
+    ))
+    .unwrap();
 
     let data_batch = ReaderBuilder::new(schema.clone())
         .with_header(true)
@@ -4,19 +4,11 @@
 //! NOTE: If this doesn't work because you do not have the appropriate GPU drivers installed,
 //! you can run `cargo run --release --example dinosaurs --no-default-features --features="cpu-perf"` instead. It will be slower for proof generation.
 use datafusion::{
-    arrow::{
-        csv::{infer_schema_from_files, ReaderBuilder},
-        datatypes::SchemaRef,
-        record_batch::RecordBatch,
-        util::pretty::pretty_format_batches,
-    },
+    arrow::{record_batch::RecordBatch, util::pretty::pretty_format_batches},
     config::ConfigOptions,
 };
 use proof_of_sql::{
-    base::database::{
-        arrow_schema_utility::get_posql_compatible_schema, OwnedTable, OwnedTableTestAccessor,
-        TableRef, TestAccessor,
-    },
+    base::database::{csv::add_csv_table, OwnedTableTestAccessor, TableRef},
     proof_primitive::dory::{
         DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
     },
@@ -25,7 +17,7 @@ use proof_of_sql::{
 use proof_of_sql_planner::sql_to_proof_plans;
 use rand::{rngs::StdRng, SeedableRng};
 use sqlparser::{dialect::GenericDialect, parser::Parser};
-use std::{fs::File, time::Instant};
+use std::time::Instant;
 
 // We generate the public parameters and the setups used by the prover and verifier for the Dory PCS.
 // The `max_nu` should be set such that the maximum table size is less than `2^(2*max_nu-1)`.
@@ -84,26 +76,17 @@ fn main() {
     let verifier_setup = VerifierSetup::from(&public_parameters);
 
     let filename = "crates/proof-of-sql-planner/examples/dinosaurs/dinosaurs.csv";
-    let inferred_schema =
-        SchemaRef::new(infer_schema_from_files(&[filename.to_string()], b',', None, true).unwrap());
-    let posql_compatible_schema = get_posql_compatible_schema(&inferred_schema);
-
-    let dinosaurs_batch = ReaderBuilder::new(posql_compatible_schema)
-        .with_header(true)
-        .build(File::open(filename).unwrap())
-        .unwrap()
-        .next()
-        .unwrap()
-        .unwrap();
 
     // Load the table into an "Accessor" so that the prover and verifier can access the data/commitments.
     let mut accessor =
         OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
-    accessor.add_table(
+    add_csv_table(
+        &mut accessor,
+        filename,
         TableRef::from_names(None, "dinosaurs"),
-        OwnedTable::try_from(dinosaurs_batch).unwrap(),
         0,
-    );
+    )
+    .unwrap();
 
     prove_and_verify_query(
         "SELECT COUNT(*) AS total_dinosaurs FROM dinosaurs",
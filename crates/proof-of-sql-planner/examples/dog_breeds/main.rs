@@ -86,7 +86,8 @@ fn main() {
     let filename = "crates/proof-of-sql-planner/examples/dog_breeds/dog_breeds.csv";
     let schema = get_posql_compatible_schema(&SchemaRef::new(
         infer_schema_from_files(&[filename.to_string()], b',', None, true).unwrap(),
-    ));
+    ))
+    .unwrap();
 
     let dog_breeds_batch = ReaderBuilder::new(schema.clone())
         .with_header(true)
@@ -62,4 +62,8 @@ impl<C: Commitment> SchemaAccessor for CommitAccessor<C> {
     fn lookup_schema(&self, table_ref: &TableRef) -> Vec<(Ident, ColumnType)> {
         self.inner.lookup_schema(table_ref)
     }
+
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.inner.has_table(table_ref)
+    }
 }
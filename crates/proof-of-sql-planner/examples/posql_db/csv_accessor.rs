@@ -103,4 +103,7 @@ impl SchemaAccessor for CsvDataAccessor {
     fn lookup_schema(&self, table_ref: &TableRef) -> Vec<(Ident, ColumnType)> {
         self.inner.lookup_schema(table_ref)
     }
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.inner.has_table(table_ref)
+    }
 }
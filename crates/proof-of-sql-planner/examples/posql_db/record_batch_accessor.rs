@@ -80,4 +80,8 @@ impl SchemaAccessor for RecordBatchAccessor {
             })
             .collect()
     }
+
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.tables.contains_key(table_ref)
+    }
 }
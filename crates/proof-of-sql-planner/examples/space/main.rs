@@ -37,7 +37,7 @@ use std::{fs::File, time::Instant};
 // max_nu = 10 => max table size is 0.5 million rows
 // max_nu = 15 => max table size is 0.5 billion rows
 // max_nu = 20 => max table size is 0.5 trillion rows
-// Note: we will eventually load these from a file.
+// Note: in production, these should be loaded with `PublicParameters::load_from_file`.
 const DORY_SETUP_MAX_NU: usize = 8;
 // This should be a "nothing-up-my-sleeve" phrase or number.
 const DORY_SEED: [u8; 32] = *b"len 32 rng seed - Space and Time";
@@ -86,7 +86,8 @@ fn main() {
     let filename = "crates/proof-of-sql-planner/examples/stocks/stocks.csv";
     let schema = get_posql_compatible_schema(&SchemaRef::new(
         infer_schema_from_files(&[filename.to_string()], b',', None, true).unwrap(),
-    ));
+    ))
+    .unwrap();
     let stocks_batch = ReaderBuilder::new(schema)
         .with_header(true)
         .build(File::open(filename).unwrap())
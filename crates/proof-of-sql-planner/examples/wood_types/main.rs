@@ -86,7 +86,7 @@ fn main() {
     let filename = "crates/proof-of-sql-planner/examples/wood_types/wood_types.csv";
     let inferred_schema =
         SchemaRef::new(infer_schema_from_files(&[filename.to_string()], b',', None, true).unwrap());
-    let posql_compatible_schema = get_posql_compatible_schema(&inferred_schema);
+    let posql_compatible_schema = get_posql_compatible_schema(&inferred_schema).unwrap();
 
     let wood_types_batch = ReaderBuilder::new(posql_compatible_schema)
         .with_header(true)
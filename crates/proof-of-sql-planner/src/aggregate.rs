@@ -60,6 +60,29 @@ pub(crate) fn aggregate_function_to_proof_expr(
     }
 }
 
+/// Returns the argument of `expr` if it is of the form `COUNT(DISTINCT <arg>)`
+pub(crate) fn count_distinct_argument(expr: &Expr) -> Option<&Expr> {
+    match expr {
+        Expr::Alias(alias) => count_distinct_argument(&alias.expr),
+        Expr::AggregateFunction(AggregateFunction {
+            distinct: true,
+            filter: None,
+            order_by: None,
+            args,
+            func_def:
+                AggregateFunctionDefinition::BuiltIn(
+                    physical_plan::aggregates::AggregateFunction::Count,
+                ),
+            ..
+        }) => match args.as_slice() {
+            [Expr::Wildcard { .. }] => None,
+            [arg] => Some(arg),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,4 +248,58 @@ mod tests {
             Err(PlannerError::UnsupportedAggregateFunction { .. })
         ));
     }
+
+    #[test]
+    fn we_can_get_the_argument_of_count_distinct() {
+        let expr = df_column("table", "a");
+        let count_distinct = |args: Vec<Expr>| {
+            Expr::AggregateFunction(AggregateFunction::new(
+                physical_plan::aggregates::AggregateFunction::Count,
+                args,
+                true,
+                None,
+                None,
+                None,
+            ))
+        };
+        assert_eq!(
+            count_distinct_argument(&count_distinct(vec![expr.clone()])),
+            Some(&expr)
+        );
+        assert_eq!(
+            count_distinct_argument(&count_distinct(vec![expr.clone()]).alias("n")),
+            Some(&expr)
+        );
+        assert_eq!(
+            count_distinct_argument(&count_distinct(vec![Expr::Wildcard { qualifier: None }])),
+            None
+        );
+        assert_eq!(
+            count_distinct_argument(&count_distinct(vec![expr.clone(); 2])),
+            None
+        );
+
+        // Not distinct
+        let count = Expr::AggregateFunction(AggregateFunction::new(
+            physical_plan::aggregates::AggregateFunction::Count,
+            vec![expr.clone()],
+            false,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(count_distinct_argument(&count), None);
+
+        // Not a count
+        let sum_distinct = Expr::AggregateFunction(AggregateFunction::new(
+            physical_plan::aggregates::AggregateFunction::Sum,
+            vec![expr.clone()],
+            true,
+            None,
+            None,
+            None,
+        ));
+        assert_eq!(count_distinct_argument(&sum_distinct), None);
+        assert_eq!(count_distinct_argument(&expr), None);
+    }
 }
@@ -0,0 +1,200 @@
+use crate::{
+    conversion::table_refs_from_statement, table_reference_to_table_ref, PoSqlContextProvider,
+};
+use alloc::vec::Vec;
+use datafusion::{
+    common::SchemaError,
+    config::ConfigOptions,
+    error::DataFusionError,
+    sql::{
+        planner::{ParserOptions, SqlToRel},
+        TableReference,
+    },
+};
+use indexmap::{IndexMap, IndexSet};
+use proof_of_sql::base::database::{ColumnType, SchemaAccessor, TableRef};
+use sqlparser::ast::{Ident, Statement};
+
+/// A column referenced by a query which is not present in the schema
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MissingColumn {
+    /// The table the column was looked up in
+    pub table_ref: TableRef,
+    /// The name of the column
+    pub column_id: Ident,
+}
+
+/// A [`SchemaAccessor`] which adds placeholder columns to the schemas of another accessor
+struct PlaceholderColumnAccessor<'a, A: SchemaAccessor> {
+    accessor: &'a A,
+    placeholders: IndexMap<TableRef, Vec<(Ident, ColumnType)>>,
+}
+
+impl<A: SchemaAccessor> Clone for PlaceholderColumnAccessor<'_, A> {
+    fn clone(&self) -> Self {
+        Self {
+            accessor: self.accessor,
+            placeholders: self.placeholders.clone(),
+        }
+    }
+}
+
+impl<A: SchemaAccessor> SchemaAccessor for PlaceholderColumnAccessor<'_, A> {
+    fn lookup_column(&self, table_ref: &TableRef, column_id: &Ident) -> Option<ColumnType> {
+        self.lookup_schema(table_ref)
+            .into_iter()
+            .find_map(|(id, column_type)| (&id == column_id).then_some(column_type))
+    }
+
+    fn lookup_schema(&self, table_ref: &TableRef) -> Vec<(Ident, ColumnType)> {
+        let mut schema = self.accessor.lookup_schema(table_ref);
+        schema.extend(
+            self.placeholders
+                .get(table_ref)
+                .into_iter()
+                .flatten()
+                .cloned(),
+        );
+        schema
+    }
+
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.accessor.has_table(table_ref)
+    }
+}
+
+/// Finds the table of `table_refs` that a column qualifier refers to
+///
+/// Qualifiers may omit the schema, e.g. `t.a` refers to a column of `sxt.t`.
+/// Unqualified columns are attributed to the first table.
+fn resolve_qualifier(
+    qualifier: Option<&TableReference>,
+    table_refs: &IndexSet<TableRef>,
+) -> Option<TableRef> {
+    let Some(qualifier) = qualifier else {
+        return table_refs.first().cloned();
+    };
+    let qualifier = table_reference_to_table_ref(qualifier).ok()?;
+    table_refs
+        .iter()
+        .find(|table_ref| {
+            table_ref.table_id() == qualifier.table_id()
+                && qualifier
+                    .schema_id()
+                    .map_or(true, |schema_id| table_ref.schema_id() == Some(schema_id))
+        })
+        .cloned()
+}
+
+/// Returns every column referenced by `statement` which is missing from the schema
+///
+/// Planning a query fails on the first missing column with `ColumnNotFound`.
+/// For exploratory tooling it is more useful to list all of them at once, so this
+/// repeatedly plans the statement, adding each missing column as a placeholder until
+/// the statement no longer fails because of a missing column.
+///
+/// Unqualified columns are attributed to the first table referenced by the statement.
+/// Errors other than missing columns are ignored, as are statements without tables.
+pub fn validate_columns<A: SchemaAccessor>(
+    statement: &Statement,
+    schemas: &A,
+    config: &ConfigOptions,
+) -> Vec<MissingColumn> {
+    let table_refs =
+        table_refs_from_statement(statement, config.sql_parser.enable_ident_normalization)
+            .unwrap_or_default();
+    let mut accessor = PlaceholderColumnAccessor {
+        accessor: schemas,
+        placeholders: IndexMap::new(),
+    };
+    let mut missing_columns = Vec::new();
+    loop {
+        let context_provider = PoSqlContextProvider::new(accessor.clone());
+        let sql_to_rel = SqlToRel::new_with_options(
+            &context_provider,
+            ParserOptions {
+                parse_float_as_decimal: config.sql_parser.parse_float_as_decimal,
+                enable_ident_normalization: config.sql_parser.enable_ident_normalization,
+            },
+        );
+        let Err(DataFusionError::SchemaError(SchemaError::FieldNotFound { field, .. }, _)) =
+            sql_to_rel.sql_statement_to_plan(statement.clone())
+        else {
+            break;
+        };
+        let Some(table_ref) = resolve_qualifier(field.relation.as_ref(), &table_refs) else {
+            break;
+        };
+        let column_id: Ident = field.name.as_str().into();
+        let missing_column = MissingColumn {
+            table_ref,
+            column_id,
+        };
+        // Guard against a placeholder which does not resolve the error
+        if missing_columns.contains(&missing_column) {
+            break;
+        }
+        accessor
+            .placeholders
+            .entry(missing_column.table_ref.clone())
+            .or_default()
+            .push((missing_column.column_id.clone(), ColumnType::BigInt));
+        missing_columns.push(missing_column);
+    }
+    missing_columns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ahash::AHasher;
+    use indexmap::indexmap_with_default;
+    use proof_of_sql::base::database::SchemaAccessorImpl;
+    use sqlparser::{dialect::GenericDialect, parser::Parser};
+
+    fn missing_columns(sql: &str) -> IndexSet<MissingColumn> {
+        let schemas = SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+            TableRef::new("sxt", "t") => vec![
+                ("a".into(), ColumnType::BigInt),
+                ("b".into(), ColumnType::VarChar),
+            ],
+            TableRef::new("sxt", "u") => vec![("c".into(), ColumnType::BigInt)],
+        });
+        let statement = Parser::parse_sql(&GenericDialect {}, sql).unwrap()[0].clone();
+        validate_columns(&statement, &schemas, &ConfigOptions::default())
+            .into_iter()
+            .collect()
+    }
+
+    fn missing_column(table: &str, column: &str) -> MissingColumn {
+        MissingColumn {
+            table_ref: TableRef::new("sxt", table),
+            column_id: column.into(),
+        }
+    }
+
+    #[test]
+    fn we_can_list_all_missing_columns_of_a_query() {
+        assert_eq!(
+            missing_columns("SELECT a, x, b + y FROM sxt.t WHERE z = 1 AND a > 0"),
+            IndexSet::from([
+                missing_column("t", "x"),
+                missing_column("t", "y"),
+                missing_column("t", "z"),
+            ])
+        );
+    }
+
+    #[test]
+    fn we_can_attribute_qualified_missing_columns_to_their_tables() {
+        assert_eq!(
+            missing_columns("SELECT t.a, u.d FROM sxt.t JOIN sxt.u ON t.e = u.c"),
+            IndexSet::from([missing_column("u", "d"), missing_column("t", "e")])
+        );
+    }
+
+    #[test]
+    fn we_report_no_missing_columns_for_valid_queries() {
+        assert!(missing_columns("SELECT a, b AS x FROM sxt.t WHERE a = 1 ORDER BY x").is_empty());
+    }
+}
@@ -1,8 +1,9 @@
 use super::table_reference_to_table_ref;
 use crate::schema_to_column_fields;
+use alloc::rc::Rc;
 use alloc::sync::Arc;
 use arrow::datatypes::{Field, Schema};
-use core::any::Any;
+use core::{any::Any, cell::RefCell};
 use datafusion::{
     common::{
         arrow::datatypes::{DataType, SchemaRef},
@@ -16,7 +17,9 @@ use datafusion::{
     prelude::SessionConfig,
     sql::{planner::ContextProvider, TableReference},
 };
-use proof_of_sql::base::database::{ColumnField, SchemaAccessor};
+use indexmap::{IndexMap, IndexSet};
+use proof_of_sql::base::database::{ColumnField, ColumnType, SchemaAccessor, TableRef};
+use sqlparser::ast::Ident;
 
 /// A [`ContextProvider`] implementation for Proof of SQL
 ///
@@ -25,6 +28,7 @@ pub struct PoSqlContextProvider<A: SchemaAccessor> {
     accessor: A,
     options: ConfigOptions,
     state: SessionState,
+    supported_functions: Option<IndexSet<String>>,
 }
 
 impl<A: SchemaAccessor> PoSqlContextProvider<A> {
@@ -38,8 +42,32 @@ impl<A: SchemaAccessor> PoSqlContextProvider<A> {
                 SessionConfig::default(),
                 RuntimeEnv::default().into(),
             ),
+            supported_functions: None,
         }
     }
+
+    /// Restrict the scalar functions available to planning to those named in `names`
+    ///
+    /// By default every scalar function known to `DataFusion` is available. Once restricted,
+    /// queries calling any other scalar function fail while planning the query instead of
+    /// during conversion to a proof plan. Aggregate functions are built into `DataFusion`
+    /// and are not affected.
+    #[must_use]
+    pub fn with_supported_functions<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.supported_functions = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Whether the scalar function `name` may be used in queries
+    fn is_supported_function(&self, name: &str) -> bool {
+        self.supported_functions
+            .as_ref()
+            .map_or(true, |names| names.contains(name))
+    }
 }
 
 impl<A: SchemaAccessor> ContextProvider for PoSqlContextProvider<A> {
@@ -54,7 +82,9 @@ impl<A: SchemaAccessor> ContextProvider for PoSqlContextProvider<A> {
         Ok(Arc::new(PoSqlTableSource::new(column_fields)) as Arc<dyn TableSource>)
     }
     fn get_function_meta(&self, name: &str) -> Option<Arc<ScalarUDF>> {
-        self.state.scalar_functions().get(name).cloned()
+        self.is_supported_function(name)
+            .then(|| self.state.scalar_functions().get(name).cloned())
+            .flatten()
     }
     //TODO: add count and sum
     fn get_aggregate_meta(&self, _name: &str) -> Option<Arc<AggregateUDF>> {
@@ -70,7 +100,12 @@ impl<A: SchemaAccessor> ContextProvider for PoSqlContextProvider<A> {
         &self.options
     }
     fn udfs_names(&self) -> Vec<String> {
-        self.state.scalar_functions().keys().cloned().collect()
+        self.state
+            .scalar_functions()
+            .keys()
+            .filter(|name| self.is_supported_function(name))
+            .cloned()
+            .collect()
     }
     fn udafs_names(&self) -> Vec<String> {
         Vec::new()
@@ -80,6 +115,53 @@ impl<A: SchemaAccessor> ContextProvider for PoSqlContextProvider<A> {
     }
 }
 
+/// A [`SchemaAccessor`] which looks up the schema of each table at most once
+///
+/// Clones share the same cache, so a single lookup serves every statement planned with it.
+pub(crate) struct CachingSchemaAccessor<'a, A: SchemaAccessor> {
+    accessor: &'a A,
+    schemas: Rc<RefCell<IndexMap<TableRef, Vec<(Ident, ColumnType)>>>>,
+}
+
+impl<'a, A: SchemaAccessor> CachingSchemaAccessor<'a, A> {
+    /// Create a new `CachingSchemaAccessor` with an empty cache
+    pub(crate) fn new(accessor: &'a A) -> Self {
+        Self {
+            accessor,
+            schemas: Rc::default(),
+        }
+    }
+}
+
+impl<A: SchemaAccessor> Clone for CachingSchemaAccessor<'_, A> {
+    fn clone(&self) -> Self {
+        Self {
+            accessor: self.accessor,
+            schemas: Rc::clone(&self.schemas),
+        }
+    }
+}
+
+impl<A: SchemaAccessor> SchemaAccessor for CachingSchemaAccessor<'_, A> {
+    fn lookup_column(&self, table_ref: &TableRef, column_id: &Ident) -> Option<ColumnType> {
+        self.lookup_schema(table_ref)
+            .into_iter()
+            .find_map(|(id, column_type)| (&id == column_id).then_some(column_type))
+    }
+
+    fn lookup_schema(&self, table_ref: &TableRef) -> Vec<(Ident, ColumnType)> {
+        self.schemas
+            .borrow_mut()
+            .entry(table_ref.clone())
+            .or_insert_with(|| self.accessor.lookup_schema(table_ref))
+            .clone()
+    }
+
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.schemas.borrow().contains_key(table_ref) || self.accessor.has_table(table_ref)
+    }
+}
+
 /// A [`TableSource`] implementation for Proof of SQL
 pub(crate) struct PoSqlTableSource {
     schema: SchemaRef,
@@ -126,7 +208,8 @@ mod tests {
     use super::*;
     use ahash::AHasher;
     use alloc::vec;
-    use core::any::TypeId;
+    use core::{any::TypeId, cell::Cell};
+    use datafusion::sql::{parser::DFParser, planner::SqlToRel};
     use indexmap::indexmap_with_default;
     use proof_of_sql::base::database::{ColumnType, SchemaAccessorImpl, TableRef};
 
@@ -227,4 +310,83 @@ mod tests {
             Err(DataFusionError::External(_))
         ));
     }
+
+    #[test]
+    fn we_can_restrict_the_functions_of_a_posql_context_provider() {
+        let accessor = SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+            TableRef::new("namespace", "a") => vec![("a".into(), ColumnType::BigInt)]
+        });
+        let context_provider =
+            PoSqlContextProvider::new(accessor).with_supported_functions(["abs"]);
+        assert_eq!(context_provider.udfs_names(), vec!["abs".to_string()]);
+        assert!(context_provider.get_function_meta("abs").is_some());
+        assert_eq!(context_provider.get_function_meta("sqrt"), None);
+
+        let sql_to_rel = SqlToRel::new(&context_provider);
+        let plan = |sql: &str| {
+            let statement = DFParser::parse_sql(sql).unwrap().pop_front().unwrap();
+            sql_to_rel.statement_to_plan(statement)
+        };
+        assert!(plan("SELECT abs(a) FROM namespace.a").is_ok());
+        assert!(matches!(
+            plan("SELECT sqrt(a) FROM namespace.a"),
+            Err(DataFusionError::Plan(message)) if message.contains("Invalid function 'sqrt'")
+        ));
+    }
+
+    // CachingSchemaAccessor
+    struct CountingSchemaAccessor {
+        accessor: SchemaAccessorImpl,
+        lookups: Cell<usize>,
+    }
+
+    impl SchemaAccessor for CountingSchemaAccessor {
+        fn lookup_column(&self, table_ref: &TableRef, column_id: &Ident) -> Option<ColumnType> {
+            self.lookups.set(self.lookups.get() + 1);
+            self.accessor.lookup_column(table_ref, column_id)
+        }
+
+        fn lookup_schema(&self, table_ref: &TableRef) -> Vec<(Ident, ColumnType)> {
+            self.lookups.set(self.lookups.get() + 1);
+            self.accessor.lookup_schema(table_ref)
+        }
+    }
+
+    #[test]
+    fn we_can_look_up_each_schema_once_with_a_caching_schema_accessor() {
+        let a = TableRef::new("namespace", "a");
+        let b = TableRef::new("namespace", "b");
+        let accessor = CountingSchemaAccessor {
+            accessor: SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+                a.clone() => vec![("a".into(), ColumnType::SmallInt),
+                    ("b".into(), ColumnType::VarChar)],
+                b.clone() => vec![("c".into(), ColumnType::Int)]
+            }),
+            lookups: Cell::new(0),
+        };
+        let caching_accessor = CachingSchemaAccessor::new(&accessor);
+        let cloned_accessor = caching_accessor.clone();
+        assert_eq!(
+            caching_accessor.lookup_schema(&a),
+            vec![
+                ("a".into(), ColumnType::SmallInt),
+                ("b".into(), ColumnType::VarChar)
+            ]
+        );
+        assert_eq!(
+            cloned_accessor.lookup_column(&a, &"b".into()),
+            Some(ColumnType::VarChar)
+        );
+        assert_eq!(caching_accessor.lookup_column(&a, &"c".into()), None);
+        assert_eq!(accessor.lookups.get(), 1);
+        assert_eq!(
+            cloned_accessor.lookup_column(&b, &"c".into()),
+            Some(ColumnType::Int)
+        );
+        assert_eq!(
+            caching_accessor.lookup_schema(&b),
+            vec![("c".into(), ColumnType::Int)]
+        );
+        assert_eq!(accessor.lookups.get(), 2);
+    }
 }
@@ -1,17 +1,23 @@
-use crate::{logical_plan_to_proof_plan, PlannerResult, PoSqlContextProvider};
+use crate::{
+    context::CachingSchemaAccessor, logical_plan_to_proof_plan,
+    statement_with_resolved_filter_aliases, table_reference_to_table_ref, PlannerError,
+    PlannerResult, PoSqlContextProvider,
+};
 use alloc::{sync::Arc, vec::Vec};
 use datafusion::{
+    common::SchemaError,
     config::ConfigOptions,
+    error::DataFusionError,
     logical_expr::LogicalPlan,
-    optimizer::{Analyzer, Optimizer, OptimizerContext, OptimizerRule},
-    sql::planner::{ParserOptions, SqlToRel},
+    optimizer::{analyzer::AnalyzerRule, Analyzer, Optimizer, OptimizerContext, OptimizerRule},
+    sql::planner::{object_name_to_table_reference, ParserOptions, SqlToRel},
 };
 use indexmap::IndexSet;
 use proof_of_sql::{
-    base::database::{ParseError, SchemaAccessor, TableRef},
+    base::database::{SchemaAccessor, TableRef},
     sql::proof_plans::DynProofPlan,
 };
-use sqlparser::ast::{visit_relations, Statement};
+use sqlparser::ast::{visit_relations, ObjectName, Query, Statement, Visit, Visitor};
 use std::ops::ControlFlow;
 
 /// Get [`Optimizer`]
@@ -33,101 +39,378 @@ pub fn optimizer() -> Optimizer {
     Optimizer::with_rules(filtered_rules)
 }
 
+/// The `DataFusion` analyzer and optimizer rules applied before conversion to a Proof of SQL plan
+///
+/// The rules shipped with `DataFusion` may change between versions, which in turn may change
+/// the generated plans. Pinning an explicit list of rules keeps plan generation stable and makes
+/// it possible to audit exactly which rewrites take place.
+#[derive(Clone)]
+pub struct PlannerRules {
+    analyzer_rules: Vec<Arc<dyn AnalyzerRule + Send + Sync>>,
+    optimizer_rules: Vec<Arc<dyn OptimizerRule + Send + Sync>>,
+}
+
+impl PlannerRules {
+    /// Create a new `PlannerRules` with the given analyzer and optimizer rules, applied in order
+    #[must_use]
+    pub fn new(
+        analyzer_rules: Vec<Arc<dyn AnalyzerRule + Send + Sync>>,
+        optimizer_rules: Vec<Arc<dyn OptimizerRule + Send + Sync>>,
+    ) -> Self {
+        Self {
+            analyzer_rules,
+            optimizer_rules,
+        }
+    }
+
+    /// Get the names of the analyzer rules in the order they are applied
+    #[must_use]
+    pub fn analyzer_rule_names(&self) -> Vec<&str> {
+        self.analyzer_rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// Get the names of the optimizer rules in the order they are applied
+    #[must_use]
+    pub fn optimizer_rule_names(&self) -> Vec<&str> {
+        self.optimizer_rules
+            .iter()
+            .map(|rule| rule.name())
+            .collect()
+    }
+
+    /// Get the [`Analyzer`] applying the analyzer rules
+    fn analyzer(&self) -> Analyzer {
+        Analyzer::with_rules(self.analyzer_rules.clone())
+    }
+
+    /// Get the [`Optimizer`] applying the optimizer rules
+    fn optimizer(&self) -> Optimizer {
+        Optimizer::with_rules(self.optimizer_rules.clone())
+    }
+}
+
+impl Default for PlannerRules {
+    /// The recommended `DataFusion` analyzer rules along with the rules of [`optimizer`]
+    fn default() -> Self {
+        Self::new(Analyzer::new().rules, optimizer().rules)
+    }
+}
+
+/// Collects the names of the common table expressions defined in a statement
+struct CteNameVisitor(IndexSet<ObjectName>);
+
+impl Visitor for CteNameVisitor {
+    type Break = ();
+
+    fn pre_visit_query(&mut self, query: &Query) -> ControlFlow<Self::Break> {
+        if let Some(with) = &query.with {
+            self.0.extend(
+                with.cte_tables
+                    .iter()
+                    .map(|cte| ObjectName(vec![cte.alias.name.clone()])),
+            );
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Check that every table referenced by `statement` exists in `schemas`
+///
+/// Table names are normalized the same way as when the statement is planned.
+fn validate_table_refs<A: SchemaAccessor>(
+    statement: &Statement,
+    schemas: &A,
+    config: &ConfigOptions,
+) -> PlannerResult<()> {
+    for table_ref in
+        table_refs_from_statement(statement, config.sql_parser.enable_ident_normalization)?
+    {
+        if !schemas.has_table(&table_ref) {
+            return Err(PlannerError::TableNotFound { table_ref });
+        }
+    }
+    Ok(())
+}
+
 /// Convert a SQL query to a Proof of SQL plan using schema from provided tables
 ///
 /// This function does the following
 /// 1. Parse the SQL query into AST using sqlparser
-/// 2. Convert the AST into a `LogicalPlan` using `SqlToRel`
-/// 3. Analyze the `LogicalPlan` using `Analyzer`
-/// 4. Optimize the `LogicalPlan` using `Optimizer`
-/// 5. Convert the optimized `LogicalPlan` into a Proof of SQL plan
+/// 2. Check that every table the AST references exists in `schemas`
+/// 3. Convert the AST into a `LogicalPlan` using `SqlToRel`
+/// 4. Analyze the `LogicalPlan` using the analyzer rules in `rules`
+/// 5. Optimize the `LogicalPlan` using the optimizer rules in `rules`
+/// 6. Convert the optimized `LogicalPlan` into a Proof of SQL plan
+///
+/// Statements are planned lazily and independently of each other,
+/// yielding one result per statement.
+fn sql_to_posql_plan_results<'a, T, F, A>(
+    statements: &'a [Statement],
+    schemas: &'a A,
+    config: &'a ConfigOptions,
+    rules: &'a PlannerRules,
+    planner_converter: F,
+) -> impl Iterator<Item = PlannerResult<T>> + 'a
+where
+    T: 'a,
+    F: Fn(&LogicalPlan, &A) -> PlannerResult<T> + 'a,
+    A: SchemaAccessor + Clone,
+{
+    let context_provider = PoSqlContextProvider::new(schemas.clone());
+    // 1. Parse the SQL query into AST using sqlparser
+    statements.iter().map(move |ast| -> PlannerResult<T> {
+        // 2. Check that every table the AST references exists in `schemas`
+        validate_table_refs(ast, schemas, config)?;
+        // 3. Convert the AST into a `LogicalPlan` using `SqlToRel`
+        let sql_to_rel = SqlToRel::new_with_options(
+            &context_provider,
+            ParserOptions {
+                parse_float_as_decimal: config.sql_parser.parse_float_as_decimal,
+                enable_ident_normalization: config.sql_parser.enable_ident_normalization,
+            },
+        );
+        let raw_logical_plan = match sql_to_rel.sql_statement_to_plan(ast.clone()) {
+            Ok(plan) => plan,
+            // `DataFusion` does not resolve projection aliases in `WHERE` clauses.
+            // Retry with the aliases resolved only if the statement fails to plan
+            // because of an unresolved column so that input columns always take
+            // precedence over aliases.
+            Err(err) if is_unresolved_column_error(&err) => {
+                let resolved_ast = statement_with_resolved_filter_aliases(ast.clone());
+                if &resolved_ast == ast {
+                    return Err(err.into());
+                }
+                sql_to_rel.sql_statement_to_plan(resolved_ast)?
+            }
+            Err(err) => return Err(err.into()),
+        };
+        // 4. Analyze the `LogicalPlan` using `Analyzer`
+        let analyzer = rules.analyzer();
+        let analyzed_logical_plan =
+            analyzer.execute_and_check(raw_logical_plan, config, |_, _| {})?;
+        // 5. Optimize the `LogicalPlan` using `Optimizer`
+        let optimizer = rules.optimizer();
+        let optimizer_context = OptimizerContext::default();
+        let optimized_logical_plan =
+            optimizer.optimize(analyzed_logical_plan, &optimizer_context, |_, _| {})?;
+        // 6. Convert the optimized `LogicalPlan` into a Proof of SQL plan
+        planner_converter(&optimized_logical_plan, schemas)
+    })
+}
+
+/// Returns true if `err` is caused by a column which does not exist in the schema
+fn is_unresolved_column_error(err: &DataFusionError) -> bool {
+    matches!(
+        err.find_root(),
+        DataFusionError::SchemaError(SchemaError::FieldNotFound { .. }, _)
+    )
+}
+
+/// Convert a SQL query to a Proof of SQL plan using schema from provided tables
+///
+/// Fails on the first statement which cannot be planned.
+/// See `sql_to_posql_plan_results` for more details
 fn sql_to_posql_plans<T, F, A>(
     statements: &[Statement],
     schemas: &A,
     config: &ConfigOptions,
+    rules: &PlannerRules,
     planner_converter: F,
 ) -> PlannerResult<Vec<T>>
 where
     F: Fn(&LogicalPlan, &A) -> PlannerResult<T>,
     A: SchemaAccessor + Clone,
 {
-    let context_provider = PoSqlContextProvider::new(schemas.clone());
-    // 1. Parse the SQL query into AST using sqlparser
-    statements
-        .iter()
-        .map(|ast| -> PlannerResult<T> {
-            // 2. Convert the AST into a `LogicalPlan` using `SqlToRel`
-            let raw_logical_plan = SqlToRel::new_with_options(
-                &context_provider,
-                ParserOptions {
-                    parse_float_as_decimal: config.sql_parser.parse_float_as_decimal,
-                    enable_ident_normalization: config.sql_parser.enable_ident_normalization,
-                },
-            )
-            .sql_statement_to_plan(ast.clone())?;
-            // 3. Analyze the `LogicalPlan` using `Analyzer`
-            let analyzer = Analyzer::new();
-            let analyzed_logical_plan =
-                analyzer.execute_and_check(raw_logical_plan, config, |_, _| {})?;
-            // 4. Optimize the `LogicalPlan` using `Optimizer`
-            let optimizer = optimizer();
-            let optimizer_context = OptimizerContext::default();
-            let optimized_logical_plan =
-                optimizer.optimize(analyzed_logical_plan, &optimizer_context, |_, _| {})?;
-            // 5. Convert the optimized `LogicalPlan` into a Proof of SQL plan
-            planner_converter(&optimized_logical_plan, schemas)
-        })
+    sql_to_posql_plan_results(statements, schemas, config, rules, planner_converter)
         .collect::<PlannerResult<Vec<_>>>()
 }
 
 /// Convert a SQL query to a `DynProofPlan` using schema from provided tables
 ///
-/// See `sql_to_posql_plans` for more details
+/// See `sql_to_posql_plan_results` for more details
 pub fn sql_to_proof_plans<A: SchemaAccessor + Clone>(
     statements: &[Statement],
     schemas: &A,
     config: &ConfigOptions,
 ) -> PlannerResult<Vec<DynProofPlan>> {
-    sql_to_posql_plans(statements, schemas, config, logical_plan_to_proof_plan)
+    sql_to_proof_plans_with_rules(statements, schemas, config, &PlannerRules::default())
+}
+
+/// Convert a SQL query to a `DynProofPlan` using schema from provided tables
+/// and an explicit set of analyzer and optimizer rules
+///
+/// See `sql_to_posql_plan_results` for more details
+pub fn sql_to_proof_plans_with_rules<A: SchemaAccessor + Clone>(
+    statements: &[Statement],
+    schemas: &A,
+    config: &ConfigOptions,
+    rules: &PlannerRules,
+) -> PlannerResult<Vec<DynProofPlan>> {
+    sql_to_posql_plans(
+        statements,
+        schemas,
+        config,
+        rules,
+        logical_plan_to_proof_plan,
+    )
+}
+
+/// Convert each of a batch of SQL queries to a `DynProofPlan` using schema from provided tables
+///
+/// Unlike [`sql_to_proof_plans`] a statement which fails to plan does not fail the whole batch.
+/// The result at each index belongs to the statement at the same index.
+pub fn sql_to_proof_plans_per_statement<A: SchemaAccessor + Clone>(
+    statements: &[Statement],
+    schemas: &A,
+    config: &ConfigOptions,
+) -> Vec<PlannerResult<DynProofPlan>> {
+    sql_to_posql_plan_results(
+        statements,
+        schemas,
+        config,
+        &PlannerRules::default(),
+        logical_plan_to_proof_plan,
+    )
+    .collect()
+}
+
+/// Convert a batch of SQL queries to `DynProofPlan`s using schema from provided tables,
+/// looking up the schema of each distinct table only once for the whole batch
+///
+/// This produces the same plans as [`sql_to_proof_plans`] and is preferable when
+/// many statements reference the same tables or when schema lookups are expensive.
+pub fn sql_to_proof_plans_with_cache<A: SchemaAccessor>(
+    statements: &[Statement],
+    schemas: &A,
+    config: &ConfigOptions,
+) -> PlannerResult<Vec<DynProofPlan>> {
+    sql_to_proof_plans(statements, &CachingSchemaAccessor::new(schemas), config)
+}
+
+/// Whether a SQL statement can be converted to a Proof of SQL plan, as reported by [`analyze_sql`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnalysisResult {
+    /// The statement is provable with the given plan
+    Supported(DynProofPlan),
+    /// The statement is not provable
+    Unsupported {
+        /// Why the statement can not be planned
+        reason: String,
+        /// The kind of `LogicalPlan` or `Expr` node which could not be planned,
+        /// or `statement` if the statement itself could not be planned
+        node: String,
+    },
+}
+
+impl From<PlannerResult<DynProofPlan>> for AnalysisResult {
+    fn from(result: PlannerResult<DynProofPlan>) -> Self {
+        match result {
+            Ok(plan) => Self::Supported(plan),
+            Err(err) => Self::Unsupported {
+                node: unsupported_node(&err),
+                reason: err.to_string(),
+            },
+        }
+    }
+}
+
+/// The kind of node which caused `err`
+fn unsupported_node(err: &PlannerError) -> String {
+    match err {
+        PlannerError::UnsupportedLogicalPlan { node } => node.to_string(),
+        PlannerError::UnsupportedJoinPlan { .. } => "join".to_string(),
+        PlannerError::UnsupportedWindowPlan { .. } => "window".to_string(),
+        PlannerError::UnsupportedAggregatePlan { .. } => "aggregate".to_string(),
+        PlannerError::UnsupportedLogicalExpression { expr } => expr.variant_name().to_string(),
+        PlannerError::UnsupportedBinaryOperator { .. } => "BinaryExpr".to_string(),
+        PlannerError::UnsupportedAggregateOperation { .. }
+        | PlannerError::UnsupportedAggregateFunction { .. } => "AggregateFunction".to_string(),
+        PlannerError::UntypedPlaceholder { .. } | PlannerError::InvalidPlaceholderId { .. } => {
+            "Placeholder".to_string()
+        }
+        _ => "statement".to_string(),
+    }
+}
+
+/// Report for each of a batch of SQL queries whether it can be converted to a `DynProofPlan`
+/// and, if not, why
+///
+/// No proofs are generated. The result at each index belongs to the statement at the same index.
+/// See [`sql_to_proof_plans_per_statement`] for more details.
+pub fn analyze_sql<A: SchemaAccessor + Clone>(
+    statements: &[Statement],
+    schemas: &A,
+    config: &ConfigOptions,
+) -> Vec<AnalysisResult> {
+    sql_to_proof_plans_per_statement(statements, schemas, config)
+        .into_iter()
+        .map(AnalysisResult::from)
+        .collect()
 }
 
 /// Given a `Statement` retrieves all unique tables in the query
-pub fn get_table_refs_from_statement(
+///
+/// Table names are normalized as `DataFusion` does by default.
+/// References to common table expressions are skipped since they are not tables.
+pub fn get_table_refs_from_statement(statement: &Statement) -> PlannerResult<IndexSet<TableRef>> {
+    table_refs_from_statement(statement, true)
+}
+
+/// Retrieves all unique tables in `statement`, normalizing their names if
+/// `enable_ident_normalization` is set and skipping references to common table expressions
+pub(crate) fn table_refs_from_statement(
     statement: &Statement,
-) -> Result<IndexSet<TableRef>, ParseError> {
-    let mut table_refs: IndexSet<TableRef> = IndexSet::<TableRef>::new();
-    visit_relations(statement, |object_name| {
-        match object_name.to_string().as_str().try_into() {
-            Ok(table_ref) => {
-                table_refs.insert(table_ref);
-                ControlFlow::Continue(())
-            }
-            e => ControlFlow::Break(e),
-        }
-    })
-    .break_value()
-    .transpose()?;
-    Ok(table_refs)
+    enable_ident_normalization: bool,
+) -> PlannerResult<IndexSet<TableRef>> {
+    let mut cte_names = CteNameVisitor(IndexSet::new());
+    let _ = statement.visit(&mut cte_names);
+    let mut relations = IndexSet::new();
+    let _ = visit_relations(statement, |object_name| {
+        relations.insert(object_name.clone());
+        ControlFlow::<()>::Continue(())
+    });
+    relations
+        .difference(&cte_names.0)
+        .map(|relation| {
+            let table_reference =
+                object_name_to_table_reference(relation.clone(), enable_ident_normalization)?;
+            table_reference_to_table_ref(&table_reference)
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::get_table_refs_from_statement;
+    use super::{get_table_refs_from_statement, is_unresolved_column_error};
     use crate::{
-        conversion::sql_to_posql_plans, sql_to_proof_plans, AggregatePlanError,
-        LogicalPlanNodeKind, PlannerError, PlannerResult,
+        analyze_sql,
+        conversion::{optimizer, sql_to_posql_plans},
+        sql_to_proof_plans, sql_to_proof_plans_per_statement, sql_to_proof_plans_with_cache,
+        sql_to_proof_plans_with_rules, AggregatePlanError, AnalysisResult, LogicalPlanNodeKind,
+        PlannerError, PlannerResult, PlannerRules, WindowPlanError,
     };
     use ahash::AHasher;
-    use datafusion::{config::ConfigOptions, logical_expr::LogicalPlan};
+    use ark_std::test_rng;
+    use datafusion::{
+        common::{Column, SchemaError},
+        config::ConfigOptions,
+        error::DataFusionError,
+        logical_expr::LogicalPlan,
+        optimizer::Analyzer,
+    };
     use indexmap::{indexmap_with_default, IndexSet};
     use proof_of_sql::{
         base::database::{
-            ColumnType, SchemaAccessor, SchemaAccessorImpl, TableRef, TableTestAccessor,
+            owned_table_utility::*, ColumnField, ColumnType, OwnedTableTestAccessor,
+            SchemaAccessor, SchemaAccessorImpl, TableRef, TableTestAccessor, TestAccessor,
+        },
+        proof_primitive::dory::{DynamicDoryEvaluationProof, ProverSetup, PublicParameters},
+        sql::{
+            proof::ProofPlan,
+            proof_plans::{AggregateExecError, DynProofPlan},
         },
-        proof_primitive::dory::DynamicDoryEvaluationProof,
-        sql::proof_plans::{AggregateExecError, DynProofPlan},
     };
-    use sqlparser::{dialect::GenericDialect, parser::Parser};
+    use sqlparser::{ast::Ident, dialect::GenericDialect, parser::Parser};
 
     #[expect(non_snake_case)]
     fn SQL_SCHEMAS() -> impl SchemaAccessor + Clone {
@@ -182,6 +465,81 @@ AND s.salary > (
         assert_eq!(table_refs, expected_table_refs);
     }
 
+    #[test]
+    fn we_can_get_normalized_table_references_without_ctes() {
+        let statement = Parser::parse_sql(
+            &GenericDialect {},
+            r#"WITH recent AS (SELECT * FROM Sxt.Orders WHERE id > 10)
+SELECT * FROM recent JOIN sxt."Customers" c ON recent.customer_id = c.id"#,
+        )
+        .unwrap()[0]
+            .clone();
+        let table_refs = get_table_refs_from_statement(&statement).unwrap();
+        let expected_table_refs: IndexSet<TableRef> = [
+            TableRef::new("sxt", "orders"),
+            TableRef::new("sxt", "Customers"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(table_refs, expected_table_refs);
+    }
+
+    #[test]
+    fn we_cannot_plan_statements_referencing_missing_tables() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id FROM test_table WHERE id IN (SELECT id FROM sxt.missing_table);",
+        )
+        .unwrap();
+        let err =
+            sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default()).unwrap_err();
+        assert!(matches!(
+            err,
+            PlannerError::TableNotFound { table_ref } if table_ref == TableRef::new("sxt", "missing_table")
+        ));
+    }
+
+    #[test]
+    fn we_can_only_fail_the_statements_referencing_missing_tables() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id FROM missing_table; SELECT id FROM TEST_TABLE;",
+        )
+        .unwrap();
+        let results = sql_to_proof_plans_per_statement(
+            &statements,
+            &SQL_SCHEMAS(),
+            &ConfigOptions::default(),
+        );
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            &results[0],
+            Err(PlannerError::TableNotFound { table_ref }) if *table_ref == TableRef::new("", "missing_table")
+        ));
+        // Table names are normalized before they are looked up
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn we_do_not_report_missing_columns_as_missing_tables() {
+        let statements =
+            Parser::parse_sql(&GenericDialect {}, "SELECT missing_column FROM test_table;")
+                .unwrap();
+        let err =
+            sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default()).unwrap_err();
+        assert!(matches!(err, PlannerError::DataFusionError { .. }));
+    }
+
+    #[test]
+    fn we_do_not_report_common_table_expressions_as_missing_tables() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "WITH cte AS (SELECT id FROM test_table) SELECT id FROM cte;",
+        )
+        .unwrap();
+        sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default()).unwrap();
+    }
+
     #[test]
     fn we_can_use_abs() {
         let statements = Parser::parse_sql(&GenericDialect {}, "SELECT ABS(-1-1);").unwrap();
@@ -189,6 +547,7 @@ AND s.salary > (
             &statements,
             &TableTestAccessor::<DynamicDoryEvaluationProof>::default(),
             &ConfigOptions::default(),
+            &PlannerRules::default(),
             |a, _| -> PlannerResult<LogicalPlan> { Ok(a.clone()) },
         )
         .unwrap();
@@ -240,7 +599,7 @@ AND s.salary > (
     }
 
     #[test]
-    fn sql_window_reports_unsupported_plan_node() {
+    fn sql_window_reports_unsupported_window_plan() {
         let statements = Parser::parse_sql(
             &GenericDialect {},
             "SELECT ROW_NUMBER() OVER () FROM test_table;",
@@ -252,12 +611,116 @@ AND s.salary > (
 
         assert!(matches!(
             err,
-            PlannerError::UnsupportedLogicalPlan {
-                node: LogicalPlanNodeKind::Window,
+            PlannerError::UnsupportedWindowPlan {
+                source: WindowPlanError::UnsupportedOrderBy { .. },
             }
         ));
     }
 
+    #[test]
+    fn sql_row_number_over_a_single_column_is_converted() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id, ROW_NUMBER() OVER (ORDER BY id DESC) AS row_num FROM test_table;",
+        )
+        .unwrap();
+        let plans =
+            sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default()).unwrap();
+        let DynProofPlan::Projection(projection) = &plans[0] else {
+            panic!(
+                "expected a projection over the row numbers, got {:?}",
+                plans[0]
+            );
+        };
+        let DynProofPlan::RowNumber(row_number) = projection.input() else {
+            panic!("expected a row number plan, got {:?}", projection.input());
+        };
+        assert_eq!(row_number.order_by_index(), 0);
+        assert!(!row_number.ascending());
+        assert_eq!(
+            plans[0]
+                .get_column_result_fields()
+                .iter()
+                .map(ColumnField::name)
+                .collect::<Vec<_>>(),
+            vec![Ident::new("id"), Ident::new("row_num")]
+        );
+    }
+
+    #[test]
+    fn sql_partitioned_or_unordered_row_number_reports_unsupported_window_plan() {
+        for (sql, expected_reason) in [
+            (
+                "SELECT ROW_NUMBER() OVER (PARTITION BY name ORDER BY id) FROM test_table;",
+                "PARTITION BY is not supported",
+            ),
+            (
+                "SELECT ROW_NUMBER() OVER (ORDER BY id, name) FROM test_table;",
+                "window must be ordered by exactly one column",
+            ),
+            (
+                "SELECT ROW_NUMBER() OVER (ORDER BY id + 1) FROM test_table;",
+                "window must be ordered by exactly one column",
+            ),
+            (
+                "SELECT RANK() OVER (ORDER BY id) FROM test_table;",
+                "window function",
+            ),
+        ] {
+            let statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+            let err = sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default())
+                .unwrap_err();
+            assert!(matches!(err, PlannerError::UnsupportedWindowPlan { .. }));
+            assert!(err.to_string().contains(expected_reason), "{err}");
+        }
+    }
+
+    #[test]
+    fn sql_string_length_is_not_supported() {
+        // Varchar columns are committed to as hashes of the strings, so their lengths can not
+        // be proven. The string functions are not registered and planning fails up front.
+        for sql in [
+            "SELECT LENGTH(name) FROM test_table;",
+            "SELECT id FROM test_table WHERE CHARACTER_LENGTH(name) > 3;",
+            "SELECT OCTET_LENGTH(name) FROM test_table;",
+        ] {
+            let statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+            let err = sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default())
+                .unwrap_err();
+            assert!(matches!(err, PlannerError::DataFusionError { .. }));
+        }
+    }
+
+    #[test]
+    fn sql_string_case_functions_are_not_supported() {
+        // The hash of a case-folded string can not be derived from the hash of the string,
+        // so UPPER and LOWER can not be proven and are not registered either.
+        for sql in [
+            "SELECT UPPER(name) FROM test_table;",
+            "SELECT id FROM test_table WHERE LOWER(name) = 'abc';",
+        ] {
+            let statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+            let err = sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default())
+                .unwrap_err();
+            assert!(matches!(err, PlannerError::DataFusionError { .. }));
+        }
+    }
+
+    #[test]
+    fn sql_substring_is_not_supported() {
+        // Varchar columns are committed to as hashes of the strings, so substrings can not be
+        // proven. SUBSTRING is not registered and planning fails up front.
+        for sql in [
+            "SELECT SUBSTRING(name FROM 2 FOR 3) FROM test_table;",
+            "SELECT id FROM test_table WHERE SUBSTRING(name FROM 1 FOR 2) = 'ab';",
+        ] {
+            let statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+            let err = sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default())
+                .unwrap_err();
+            assert!(matches!(err, PlannerError::DataFusionError { .. }));
+        }
+    }
+
     #[test]
     fn sql_distinct_on_supported_type_still_converts() {
         let statements =
@@ -268,4 +731,320 @@ AND s.salary > (
 
         assert!(matches!(plans.as_slice(), [DynProofPlan::Projection(_)]));
     }
+
+    #[test]
+    fn we_can_plan_deterministically_with_pinned_rules() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id FROM test_table WHERE id > 5;",
+        )
+        .unwrap();
+        let pinned_rules = || {
+            PlannerRules::new(
+                Analyzer::new().rules,
+                optimizer()
+                    .rules
+                    .into_iter()
+                    .filter(|rule| {
+                        ["optimize_projections", "push_down_filter"].contains(&rule.name())
+                    })
+                    .collect(),
+            )
+        };
+        let rules = pinned_rules();
+        assert_eq!(
+            rules.optimizer_rule_names(),
+            vec!["push_down_filter", "optimize_projections"]
+        );
+
+        let plans = sql_to_proof_plans_with_rules(
+            &statements,
+            &SQL_SCHEMAS(),
+            &ConfigOptions::default(),
+            &rules,
+        )
+        .unwrap();
+        let replanned = sql_to_proof_plans_with_rules(
+            &statements,
+            &SQL_SCHEMAS(),
+            &ConfigOptions::default(),
+            &pinned_rules(),
+        )
+        .unwrap();
+        assert_eq!(plans, replanned);
+        assert!(matches!(plans.as_slice(), [DynProofPlan::Filter(_)]));
+    }
+
+    #[test]
+    fn default_rules_match_the_default_planner() {
+        let rules = PlannerRules::default();
+        assert_eq!(
+            rules.analyzer_rule_names(),
+            Analyzer::new()
+                .rules
+                .iter()
+                .map(|rule| rule.name())
+                .collect::<Vec<_>>()
+        );
+        assert!(!rules
+            .optimizer_rule_names()
+            .contains(&"common_sub_expression_eliminate"));
+
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id FROM test_table WHERE id > 5;",
+        )
+        .unwrap();
+        assert_eq!(
+            sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default()).unwrap(),
+            sql_to_proof_plans_with_rules(
+                &statements,
+                &SQL_SCHEMAS(),
+                &ConfigOptions::default(),
+                &rules
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn we_can_plan_a_batch_of_statements_with_a_schema_cache() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id FROM test_table WHERE id > 5;
+            SELECT name, payload FROM test_table;
+            SELECT id, name FROM test_table WHERE name = 'a' LIMIT 3;
+            SELECT count(*) FROM test_table;",
+        )
+        .unwrap();
+        let cached_plans =
+            sql_to_proof_plans_with_cache(&statements, &SQL_SCHEMAS(), &ConfigOptions::default())
+                .unwrap();
+        let plans =
+            sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default()).unwrap();
+        assert_eq!(cached_plans.len(), 4);
+        assert_eq!(cached_plans, plans);
+    }
+
+    #[test]
+    fn we_can_drop_filters_decided_by_column_types() {
+        let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+        let prover_setup = ProverSetup::from(&public_parameters);
+        let table_ref = TableRef::new("sxt", "t");
+        let mut accessor =
+            OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(
+                &prover_setup,
+            );
+        accessor.add_table(
+            table_ref,
+            owned_table([bigint("a", [1_i64, 2, 3]), uint8("c", [0_u8, 1, 2])]),
+            0,
+        );
+
+        // An unsigned column makes both predicates constant
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT a, c FROM sxt.t WHERE c >= 0; SELECT a, c FROM sxt.t WHERE c < 0;",
+        )
+        .unwrap();
+        let plans = sql_to_proof_plans(&statements, &accessor, &ConfigOptions::default()).unwrap();
+        let DynProofPlan::Slice(empty) = &plans[1] else {
+            panic!("Expected a slice, got {:?}", plans[1]);
+        };
+        assert!(matches!(plans[0], DynProofPlan::Table(_)));
+        assert_eq!(empty.input(), &plans[0]);
+        assert_eq!((empty.skip(), empty.fetch()), (0, Some(0)));
+
+        // Values of a signed column may be negative, so its predicates have to be proven
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT a, c FROM sxt.t WHERE a >= 0; SELECT a, c FROM sxt.t WHERE a < 0;",
+        )
+        .unwrap();
+        let plans = sql_to_proof_plans(&statements, &accessor, &ConfigOptions::default()).unwrap();
+        assert!(matches!(
+            plans.as_slice(),
+            [DynProofPlan::Filter(_), DynProofPlan::Filter(_)]
+        ));
+    }
+
+    #[test]
+    fn we_can_plan_a_filter_referencing_a_projection_alias() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id + 1 AS next_id FROM test_table WHERE next_id > 10;",
+        )
+        .unwrap();
+        let resolved_statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id + 1 AS next_id FROM test_table WHERE (id + 1) > 10;",
+        )
+        .unwrap();
+        let plans =
+            sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default()).unwrap();
+        assert_eq!(
+            plans,
+            sql_to_proof_plans(
+                &resolved_statements,
+                &SQL_SCHEMAS(),
+                &ConfigOptions::default()
+            )
+            .unwrap()
+        );
+        assert!(matches!(plans.as_slice(), [DynProofPlan::Filter(_)]));
+    }
+
+    #[test]
+    fn we_prefer_input_columns_over_projection_aliases_in_filters() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id + 1 AS id FROM test_table WHERE id > 10;",
+        )
+        .unwrap();
+        let expected_statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id + 1 AS id FROM test_table WHERE test_table.id > 10;",
+        )
+        .unwrap();
+        assert_eq!(
+            sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default()).unwrap(),
+            sql_to_proof_plans(
+                &expected_statements,
+                &SQL_SCHEMAS(),
+                &ConfigOptions::default()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn we_only_retry_planning_with_resolved_aliases_for_unresolved_columns() {
+        let field_not_found = || {
+            DataFusionError::SchemaError(
+                SchemaError::FieldNotFound {
+                    field: Box::new(Column::from_name("next_id")),
+                    valid_fields: vec![],
+                },
+                Box::new(None),
+            )
+        };
+        assert!(is_unresolved_column_error(&field_not_found()));
+        assert!(is_unresolved_column_error(&DataFusionError::Context(
+            "planning filter".to_string(),
+            Box::new(field_not_found()),
+        )));
+        assert!(!is_unresolved_column_error(&DataFusionError::Plan(
+            "Invalid function 'next_id'".to_string()
+        )));
+        assert!(!is_unresolved_column_error(&DataFusionError::SchemaError(
+            SchemaError::AmbiguousReference {
+                field: Column::from_name("next_id"),
+            },
+            Box::new(None),
+        )));
+    }
+
+    #[test]
+    fn we_can_plan_each_statement_of_a_batch_independently() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id FROM test_table WHERE id > 5;
+            PREPARE my_plan(BIGINT) AS SELECT id FROM test_table WHERE id = $1;
+            SELECT name FROM test_table;",
+        )
+        .unwrap();
+        let results = sql_to_proof_plans_per_statement(
+            &statements,
+            &SQL_SCHEMAS(),
+            &ConfigOptions::default(),
+        );
+        assert_eq!(results.len(), 3);
+        let expected_plans = sql_to_proof_plans(
+            &[statements[0].clone(), statements[2].clone()],
+            &SQL_SCHEMAS(),
+            &ConfigOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(results[0].as_ref().unwrap(), &expected_plans[0]);
+        assert!(matches!(
+            results[1],
+            Err(PlannerError::UnsupportedLogicalPlan {
+                node: LogicalPlanNodeKind::Prepare
+            })
+        ));
+        assert_eq!(results[2].as_ref().unwrap(), &expected_plans[1]);
+
+        // The batch planner fails as a whole
+        assert!(matches!(
+            sql_to_proof_plans(&statements, &SQL_SCHEMAS(), &ConfigOptions::default()),
+            Err(PlannerError::UnsupportedLogicalPlan {
+                node: LogicalPlanNodeKind::Prepare
+            })
+        ));
+    }
+
+    #[test]
+    fn we_can_analyze_which_statements_are_provable() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT id FROM test_table WHERE id > 5;
+            SELECT id, ROW_NUMBER() OVER (PARTITION BY name ORDER BY id) AS row_num FROM test_table;
+            SELECT a.id FROM test_table a LEFT JOIN test_table b ON a.id = b.id;",
+        )
+        .unwrap();
+        let results = analyze_sql(&statements, &SQL_SCHEMAS(), &ConfigOptions::default());
+        assert_eq!(results.len(), 3);
+        let expected_plan =
+            sql_to_proof_plans(&statements[..1], &SQL_SCHEMAS(), &ConfigOptions::default())
+                .unwrap()
+                .remove(0);
+        assert_eq!(results[0], AnalysisResult::Supported(expected_plan));
+        assert_eq!(
+            results[1],
+            AnalysisResult::Unsupported {
+                reason: "Window logical plan is not supported: PARTITION BY is not supported"
+                    .to_string(),
+                node: "window".to_string(),
+            }
+        );
+        assert_eq!(
+            results[2],
+            AnalysisResult::Unsupported {
+                reason: "Join logical plan is not supported: join type Left is not supported"
+                    .to_string(),
+                node: "join".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn unsupported_statements_report_which_feature_is_missing() {
+        let statements = Parser::parse_sql(
+            &GenericDialect {},
+            "SELECT a.id FROM test_table a RIGHT JOIN test_table b ON a.id = b.id;
+            SELECT id, RANK() OVER (ORDER BY id) FROM test_table;
+            SELECT COUNT(*) FROM test_table GROUP BY CASE WHEN id > 1 THEN 1 ELSE 0 END;",
+        )
+        .unwrap();
+        let results = analyze_sql(&statements, &SQL_SCHEMAS(), &ConfigOptions::default());
+        assert_eq!(
+            results[0],
+            AnalysisResult::Unsupported {
+                reason: "Join logical plan is not supported: join type Right is not supported"
+                    .to_string(),
+                node: "join".to_string(),
+            }
+        );
+        let AnalysisResult::Unsupported { reason, node } = &results[1] else {
+            panic!("RANK should not be supported");
+        };
+        assert_eq!(node, "window");
+        assert!(reason.starts_with("Window logical plan is not supported: window function"));
+        assert!(reason.to_lowercase().contains("rank"));
+        let AnalysisResult::Unsupported { reason, node } = &results[2] else {
+            panic!("grouping by CASE should not be supported");
+        };
+        assert_eq!(node, "Case");
+        assert!(reason.starts_with("Logical expression Case"));
+    }
 }
@@ -9,7 +9,7 @@ use datafusion::{
 };
 use derive_more::Display;
 use proof_of_sql::{
-    base::math::decimal::DecimalError,
+    base::{database::TableRef, math::decimal::DecimalError, PlaceholderError},
     sql::{proof_plans::AggregateExecError, AnalyzeError},
 };
 use snafu::Snafu;
@@ -49,7 +49,7 @@ pub enum AggregatePlanError {
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq, Snafu)]
 pub enum JoinPlanError {
-    /// Only inner joins can be converted to the current proof plan.
+    /// Only inner and left semi joins can be converted to the current proof plan.
     #[snafu(display("join type {join_type} is not supported"))]
     UnsupportedJoinType {
         /// Unsupported join type.
@@ -61,9 +61,9 @@ pub enum JoinPlanError {
         /// Unsupported join constraint.
         constraint: JoinConstraint,
     },
-    /// The join predicate is not a supported pair of matching column references.
+    /// The join predicate is not an equality between a left and a right input column.
     #[snafu(display(
-        "join predicate must pair supported columns with the same unqualified name, found {left} and {right}"
+        "join predicate must compare a left input column with a right input column, found {left} and {right}"
     ))]
     UnsupportedPredicate {
         /// Left-hand join expression.
@@ -71,6 +71,45 @@ pub enum JoinPlanError {
         /// Right-hand join expression.
         right: String,
     },
+    /// The join carries a non-equi predicate in its `filter`, e.g. an inequality condition.
+    #[snafu(display("non-equi join predicate {predicate} is not supported"))]
+    UnsupportedFilterPredicate {
+        /// Offending join filter expression.
+        predicate: String,
+    },
+    /// Semi joins can only be converted to the current proof plan with a single key.
+    #[snafu(display("semi join must have exactly one key, found {count}"))]
+    UnsupportedSemiJoinKeyCount {
+        /// Number of join keys.
+        count: usize,
+    },
+}
+
+/// Errors encountered while converting a window logical plan.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq, Snafu)]
+pub enum WindowPlanError {
+    /// Only a single window expression can be converted to the current proof plan.
+    #[snafu(display("exactly one window expression is supported, found {count}"))]
+    UnsupportedWindowExpressionCount {
+        /// Number of window expressions.
+        count: usize,
+    },
+    /// Only `ROW_NUMBER` can be converted to the current proof plan.
+    #[snafu(display("window function {function} is not supported"))]
+    UnsupportedWindowFunction {
+        /// Unsupported window function.
+        function: String,
+    },
+    /// Partitioned windows can not be converted to the current proof plan.
+    #[snafu(display("PARTITION BY is not supported"))]
+    UnsupportedPartitionBy,
+    /// The window is not ordered by exactly one column.
+    #[snafu(display("window must be ordered by exactly one column, found [{order_by}]"))]
+    UnsupportedOrderBy {
+        /// Ordering expressions of the window.
+        order_by: String,
+    },
 }
 
 /// Kind of `DataFusion` logical plan node presented to the Proof of SQL converter.
@@ -164,14 +203,26 @@ pub enum PlannerError {
         /// Underlying datafusion error
         source: DataFusionError,
     },
+    /// Returned when binding params to placeholders fails
+    #[snafu(transparent)]
+    PlaceholderError {
+        /// Underlying placeholder error
+        source: PlaceholderError,
+    },
+    /// Returned when the same placeholder is used with different types
+    #[snafu(display("Placeholder with index {index} is used with inconsistent types"))]
+    InconsistentPlaceholderType {
+        /// The zero-based index of the placeholder
+        index: usize,
+    },
     /// Returned if a column is not found
     #[snafu(display("Column not found"))]
     ColumnNotFound,
     /// Returned if a table is not found
-    #[snafu(display("Table not found: {}", table_name))]
+    #[snafu(display("Table not found: {table_ref}"))]
     TableNotFound {
-        /// Table name
-        table_name: String,
+        /// The missing table
+        table_ref: TableRef,
     },
     /// Returned when a placeholder id is invalid
     #[snafu(display("Placeholder id {id:?} is invalid"))]
@@ -233,12 +284,29 @@ pub enum PlannerError {
         /// Specific join conversion error.
         source: JoinPlanError,
     },
+    /// Returned when a window logical plan cannot be converted.
+    #[snafu(
+        context(false),
+        display("Window logical plan is not supported: {source}")
+    )]
+    UnsupportedWindowPlan {
+        /// Specific window conversion error.
+        source: WindowPlanError,
+    },
     /// Returned when a `LogicalPlan` node or shape is not supported.
     #[snafu(display("Logical plan node or shape {node} is not supported"))]
     UnsupportedLogicalPlan {
         /// Kind of node whose particular shape was unsupported.
         node: LogicalPlanNodeKind,
     },
+    /// Returned when the `LogicalPlan` is nested deeper than the maximum plan depth
+    #[snafu(display(
+        "LogicalPlan is nested {depth} levels deep, which exceeds the maximum plan depth"
+    ))]
+    PlanTooDeep {
+        /// Nesting depth of the plan.
+        depth: usize,
+    },
     /// Returned when the `LogicalPlan` is not resolved
     #[snafu(display("LogicalPlan is not resolved"))]
     UnresolvedLogicalPlan,
@@ -279,7 +347,28 @@ mod tests {
                 right: "right.b".to_string(),
             }
             .to_string(),
-            "join predicate must pair supported columns with the same unqualified name, found left.a and right.b"
+            "join predicate must compare a left input column with a right input column, found left.a and right.b"
+        );
+        assert_eq!(
+            JoinPlanError::UnsupportedFilterPredicate {
+                predicate: "left.a < right.b".to_string(),
+            }
+            .to_string(),
+            "non-equi join predicate left.a < right.b is not supported"
+        );
+        assert_eq!(
+            PlannerError::from(WindowPlanError::UnsupportedWindowFunction {
+                function: "RANK".to_string(),
+            })
+            .to_string(),
+            "Window logical plan is not supported: window function RANK is not supported"
+        );
+        assert_eq!(
+            WindowPlanError::UnsupportedOrderBy {
+                order_by: "id ASC NULLS LAST, name ASC NULLS LAST".to_string(),
+            }
+            .to_string(),
+            "window must be ordered by exactly one column, found [id ASC NULLS LAST, name ASC NULLS LAST]"
         );
     }
 
@@ -2,16 +2,23 @@ use super::{
     column_to_column_ref, placeholder_to_placeholder_expr, scalar_value_to_literal_value,
     PlannerError, PlannerResult,
 };
-use datafusion::logical_expr::{
-    expr::{Alias, Between, Cast, InList, Placeholder},
-    BinaryExpr, Expr, Operator,
+use arrow::datatypes::DataType;
+use datafusion::{
+    common::ScalarValue,
+    logical_expr::{
+        expr::{Alias, Between, Cast, InList, Like, Placeholder, ScalarFunction, TryCast},
+        BinaryExpr, Expr, Operator,
+    },
 };
 use indexmap::IndexSet;
 use proof_of_sql::{
-    base::database::{ColumnType, LiteralValue},
+    base::{
+        database::{ColumnType, LiteralValue},
+        math::i256::I256,
+    },
     sql::{
         proof_exprs::{DynProofExpr, ProofExpr},
-        scale_cast_binary_op,
+        scale_cast_binary_op, AnalyzeError, AnalyzeResult,
     },
 };
 use sqlparser::ast::Ident;
@@ -29,7 +36,12 @@ pub(crate) fn get_column_idents_from_expr(expr: &Expr) -> IndexSet<Ident> {
             left_idents.extend(get_column_idents_from_expr(right));
             left_idents
         }
-        Expr::Not(inner) => get_column_idents_from_expr(inner),
+        Expr::Not(inner)
+        | Expr::Negative(inner)
+        | Expr::IsTrue(inner)
+        | Expr::IsFalse(inner)
+        | Expr::IsNotTrue(inner)
+        | Expr::IsNotFalse(inner) => get_column_idents_from_expr(inner),
         Expr::InList(InList { expr, list, .. }) => {
             let mut idents = get_column_idents_from_expr(expr);
             for value in list {
@@ -37,14 +49,17 @@ pub(crate) fn get_column_idents_from_expr(expr: &Expr) -> IndexSet<Ident> {
             }
             idents
         }
-        Expr::Alias(Alias { expr, .. }) | Expr::Cast(Cast { expr, .. }) => {
-            get_column_idents_from_expr(expr)
-        }
+        Expr::Alias(Alias { expr, .. })
+        | Expr::Cast(Cast { expr, .. })
+        | Expr::TryCast(TryCast { expr, .. }) => get_column_idents_from_expr(expr),
         Expr::AggregateFunction(agg) => agg
             .args
             .iter()
             .flat_map(get_column_idents_from_expr)
             .collect(),
+        Expr::ScalarFunction(ScalarFunction { args, .. }) => {
+            args.iter().flat_map(get_column_idents_from_expr).collect()
+        }
         Expr::Between(Between {
             expr, low, high, ..
         }) => {
@@ -57,6 +72,96 @@ pub(crate) fn get_column_idents_from_expr(expr: &Expr) -> IndexSet<Ident> {
     }
 }
 
+/// Returns the zero of a numeric [`ColumnType`]
+///
+/// Non-numeric types have no zero, so `BigInt` is used and the operation consuming it fails
+/// with a type error instead.
+fn zero_literal(column_type: ColumnType) -> LiteralValue {
+    match column_type {
+        ColumnType::Uint8 => LiteralValue::Uint8(0),
+        ColumnType::TinyInt => LiteralValue::TinyInt(0),
+        ColumnType::SmallInt => LiteralValue::SmallInt(0),
+        ColumnType::Int => LiteralValue::Int(0),
+        ColumnType::Int128 => LiteralValue::Int128(0),
+        ColumnType::Decimal75(precision, scale) => {
+            LiteralValue::Decimal75(precision, scale, I256::from(0_i32))
+        }
+        ColumnType::Scalar => LiteralValue::Scalar([0; 4]),
+        _ => LiteralValue::BigInt(0),
+    }
+}
+
+/// Returns whether `TRY_CAST(expr AS data_type)` is NULL because `expr` is a string literal
+/// which can not be converted to `data_type`
+fn is_failed_try_cast(expr: &Expr, data_type: &DataType) -> bool {
+    matches!(
+        expr,
+        Expr::Literal(value @ (ScalarValue::Utf8(Some(_)) | ScalarValue::LargeUtf8(Some(_))))
+            if data_type.is_numeric() && value.cast_to(data_type).is_err()
+    )
+}
+
+/// Returns whether `expr` is NULL for every row
+///
+/// NULL propagates through arithmetic, comparisons, `NOT` and casts, e.g. `a = NULL` and
+/// `NOT (a + NULL < 1)` are always NULL. `NULL AND x` and `NULL OR x` depend on `x`,
+/// so they are only NULL if both sides are. `TRY_CAST('abc' AS INT)` is NULL as well.
+fn is_null_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(value) => value.is_null(),
+        Expr::TryCast(TryCast { expr, data_type }) => {
+            is_failed_try_cast(expr, data_type) || is_null_expr(expr)
+        }
+        Expr::Alias(Alias { expr, .. })
+        | Expr::Cast(Cast { expr, .. })
+        | Expr::Not(expr)
+        | Expr::Negative(expr) => is_null_expr(expr),
+        Expr::BinaryExpr(BinaryExpr { left, right, op }) => match op {
+            Operator::And | Operator::Or => is_null_expr(left) && is_null_expr(right),
+            Operator::IsDistinctFrom | Operator::IsNotDistinctFrom => false,
+            _ => is_null_expr(left) || is_null_expr(right),
+        },
+        _ => false,
+    }
+}
+
+/// Returns whether `expr` contains a NULL literal that is not hidden by a boolean test
+/// such as `IS NULL`
+fn contains_null_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(value) => value.is_null(),
+        Expr::TryCast(TryCast { expr, data_type }) => {
+            is_failed_try_cast(expr, data_type) || contains_null_literal(expr)
+        }
+        Expr::Alias(Alias { expr, .. })
+        | Expr::Cast(Cast { expr, .. })
+        | Expr::Not(expr)
+        | Expr::Negative(expr) => contains_null_literal(expr),
+        Expr::BinaryExpr(BinaryExpr { left, right, .. }) => {
+            contains_null_literal(left) || contains_null_literal(right)
+        }
+        _ => false,
+    }
+}
+
+/// Convert a `DataFusion` [`Expr`] used as a predicate into a provable [`DynProofExpr`]
+///
+/// Under three-valued logic a predicate which is NULL, i.e. UNKNOWN, never holds,
+/// so it is lowered to `false`. Comparisons with NULL below `AND` and `OR` are lowered the same way,
+/// and `NOT` is pushed down to them first. This keeps exactly the rows for which the predicate is TRUE.
+///
+/// # Errors
+/// Returns a [`PlannerError`] if the expression can not be converted by [`expr_to_proof_expr`].
+pub(crate) fn predicate_to_proof_expr(
+    expr: &Expr,
+    schema: &[(Ident, ColumnType)],
+) -> PlannerResult<DynProofExpr> {
+    if is_null_expr(expr) {
+        return Ok(DynProofExpr::new_literal(LiteralValue::Boolean(false)));
+    }
+    expr_to_proof_expr(expr, schema)
+}
+
 /// Convert a [`BinaryExpr`] to [`DynProofExpr`]
 fn binary_expr_to_proof_expr(
     left: &Expr,
@@ -64,6 +169,27 @@ fn binary_expr_to_proof_expr(
     op: Operator,
     schema: &[(Ident, ColumnType)],
 ) -> PlannerResult<DynProofExpr> {
+    match op {
+        // A comparison with NULL is UNKNOWN, which never holds
+        Operator::Eq
+        | Operator::NotEq
+        | Operator::Lt
+        | Operator::Gt
+        | Operator::LtEq
+        | Operator::GtEq
+            if is_null_expr(left) || is_null_expr(right) =>
+        {
+            return Ok(DynProofExpr::new_literal(LiteralValue::Boolean(false)));
+        }
+        Operator::And | Operator::Or => {
+            return binary_proof_exprs_to_proof_expr(
+                predicate_to_proof_expr(left, schema)?,
+                predicate_to_proof_expr(right, schema)?,
+                op,
+            );
+        }
+        _ => {}
+    }
     let left_proof_expr = expr_to_proof_expr(left, schema)?;
     let right_proof_expr = expr_to_proof_expr(right, schema)?;
     binary_proof_exprs_to_proof_expr(left_proof_expr, right_proof_expr, op)
@@ -167,9 +293,88 @@ pub fn expr_to_proof_expr(
         Expr::Literal(val) => Ok(DynProofExpr::new_literal(scalar_value_to_literal_value(
             val.clone(),
         )?)),
-        Expr::Not(expr) => {
+        Expr::Not(inner_expr) => match inner_expr.as_ref() {
+            // NOT UNKNOWN is UNKNOWN
+            _ if is_null_expr(inner_expr) => {
+                Ok(DynProofExpr::new_literal(LiteralValue::Boolean(false)))
+            }
+            // Push the negation down to the comparisons with NULL, which are lowered to `false`
+            Expr::BinaryExpr(BinaryExpr {
+                left,
+                right,
+                op: op @ (Operator::And | Operator::Or),
+            }) if contains_null_literal(inner_expr) => binary_expr_to_proof_expr(
+                &Expr::Not(left.clone()),
+                &Expr::Not(right.clone()),
+                if *op == Operator::And {
+                    Operator::Or
+                } else {
+                    Operator::And
+                },
+                schema,
+            ),
+            Expr::Not(expr) if contains_null_literal(expr) => expr_to_proof_expr(expr, schema),
+            _ => {
+                let proof_expr = expr_to_proof_expr(inner_expr, schema)?;
+                Ok(DynProofExpr::try_new_not(proof_expr)?)
+            }
+        },
+        // `-a` is lowered to `0 - a` with a zero of the same type as `a`.
+        // Like any subtraction the result has one more digit of precision than `a`,
+        // so negating the minimum of a signed integer type such as `i64::MIN` can not overflow.
+        Expr::Negative(expr) => {
             let proof_expr = expr_to_proof_expr(expr, schema)?;
-            Ok(DynProofExpr::try_new_not(proof_expr)?)
+            let zero = DynProofExpr::new_literal(zero_literal(proof_expr.data_type()));
+            Ok(DynProofExpr::try_new_subtract(zero, proof_expr)?)
+        }
+        // Columns are never null, so only expressions involving a NULL literal can be null
+        Expr::IsNull(inner_expr) | Expr::IsNotNull(inner_expr) => {
+            let is_null = is_null_expr(inner_expr);
+            if !is_null {
+                expr_to_proof_expr(inner_expr, schema)?;
+            }
+            Ok(DynProofExpr::new_literal(LiteralValue::Boolean(
+                is_null == matches!(expr, Expr::IsNull(_)),
+            )))
+        }
+        // Columns are never null, so `x IS TRUE` and `x IS NOT FALSE` are `x` itself,
+        // while `x IS FALSE` and `x IS NOT TRUE` are `NOT x`.
+        // NULL is neither true nor false, so only the negative forms hold for it.
+        Expr::IsTrue(inner_expr)
+        | Expr::IsFalse(inner_expr)
+        | Expr::IsNotTrue(inner_expr)
+        | Expr::IsNotFalse(inner_expr) => {
+            if is_null_expr(inner_expr) {
+                return Ok(DynProofExpr::new_literal(LiteralValue::Boolean(matches!(
+                    expr,
+                    Expr::IsNotTrue(_) | Expr::IsNotFalse(_)
+                ))));
+            }
+            if contains_null_literal(inner_expr) {
+                // `x IS TRUE` holds exactly when the predicate `x` does and `x IS FALSE` when
+                // the predicate `NOT x` does. Neither is ever UNKNOWN, so the other forms
+                // are their negations.
+                let (predicate, negated) = match expr {
+                    Expr::IsTrue(_) => (inner_expr.as_ref().clone(), false),
+                    Expr::IsNotTrue(_) => (inner_expr.as_ref().clone(), true),
+                    Expr::IsFalse(_) => (Expr::Not(inner_expr.clone()), false),
+                    _ => (Expr::Not(inner_expr.clone()), true),
+                };
+                let proof_expr = predicate_to_proof_expr(&predicate, schema)?;
+                return if negated {
+                    Ok(DynProofExpr::try_new_not(proof_expr)?)
+                } else {
+                    Ok(proof_expr)
+                };
+            }
+            let proof_expr = expr_to_proof_expr(inner_expr, schema)?;
+            match (expr, proof_expr.data_type()) {
+                (Expr::IsFalse(_) | Expr::IsNotTrue(_), _) => {
+                    Ok(DynProofExpr::try_new_not(proof_expr)?)
+                }
+                (_, ColumnType::Boolean) => Ok(proof_expr),
+                (_, expr_type) => Err(AnalyzeError::InvalidDataType { expr_type }.into()),
+            }
         }
         Expr::InList(InList {
             expr,
@@ -240,6 +445,15 @@ pub fn expr_to_proof_expr(
                         Placeholder::new(placeholder.id.clone(), Some(cast.data_type.clone()));
                     placeholder_to_placeholder_expr(&typed_placeholder)
                 }
+                // handle cases such as `CAST('42' AS BIGINT)` by parsing the string when planning.
+                // Varchar columns are committed to as hashes of the strings, so casting them to
+                // numeric types can not be proven and is rejected below like other invalid casts.
+                Expr::Literal(
+                    value @ (ScalarValue::Utf8(Some(_)) | ScalarValue::LargeUtf8(Some(_))),
+                ) if cast.data_type.is_numeric() => {
+                    let literal = scalar_value_to_literal_value(value.cast_to(&cast.data_type)?)?;
+                    Ok(DynProofExpr::new_literal(literal))
+                }
                 _ => {
                     let from_expr = expr_to_proof_expr(&cast.expr, schema)?;
                     let to_type = cast.data_type.clone().try_into().map_err(|_| {
@@ -256,12 +470,47 @@ pub fn expr_to_proof_expr(
                 }
             }
         }
+        // `TRY_CAST` is NULL where `CAST` fails. The provable casts are lossless and never fail,
+        // so it is lowered like `CAST` unless it is a string literal which can not be converted.
+        Expr::TryCast(TryCast {
+            expr: inner_expr,
+            data_type,
+        }) => {
+            if is_failed_try_cast(inner_expr, data_type) {
+                expr_to_proof_expr(&Expr::Literal(ScalarValue::Null), schema)
+            } else {
+                expr_to_proof_expr(
+                    &Expr::Cast(Cast::new(inner_expr.clone(), data_type.clone())),
+                    schema,
+                )
+            }
+        }
         Expr::Between(Between {
             expr,
             negated,
             low,
             high,
         }) => between_to_proof_expr(expr, *negated, low, high, schema),
+        Expr::ScalarFunction(ScalarFunction { func_def, args }) if func_def.name() == "round" => {
+            round_to_proof_expr(expr, args, schema)
+        }
+        Expr::ScalarFunction(ScalarFunction { func_def, args }) if func_def.name() == "floor" => {
+            floor_or_ceil_to_proof_expr(expr, args, schema, DynProofExpr::try_new_floor)
+        }
+        Expr::ScalarFunction(ScalarFunction { func_def, args }) if func_def.name() == "ceil" => {
+            floor_or_ceil_to_proof_expr(expr, args, schema, DynProofExpr::try_new_ceil)
+        }
+        Expr::ScalarFunction(ScalarFunction { func_def, args }) if func_def.name() == "abs" => {
+            match args.as_slice() {
+                [value] => Ok(DynProofExpr::try_new_abs(expr_to_proof_expr(
+                    value, schema,
+                )?)?),
+                _ => Err(PlannerError::UnsupportedLogicalExpression {
+                    expr: Box::new(expr.clone()),
+                }),
+            }
+        }
+        Expr::Like(like) => like_to_proof_expr(expr, like, schema),
         _ => Err(PlannerError::UnsupportedLogicalExpression {
             expr: Box::new(expr.clone()),
         }),
@@ -292,6 +541,121 @@ fn between_to_proof_expr(
     }
 }
 
+/// Convert a [`Like`] expression to [`DynProofExpr`]
+///
+/// Varchar columns are committed to as hashes of the strings, so only patterns without
+/// wildcards or escapes can be proven. These match exactly the pattern itself and are
+/// lowered to an equality, negated for `NOT LIKE`.
+fn like_to_proof_expr(
+    expr: &Expr,
+    like: &Like,
+    schema: &[(Ident, ColumnType)],
+) -> PlannerResult<DynProofExpr> {
+    let Like {
+        negated,
+        expr: value,
+        pattern,
+        escape_char: None,
+        case_insensitive: false,
+    } = like
+    else {
+        return Err(PlannerError::UnsupportedLogicalExpression {
+            expr: Box::new(expr.clone()),
+        });
+    };
+    let pattern =
+        match pattern.as_ref() {
+            Expr::Literal(
+                ScalarValue::Utf8(Some(pattern)) | ScalarValue::LargeUtf8(Some(pattern)),
+            ) if !pattern.contains(['%', '_', '\\']) => pattern,
+            _ => {
+                return Err(PlannerError::UnsupportedLogicalExpression {
+                    expr: Box::new(expr.clone()),
+                })
+            }
+        };
+    let equals = DynProofExpr::try_new_equals(
+        expr_to_proof_expr(value, schema)?,
+        DynProofExpr::new_literal(LiteralValue::VarChar(pattern.clone())),
+    )?;
+    if *negated {
+        Ok(DynProofExpr::try_new_not(equals)?)
+    } else {
+        Ok(equals)
+    }
+}
+
+/// Convert a `round(value[, scale])` call to [`DynProofExpr`]
+///
+/// `DataFusion` only declares `round` for floats, so the analyzer may wrap the
+/// value in a cast to a float type. That cast is dropped here so that the value
+/// is rounded exactly. Rounding to a scale that is not smaller than the
+/// scale of the value is a no-op.
+///
+/// Like SQL `round`, the resulting expression rounds ties away from zero.
+fn round_to_proof_expr(
+    expr: &Expr,
+    args: &[Expr],
+    schema: &[(Ident, ColumnType)],
+) -> PlannerResult<DynProofExpr> {
+    let unsupported = || PlannerError::UnsupportedLogicalExpression {
+        expr: Box::new(expr.clone()),
+    };
+    let (value, scale) = match args {
+        [value] => (value, 0),
+        [value, Expr::Literal(ScalarValue::Int64(Some(scale)))] => {
+            (value, i8::try_from(*scale).map_err(|_| unsupported())?)
+        }
+        [value, Expr::Literal(ScalarValue::Int32(Some(scale)))] => {
+            (value, i8::try_from(*scale).map_err(|_| unsupported())?)
+        }
+        _ => return Err(unsupported()),
+    };
+    let value_proof_expr = expr_to_proof_expr(strip_float_cast(value), schema)?;
+    let value_type = value_proof_expr.data_type();
+    if value_type.is_numeric() && value_type.scale().is_some_and(|s| s <= scale) {
+        return Ok(value_proof_expr);
+    }
+    Ok(DynProofExpr::try_new_round(value_proof_expr, scale)?)
+}
+
+/// Convert a `floor(value)` or `ceil(value)` call to [`DynProofExpr`]
+///
+/// As with `round`, a cast of the value to a float type is dropped. The value
+/// is rounded to an integral decimal, so integers are left unchanged.
+fn floor_or_ceil_to_proof_expr(
+    expr: &Expr,
+    args: &[Expr],
+    schema: &[(Ident, ColumnType)],
+    try_new: fn(DynProofExpr, i8) -> AnalyzeResult<DynProofExpr>,
+) -> PlannerResult<DynProofExpr> {
+    let [value] = args else {
+        return Err(PlannerError::UnsupportedLogicalExpression {
+            expr: Box::new(expr.clone()),
+        });
+    };
+    let value_proof_expr = expr_to_proof_expr(strip_float_cast(value), schema)?;
+    let value_type = value_proof_expr.data_type();
+    if value_type.is_numeric() && value_type.scale().is_some_and(|s| s <= 0) {
+        return Ok(value_proof_expr);
+    }
+    Ok(try_new(value_proof_expr, 0)?)
+}
+
+/// Returns the operand of a cast to a float type, or `value` if it is not such a cast
+///
+/// `DataFusion` only declares the rounding functions for floats, so the analyzer may
+/// wrap their argument in such a cast. Dropping it lets the value be rounded exactly.
+fn strip_float_cast(value: &Expr) -> &Expr {
+    match value {
+        Expr::Cast(Cast {
+            expr,
+            data_type: DataType::Float64 | DataType::Float32,
+        }) => expr,
+        _ => value,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,11 +665,11 @@ mod tests {
     use datafusion::{
         catalog::TableReference,
         common::{Column, ScalarValue},
-        logical_expr::{expr::Placeholder, lit, Cast},
+        logical_expr::{expr::Placeholder, lit, Cast, TryCast},
     };
     use proof_of_sql::base::{
         database::{ColumnRef, ColumnType, LiteralValue, TableRef},
-        math::decimal::Precision,
+        math::{decimal::Precision, i256::I256},
     };
 
     #[expect(non_snake_case)]
@@ -455,6 +819,44 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn we_convert_a_varchar_not_in_list_to_a_negated_or_chain() {
+        // `sector NOT IN ('A', 'B')` is `NOT (sector = 'A' OR sector = 'B')`
+        let expr =
+            df_column("namespace.table_name", "sector").in_list(vec![lit("A"), lit("B")], true);
+        let schema = vec![("sector".into(), ColumnType::VarChar)];
+        let column = DynProofExpr::new_column(ColumnRef::new(
+            TableRef::from_names(Some("namespace"), "table_name"),
+            "sector".into(),
+            ColumnType::VarChar,
+        ));
+        let expected = DynProofExpr::try_new_not(
+            DynProofExpr::try_new_or(
+                DynProofExpr::try_new_equals(
+                    column.clone(),
+                    DynProofExpr::new_literal(LiteralValue::VarChar("A".to_string())),
+                )
+                .unwrap(),
+                DynProofExpr::try_new_equals(
+                    column,
+                    DynProofExpr::new_literal(LiteralValue::VarChar("B".to_string())),
+                )
+                .unwrap(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(expr_to_proof_expr(&expr, &schema).unwrap(), expected);
+
+        // `sector NOT IN ()` is always true
+        let expr = df_column("namespace.table_name", "sector").in_list(vec![], true);
+        assert_eq!(
+            expr_to_proof_expr(&expr, &schema).unwrap(),
+            DynProofExpr::try_new_not(DynProofExpr::new_literal(LiteralValue::Boolean(false)))
+                .unwrap()
+        );
+    }
+
     // BinaryExpr
     #[test]
     fn we_can_convert_comparison_binary_expr_to_proof_expr() {
@@ -833,6 +1235,174 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_convert_negative_expr_to_proof_expr() {
+        let expr = Expr::Negative(Box::new(df_column("table_name", "column")));
+        let schema = vec![("column".into(), ColumnType::BigInt)];
+        assert_eq!(
+            expr_to_proof_expr(&expr, &schema).unwrap(),
+            DynProofExpr::try_new_subtract(
+                DynProofExpr::new_literal(LiteralValue::BigInt(0)),
+                DynProofExpr::new_column(ColumnRef::new(
+                    TableRef::from_names(None, "table_name"),
+                    "column".into(),
+                    ColumnType::BigInt
+                ))
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn we_can_convert_negative_expr_of_each_numeric_type_to_proof_expr() {
+        // Negation widens the precision by one digit so that e.g. `-i64::MIN` fits
+        let expr = Expr::Negative(Box::new(df_column("table_name", "column")));
+        for (column_type, expected_type) in [
+            (
+                ColumnType::Uint8,
+                ColumnType::Decimal75(Precision::new(4).unwrap(), 0),
+            ),
+            (
+                ColumnType::TinyInt,
+                ColumnType::Decimal75(Precision::new(4).unwrap(), 0),
+            ),
+            (
+                ColumnType::SmallInt,
+                ColumnType::Decimal75(Precision::new(6).unwrap(), 0),
+            ),
+            (
+                ColumnType::Int,
+                ColumnType::Decimal75(Precision::new(11).unwrap(), 0),
+            ),
+            (
+                ColumnType::BigInt,
+                ColumnType::Decimal75(Precision::new(20).unwrap(), 0),
+            ),
+            (
+                ColumnType::Int128,
+                ColumnType::Decimal75(Precision::new(40).unwrap(), 0),
+            ),
+            (
+                ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+                ColumnType::Decimal75(Precision::new(11).unwrap(), 2),
+            ),
+            (
+                ColumnType::Decimal75(Precision::new(75).unwrap(), -3),
+                ColumnType::Decimal75(Precision::new(75).unwrap(), -3),
+            ),
+            (ColumnType::Scalar, ColumnType::Scalar),
+        ] {
+            let schema = vec![("column".into(), column_type)];
+            assert_eq!(
+                expr_to_proof_expr(&expr, &schema).unwrap().data_type(),
+                expected_type
+            );
+        }
+    }
+
+    #[test]
+    fn we_cannot_convert_negative_expr_of_non_numeric_types_to_proof_expr() {
+        let expr = Expr::Negative(Box::new(df_column("table_name", "column")));
+        for column_type in [ColumnType::Boolean, ColumnType::VarChar] {
+            let schema = vec![("column".into(), column_type)];
+            assert!(matches!(
+                expr_to_proof_expr(&expr, &schema),
+                Err(PlannerError::AnalyzeError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn we_can_convert_negated_comparison_to_proof_expr() {
+        let expr = Expr::Not(Box::new(
+            df_column("namespace.table_name", "column").eq(lit(1_i32)),
+        ));
+        let schema = vec![("column".into(), ColumnType::Int)];
+        assert_eq!(
+            expr_to_proof_expr(&expr, &schema).unwrap(),
+            DynProofExpr::try_new_not(
+                DynProofExpr::try_new_equals(
+                    COLUMN_INT(),
+                    DynProofExpr::new_literal(LiteralValue::Int(1))
+                )
+                .unwrap()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn we_can_convert_negated_in_list_to_a_negated_positive_form() {
+        let schema = vec![("column".into(), ColumnType::BigInt)];
+        let values = vec![lit(1_i64), lit(2_i64)];
+        let positive = expr_to_proof_expr(
+            &df_column("namespace.table_name", "column").in_list(values.clone(), false),
+            &schema,
+        )
+        .unwrap();
+        assert_eq!(
+            expr_to_proof_expr(
+                &df_column("namespace.table_name", "column").in_list(values, true),
+                &schema
+            )
+            .unwrap(),
+            DynProofExpr::try_new_not(positive).unwrap()
+        );
+    }
+
+    // Like
+    #[expect(non_snake_case)]
+    fn COLUMN_VARCHAR() -> DynProofExpr {
+        DynProofExpr::new_column(ColumnRef::new(
+            TableRef::from_names(Some("namespace"), "table_name"),
+            "column".into(),
+            ColumnType::VarChar,
+        ))
+    }
+
+    #[test]
+    fn we_can_convert_like_without_wildcards_to_proof_expr() {
+        let schema = vec![("column".into(), ColumnType::VarChar)];
+        let equals = DynProofExpr::try_new_equals(
+            COLUMN_VARCHAR(),
+            DynProofExpr::new_literal(LiteralValue::VarChar("abc".to_string())),
+        )
+        .unwrap();
+        let expr = df_column("namespace.table_name", "column").like(lit("abc"));
+        assert_eq!(expr_to_proof_expr(&expr, &schema).unwrap(), equals);
+        let expr = df_column("namespace.table_name", "column").not_like(lit("abc"));
+        assert_eq!(
+            expr_to_proof_expr(&expr, &schema).unwrap(),
+            DynProofExpr::try_new_not(equals).unwrap()
+        );
+    }
+
+    #[test]
+    fn we_cannot_convert_like_with_wildcards_or_options_to_proof_expr() {
+        let schema = vec![("column".into(), ColumnType::VarChar)];
+        let column = df_column("namespace.table_name", "column");
+        let exprs = [
+            column.clone().like(lit("ab%")),
+            column.clone().not_like(lit("a_c")),
+            column.clone().like(lit("a\\%")),
+            column.clone().ilike(lit("abc")),
+            Expr::Like(Like::new(
+                false,
+                Box::new(column.clone()),
+                Box::new(lit("abc")),
+                Some('#'),
+                false,
+            )),
+            column.clone().like(column),
+        ];
+        for expr in exprs {
+            assert!(matches!(
+                expr_to_proof_expr(&expr, &schema),
+                Err(PlannerError::UnsupportedLogicalExpression { .. })
+            ));
+        }
+    }
+
     // Cast
     #[test]
     fn we_can_convert_cast_expr_to_proof_expr() {
@@ -894,6 +1464,118 @@ mod tests {
         ));
     }
 
+    // TryCast
+    fn try_cast(expr: Expr, data_type: DataType) -> Expr {
+        Expr::TryCast(TryCast::new(Box::new(expr), data_type))
+    }
+
+    #[test]
+    fn we_can_convert_try_cast_expr_to_proof_expr_like_a_cast() {
+        let schema = vec![("column2".into(), ColumnType::BigInt)];
+        let col = df_column("namespace.table_name", "column2");
+        assert_eq!(
+            expr_to_proof_expr(&try_cast(col.clone(), DataType::Decimal128(20, 0)), &schema)
+                .unwrap(),
+            expr_to_proof_expr(
+                &Expr::Cast(Cast::new(Box::new(col), DataType::Decimal128(20, 0))),
+                &schema
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            expr_to_proof_expr(&try_cast(lit("42"), DataType::Int64), &schema).unwrap(),
+            DynProofExpr::new_literal(LiteralValue::BigInt(42))
+        );
+    }
+
+    #[test]
+    fn we_can_convert_predicates_with_failed_try_casts_of_string_literals() {
+        let schema = vec![("column2".into(), ColumnType::BigInt)];
+        let col = df_column("namespace.table_name", "column2");
+        // TRY_CAST('x' AS BIGINT) is NULL, so only the first comparison can hold
+        let expr = col
+            .clone()
+            .eq(try_cast(lit("1"), DataType::Int64))
+            .or(col.eq(try_cast(lit("x"), DataType::Int64)));
+        assert_eq!(
+            predicate_to_proof_expr(&expr, &schema).unwrap(),
+            DynProofExpr::try_new_or(
+                DynProofExpr::try_new_equals(
+                    COLUMN2_BIGINT(),
+                    DynProofExpr::new_literal(LiteralValue::BigInt(1))
+                )
+                .unwrap(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(false))
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            expr_to_proof_expr(&try_cast(lit("x"), DataType::Int64).is_null(), &schema).unwrap(),
+            DynProofExpr::new_literal(LiteralValue::Boolean(true))
+        );
+        // NULL is not supported outside of predicates
+        assert!(expr_to_proof_expr(&try_cast(lit("x"), DataType::Int64), &schema).is_err());
+    }
+
+    #[test]
+    fn we_cannot_convert_try_cast_of_varchar_column_to_proof_expr() {
+        let schema = vec![("column".into(), ColumnType::VarChar)];
+        let expr = try_cast(df_column("namespace.table_name", "column"), DataType::Int64);
+        assert!(expr_to_proof_expr(&expr, &schema).is_err());
+    }
+
+    #[test]
+    fn we_can_convert_cast_of_numeric_string_literal_to_proof_expr() {
+        let expr = Expr::Cast(Cast::new(
+            Box::new(Expr::Literal(ScalarValue::Utf8(Some("-42".to_string())))),
+            DataType::Int64,
+        ));
+        assert_eq!(
+            expr_to_proof_expr(&expr, &Vec::new()).unwrap(),
+            DynProofExpr::new_literal(LiteralValue::BigInt(-42))
+        );
+
+        let expr = Expr::Cast(Cast::new(
+            Box::new(Expr::Literal(ScalarValue::Utf8(Some("12.5".to_string())))),
+            DataType::Decimal128(10, 2),
+        ));
+        assert_eq!(
+            expr_to_proof_expr(&expr, &Vec::new()).unwrap(),
+            DynProofExpr::new_literal(LiteralValue::Decimal75(
+                Precision::new(10).unwrap(),
+                2,
+                I256::from(1250_i128)
+            ))
+        );
+    }
+
+    #[test]
+    fn we_cannot_convert_cast_of_malformed_numeric_string_literal_to_proof_expr() {
+        for value in ["abc", "1.5", "", "99999999999999999999"] {
+            let expr = Expr::Cast(Cast::new(
+                Box::new(Expr::Literal(ScalarValue::Utf8(Some(value.to_string())))),
+                DataType::Int64,
+            ));
+            assert!(matches!(
+                expr_to_proof_expr(&expr, &Vec::new()),
+                Err(PlannerError::DataFusionError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn we_cannot_convert_cast_of_varchar_column_to_numeric_proof_expr() {
+        let expr = Expr::Cast(Cast::new(
+            Box::new(df_column("namespace.table_name", "price")),
+            DataType::Int64,
+        ));
+        let schema = vec![("price".into(), ColumnType::VarChar)];
+        assert!(matches!(
+            expr_to_proof_expr(&expr, &schema),
+            Err(PlannerError::AnalyzeError { .. })
+        ));
+    }
+
     // Placeholder
     #[test]
     fn we_can_convert_placeholder_to_proof_expr() {
@@ -938,6 +1620,450 @@ mod tests {
         ));
     }
 
+    // Abs
+    fn df_abs(args: Vec<Expr>) -> Expr {
+        Expr::ScalarFunction(ScalarFunction::new_udf(
+            datafusion::functions::math::abs(),
+            args,
+        ))
+    }
+
+    #[test]
+    fn we_can_convert_abs_expr_to_proof_expr() {
+        let schema = vec![
+            ("column2".into(), ColumnType::BigInt),
+            (
+                "column3".into(),
+                ColumnType::Decimal75(Precision::new(75).unwrap(), 5),
+            ),
+        ];
+        assert_eq!(
+            expr_to_proof_expr(
+                &df_abs(vec![df_column("namespace.table_name", "column2")]),
+                &schema
+            )
+            .unwrap(),
+            DynProofExpr::try_new_abs(COLUMN2_BIGINT()).unwrap()
+        );
+        assert_eq!(
+            expr_to_proof_expr(
+                &df_abs(vec![df_column("namespace.table_name", "column3")]),
+                &schema
+            )
+            .unwrap(),
+            DynProofExpr::try_new_abs(COLUMN3_DECIMAL_75_5()).unwrap()
+        );
+    }
+
+    #[test]
+    fn we_cannot_convert_abs_expr_with_unsupported_arguments() {
+        let schema = vec![
+            ("column1".into(), ColumnType::Boolean),
+            ("column2".into(), ColumnType::BigInt),
+        ];
+        let column2 = df_column("namespace.table_name", "column2");
+        assert!(matches!(
+            expr_to_proof_expr(&df_abs(vec![column2.clone(), column2]), &schema),
+            Err(PlannerError::UnsupportedLogicalExpression { .. })
+        ));
+        assert!(matches!(
+            expr_to_proof_expr(
+                &df_abs(vec![df_column("namespace.table_name", "column1")]),
+                &schema
+            ),
+            Err(PlannerError::AnalyzeError { .. })
+        ));
+    }
+
+    // Round
+    fn df_round(args: Vec<Expr>) -> Expr {
+        Expr::ScalarFunction(ScalarFunction::new_udf(
+            datafusion::functions::math::round(),
+            args,
+        ))
+    }
+
+    #[test]
+    fn we_can_convert_round_expr_to_proof_expr() {
+        let schema = vec![(
+            "column3".into(),
+            ColumnType::Decimal75(Precision::new(75).unwrap(), 5),
+        )];
+        // The analyzer casts decimal arguments to float which we strip
+        let value = Expr::Cast(Cast::new(
+            Box::new(df_column("namespace.table_name", "column3")),
+            DataType::Float64,
+        ));
+        let expr = df_round(vec![value.clone(), lit(2_i64)]);
+        assert_eq!(
+            expr_to_proof_expr(&expr, &schema).unwrap(),
+            DynProofExpr::try_new_round(COLUMN3_DECIMAL_75_5(), 2).unwrap()
+        );
+        let expr = df_round(vec![value]);
+        assert_eq!(
+            expr_to_proof_expr(&expr, &schema).unwrap(),
+            DynProofExpr::try_new_round(COLUMN3_DECIMAL_75_5(), 0).unwrap()
+        );
+        let expr = df_round(vec![
+            df_column("namespace.table_name", "column3"),
+            lit(-3_i64),
+        ]);
+        assert_eq!(
+            expr_to_proof_expr(&expr, &schema).unwrap(),
+            DynProofExpr::try_new_round(COLUMN3_DECIMAL_75_5(), -3).unwrap()
+        );
+    }
+
+    #[test]
+    fn we_can_convert_round_expr_to_a_larger_scale_as_a_no_op() {
+        let schema = vec![
+            (
+                "column3".into(),
+                ColumnType::Decimal75(Precision::new(75).unwrap(), 5),
+            ),
+            ("column2".into(), ColumnType::BigInt),
+        ];
+        let expr = df_round(vec![
+            df_column("namespace.table_name", "column3"),
+            lit(5_i64),
+        ]);
+        assert_eq!(
+            expr_to_proof_expr(&expr, &schema).unwrap(),
+            COLUMN3_DECIMAL_75_5()
+        );
+        let expr = df_round(vec![Expr::Cast(Cast::new(
+            Box::new(df_column("namespace.table_name", "column2")),
+            DataType::Float64,
+        ))]);
+        assert_eq!(
+            expr_to_proof_expr(&expr, &schema).unwrap(),
+            COLUMN2_BIGINT()
+        );
+    }
+
+    #[test]
+    fn we_cannot_convert_round_expr_with_unsupported_arguments() {
+        let schema = vec![
+            (
+                "column3".into(),
+                ColumnType::Decimal75(Precision::new(75).unwrap(), 5),
+            ),
+            ("column1".into(), ColumnType::Boolean),
+        ];
+        let column3 = df_column("namespace.table_name", "column3");
+        // Non-literal scale
+        let expr = df_round(vec![column3.clone(), column3.clone()]);
+        assert!(matches!(
+            expr_to_proof_expr(&expr, &schema),
+            Err(PlannerError::UnsupportedLogicalExpression { .. })
+        ));
+        // Scale out of range
+        let expr = df_round(vec![column3.clone(), lit(1000_i64)]);
+        assert!(matches!(
+            expr_to_proof_expr(&expr, &schema),
+            Err(PlannerError::UnsupportedLogicalExpression { .. })
+        ));
+        // Too many arguments
+        let expr = df_round(vec![column3, lit(1_i64), lit(1_i64)]);
+        assert!(matches!(
+            expr_to_proof_expr(&expr, &schema),
+            Err(PlannerError::UnsupportedLogicalExpression { .. })
+        ));
+        // Non-numeric value
+        let expr = df_round(vec![df_column("namespace.table_name", "column1")]);
+        assert!(matches!(
+            expr_to_proof_expr(&expr, &schema),
+            Err(PlannerError::AnalyzeError { .. })
+        ));
+    }
+
+    // Floor / Ceil
+    fn df_floor(args: Vec<Expr>) -> Expr {
+        Expr::ScalarFunction(ScalarFunction::new_udf(
+            datafusion::functions::math::floor(),
+            args,
+        ))
+    }
+
+    fn df_ceil(args: Vec<Expr>) -> Expr {
+        Expr::ScalarFunction(ScalarFunction::new_udf(
+            datafusion::functions::math::ceil(),
+            args,
+        ))
+    }
+
+    #[test]
+    fn we_can_convert_floor_and_ceil_exprs_to_proof_exprs() {
+        let schema = vec![
+            (
+                "column3".into(),
+                ColumnType::Decimal75(Precision::new(75).unwrap(), 5),
+            ),
+            ("column2".into(), ColumnType::BigInt),
+        ];
+        // The analyzer casts decimal arguments to float which we strip
+        let value = Expr::Cast(Cast::new(
+            Box::new(df_column("namespace.table_name", "column3")),
+            DataType::Float64,
+        ));
+        assert_eq!(
+            expr_to_proof_expr(&df_floor(vec![value.clone()]), &schema).unwrap(),
+            DynProofExpr::try_new_floor(COLUMN3_DECIMAL_75_5(), 0).unwrap()
+        );
+        assert_eq!(
+            expr_to_proof_expr(&df_ceil(vec![value]), &schema).unwrap(),
+            DynProofExpr::try_new_ceil(COLUMN3_DECIMAL_75_5(), 0).unwrap()
+        );
+        // Integers are left unchanged
+        let value = Expr::Cast(Cast::new(
+            Box::new(df_column("namespace.table_name", "column2")),
+            DataType::Float64,
+        ));
+        assert_eq!(
+            expr_to_proof_expr(&df_floor(vec![value.clone()]), &schema).unwrap(),
+            COLUMN2_BIGINT()
+        );
+        assert_eq!(
+            expr_to_proof_expr(&df_ceil(vec![value]), &schema).unwrap(),
+            COLUMN2_BIGINT()
+        );
+    }
+
+    #[test]
+    fn we_cannot_convert_floor_and_ceil_exprs_with_unsupported_arguments() {
+        let schema = vec![
+            (
+                "column3".into(),
+                ColumnType::Decimal75(Precision::new(75).unwrap(), 5),
+            ),
+            ("column1".into(), ColumnType::Boolean),
+        ];
+        let column3 = df_column("namespace.table_name", "column3");
+        for df_fn in [df_floor, df_ceil] {
+            // Too many arguments
+            let expr = df_fn(vec![column3.clone(), lit(1_i64)]);
+            assert!(matches!(
+                expr_to_proof_expr(&expr, &schema),
+                Err(PlannerError::UnsupportedLogicalExpression { .. })
+            ));
+            // Non-numeric value
+            let expr = df_fn(vec![df_column("namespace.table_name", "column1")]);
+            assert!(matches!(
+                expr_to_proof_expr(&expr, &schema),
+                Err(PlannerError::AnalyzeError { .. })
+            ));
+        }
+    }
+
+    // IsNull / IsNotNull
+    #[test]
+    fn we_can_convert_is_null_and_is_not_null_to_constants() {
+        let schema = vec![("column".into(), ColumnType::BigInt)];
+        let col = df_column("namespace.table_name", "column");
+        assert_eq!(
+            expr_to_proof_expr(&col.clone().is_null(), &schema).unwrap(),
+            DynProofExpr::new_literal(LiteralValue::Boolean(false))
+        );
+        assert_eq!(
+            expr_to_proof_expr(&col.clone().add(lit(1_i64)).is_not_null(), &schema).unwrap(),
+            DynProofExpr::new_literal(LiteralValue::Boolean(true))
+        );
+        assert_eq!(
+            expr_to_proof_expr(&!col.is_not_null(), &schema).unwrap(),
+            DynProofExpr::try_new_not(DynProofExpr::new_literal(LiteralValue::Boolean(true)))
+                .unwrap()
+        );
+    }
+
+    // IsTrue / IsFalse / IsNotTrue / IsNotFalse
+    #[test]
+    fn we_can_convert_boolean_tests_of_boolean_columns() {
+        let schema = vec![("column1".into(), ColumnType::Boolean)];
+        let col = df_column("namespace.table_name", "column1");
+        assert_eq!(
+            expr_to_proof_expr(&col.clone().is_true(), &schema).unwrap(),
+            COLUMN1_BOOLEAN()
+        );
+        assert_eq!(
+            expr_to_proof_expr(&col.clone().is_not_false(), &schema).unwrap(),
+            COLUMN1_BOOLEAN()
+        );
+        assert_eq!(
+            expr_to_proof_expr(&col.clone().is_false(), &schema).unwrap(),
+            DynProofExpr::try_new_not(COLUMN1_BOOLEAN()).unwrap()
+        );
+        assert_eq!(
+            expr_to_proof_expr(&col.is_not_true(), &schema).unwrap(),
+            DynProofExpr::try_new_not(COLUMN1_BOOLEAN()).unwrap()
+        );
+    }
+
+    #[test]
+    fn we_can_convert_boolean_tests_of_boolean_expressions() {
+        let schema = vec![
+            ("column1".into(), ColumnType::Boolean),
+            ("column2".into(), ColumnType::Boolean),
+        ];
+        let expr = df_column("namespace.table_name", "column1")
+            .and(df_column("namespace.table_name", "column2"));
+        let expected = DynProofExpr::try_new_and(COLUMN1_BOOLEAN(), COLUMN2_BOOLEAN()).unwrap();
+        assert_eq!(
+            expr_to_proof_expr(&expr.clone().is_not_false(), &schema).unwrap(),
+            expected
+        );
+        assert_eq!(
+            expr_to_proof_expr(&expr.is_not_true(), &schema).unwrap(),
+            DynProofExpr::try_new_not(expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn we_can_convert_boolean_tests_of_null_to_constants() {
+        let schema = vec![("column1".into(), ColumnType::Boolean)];
+        for null in [lit(ScalarValue::Boolean(None)), lit(ScalarValue::Null)] {
+            assert_eq!(
+                expr_to_proof_expr(&null.clone().is_true(), &schema).unwrap(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(false))
+            );
+            assert_eq!(
+                expr_to_proof_expr(&null.clone().is_false(), &schema).unwrap(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(false))
+            );
+            assert_eq!(
+                expr_to_proof_expr(&null.clone().is_not_true(), &schema).unwrap(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(true))
+            );
+            assert_eq!(
+                expr_to_proof_expr(&null.is_not_false(), &schema).unwrap(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(true))
+            );
+        }
+    }
+
+    // Comparisons with NULL
+    #[test]
+    fn we_can_convert_comparisons_with_null_to_false() {
+        let schema = vec![("column2".into(), ColumnType::BigInt)];
+        let col = df_column("namespace.table_name", "column2");
+        let null = lit(ScalarValue::Int64(None));
+        let false_literal = DynProofExpr::new_literal(LiteralValue::Boolean(false));
+        for expr in [
+            col.clone().eq(null.clone()),
+            col.clone().not_eq(null.clone()),
+            col.clone().lt(null.clone()),
+            col.clone().gt(null.clone()),
+            col.clone().lt_eq(null.clone()),
+            null.clone().gt_eq(col.clone()),
+            col.clone().add(null.clone()).eq(lit(1_i64)),
+            !col.clone().eq(null.clone()),
+            lit(ScalarValue::Null).eq(null),
+        ] {
+            assert_eq!(expr_to_proof_expr(&expr, &schema).unwrap(), false_literal);
+            assert_eq!(
+                predicate_to_proof_expr(&expr, &schema).unwrap(),
+                false_literal
+            );
+        }
+        assert_eq!(
+            predicate_to_proof_expr(&lit(ScalarValue::Boolean(None)), &schema).unwrap(),
+            false_literal
+        );
+        // A non-null comparison is unaffected
+        assert_eq!(
+            predicate_to_proof_expr(&col.eq(lit(1_i64)), &schema).unwrap(),
+            DynProofExpr::try_new_equals(
+                COLUMN2_BIGINT(),
+                DynProofExpr::new_literal(LiteralValue::BigInt(1))
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn we_can_convert_logical_operators_over_comparisons_with_null() {
+        let schema = vec![
+            ("column1".into(), ColumnType::Boolean),
+            ("column2".into(), ColumnType::BigInt),
+        ];
+        let unknown =
+            df_column("namespace.table_name", "column2").eq(lit(ScalarValue::Int64(None)));
+        let col = df_column("namespace.table_name", "column1");
+        let false_literal = || DynProofExpr::new_literal(LiteralValue::Boolean(false));
+        // UNKNOWN OR column1 holds exactly when column1 does
+        let unknown_or_col = unknown.clone().or(col.clone());
+        assert_eq!(
+            expr_to_proof_expr(&unknown_or_col, &schema).unwrap(),
+            DynProofExpr::try_new_or(false_literal(), COLUMN1_BOOLEAN()).unwrap()
+        );
+        // NOT (UNKNOWN OR column1) is NOT UNKNOWN AND NOT column1, which never holds
+        let not_column1 = DynProofExpr::try_new_not(COLUMN1_BOOLEAN()).unwrap();
+        let never = DynProofExpr::try_new_and(false_literal(), not_column1.clone()).unwrap();
+        assert_eq!(
+            expr_to_proof_expr(&!unknown_or_col.clone(), &schema).unwrap(),
+            never
+        );
+        // NOT (UNKNOWN AND column1) is NOT UNKNOWN OR NOT column1
+        assert_eq!(
+            expr_to_proof_expr(&!unknown.clone().and(col.clone()), &schema).unwrap(),
+            DynProofExpr::try_new_or(false_literal(), not_column1).unwrap()
+        );
+        // NOT NOT UNKNOWN is UNKNOWN
+        assert_eq!(
+            expr_to_proof_expr(&!!unknown_or_col.clone(), &schema).unwrap(),
+            DynProofExpr::try_new_or(false_literal(), COLUMN1_BOOLEAN()).unwrap()
+        );
+        // Boolean tests are never UNKNOWN
+        assert_eq!(
+            expr_to_proof_expr(&unknown.clone().is_null(), &schema).unwrap(),
+            DynProofExpr::new_literal(LiteralValue::Boolean(true))
+        );
+        assert_eq!(
+            expr_to_proof_expr(&unknown.clone().is_not_null(), &schema).unwrap(),
+            false_literal()
+        );
+        assert_eq!(
+            expr_to_proof_expr(&unknown.is_not_false(), &schema).unwrap(),
+            DynProofExpr::new_literal(LiteralValue::Boolean(true))
+        );
+        assert_eq!(
+            expr_to_proof_expr(&unknown_or_col.clone().is_false(), &schema).unwrap(),
+            never
+        );
+        assert_eq!(
+            expr_to_proof_expr(&unknown_or_col.is_not_false(), &schema).unwrap(),
+            DynProofExpr::try_new_not(never).unwrap()
+        );
+    }
+
+    #[test]
+    fn we_cannot_convert_boolean_tests_of_non_boolean_expressions() {
+        let schema = vec![("column".into(), ColumnType::BigInt)];
+        let col = df_column("namespace.table_name", "column");
+        for expr in [
+            col.clone().is_true(),
+            col.clone().is_false(),
+            col.clone().is_not_true(),
+            col.is_not_false(),
+        ] {
+            assert!(matches!(
+                expr_to_proof_expr(&expr, &schema),
+                Err(PlannerError::AnalyzeError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn we_cannot_convert_is_null_of_unsupported_expressions() {
+        let schema = vec![("column".into(), ColumnType::BigInt)];
+        assert!(matches!(
+            expr_to_proof_expr(
+                &df_column("namespace.table_name", "missing").is_null(),
+                &schema
+            ),
+            Err(PlannerError::ColumnNotFound)
+        ));
+    }
+
     // Between
     #[test]
     fn we_can_convert_between_expr_to_proof_expr() {
@@ -994,6 +2120,43 @@ mod tests {
         assert_eq!(expr_to_proof_expr(&expr, &schema).unwrap(), expected);
     }
 
+    #[test]
+    fn we_can_convert_between_expr_with_mixed_numeric_types_to_proof_expr() {
+        let schema = vec![(
+            "price".into(),
+            ColumnType::Decimal75(Precision::new(5).unwrap(), 2),
+        )];
+
+        let col = df_column("namespace.table_name", "price");
+        let low = Expr::Literal(ScalarValue::Decimal128(Some(2550), 4, 2));
+        let high = Expr::Literal(ScalarValue::Int32(Some(75)));
+        let expr = col.between(low, high);
+
+        let col_expr = DynProofExpr::new_column(ColumnRef::new(
+            TableRef::from_names(Some("namespace"), "table_name"),
+            "price".into(),
+            ColumnType::Decimal75(Precision::new(5).unwrap(), 2),
+        ));
+        let low_expr = DynProofExpr::new_literal(LiteralValue::Decimal75(
+            Precision::new(4).unwrap(),
+            2,
+            2550_i128.into(),
+        ));
+        let high_expr = DynProofExpr::new_literal(LiteralValue::Int(75));
+
+        // Bounds are inclusive, i.e. NOT (price < 25.50 OR price > 75)
+        let expected = DynProofExpr::try_new_not(
+            DynProofExpr::try_new_or(
+                DynProofExpr::try_new_inequality(col_expr.clone(), low_expr, true).unwrap(),
+                DynProofExpr::try_new_inequality(col_expr, high_expr, false).unwrap(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(expr_to_proof_expr(&expr, &schema).unwrap(), expected);
+    }
+
     #[test]
     fn we_can_extract_column_idents_from_between_expr() {
         let col = df_column("table", "val");
@@ -1048,6 +2211,19 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn we_can_extract_column_idents_from_boolean_tests() {
+        let expected: IndexSet<Ident> = ["bool_col".into()].into_iter().collect();
+        for expr in [
+            df_column("table", "bool_col").is_true(),
+            df_column("table", "bool_col").is_false(),
+            df_column("table", "bool_col").is_not_true(),
+            df_column("table", "bool_col").is_not_false(),
+        ] {
+            assert_eq!(get_column_idents_from_expr(&expr), expected);
+        }
+    }
+
     #[test]
     fn we_can_extract_column_idents_from_alias_expr() {
         let expr = df_column("table", "col_x").alias("alias_name");
@@ -0,0 +1,151 @@
+use indexmap::IndexMap;
+use sqlparser::ast::{Expr, Ident, Query, SelectItem, SetExpr, Statement, VisitMut, VisitorMut};
+use std::ops::ControlFlow;
+
+/// Replaces identifiers in the `WHERE` clause of a `SELECT` that refer to aliases of its projection
+/// with the aliased expressions
+fn resolve_filter_aliases_in_set_expr(set_expr: &mut SetExpr) {
+    match set_expr {
+        SetExpr::Select(select) => {
+            let alias_map = select
+                .projection
+                .iter()
+                .filter_map(|item| match item {
+                    SelectItem::ExprWithAlias { expr, alias } => {
+                        Some((alias.clone(), expr.clone()))
+                    }
+                    _ => None,
+                })
+                .collect::<IndexMap<_, _>>();
+            if let Some(selection) = select.selection.as_mut() {
+                let _ = selection.visit(&mut SelectionAliasVisitor {
+                    alias_map: &alias_map,
+                    subquery_depth: 0,
+                });
+            }
+        }
+        SetExpr::SetOperation { left, right, .. } => {
+            resolve_filter_aliases_in_set_expr(left);
+            resolve_filter_aliases_in_set_expr(right);
+        }
+        _ => (),
+    }
+}
+
+/// Replaces identifiers naming an alias in `alias_map` with the aliased expressions
+///
+/// Identifiers inside subqueries are left alone since they are in the scope of the subquery.
+/// Subqueries resolve the aliases of their own projections when they are visited as queries.
+struct SelectionAliasVisitor<'a> {
+    alias_map: &'a IndexMap<Ident, Expr>,
+    subquery_depth: usize,
+}
+
+impl VisitorMut for SelectionAliasVisitor<'_> {
+    type Break = ();
+
+    fn pre_visit_query(&mut self, _query: &mut Query) -> ControlFlow<Self::Break> {
+        self.subquery_depth += 1;
+        ControlFlow::Continue(())
+    }
+
+    fn post_visit_query(&mut self, _query: &mut Query) -> ControlFlow<Self::Break> {
+        self.subquery_depth -= 1;
+        ControlFlow::Continue(())
+    }
+
+    fn post_visit_expr(&mut self, expr: &mut Expr) -> ControlFlow<Self::Break> {
+        if self.subquery_depth == 0 {
+            if let Expr::Identifier(ident) = expr {
+                if let Some(aliased_expr) = self.alias_map.get(ident) {
+                    *expr = Expr::Nested(Box::new(aliased_expr.clone()));
+                }
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+struct FilterAliasVisitor;
+
+impl VisitorMut for FilterAliasVisitor {
+    type Break = ();
+
+    fn pre_visit_query(&mut self, query: &mut Query) -> ControlFlow<Self::Break> {
+        resolve_filter_aliases_in_set_expr(&mut query.body);
+        ControlFlow::Continue(())
+    }
+}
+
+/// Returns the sqlparser statement with projection aliases referenced in `WHERE` clauses
+/// replaced by the expressions they alias.
+///
+/// e.g. `SELECT a + b AS s FROM t WHERE s > 10` becomes `SELECT a + b AS s FROM t WHERE (a + b) > 10`.
+///
+/// Note that this does not check whether an identifier also names an input column.
+/// Such a column should take precedence over the alias, so this should only be applied
+/// to statements which fail to plan otherwise.
+pub fn statement_with_resolved_filter_aliases(mut statement: Statement) -> Statement {
+    let _ = statement.visit(&mut FilterAliasVisitor);
+    statement
+}
+
+#[cfg(test)]
+mod tests {
+    use super::statement_with_resolved_filter_aliases;
+    use sqlparser::{dialect::GenericDialect, parser::Parser};
+
+    fn assert_resolves_to(sql: &str, expected_sql: &str) {
+        let statement = Parser::parse_sql(&GenericDialect {}, sql).unwrap()[0].clone();
+        let expected_statement =
+            Parser::parse_sql(&GenericDialect {}, expected_sql).unwrap()[0].clone();
+        assert_eq!(
+            statement_with_resolved_filter_aliases(statement),
+            expected_statement
+        );
+    }
+
+    #[test]
+    fn we_can_resolve_filter_aliases() {
+        assert_resolves_to(
+            "SELECT a + b AS s, c FROM t WHERE s > 10 AND c = 1",
+            "SELECT a + b AS s, c FROM t WHERE (a + b) > 10 AND c = 1",
+        );
+        assert_resolves_to(
+            "SELECT a AS x FROM t WHERE x = 1 UNION ALL SELECT b AS y FROM u WHERE y = x",
+            "SELECT a AS x FROM t WHERE (a) = 1 UNION ALL SELECT b AS y FROM u WHERE (b) = x",
+        );
+        assert_resolves_to(
+            "SELECT s FROM (SELECT a * 2 AS s FROM t WHERE s < 4) AS q WHERE s > 2",
+            "SELECT s FROM (SELECT a * 2 AS s FROM t WHERE (a * 2) < 4) AS q WHERE s > 2",
+        );
+    }
+
+    #[test]
+    fn we_do_not_resolve_filter_aliases_inside_subqueries() {
+        assert_resolves_to(
+            "SELECT a AS x FROM t WHERE x IN (SELECT x FROM u WHERE x > 1)",
+            "SELECT a AS x FROM t WHERE (a) IN (SELECT x FROM u WHERE x > 1)",
+        );
+        assert_resolves_to(
+            "SELECT a AS x FROM t WHERE x > (SELECT MAX(x) FROM u) AND EXISTS (SELECT 1 FROM u WHERE x = 1)",
+            "SELECT a AS x FROM t WHERE (a) > (SELECT MAX(x) FROM u) AND EXISTS (SELECT 1 FROM u WHERE x = 1)",
+        );
+        assert_resolves_to(
+            "SELECT a AS x FROM t WHERE x IN (SELECT b AS y FROM u WHERE y > 1)",
+            "SELECT a AS x FROM t WHERE (a) IN (SELECT b AS y FROM u WHERE (b) > 1)",
+        );
+    }
+
+    #[test]
+    fn we_leave_statements_without_filter_aliases_unchanged() {
+        assert_resolves_to(
+            "SELECT a + b AS s FROM t WHERE t.s > 10 ORDER BY s",
+            "SELECT a + b AS s FROM t WHERE t.s > 10 ORDER BY s",
+        );
+        assert_resolves_to(
+            "SELECT a, b FROM t WHERE a > b",
+            "SELECT a, b FROM t WHERE a > b",
+        );
+    }
+}
@@ -2,26 +2,42 @@
 #![cfg_attr(test, expect(clippy::missing_panics_doc))]
 extern crate alloc;
 mod aggregate;
-pub(crate) use aggregate::{aggregate_function_to_proof_expr, AggregateFunc};
+pub(crate) use aggregate::{
+    aggregate_function_to_proof_expr, count_distinct_argument, AggregateFunc,
+};
 pub(crate) mod config;
 pub use config::datafusion_config_no_normalization;
 mod context;
 pub use context::PoSqlContextProvider;
 #[cfg(test)]
 pub(crate) use context::PoSqlTableSource;
+mod column_validation;
+pub use column_validation::{validate_columns, MissingColumn};
 mod conversion;
-pub use conversion::{get_table_refs_from_statement, sql_to_proof_plans};
+pub use conversion::{
+    analyze_sql, get_table_refs_from_statement, sql_to_proof_plans,
+    sql_to_proof_plans_per_statement, sql_to_proof_plans_with_cache, sql_to_proof_plans_with_rules,
+    AnalysisResult, PlannerRules,
+};
 #[cfg(test)]
 mod df_util;
 mod expr;
 pub use expr::expr_to_proof_expr;
-pub(crate) use expr::get_column_idents_from_expr;
+pub(crate) use expr::{get_column_idents_from_expr, predicate_to_proof_expr};
 mod error;
 pub use error::{
     AggregatePlanError, JoinPlanError, LogicalPlanNodeKind, PlannerError, PlannerResult,
+    WindowPlanError,
 };
+mod placeholder_binding;
+pub use placeholder_binding::{bind_placeholders, validate_placeholders};
 mod plan;
-pub use plan::logical_plan_to_proof_plan;
+pub use plan::{
+    logical_plan_to_proof_plan, logical_plan_to_proof_plan_with_limits, simplify_proof_plan,
+    DEFAULT_MAX_PLAN_DEPTH,
+};
+mod filter_alias_resolver;
+pub use filter_alias_resolver::statement_with_resolved_filter_aliases;
 mod uppercase_column_visitor;
 pub use uppercase_column_visitor::{statement_with_uppercase_identifiers, uppercase_identifier};
 mod util;
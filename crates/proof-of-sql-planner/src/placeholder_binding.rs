@@ -0,0 +1,158 @@
+use super::{PlannerError, PlannerResult};
+use indexmap::{map::Entry, IndexMap};
+use proof_of_sql::{
+    base::database::{ColumnType, LiteralValue},
+    sql::proof_plans::DynProofPlan,
+};
+
+/// Binds `params` to the placeholders of a plan, replacing each placeholder with a literal
+///
+/// This allows a parameterized query to be planned once and then bound for each execution.
+/// Following `PostgreSQL` convention `$1` is bound to the first param.
+///
+/// # Errors
+///
+/// Returns [`PlannerError::PlaceholderError`] if
+/// the number of params does not match the placeholders or a param has the wrong type.
+pub fn bind_placeholders(
+    plan: &DynProofPlan,
+    params: &[LiteralValue],
+) -> PlannerResult<DynProofPlan> {
+    Ok(plan.try_bind_placeholders(params)?)
+}
+
+/// Checks that every occurrence of each placeholder in a plan has the same type
+///
+/// This can be called on the output of [`logical_plan_to_proof_plan`](super::logical_plan_to_proof_plan)
+/// to reject queries such as `SELECT a FROM t WHERE a = $1::bigint OR b = $1::int`
+/// before any params are bound.
+///
+/// # Errors
+///
+/// Returns [`PlannerError::InconsistentPlaceholderType`] for the first placeholder found with
+/// more than one type.
+pub fn validate_placeholders(plan: &DynProofPlan) -> PlannerResult<()> {
+    let mut placeholder_types: IndexMap<usize, ColumnType> = IndexMap::new();
+    for (index, column_type) in plan.get_placeholders() {
+        match placeholder_types.entry(index) {
+            Entry::Vacant(entry) => {
+                entry.insert(column_type);
+            }
+            Entry::Occupied(entry) if *entry.get() != column_type => {
+                return Err(PlannerError::InconsistentPlaceholderType { index });
+            }
+            Entry::Occupied(_) => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql_to_proof_plans;
+    use ahash::AHasher;
+    use datafusion::config::ConfigOptions;
+    use indexmap::indexmap_with_default;
+    use proof_of_sql::base::{
+        database::{ColumnType, SchemaAccessorImpl, TableRef},
+        PlaceholderError,
+    };
+    use sqlparser::{dialect::GenericDialect, parser::Parser};
+
+    fn plan(sql: &str) -> DynProofPlan {
+        let schemas = SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+            TableRef::new("sxt", "t") => vec![
+                ("a".into(), ColumnType::BigInt),
+                ("b".into(), ColumnType::VarChar),
+                ("c".into(), ColumnType::Int),
+            ],
+        });
+        let statements = Parser::parse_sql(&GenericDialect {}, sql).unwrap();
+        sql_to_proof_plans(&statements, &schemas, &ConfigOptions::default())
+            .unwrap()
+            .remove(0)
+    }
+
+    #[test]
+    fn we_can_bind_placeholders_in_a_filter() {
+        let parameterized = plan("SELECT a FROM sxt.t WHERE a > $1::bigint AND b = $2::varchar");
+        let bound = bind_placeholders(
+            &parameterized,
+            &[
+                LiteralValue::BigInt(5),
+                LiteralValue::VarChar("x".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(bound, plan("SELECT a FROM sxt.t WHERE a > 5 AND b = 'x'"));
+    }
+
+    #[test]
+    fn we_can_bind_no_params_to_a_plan_without_placeholders() {
+        let unparameterized = plan("SELECT a FROM sxt.t WHERE a > 5");
+        assert_eq!(
+            bind_placeholders(&unparameterized, &[]).unwrap(),
+            unparameterized
+        );
+    }
+
+    #[test]
+    fn we_cannot_bind_the_wrong_number_of_params() {
+        let parameterized = plan("SELECT a FROM sxt.t WHERE a > $1::bigint");
+        assert!(matches!(
+            bind_placeholders(&parameterized, &[]),
+            Err(PlannerError::PlaceholderError {
+                source: PlaceholderError::InvalidPlaceholderIndex {
+                    index: 0,
+                    num_params: 0
+                }
+            })
+        ));
+        assert!(matches!(
+            bind_placeholders(
+                &parameterized,
+                &[LiteralValue::BigInt(5), LiteralValue::BigInt(6)]
+            ),
+            Err(PlannerError::PlaceholderError {
+                source: PlaceholderError::InvalidParamCount {
+                    expected: 1,
+                    actual: 2
+                }
+            })
+        ));
+    }
+
+    #[test]
+    fn we_cannot_bind_params_of_the_wrong_type() {
+        let parameterized = plan("SELECT a FROM sxt.t WHERE a > $1::bigint");
+        assert!(matches!(
+            bind_placeholders(&parameterized, &[LiteralValue::Int(5)]),
+            Err(PlannerError::PlaceholderError {
+                source: PlaceholderError::InvalidPlaceholderType {
+                    index: 0,
+                    expected: ColumnType::BigInt,
+                    actual: ColumnType::Int
+                }
+            })
+        ));
+    }
+
+    #[test]
+    fn we_can_validate_consistently_typed_placeholders() {
+        let parameterized = plan(
+            "SELECT a, $2::varchar AS label FROM sxt.t WHERE a > $1::bigint OR a < $1::bigint - 10",
+        );
+        assert!(validate_placeholders(&parameterized).is_ok());
+        assert!(validate_placeholders(&plan("SELECT a FROM sxt.t")).is_ok());
+    }
+
+    #[test]
+    fn we_cannot_validate_placeholders_with_conflicting_types() {
+        let parameterized = plan("SELECT a FROM sxt.t WHERE a = $2::bigint OR c = $2::int");
+        assert!(matches!(
+            validate_placeholders(&parameterized),
+            Err(PlannerError::InconsistentPlaceholderType { index: 1 })
+        ));
+    }
+}
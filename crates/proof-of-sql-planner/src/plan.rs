@@ -1,24 +1,29 @@
 use super::{
-    aggregate_function_to_proof_expr, expr_to_proof_expr, get_column_idents_from_expr,
-    table_reference_to_table_ref, AggregateFunc, AggregatePlanError, JoinPlanError,
-    LogicalPlanNodeKind, PlannerError, PlannerResult,
+    aggregate_function_to_proof_expr, count_distinct_argument, expr_to_proof_expr,
+    get_column_idents_from_expr, predicate_to_proof_expr, table_reference_to_table_ref,
+    AggregateFunc, AggregatePlanError, JoinPlanError, LogicalPlanNodeKind, PlannerError,
+    PlannerResult, WindowPlanError,
 };
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use datafusion::{
     common::{DFSchema, JoinConstraint, JoinType},
     logical_expr::{
-        Aggregate, Expr, Filter, Join, Limit, LogicalPlan, Projection, SubqueryAlias, TableScan,
-        Union,
+        expr::{Sort, WindowFunction},
+        Aggregate, BuiltInWindowFunction, CrossJoin, Distinct, Expr, Filter, Join, Limit,
+        LogicalPlan, Projection, SubqueryAlias, TableScan, Union, Window, WindowFunctionDefinition,
     },
     sql::{sqlparser::ast::Ident, TableReference},
 };
 use indexmap::{IndexMap, IndexSet};
 use proof_of_sql::{
-    base::database::{ColumnField, ColumnRef, ColumnType, LiteralValue, SchemaAccessor, TableRef},
+    base::database::{
+        try_sum_column_type, ColumnField, ColumnRef, ColumnType, LiteralValue, SchemaAccessor,
+        TableRef,
+    },
     sql::{
         proof::ProofPlan,
         proof_exprs::{AliasedDynProofExpr, DynProofExpr, ProofExpr},
-        proof_plans::{DynProofPlan, SortMergeJoinExec},
+        proof_plans::{CrossJoinExec, DynProofPlan, SortMergeJoinExec},
     },
 };
 
@@ -120,14 +125,38 @@ fn table_scan_to_filter(
     let table_exec = DynProofPlan::new_table(table_ref, input_column_fields);
     let filter_proof_exprs = filters
         .iter()
-        .map(|f| expr_to_proof_expr(f, &input_schema))
+        .map(|f| predicate_to_proof_expr(f, &input_schema))
         .reduce(|a, b| Ok(DynProofExpr::try_new_and(a?, b?)?))
-        .expect("At least one filter expression is required")?;
-    Ok(DynProofPlan::new_filter(
-        aliased_dyn_proof_exprs,
-        table_exec,
-        filter_proof_exprs,
-    ))
+        .expect("At least one filter expression is required")?
+        .simplify_comparisons();
+    Ok(match constant_predicate_value(&filter_proof_exprs) {
+        Some(true) => simplify_proof_plan(DynProofPlan::new_projection(
+            aliased_dyn_proof_exprs,
+            table_exec,
+        )),
+        Some(false) => DynProofPlan::new_slice(
+            simplify_proof_plan(DynProofPlan::new_projection(
+                aliased_dyn_proof_exprs,
+                table_exec,
+            )),
+            0,
+            Some(0),
+        ),
+        None => DynProofPlan::new_filter(aliased_dyn_proof_exprs, table_exec, filter_proof_exprs),
+    })
+}
+
+/// Returns the value of a filter predicate that is a boolean literal, e.g. after constant folding
+///
+/// Such filters need no proof of the predicate: `TRUE` keeps every row and `FALSE` none.
+fn constant_predicate_value(predicate: &DynProofExpr) -> Option<bool> {
+    match predicate {
+        DynProofExpr::Literal(literal) => match literal.value() {
+            LiteralValue::Boolean(value) => Some(*value),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
 /// Converts a [`datafusion::logical_expr::Projection`] to a [`DynProofPlan`]
@@ -137,7 +166,7 @@ fn projection_to_proof_plan(
     output_schema: &DFSchema,
     schemas: &impl SchemaAccessor,
 ) -> PlannerResult<DynProofPlan> {
-    let input_plan = logical_plan_to_proof_plan(input, schemas)?;
+    let input_plan = logical_plan_to_proof_plan_impl(input, schemas)?;
     let input_schema = input_plan
         .get_column_result_fields()
         .iter()
@@ -155,11 +184,21 @@ fn projection_to_proof_plan(
             })
         })
         .collect::<PlannerResult<Vec<_>>>()?;
-    Ok(DynProofPlan::new_projection(aliased_exprs, input_plan))
+    Ok(simplify_proof_plan(DynProofPlan::new_projection(
+        aliased_exprs,
+        input_plan,
+    )))
 }
 
 /// Convert a [`datafusion::logical_plan::LogicalPlan`] to a [`DynProofPlan`] for GROUP BYs
 ///
+/// `DataFusion` lowers `SELECT DISTINCT` to an aggregate without aggregate expressions.
+/// Since the group by proof always yields each unique group exactly once this requires no
+/// special handling: the implicit count is simply left out of the output.
+///
+/// Grouping expressions need not be bare columns, e.g. `GROUP BY a + b`:
+/// the aggregate evaluates them on its input like any other expression.
+///
 /// TODO: Improve how we handle GROUP BYs so that all the tech debt is resolved
 ///
 /// # Panics
@@ -172,7 +211,14 @@ fn aggregate_to_proof_plan(
     schemas: &impl SchemaAccessor,
     alias_map: &IndexMap<String, String>,
 ) -> PlannerResult<DynProofPlan> {
-    let input_plan = logical_plan_to_proof_plan(input, schemas)?;
+    if let [count_expr] = aggr_expr {
+        if let Some(argument) = count_distinct_argument(count_expr) {
+            return count_distinct_to_proof_plan(
+                input, group_expr, count_expr, argument, schemas, alias_map,
+            );
+        }
+    }
+    let input_plan = logical_plan_to_proof_plan_impl(input, schemas)?;
     let input_schema = input_plan
         .get_column_result_fields()
         .iter()
@@ -249,10 +295,11 @@ fn aggregate_to_proof_plan(
         .zip(&mut inner_aliases)
         .map(|((expr, alias), inner_alias)| {
             let inner_alias: Ident = inner_alias.to_string().as_str().into();
+            let data_type = expr.data_type();
             let proof_expr = DynProofExpr::new_column(ColumnRef::new(
                 dummy_table_ref.clone(),
                 inner_alias.clone(),
-                expr.data_type(),
+                try_sum_column_type(data_type).unwrap_or(data_type),
             ));
             (
                 AliasedDynProofExpr {
@@ -299,24 +346,285 @@ fn aggregate_to_proof_plan(
     ))
 }
 
+/// Converts a GROUP BY whose only aggregate expression is `COUNT(DISTINCT <argument>)`
+/// to a [`DynProofPlan`]
+///
+/// The inner aggregate groups by the grouping expressions along with `<argument>`,
+/// which yields each distinct combination exactly once, and the outer aggregate counts
+/// these combinations per group. `DataFusion`'s `single_distinct_aggregation_to_group_by`
+/// optimizer rule performs the same rewrite when it is enabled.
+fn count_distinct_to_proof_plan(
+    input: &LogicalPlan,
+    group_expr: &[Expr],
+    count_expr: &Expr,
+    argument: &Expr,
+    schemas: &impl SchemaAccessor,
+    alias_map: &IndexMap<String, String>,
+) -> PlannerResult<DynProofPlan> {
+    let input_plan = logical_plan_to_proof_plan_impl(input, schemas)?;
+    let input_schema = input_plan
+        .get_column_result_fields()
+        .iter()
+        .map(|field| (field.name(), field.data_type()))
+        .collect::<Vec<_>>();
+    let dummy_table_ref = TableRef::from_names(None, "");
+    let inner_group_by_exprs = group_expr
+        .iter()
+        .chain(core::iter::once(argument))
+        .enumerate()
+        .map(|(i, e)| -> PlannerResult<_> {
+            Ok(AliasedDynProofExpr {
+                expr: expr_to_proof_expr(e, &input_schema)?,
+                alias: i.to_string().as_str().into(),
+            })
+        })
+        .collect::<PlannerResult<Vec<_>>>()?;
+    let group_by_exprs = group_expr
+        .iter()
+        .zip(&inner_group_by_exprs)
+        .map(|(e, inner)| -> PlannerResult<_> {
+            let name_string = e.clone().unalias().display_name()?;
+            let alias = alias_map.get(&name_string).ok_or(
+                AggregatePlanError::MissingGroupExpressionAlias {
+                    expression: name_string,
+                },
+            )?;
+            Ok(AliasedDynProofExpr {
+                expr: DynProofExpr::new_column(ColumnRef::new(
+                    dummy_table_ref.clone(),
+                    inner.alias.clone(),
+                    inner.expr.data_type(),
+                )),
+                alias: alias.as_str().into(),
+            })
+        })
+        .collect::<PlannerResult<Vec<_>>>()?;
+    let count_name = count_expr.clone().unalias().display_name()?;
+    let count_alias =
+        alias_map
+            .get(&count_name)
+            .ok_or(AggregatePlanError::MissingAggregateExpressionAlias {
+                expression: count_name,
+            })?;
+    let inner_count_alias: Ident = inner_group_by_exprs.len().to_string().as_str().into();
+    let distinct_plan = DynProofPlan::try_new_aggregate(
+        inner_group_by_exprs,
+        Vec::new(),
+        inner_count_alias,
+        input_plan,
+        DynProofExpr::new_literal(LiteralValue::Boolean(true)),
+    )
+    .map_err(AggregatePlanError::from)?;
+    Ok(DynProofPlan::try_new_aggregate(
+        group_by_exprs,
+        Vec::new(),
+        count_alias.as_str().into(),
+        distinct_plan,
+        DynProofExpr::new_literal(LiteralValue::Boolean(true)),
+    )
+    .map_err(AggregatePlanError::from)?)
+}
+
+/// Converts a [`datafusion::logical_expr::Distinct::All`] to a [`DynProofPlan`]
+///
+/// This is an aggregate grouping by every input column without aggregate expressions,
+/// which is how `UNION` (as opposed to `UNION ALL`) removes duplicate rows.
+/// `DataFusion` usually performs this rewrite itself during optimization.
+fn distinct_to_proof_plan(
+    input: &LogicalPlan,
+    schemas: &impl SchemaAccessor,
+) -> PlannerResult<DynProofPlan> {
+    let group_expr = input
+        .schema()
+        .columns()
+        .into_iter()
+        .map(Expr::Column)
+        .collect::<Vec<_>>();
+    let alias_map = group_expr
+        .iter()
+        .zip(input.schema().fields().iter())
+        .map(|(expr, field)| Ok((expr.display_name()?, field.name().clone())))
+        .collect::<PlannerResult<IndexMap<_, _>>>()?;
+    aggregate_to_proof_plan(input, &group_expr, &[], schemas, &alias_map)
+}
+
+/// Convert a [`Window`] to a [`DynProofPlan`]
+///
+/// Only a single `ROW_NUMBER() OVER (ORDER BY <column> [ASC | DESC])` is supported, i.e. no
+/// `PARTITION BY` and exactly one bare column to order by. The window frame is irrelevant for
+/// `ROW_NUMBER` and is ignored, as is the placement of nulls since columns are never null.
+fn window_to_proof_plan(
+    window: &Window,
+    schema_accessor: &impl SchemaAccessor,
+) -> PlannerResult<DynProofPlan> {
+    let [window_expr] = window.window_expr.as_slice() else {
+        return Err(WindowPlanError::UnsupportedWindowExpressionCount {
+            count: window.window_expr.len(),
+        }
+        .into());
+    };
+    let window_field = window
+        .schema
+        .fields()
+        .last()
+        .ok_or(PlannerError::ColumnNotFound)?;
+    let Expr::WindowFunction(WindowFunction {
+        fun,
+        partition_by,
+        order_by,
+        ..
+    }) = window_expr
+    else {
+        return Err(WindowPlanError::UnsupportedWindowFunction {
+            function: window_expr.to_string(),
+        }
+        .into());
+    };
+    if !matches!(
+        fun,
+        WindowFunctionDefinition::BuiltInWindowFunction(BuiltInWindowFunction::RowNumber)
+    ) {
+        return Err(WindowPlanError::UnsupportedWindowFunction {
+            function: fun.to_string(),
+        }
+        .into());
+    }
+    if !partition_by.is_empty() {
+        return Err(WindowPlanError::UnsupportedPartitionBy.into());
+    }
+    let [Expr::Sort(Sort { expr, asc, .. })] = order_by.as_slice() else {
+        return Err(WindowPlanError::UnsupportedOrderBy {
+            order_by: order_by
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+        .into());
+    };
+    let Expr::Column(order_by_column) = expr.as_ref() else {
+        return Err(WindowPlanError::UnsupportedOrderBy {
+            order_by: expr.to_string(),
+        }
+        .into());
+    };
+    let input_plan = logical_plan_to_proof_plan_impl(&window.input, schema_accessor)?;
+    let order_by_ident: Ident = order_by_column.name.as_str().into();
+    let order_by_index = input_plan
+        .get_column_result_fields()
+        .iter()
+        .position(|field| field.name() == order_by_ident)
+        .ok_or(PlannerError::ColumnNotFound)?;
+    Ok(DynProofPlan::try_new_row_number(
+        input_plan,
+        order_by_index,
+        *asc,
+        window_field.name().as_str().into(),
+    )?)
+}
+
+/// Resolve a single equi-join key pair to its `(left index, right index)` in the
+/// result fields of the join inputs, returning the key idents on both sides
+///
+/// Each column is assigned to the input whose schema contains it, so qualifiers decide the sides.
+/// The pair may be given in either orientation as long as one column belongs to each side.
+fn resolve_join_key(
+    left_expr: &Expr,
+    right_expr: &Expr,
+    left_schema: &DFSchema,
+    right_schema: &DFSchema,
+    left_column_result_fields: &IndexSet<Ident>,
+    right_column_result_fields: &IndexSet<Ident>,
+) -> PlannerResult<((usize, usize), (Ident, Ident))> {
+    let unsupported_predicate = || JoinPlanError::UnsupportedPredicate {
+        left: left_expr.to_string(),
+        right: right_expr.to_string(),
+    };
+    let (Expr::Column(col_a), Expr::Column(col_b)) = (left_expr, right_expr) else {
+        return Err(unsupported_predicate().into());
+    };
+    let (left_col, right_col) = if left_schema.has_column(col_a) && right_schema.has_column(col_b) {
+        (col_a, col_b)
+    } else if left_schema.has_column(col_b) && right_schema.has_column(col_a) {
+        (col_b, col_a)
+    } else {
+        return Err(unsupported_predicate().into());
+    };
+    let (left_ident, right_ident) = (
+        Ident::new(left_col.name.clone()),
+        Ident::new(right_col.name.clone()),
+    );
+    let indices = (
+        left_column_result_fields
+            .get_index_of(&left_ident)
+            .ok_or_else(unsupported_predicate)?,
+        right_column_result_fields
+            .get_index_of(&right_ident)
+            .ok_or_else(unsupported_predicate)?,
+    );
+    Ok((indices, (left_ident, right_ident)))
+}
+
+/// Convert a cross join of `left` and `right` to a [`DynProofPlan`]
+///
+/// The result may have at most [`CrossJoinExec::DEFAULT_MAX_OUTPUT_ROWS`] rows.
+fn cross_join_to_proof_plan(
+    left: &LogicalPlan,
+    right: &LogicalPlan,
+    schema_accessor: &impl SchemaAccessor,
+) -> PlannerResult<DynProofPlan> {
+    Ok(DynProofPlan::try_new_cross_join(
+        logical_plan_to_proof_plan_impl(left, schema_accessor)?,
+        logical_plan_to_proof_plan_impl(right, schema_accessor)?,
+        CrossJoinExec::DEFAULT_MAX_OUTPUT_ROWS,
+    )?)
+}
+
+/// Convert a [`Join`] to a [`DynProofPlan`]
+///
+/// Only inner and left semi equi-joins are supported. Each pair in `on` must compare a column of
+/// the left input with a column of the right input and no residual `filter` may be present. Keys
+/// are named after the left side in the join output and any right key with a different name is
+/// added back as an alias.
+///
+/// Left semi joins, which `DataFusion` produces for `IN` subqueries such as
+/// `WHERE id IN (SELECT id FROM other)`, must have exactly one key and are converted to a
+/// [`DynProofPlan::SemiJoin`] returning the columns of the left input.
+///
+/// Inner joins without keys are cross joins, see [`cross_join_to_proof_plan`].
 fn join_to_proof_plan(
     join: &Join,
     schema_accessor: &impl SchemaAccessor,
 ) -> PlannerResult<DynProofPlan> {
-    if join.join_type != JoinType::Inner {
+    if !matches!(join.join_type, JoinType::Inner | JoinType::LeftSemi) {
         return Err(JoinPlanError::UnsupportedJoinType {
             join_type: join.join_type,
         }
         .into());
     }
+    if let Some(predicate) = &join.filter {
+        return Err(JoinPlanError::UnsupportedFilterPredicate {
+            predicate: predicate.to_string(),
+        }
+        .into());
+    }
     if join.join_constraint != JoinConstraint::On {
         return Err(JoinPlanError::UnsupportedJoinConstraint {
             constraint: join.join_constraint,
         }
         .into());
     }
-    let left_plan = Box::new(logical_plan_to_proof_plan(&join.left, schema_accessor)?);
-    let right_plan = Box::new(logical_plan_to_proof_plan(&join.right, schema_accessor)?);
+    if join.join_type == JoinType::Inner && join.on.is_empty() {
+        return cross_join_to_proof_plan(&join.left, &join.right, schema_accessor);
+    }
+    let left_plan = Box::new(logical_plan_to_proof_plan_impl(
+        &join.left,
+        schema_accessor,
+    )?);
+    let right_plan = Box::new(logical_plan_to_proof_plan_impl(
+        &join.right,
+        schema_accessor,
+    )?);
     let left_column_result_fields = left_plan
         .get_column_result_fields()
         .into_iter()
@@ -330,28 +638,33 @@ fn join_to_proof_plan(
     let on_indices_and_idents = join
         .on
         .iter()
-        .filter_map(|(left_expr, right_expr)| {
-            Some(match (left_expr, right_expr) {
-                (Expr::Column(col_a), Expr::Column(col_b)) if col_a.name == col_b.name => {
-                    let column_id = Ident::new(col_a.name.clone());
-                    Ok((
-                        (
-                            left_column_result_fields.get_index_of(&column_id)?,
-                            right_column_result_fields.get_index_of(&column_id)?,
-                        ),
-                        column_id,
-                    ))
-                }
-                _ => Err(JoinPlanError::UnsupportedPredicate {
-                    left: left_expr.to_string(),
-                    right: right_expr.to_string(),
-                }
-                .into()),
-            })
+        .map(|(left_expr, right_expr)| {
+            resolve_join_key(
+                left_expr,
+                right_expr,
+                join.left.schema(),
+                join.right.schema(),
+                &left_column_result_fields,
+                &right_column_result_fields,
+            )
         })
         .collect::<PlannerResult<Vec<_>>>()?;
-    let (on_indices, join_idents): (Vec<(usize, usize)>, Vec<Ident>) =
+    let (on_indices, key_idents): (Vec<(usize, usize)>, Vec<(Ident, Ident)>) =
         on_indices_and_idents.into_iter().unzip();
+    if join.join_type == JoinType::LeftSemi {
+        let [(left_index, right_index)] = on_indices[..] else {
+            return Err(JoinPlanError::UnsupportedSemiJoinKeyCount {
+                count: on_indices.len(),
+            }
+            .into());
+        };
+        return Ok(DynProofPlan::try_new_semi_join(
+            *left_plan,
+            *right_plan,
+            left_index,
+            right_index,
+        )?);
+    }
     let (left_indices, right_indices): (Vec<usize>, Vec<usize>) = on_indices.into_iter().unzip();
     let (left_indices_cloned, right_indices_cloned) = (left_indices.clone(), right_indices.clone());
     let left_other_column_idents = left_column_result_fields
@@ -363,24 +676,122 @@ fn join_to_proof_plan(
         .into_iter()
         .enumerate()
         .filter_map(|(i, col_ident)| (!right_indices.contains(&i)).then_some(col_ident));
-    Ok(DynProofPlan::SortMergeJoin(SortMergeJoinExec::new(
+    let join_plan = DynProofPlan::SortMergeJoin(SortMergeJoinExec::new(
         left_plan,
         right_plan,
         left_indices_cloned,
         right_indices_cloned,
-        join_idents
-            .into_iter()
+        key_idents
+            .iter()
+            .map(|(left_ident, _)| left_ident.clone())
             .chain(left_other_column_idents)
             .chain(right_other_column_idents)
             .collect(),
-    )))
+    ));
+    // Right keys named differently from their left counterparts are exposed as aliases
+    let renamed_right_keys = key_idents
+        .into_iter()
+        .filter(|(left_ident, right_ident)| left_ident != right_ident)
+        .collect::<Vec<_>>();
+    if renamed_right_keys.is_empty() {
+        return Ok(join_plan);
+    }
+    let join_result_fields = join_plan.get_column_result_fields();
+    let column_expr = |field: &ColumnField| {
+        DynProofExpr::new_column(ColumnRef::new(
+            TableRef::from_names(None, ""), // Dummy table ref
+            field.name(),
+            field.data_type(),
+        ))
+    };
+    let aliased_exprs = join_result_fields
+        .iter()
+        .map(|field| AliasedDynProofExpr {
+            expr: column_expr(field),
+            alias: field.name(),
+        })
+        .chain(
+            renamed_right_keys
+                .into_iter()
+                .filter_map(|(left_ident, right_ident)| {
+                    let field = join_result_fields
+                        .iter()
+                        .find(|field| field.name() == left_ident)?;
+                    Some(AliasedDynProofExpr {
+                        expr: column_expr(field),
+                        alias: right_ident,
+                    })
+                }),
+        )
+        .collect();
+    Ok(DynProofPlan::new_projection(aliased_exprs, join_plan))
+}
+
+/// Simplify a [`DynProofPlan`] without changing its result
+///
+/// Adjacent slices are collapsed into a single slice and
+/// identity projections directly over a table are removed.
+/// Lowering a `Limit` over a `TableScan` with a fetch, for instance, produces a slice of a slice,
+/// which is proven as two separate plans unless it is simplified.
+#[must_use]
+pub fn simplify_proof_plan(plan: DynProofPlan) -> DynProofPlan {
+    plan.simplify()
+}
+
+/// Default maximum nesting depth of a [`LogicalPlan`] accepted by [`logical_plan_to_proof_plan`]
+pub const DEFAULT_MAX_PLAN_DEPTH: usize = 256;
+
+/// Returns the number of nodes on the longest path from `plan` to a leaf
+///
+/// The plan is walked with an explicit stack so that arbitrarily deep plans can be measured
+/// without recursing.
+fn logical_plan_depth(plan: &LogicalPlan) -> usize {
+    let mut max_depth = 0;
+    let mut stack = vec![(plan, 1)];
+    while let Some((node, depth)) = stack.pop() {
+        max_depth = max_depth.max(depth);
+        stack.extend(node.inputs().into_iter().map(|input| (input, depth + 1)));
+    }
+    max_depth
 }
 
 /// Visit a [`datafusion::logical_plan::LogicalPlan`] and return a [`DynProofPlan`]
-#[expect(clippy::too_many_lines)]
+///
+/// Plans nested deeper than [`DEFAULT_MAX_PLAN_DEPTH`] are rejected,
+/// see [`logical_plan_to_proof_plan_with_limits`].
 pub fn logical_plan_to_proof_plan(
     plan: &LogicalPlan,
     schema_accessor: &impl SchemaAccessor,
+) -> PlannerResult<DynProofPlan> {
+    logical_plan_to_proof_plan_with_limits(plan, schema_accessor, DEFAULT_MAX_PLAN_DEPTH)
+}
+
+/// Visit a [`datafusion::logical_plan::LogicalPlan`] nested at most `max_depth` deep
+/// and return a [`DynProofPlan`]
+///
+/// The conversion recurses once per level of the plan, so deeply nested plans,
+/// e.g. from machine-generated SQL, could otherwise overflow the stack.
+///
+/// # Errors
+/// Returns [`PlannerError::PlanTooDeep`] if the plan is nested deeper than `max_depth`.
+pub fn logical_plan_to_proof_plan_with_limits(
+    plan: &LogicalPlan,
+    schema_accessor: &impl SchemaAccessor,
+    max_depth: usize,
+) -> PlannerResult<DynProofPlan> {
+    let depth = logical_plan_depth(plan);
+    if depth > max_depth {
+        return Err(PlannerError::PlanTooDeep { depth });
+    }
+    logical_plan_to_proof_plan_impl(plan, schema_accessor)
+}
+
+/// Visit a [`datafusion::logical_plan::LogicalPlan`] and return a [`DynProofPlan`]
+/// without checking its depth
+#[expect(clippy::too_many_lines)]
+fn logical_plan_to_proof_plan_impl(
+    plan: &LogicalPlan,
+    schema_accessor: &impl SchemaAccessor,
 ) -> PlannerResult<DynProofPlan> {
     match plan {
         LogicalPlan::EmptyRelation { .. } => Ok(DynProofPlan::new_empty()),
@@ -441,13 +852,18 @@ pub fn logical_plan_to_proof_plan(
         LogicalPlan::Filter(Filter {
             input, predicate, ..
         }) => {
-            let input_plan = logical_plan_to_proof_plan(input, schema_accessor)?;
+            let input_plan = logical_plan_to_proof_plan_impl(input, schema_accessor)?;
             let input_schema = input_plan
                 .get_column_result_fields()
                 .iter()
                 .map(|field| (field.name(), field.data_type()))
                 .collect::<Vec<_>>();
-            let filter_proof_expr = expr_to_proof_expr(predicate, &input_schema)?;
+            let filter_proof_expr = predicate_to_proof_expr(predicate, &input_schema)?;
+            match constant_predicate_value(&filter_proof_expr) {
+                Some(true) => return Ok(input_plan),
+                Some(false) => return Ok(DynProofPlan::new_slice(input_plan, 0, Some(0))),
+                None => {}
+            }
             let aliased_exprs = input_plan
                 .get_column_result_fields()
                 .iter()
@@ -455,7 +871,7 @@ pub fn logical_plan_to_proof_plan(
                     let alias = field.name();
                     Ok(AliasedDynProofExpr {
                         expr: DynProofExpr::new_column(ColumnRef::new(
-                            TableRef::from_names(None, "__filter_input__"), // Dummy table ref
+                            TableRef::from_names(None, ""), // Dummy table ref
                             alias.clone(),
                             field.data_type(),
                         )),
@@ -471,21 +887,31 @@ pub fn logical_plan_to_proof_plan(
         }
         // Limit
         LogicalPlan::Limit(Limit { input, fetch, skip }) => {
-            let input_plan = logical_plan_to_proof_plan(input, schema_accessor)?;
-            Ok(DynProofPlan::new_slice(input_plan, *skip, *fetch))
+            let input_plan = logical_plan_to_proof_plan_impl(input, schema_accessor)?;
+            Ok(simplify_proof_plan(DynProofPlan::new_slice(
+                input_plan, *skip, *fetch,
+            )))
         }
         // Union
         LogicalPlan::Union(Union { inputs, schema: _ }) => {
             let input_plans = inputs
                 .iter()
-                .map(|input| logical_plan_to_proof_plan(input, schema_accessor))
+                .map(|input| logical_plan_to_proof_plan_impl(input, schema_accessor))
                 .collect::<PlannerResult<Vec<_>>>()?;
             Ok(DynProofPlan::try_new_union(input_plans)?)
         }
+        // Distinct, e.g. from `UNION`
+        LogicalPlan::Distinct(Distinct::All(input)) => {
+            distinct_to_proof_plan(input, schema_accessor)
+        }
         LogicalPlan::Join(join) => join_to_proof_plan(join, schema_accessor),
+        LogicalPlan::CrossJoin(CrossJoin { left, right, .. }) => {
+            cross_join_to_proof_plan(left, right, schema_accessor)
+        }
         LogicalPlan::SubqueryAlias(SubqueryAlias { input, .. }) => {
-            logical_plan_to_proof_plan(input, schema_accessor)
+            logical_plan_to_proof_plan_impl(input, schema_accessor)
         }
+        LogicalPlan::Window(window) => window_to_proof_plan(window, schema_accessor),
         _ => Err(PlannerError::UnsupportedLogicalPlan {
             node: LogicalPlanNodeKind::from_unsupported_logical_plan(plan),
         }),
@@ -495,9 +921,7 @@ pub fn logical_plan_to_proof_plan(
 impl LogicalPlanNodeKind {
     fn from_unsupported_logical_plan(plan: &LogicalPlan) -> Self {
         match plan {
-            LogicalPlan::Window(_) => Self::Window,
             LogicalPlan::Sort(_) => Self::Sort,
-            LogicalPlan::CrossJoin(_) => Self::CrossJoin,
             LogicalPlan::Repartition(_) => Self::Repartition,
             LogicalPlan::TableScan(_) => Self::TableScan,
             LogicalPlan::Subquery(_) => Self::Subquery,
@@ -518,9 +942,11 @@ impl LogicalPlanNodeKind {
             | LogicalPlan::Filter(_)
             | LogicalPlan::Aggregate(_)
             | LogicalPlan::Join(_)
+            | LogicalPlan::CrossJoin(_)
             | LogicalPlan::Union(_)
             | LogicalPlan::EmptyRelation(_)
             | LogicalPlan::SubqueryAlias(_)
+            | LogicalPlan::Window(_)
             | LogicalPlan::Limit(_) => {
                 unreachable!("supported logical plan nodes are handled before classification")
             }
@@ -555,6 +981,7 @@ mod tests {
         sql::{
             proof_exprs::{ColumnExpr, TableExpr},
             proof_plans::AggregateExecError,
+            AnalyzeError,
         },
     };
 
@@ -736,6 +1163,18 @@ mod tests {
         })
     }
 
+    #[expect(non_snake_case)]
+    fn COUNT_DISTINCT_A() -> Expr {
+        Expr::AggregateFunction(AggregateFunction {
+            func_def: COUNT,
+            args: vec![df_column("table", "a")],
+            distinct: true,
+            filter: None,
+            order_by: None,
+            null_treatment: None,
+        })
+    }
+
     #[expect(non_snake_case)]
     fn SUM_B() -> Expr {
         Expr::AggregateFunction(AggregateFunction {
@@ -1130,7 +1569,7 @@ mod tests {
                 expr: DynProofExpr::new_column(ColumnRef::new(
                     dummy_ref_table.clone(),
                     "2".into(),
-                    ColumnType::Boolean,
+                    ColumnType::BigInt,
                 )),
                 alias: "sum_d".into(),
             },
@@ -1311,77 +1750,14 @@ mod tests {
     }
 
     #[test]
-    fn we_report_unsupported_grouping_types() {
-        for data_type in [ColumnType::VarChar, ColumnType::VarBinary] {
-            let table_ref = TableRef::new("", "table");
-            let schemas = SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
-                table_ref => vec![("value".into(), data_type)]
-            });
-            let source: Arc<dyn TableSource> =
-                Arc::new(PoSqlTableSource::new(vec![ColumnField::new(
-                    "value".into(),
-                    data_type,
-                )]));
-            let input_plan = LogicalPlan::TableScan(
-                TableScan::try_new("table", source, Some(vec![0]), vec![], None).unwrap(),
-            );
-            let group_expr = vec![df_column("table", "value")];
-            let alias_map = indexmap! {
-                "table.value".to_string() => "value".to_string(),
-            };
-
-            let err = aggregate_to_proof_plan(&input_plan, &group_expr, &[], &schemas, &alias_map)
-                .unwrap_err();
-
-            assert!(
-                matches!(
-                    err,
-                    PlannerError::UnsupportedAggregatePlan {
-                        source: AggregatePlanError::AggregateExec {
-                            source: AggregateExecError::UnsupportedGroupByExpressionType {
-                                data_type: actual_type
-                            }
-                        },
-                        ..
-                    } if actual_type == data_type
-                ),
-                "{err:?}"
-            );
-        }
-    }
-
-    #[test]
-    fn we_report_missing_group_expression_alias() {
-        let input_plan = LogicalPlan::TableScan(
-            TableScan::try_new(
-                "table",
-                TABLE_SOURCE(),
-                Some(vec![0, 1, 2, 3]),
-                vec![],
-                None,
-            )
-            .unwrap(),
-        );
-        let group_expr = vec![df_column("table", "a")];
-        let aggr_expr = vec![COUNT_1()];
-        let alias_map = indexmap! {
-            "COUNT(Int64(1))".to_string() => "count_1".to_string(),
-        };
-
-        let err =
-            aggregate_to_proof_plan(&input_plan, &group_expr, &aggr_expr, &SCHEMAS(), &alias_map)
-                .unwrap_err();
-
-        assert!(matches!(
-            err,
-            PlannerError::UnsupportedAggregatePlan {
-                source: AggregatePlanError::MissingGroupExpressionAlias { expression },
-            } if expression == "table.a"
+    fn we_can_aggregate_with_an_expression_group_expr() {
+        let a_plus_b = Expr::BinaryExpr(BinaryExpr::new(
+            Box::new(df_column("table", "a")),
+            Operator::Plus,
+            Box::new(df_column("table", "b")),
         ));
-    }
-
-    #[test]
-    fn we_report_missing_aggregate_expression_alias() {
+        let group_expr = vec![a_plus_b.clone()];
+        let aggr_expr = vec![COUNT_1()];
         let input_plan = LogicalPlan::TableScan(
             TableScan::try_new(
                 "table",
@@ -1392,15 +1768,268 @@ mod tests {
             )
             .unwrap(),
         );
-        let group_expr = vec![df_column("table", "a")];
-        let aggr_expr = vec![COUNT_1()];
         let alias_map = indexmap! {
-            "table.a".to_string() => "a".to_string(),
+            a_plus_b.display_name().unwrap() => "a_plus_b".to_string(),
+            "COUNT(Int64(1))".to_string() => "count_1".to_string(),
         };
 
-        let err =
+        let result =
             aggregate_to_proof_plan(&input_plan, &group_expr, &aggr_expr, &SCHEMAS(), &alias_map)
-                .unwrap_err();
+                .unwrap();
+
+        // The aggregate evaluates the grouping expression on its input directly,
+        // so no projection is needed to materialize it first
+        let dummy_ref_table = TableRef::from_names(None, "");
+        let expected = DynProofPlan::new_projection(
+            vec![
+                AliasedDynProofExpr {
+                    expr: DynProofExpr::new_column(ColumnRef::new(
+                        dummy_ref_table.clone(),
+                        "0".into(),
+                        ColumnType::BigInt,
+                    )),
+                    alias: "a_plus_b".into(),
+                },
+                AliasedDynProofExpr {
+                    expr: DynProofExpr::new_column(ColumnRef::new(
+                        dummy_ref_table,
+                        "1".into(),
+                        ColumnType::BigInt,
+                    )),
+                    alias: "count_1".into(),
+                },
+            ],
+            DynProofPlan::try_new_aggregate(
+                vec![AliasedDynProofExpr {
+                    expr: DynProofExpr::try_new_add(
+                        DynProofExpr::new_column(ColumnRef::new(
+                            TABLE_REF_TABLE(),
+                            "a".into(),
+                            ColumnType::BigInt,
+                        )),
+                        DynProofExpr::new_column(ColumnRef::new(
+                            TABLE_REF_TABLE(),
+                            "b".into(),
+                            ColumnType::Int,
+                        )),
+                    )
+                    .unwrap(),
+                    alias: "0".into(),
+                }],
+                vec![],
+                "1".into(),
+                TABLE_EXEC(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(true)),
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn we_cannot_aggregate_with_an_unsupported_function_in_the_group_expr() {
+        let sqrt_a = Expr::ScalarFunction(datafusion::logical_expr::expr::ScalarFunction::new_udf(
+            datafusion::functions::math::sqrt(),
+            vec![df_column("table", "a")],
+        ));
+        let group_expr = vec![sqrt_a.clone()];
+        let aggr_expr = vec![COUNT_1()];
+        let input_plan = LogicalPlan::TableScan(
+            TableScan::try_new(
+                "table",
+                TABLE_SOURCE(),
+                Some(vec![0, 1, 2, 3]),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        );
+        let alias_map = indexmap! {
+            sqrt_a.display_name().unwrap() => "sqrt_a".to_string(),
+            "COUNT(Int64(1))".to_string() => "count_1".to_string(),
+        };
+
+        let result =
+            aggregate_to_proof_plan(&input_plan, &group_expr, &aggr_expr, &SCHEMAS(), &alias_map);
+        assert!(
+            matches!(
+                result,
+                Err(PlannerError::UnsupportedLogicalExpression { .. })
+            ),
+            "{result:?}"
+        );
+    }
+
+    #[test]
+    fn we_can_aggregate_with_count_distinct() {
+        let aggr_expr = vec![COUNT_DISTINCT_A()];
+        let input_plan = LogicalPlan::TableScan(
+            TableScan::try_new(
+                "table",
+                TABLE_SOURCE(),
+                Some(vec![0, 1, 2, 3]),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        );
+        let alias_map = indexmap! {
+            "COUNT(DISTINCT table.a)".to_string() => "num_a".to_string(),
+        };
+
+        let result =
+            aggregate_to_proof_plan(&input_plan, &[], &aggr_expr, &SCHEMAS(), &alias_map).unwrap();
+
+        let expected = DynProofPlan::try_new_aggregate(
+            vec![],
+            vec![],
+            "num_a".into(),
+            DynProofPlan::try_new_aggregate(
+                vec![AliasedDynProofExpr {
+                    expr: DynProofExpr::new_column(ColumnRef::new(
+                        TABLE_REF_TABLE(),
+                        "a".into(),
+                        ColumnType::BigInt,
+                    )),
+                    alias: "0".into(),
+                }],
+                vec![],
+                "1".into(),
+                TABLE_EXEC(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(true)),
+            )
+            .unwrap(),
+            DynProofExpr::new_literal(LiteralValue::Boolean(true)),
+        )
+        .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn we_cannot_aggregate_with_count_distinct_and_group_by() {
+        let group_expr = vec![df_column("table", "c")];
+        let aggr_expr = vec![COUNT_DISTINCT_A()];
+        let input_plan = LogicalPlan::TableScan(
+            TableScan::try_new(
+                "table",
+                TABLE_SOURCE(),
+                Some(vec![0, 1, 2, 3]),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        );
+        let alias_map = indexmap! {
+            "table.c".to_string() => "c".to_string(),
+            "COUNT(DISTINCT table.a)".to_string() => "num_a".to_string(),
+        };
+
+        // The inner aggregate groups by both `c` and `a`
+        let err =
+            aggregate_to_proof_plan(&input_plan, &group_expr, &aggr_expr, &SCHEMAS(), &alias_map)
+                .unwrap_err();
+        assert!(matches!(
+            err,
+            PlannerError::UnsupportedAggregatePlan {
+                source: AggregatePlanError::AggregateExec {
+                    source: AggregateExecError::UnsupportedGroupByExpressionCount { count: 2 }
+                },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn we_report_unsupported_grouping_types() {
+        for data_type in [ColumnType::VarChar, ColumnType::VarBinary] {
+            let table_ref = TableRef::new("", "table");
+            let schemas = SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+                table_ref => vec![("value".into(), data_type)]
+            });
+            let source: Arc<dyn TableSource> =
+                Arc::new(PoSqlTableSource::new(vec![ColumnField::new(
+                    "value".into(),
+                    data_type,
+                )]));
+            let input_plan = LogicalPlan::TableScan(
+                TableScan::try_new("table", source, Some(vec![0]), vec![], None).unwrap(),
+            );
+            let group_expr = vec![df_column("table", "value")];
+            let alias_map = indexmap! {
+                "table.value".to_string() => "value".to_string(),
+            };
+
+            let err = aggregate_to_proof_plan(&input_plan, &group_expr, &[], &schemas, &alias_map)
+                .unwrap_err();
+
+            assert!(
+                matches!(
+                    err,
+                    PlannerError::UnsupportedAggregatePlan {
+                        source: AggregatePlanError::AggregateExec {
+                            source: AggregateExecError::UnsupportedGroupByExpressionType {
+                                data_type: actual_type
+                            }
+                        },
+                        ..
+                    } if actual_type == data_type
+                ),
+                "{err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn we_report_missing_group_expression_alias() {
+        let input_plan = LogicalPlan::TableScan(
+            TableScan::try_new(
+                "table",
+                TABLE_SOURCE(),
+                Some(vec![0, 1, 2, 3]),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        );
+        let group_expr = vec![df_column("table", "a")];
+        let aggr_expr = vec![COUNT_1()];
+        let alias_map = indexmap! {
+            "COUNT(Int64(1))".to_string() => "count_1".to_string(),
+        };
+
+        let err =
+            aggregate_to_proof_plan(&input_plan, &group_expr, &aggr_expr, &SCHEMAS(), &alias_map)
+                .unwrap_err();
+
+        assert!(matches!(
+            err,
+            PlannerError::UnsupportedAggregatePlan {
+                source: AggregatePlanError::MissingGroupExpressionAlias { expression },
+            } if expression == "table.a"
+        ));
+    }
+
+    #[test]
+    fn we_report_missing_aggregate_expression_alias() {
+        let input_plan = LogicalPlan::TableScan(
+            TableScan::try_new(
+                "table",
+                TABLE_SOURCE(),
+                Some(vec![0, 1, 2, 3]),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        );
+        let group_expr = vec![df_column("table", "a")];
+        let aggr_expr = vec![COUNT_1()];
+        let alias_map = indexmap! {
+            "table.a".to_string() => "a".to_string(),
+        };
+
+        let err =
+            aggregate_to_proof_plan(&input_plan, &group_expr, &aggr_expr, &SCHEMAS(), &alias_map)
+                .unwrap_err();
 
         assert!(matches!(
             err,
@@ -1594,43 +2223,167 @@ mod tests {
     }
 
     #[test]
-    fn we_can_aggregate_with_fetch_limit() {
-        // Setup group expression
-        let group_expr = vec![df_column("table", "a")];
-
-        // Create the aggregate expressions
-        let aggr_expr = vec![
-            COUNT_1(), // COUNT
-        ];
-
-        // Create the input plan with fetch limit
+    fn we_can_convert_single_column_distinct_aggregate() {
+        // `SELECT DISTINCT a FROM table` is an aggregate without aggregate expressions
         let input_plan = LogicalPlan::TableScan(
             TableScan::try_new(
                 "table",
                 TABLE_SOURCE(),
                 Some(vec![0, 1, 2, 3]),
                 vec![],
-                Some(10),
+                None,
             )
             .unwrap(),
         );
-        let alias_map = indexmap! {
-            "table.a".to_string() => "a".to_string(),
-            "COUNT(Int64(1))".to_string() => "count_1".to_string(),
-        };
-
-        let result =
-            aggregate_to_proof_plan(&input_plan, &group_expr, &aggr_expr, &SCHEMAS(), &alias_map)
-                .unwrap();
-        assert!(matches!(&result, DynProofPlan::Projection(_)));
-        assert_eq!(
-            result.get_column_result_fields(),
-            vec![
-                ColumnField::new("a".into(), ColumnType::BigInt),
-                ColumnField::new("count_1".into(), ColumnType::BigInt),
-            ]
+        let plan = LogicalPlan::Aggregate(
+            Aggregate::try_new(Arc::new(input_plan), vec![df_column("table", "a")], vec![])
+                .unwrap(),
         );
-    }
+        let result = logical_plan_to_proof_plan(&plan, &SCHEMAS()).unwrap();
+        let expected = DynProofPlan::new_projection(
+            vec![AliasedDynProofExpr {
+                expr: DynProofExpr::new_column(ColumnRef::new(
+                    TableRef::from_names(None, ""),
+                    "0".into(),
+                    ColumnType::BigInt,
+                )),
+                alias: "a".into(),
+            }],
+            DynProofPlan::try_new_aggregate(
+                vec![AliasedDynProofExpr {
+                    expr: DynProofExpr::new_column(ColumnRef::new(
+                        TABLE_REF_TABLE(),
+                        "a".into(),
+                        ColumnType::BigInt,
+                    )),
+                    alias: "0".into(),
+                }],
+                vec![],
+                "1".into(),
+                TABLE_EXEC(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(true)),
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn we_can_convert_multi_column_distinct_aggregate() {
+        // `SELECT DISTINCT b, a FROM table`
+        let input_plan = LogicalPlan::TableScan(
+            TableScan::try_new(
+                "table",
+                TABLE_SOURCE(),
+                Some(vec![0, 1, 2, 3]),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        );
+        let plan = LogicalPlan::Aggregate(
+            Aggregate::try_new(
+                Arc::new(input_plan),
+                vec![df_column("table", "b"), df_column("table", "a")],
+                vec![],
+            )
+            .unwrap(),
+        );
+        let result = logical_plan_to_proof_plan(&plan, &SCHEMAS()).unwrap();
+        let dummy_ref_table = TableRef::from_names(None, "");
+        let expected = DynProofPlan::new_projection(
+            vec![
+                AliasedDynProofExpr {
+                    expr: DynProofExpr::new_column(ColumnRef::new(
+                        dummy_ref_table.clone(),
+                        "0".into(),
+                        ColumnType::Int,
+                    )),
+                    alias: "b".into(),
+                },
+                AliasedDynProofExpr {
+                    expr: DynProofExpr::new_column(ColumnRef::new(
+                        dummy_ref_table,
+                        "1".into(),
+                        ColumnType::BigInt,
+                    )),
+                    alias: "a".into(),
+                },
+            ],
+            DynProofPlan::try_new_aggregate(
+                vec![
+                    AliasedDynProofExpr {
+                        expr: DynProofExpr::new_column(ColumnRef::new(
+                            TABLE_REF_TABLE(),
+                            "b".into(),
+                            ColumnType::Int,
+                        )),
+                        alias: "0".into(),
+                    },
+                    AliasedDynProofExpr {
+                        expr: DynProofExpr::new_column(ColumnRef::new(
+                            TABLE_REF_TABLE(),
+                            "a".into(),
+                            ColumnType::BigInt,
+                        )),
+                        alias: "1".into(),
+                    },
+                ],
+                vec![],
+                "2".into(),
+                TABLE_EXEC(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(true)),
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, expected);
+        assert_eq!(
+            result.get_column_result_fields(),
+            vec![
+                ColumnField::new("b".into(), ColumnType::Int),
+                ColumnField::new("a".into(), ColumnType::BigInt),
+            ]
+        );
+    }
+
+    #[test]
+    fn we_can_aggregate_with_fetch_limit() {
+        // Setup group expression
+        let group_expr = vec![df_column("table", "a")];
+
+        // Create the aggregate expressions
+        let aggr_expr = vec![
+            COUNT_1(), // COUNT
+        ];
+
+        // Create the input plan with fetch limit
+        let input_plan = LogicalPlan::TableScan(
+            TableScan::try_new(
+                "table",
+                TABLE_SOURCE(),
+                Some(vec![0, 1, 2, 3]),
+                vec![],
+                Some(10),
+            )
+            .unwrap(),
+        );
+        let alias_map = indexmap! {
+            "table.a".to_string() => "a".to_string(),
+            "COUNT(Int64(1))".to_string() => "count_1".to_string(),
+        };
+
+        let result =
+            aggregate_to_proof_plan(&input_plan, &group_expr, &aggr_expr, &SCHEMAS(), &alias_map)
+                .unwrap();
+        assert!(matches!(&result, DynProofPlan::Projection(_)));
+        assert_eq!(
+            result.get_column_result_fields(),
+            vec![
+                ColumnField::new("a".into(), ColumnType::BigInt),
+                ColumnField::new("count_1".into(), ColumnType::BigInt),
+            ]
+        );
+    }
 
     // EmptyRelation
     #[test]
@@ -1906,6 +2659,63 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn we_can_convert_identity_projection_plan_to_table_exec() {
+        let plan = LogicalPlan::Projection(
+            Projection::try_new(
+                vec![df_column("table", "a"), df_column("table", "b")],
+                Arc::new(LogicalPlan::TableScan(
+                    TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
+                        .unwrap(),
+                )),
+            )
+            .unwrap(),
+        );
+        let schemas = SCHEMAS();
+        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
+        let expected = DynProofPlan::new_table(
+            TABLE_REF_TABLE(),
+            vec![
+                ColumnField::new("a".into(), ColumnType::BigInt),
+                ColumnField::new("b".into(), ColumnType::Int),
+            ],
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn we_can_convert_reordering_and_subset_projection_plans_to_proof_plans() {
+        let table = DynProofPlan::new_table(
+            TABLE_REF_TABLE(),
+            vec![
+                ColumnField::new("a".into(), ColumnType::BigInt),
+                ColumnField::new("b".into(), ColumnType::Int),
+            ],
+        );
+        let schemas = SCHEMAS();
+        for (exprs, aliased_exprs) in [
+            (
+                vec![df_column("table", "b"), df_column("table", "a")],
+                vec![ALIASED_B(), ALIASED_A()],
+            ),
+            (vec![df_column("table", "b")], vec![ALIASED_B()]),
+        ] {
+            let plan = LogicalPlan::Projection(
+                Projection::try_new(
+                    exprs,
+                    Arc::new(LogicalPlan::TableScan(
+                        TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
+                            .unwrap(),
+                    )),
+                )
+                .unwrap(),
+            );
+            let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
+            let expected = DynProofPlan::new_projection(aliased_exprs, table.clone());
+            assert_eq!(result, expected);
+        }
+    }
+
     // Limit
     // Note that either fetch or skip will exist or optimizer will remove the Limit node
     #[test]
@@ -1928,16 +2738,12 @@ mod tests {
         let schemas = SCHEMAS();
         let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
         let expected = DynProofPlan::new_slice(
-            DynProofPlan::new_slice(
-                DynProofPlan::new_table(
-                    TABLE_REF_TABLE(),
-                    vec![
-                        ColumnField::new("a".into(), ColumnType::BigInt),
-                        ColumnField::new("b".into(), ColumnType::Int),
-                    ],
-                ),
-                0,
-                Some(5),
+            DynProofPlan::new_table(
+                TABLE_REF_TABLE(),
+                vec![
+                    ColumnField::new("a".into(), ColumnType::BigInt),
+                    ColumnField::new("b".into(), ColumnType::Int),
+                ],
             ),
             2,
             Some(3),
@@ -1945,53 +2751,73 @@ mod tests {
         assert_eq!(result, expected);
     }
 
-    #[test]
-    fn we_can_convert_limit_plan_with_fetch_no_skip_to_proof_plan() {
-        //TODO: Optimize proof plan to remove redundant slices
-        let plan = LogicalPlan::Limit(Limit {
+    fn limit_plan(skip: usize, fetch: Option<usize>) -> LogicalPlan {
+        LogicalPlan::Limit(Limit {
             input: Arc::new(LogicalPlan::TableScan(
-                TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 1]), vec![], Some(3))
+                TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
                     .unwrap(),
             )),
-            fetch: Some(3),
-            skip: 0,
-        });
+            fetch,
+            skip,
+        })
+    }
 
+    #[test]
+    fn we_can_convert_limit_all_to_proof_plan_without_a_slice() {
         let schemas = SCHEMAS();
-        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
-
-        let expected = DynProofPlan::new_slice(
-            DynProofPlan::new_slice(
-                DynProofPlan::new_table(
-                    TABLE_REF_TABLE(),
-                    vec![
-                        ColumnField::new("a".into(), ColumnType::BigInt),
-                        ColumnField::new("b".into(), ColumnType::Int),
-                    ],
-                ),
-                0,
-                Some(3),
-            ),
-            0,
-            Some(3),
+        let result = logical_plan_to_proof_plan(&limit_plan(0, None), &schemas).unwrap();
+        let expected = DynProofPlan::new_table(
+            TABLE_REF_TABLE(),
+            vec![
+                ColumnField::new("a".into(), ColumnType::BigInt),
+                ColumnField::new("b".into(), ColumnType::Int),
+            ],
         );
         assert_eq!(result, expected);
     }
 
     #[test]
-    fn we_can_convert_limit_plan_with_skip_no_fetch_to_proof_plan() {
-        let plan = LogicalPlan::Limit(Limit {
-            input: Arc::new(LogicalPlan::TableScan(
+    fn we_can_convert_limit_zero_and_huge_limits_to_proof_plans() {
+        let schemas = SCHEMAS();
+        let table = DynProofPlan::new_table(
+            TABLE_REF_TABLE(),
+            vec![
+                ColumnField::new("a".into(), ColumnType::BigInt),
+                ColumnField::new("b".into(), ColumnType::Int),
+            ],
+        );
+        for (skip, fetch) in [
+            (0, Some(0)),
+            (usize::MAX, Some(usize::MAX)),
+            (usize::MAX - 1, Some(usize::MAX)),
+            (usize::MAX, None),
+        ] {
+            let result = logical_plan_to_proof_plan(&limit_plan(skip, fetch), &schemas).unwrap();
+            assert_eq!(result, DynProofPlan::new_slice(table.clone(), skip, fetch));
+        }
+    }
+
+    fn nested_limit_plan(depth: usize) -> LogicalPlan {
+        (1..depth).fold(
+            LogicalPlan::TableScan(
                 TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
                     .unwrap(),
-            )),
-            fetch: None,
-            skip: 2,
-        });
+            ),
+            |input, _| {
+                LogicalPlan::Limit(Limit {
+                    input: Arc::new(input),
+                    fetch: Some(3),
+                    skip: 1,
+                })
+            },
+        )
+    }
 
+    #[test]
+    fn we_can_convert_plan_nested_up_to_the_maximum_depth() {
         let schemas = SCHEMAS();
-        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
-
+        let result =
+            logical_plan_to_proof_plan_with_limits(&nested_limit_plan(4), &schemas, 4).unwrap();
         let expected = DynProofPlan::new_slice(
             DynProofPlan::new_table(
                 TABLE_REF_TABLE(),
@@ -2000,27 +2826,129 @@ mod tests {
                     ColumnField::new("b".into(), ColumnType::Int),
                 ],
             ),
-            2,
-            None,
+            3,
+            Some(1),
         );
         assert_eq!(result, expected);
     }
 
-    // Union
     #[test]
-    fn we_can_convert_union_plan_to_proof_plan() {
-        let plan = LogicalPlan::Union(Union {
-            schema: Arc::new(df_schema(
-                "table",
-                vec![("a1", DataType::Int64), ("b1", DataType::Int32)],
-            )),
-            inputs: vec![
-                Arc::new(LogicalPlan::TableScan(
-                    TableScan::try_new("table1", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
-                        .unwrap(),
-                )),
-                Arc::new(LogicalPlan::TableScan(
-                    TableScan::try_new("table2", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
+    fn we_cannot_convert_plan_nested_beyond_the_maximum_depth() {
+        let schemas = SCHEMAS();
+        assert!(matches!(
+            logical_plan_to_proof_plan_with_limits(&nested_limit_plan(5), &schemas, 4),
+            Err(PlannerError::PlanTooDeep { depth: 5 })
+        ));
+        let depth = DEFAULT_MAX_PLAN_DEPTH + 1;
+        assert!(matches!(
+            logical_plan_to_proof_plan(&nested_limit_plan(depth), &schemas),
+            Err(PlannerError::PlanTooDeep { depth: d }) if d == depth
+        ));
+    }
+
+    #[test]
+    fn we_can_convert_limit_plan_with_fetch_no_skip_to_proof_plan() {
+        let plan = LogicalPlan::Limit(Limit {
+            input: Arc::new(LogicalPlan::TableScan(
+                TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 1]), vec![], Some(3))
+                    .unwrap(),
+            )),
+            fetch: Some(3),
+            skip: 0,
+        });
+
+        let schemas = SCHEMAS();
+        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
+
+        let expected = DynProofPlan::new_slice(
+            DynProofPlan::new_table(
+                TABLE_REF_TABLE(),
+                vec![
+                    ColumnField::new("a".into(), ColumnType::BigInt),
+                    ColumnField::new("b".into(), ColumnType::Int),
+                ],
+            ),
+            0,
+            Some(3),
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn we_can_convert_limit_plan_with_skip_no_fetch_to_proof_plan() {
+        let plan = LogicalPlan::Limit(Limit {
+            input: Arc::new(LogicalPlan::TableScan(
+                TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
+                    .unwrap(),
+            )),
+            fetch: None,
+            skip: 2,
+        });
+
+        let schemas = SCHEMAS();
+        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
+
+        let expected = DynProofPlan::new_slice(
+            DynProofPlan::new_table(
+                TABLE_REF_TABLE(),
+                vec![
+                    ColumnField::new("a".into(), ColumnType::BigInt),
+                    ColumnField::new("b".into(), ColumnType::Int),
+                ],
+            ),
+            2,
+            None,
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn we_can_convert_nested_limit_plans_to_a_single_slice() {
+        let plan = LogicalPlan::Limit(Limit {
+            input: Arc::new(LogicalPlan::Limit(Limit {
+                input: Arc::new(LogicalPlan::TableScan(
+                    TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
+                        .unwrap(),
+                )),
+                fetch: Some(4),
+                skip: 2,
+            })),
+            fetch: Some(10),
+            skip: 1,
+        });
+
+        let schemas = SCHEMAS();
+        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
+
+        let expected = DynProofPlan::new_slice(
+            DynProofPlan::new_table(
+                TABLE_REF_TABLE(),
+                vec![
+                    ColumnField::new("a".into(), ColumnType::BigInt),
+                    ColumnField::new("b".into(), ColumnType::Int),
+                ],
+            ),
+            3,
+            Some(3),
+        );
+        assert_eq!(result, expected);
+    }
+
+    // Union
+    #[test]
+    fn we_can_convert_union_plan_to_proof_plan() {
+        let plan = LogicalPlan::Union(Union {
+            schema: Arc::new(df_schema(
+                "table",
+                vec![("a1", DataType::Int64), ("b1", DataType::Int32)],
+            )),
+            inputs: vec![
+                Arc::new(LogicalPlan::TableScan(
+                    TableScan::try_new("table1", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
+                        .unwrap(),
+                )),
+                Arc::new(LogicalPlan::TableScan(
+                    TableScan::try_new("table2", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
                         .unwrap(),
                 )),
                 Arc::new(LogicalPlan::TableScan(
@@ -2064,6 +2992,111 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[expect(non_snake_case)]
+    fn UNION_ALL_PLAN() -> LogicalPlan {
+        LogicalPlan::Union(Union {
+            schema: Arc::new(df_schema(
+                "table1",
+                vec![("a1", DataType::Int64), ("b1", DataType::Int32)],
+            )),
+            inputs: vec![
+                Arc::new(LogicalPlan::TableScan(
+                    TableScan::try_new("table1", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
+                        .unwrap(),
+                )),
+                Arc::new(LogicalPlan::TableScan(
+                    TableScan::try_new("table2", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None)
+                        .unwrap(),
+                )),
+            ],
+        })
+    }
+
+    #[expect(non_snake_case)]
+    fn UNION_ALL_PROOF_PLAN() -> DynProofPlan {
+        DynProofPlan::try_new_union(vec![
+            DynProofPlan::new_table(
+                TableRef::from_names(None, "table1"),
+                vec![
+                    ColumnField::new("a1".into(), ColumnType::BigInt),
+                    ColumnField::new("b1".into(), ColumnType::Int),
+                ],
+            ),
+            DynProofPlan::new_table(
+                TableRef::from_names(None, "table2"),
+                vec![
+                    ColumnField::new("a2".into(), ColumnType::BigInt),
+                    ColumnField::new("b2".into(), ColumnType::Int),
+                ],
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn we_can_convert_union_all_plan_without_deduplication() {
+        let result = logical_plan_to_proof_plan(&UNION_ALL_PLAN(), &UNION_SCHEMAS()).unwrap();
+        assert_eq!(result, UNION_ALL_PROOF_PLAN());
+    }
+
+    #[test]
+    fn we_can_convert_union_distinct_plan_to_deduplicated_union() {
+        // `UNION` is a `Distinct` wrapping the `UNION ALL`
+        let plan = LogicalPlanBuilder::from(UNION_ALL_PLAN())
+            .distinct()
+            .unwrap()
+            .build()
+            .unwrap();
+        let result = logical_plan_to_proof_plan(&plan, &UNION_SCHEMAS()).unwrap();
+        let dummy_table_ref = TableRef::from_names(None, "");
+        let expected = DynProofPlan::new_projection(
+            vec![
+                AliasedDynProofExpr {
+                    expr: DynProofExpr::new_column(ColumnRef::new(
+                        dummy_table_ref.clone(),
+                        "0".into(),
+                        ColumnType::BigInt,
+                    )),
+                    alias: "a1".into(),
+                },
+                AliasedDynProofExpr {
+                    expr: DynProofExpr::new_column(ColumnRef::new(
+                        dummy_table_ref,
+                        "1".into(),
+                        ColumnType::Int,
+                    )),
+                    alias: "b1".into(),
+                },
+            ],
+            DynProofPlan::try_new_aggregate(
+                vec![
+                    AliasedDynProofExpr {
+                        expr: DynProofExpr::new_column(ColumnRef::new(
+                            TableRef::from_names(None, "table1"),
+                            "a1".into(),
+                            ColumnType::BigInt,
+                        )),
+                        alias: "0".into(),
+                    },
+                    AliasedDynProofExpr {
+                        expr: DynProofExpr::new_column(ColumnRef::new(
+                            TableRef::from_names(None, "table1"),
+                            "b1".into(),
+                            ColumnType::Int,
+                        )),
+                        alias: "1".into(),
+                    },
+                ],
+                vec![],
+                "2".into(),
+                UNION_ALL_PROOF_PLAN(),
+                DynProofExpr::new_literal(LiteralValue::Boolean(true)),
+            )
+            .unwrap(),
+        );
+        assert_eq!(result, expected);
+    }
+
     // Aggregate
     #[test]
     fn we_can_convert_supported_simple_agg_plan_to_proof_plan() {
@@ -2385,14 +3418,6 @@ mod tests {
                     .unwrap(),
                 LogicalPlanNodeKind::Sort,
             ),
-            (
-                LogicalPlanBuilder::from(empty_plan.clone())
-                    .cross_join(empty_plan.clone())
-                    .unwrap()
-                    .build()
-                    .unwrap(),
-                LogicalPlanNodeKind::CrossJoin,
-            ),
             (
                 LogicalPlanBuilder::from(empty_plan.clone())
                     .repartition(Partitioning::RoundRobinBatch(2))
@@ -2447,7 +3472,7 @@ mod tests {
             ),
             (
                 LogicalPlanBuilder::from(empty_plan.clone())
-                    .distinct()
+                    .distinct_on(vec![lit(1)], vec![lit(1)], None)
                     .unwrap()
                     .build()
                     .unwrap(),
@@ -2566,6 +3591,429 @@ mod tests {
         ));
     }
 
+    #[expect(non_snake_case)]
+    fn JOIN_SCHEMAS() -> impl SchemaAccessor {
+        SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+            TableRef::new("", "left") => vec![("a".into(), ColumnType::BigInt),
+                ("b".into(), ColumnType::Int), ("c".into(), ColumnType::VarChar)],
+            TableRef::new("", "right") => vec![("a".into(), ColumnType::BigInt),
+                ("e".into(), ColumnType::Int), ("d".into(), ColumnType::Boolean)],
+        })
+    }
+
+    fn join_table_scan(table_name: &str, schema_accessor: &impl SchemaAccessor) -> LogicalPlan {
+        let column_fields = schema_accessor
+            .lookup_schema(&TableRef::from_names(None, table_name))
+            .into_iter()
+            .map(|(name, data_type)| ColumnField::new(name, data_type))
+            .collect::<Vec<_>>();
+        let projection = (0..column_fields.len()).collect();
+        LogicalPlan::TableScan(
+            TableScan::try_new(
+                table_name,
+                Arc::new(PoSqlTableSource::new(column_fields)),
+                Some(projection),
+                vec![],
+                None,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn join_table_exec(table_name: &str, schema_accessor: &impl SchemaAccessor) -> DynProofPlan {
+        let table_ref = TableRef::from_names(None, table_name);
+        let column_fields = schema_accessor
+            .lookup_schema(&table_ref)
+            .into_iter()
+            .map(|(name, data_type)| ColumnField::new(name, data_type))
+            .collect();
+        DynProofPlan::new_table(table_ref, column_fields)
+    }
+
+    fn join_of_type(
+        join_type: JoinType,
+        on: Vec<(Expr, Expr)>,
+        schema_accessor: &impl SchemaAccessor,
+    ) -> LogicalPlan {
+        LogicalPlan::Join(Join {
+            left: Arc::new(join_table_scan("left", schema_accessor)),
+            right: Arc::new(join_table_scan("right", schema_accessor)),
+            on,
+            filter: None,
+            join_type,
+            join_constraint: JoinConstraint::On,
+            schema: Arc::new(DFSchema::empty()),
+            null_equals_null: false,
+        })
+    }
+
+    fn inner_join(on: Vec<(Expr, Expr)>, schema_accessor: &impl SchemaAccessor) -> LogicalPlan {
+        join_of_type(JoinType::Inner, on, schema_accessor)
+    }
+
+    #[expect(non_snake_case)]
+    fn CROSS_JOIN_SCHEMAS() -> impl SchemaAccessor {
+        SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+            TableRef::new("", "left") => vec![("a".into(), ColumnType::BigInt),
+                ("b".into(), ColumnType::VarChar)],
+            TableRef::new("", "right") => vec![("c".into(), ColumnType::Int)],
+        })
+    }
+
+    #[test]
+    fn we_can_convert_cross_join() {
+        let schemas = CROSS_JOIN_SCHEMAS();
+        let expected = DynProofPlan::try_new_cross_join(
+            join_table_exec("left", &schemas),
+            join_table_exec("right", &schemas),
+            CrossJoinExec::DEFAULT_MAX_OUTPUT_ROWS,
+        )
+        .unwrap();
+        let inner_join_without_keys = inner_join(vec![], &schemas);
+        assert_eq!(
+            logical_plan_to_proof_plan(&inner_join_without_keys, &schemas).unwrap(),
+            expected
+        );
+        let cross_join = LogicalPlanBuilder::from(join_table_scan("left", &schemas))
+            .cross_join(join_table_scan("right", &schemas))
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(
+            logical_plan_to_proof_plan(&cross_join, &schemas).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn we_report_cross_join_with_common_column_names() {
+        let schemas = JOIN_SCHEMAS();
+        let err = logical_plan_to_proof_plan(&inner_join(vec![], &schemas), &schemas).unwrap_err();
+        assert!(matches!(
+            err,
+            PlannerError::AnalyzeError {
+                source: AnalyzeError::DuplicateColumnName { name },
+            } if name == "a"
+        ));
+    }
+
+    #[test]
+    fn we_can_convert_left_semi_join() {
+        let schemas = JOIN_SCHEMAS();
+        let plan = join_of_type(
+            JoinType::LeftSemi,
+            vec![(df_column("right", "e"), df_column("left", "b"))],
+            &schemas,
+        );
+        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
+        let expected = DynProofPlan::try_new_semi_join(
+            join_table_exec("left", &schemas),
+            join_table_exec("right", &schemas),
+            1,
+            1,
+        )
+        .unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn we_report_left_semi_join_with_multiple_keys() {
+        let schemas = JOIN_SCHEMAS();
+        let plan = join_of_type(
+            JoinType::LeftSemi,
+            vec![
+                (df_column("left", "a"), df_column("right", "a")),
+                (df_column("left", "b"), df_column("right", "e")),
+            ],
+            &schemas,
+        );
+        let join_err = logical_plan_to_proof_plan(&plan, &schemas).unwrap_err();
+        assert!(matches!(
+            join_err,
+            PlannerError::UnsupportedJoinPlan {
+                source: JoinPlanError::UnsupportedSemiJoinKeyCount { count: 2 },
+            }
+        ));
+    }
+
+    #[test]
+    fn we_report_left_semi_join_on_unsupported_key_type() {
+        let schemas = SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+            TableRef::new("", "left") => vec![("a".into(), ColumnType::VarChar)],
+            TableRef::new("", "right") => vec![("a".into(), ColumnType::VarChar)],
+        });
+        let plan = join_of_type(
+            JoinType::LeftSemi,
+            vec![(df_column("left", "a"), df_column("right", "a"))],
+            &schemas,
+        );
+        let err = logical_plan_to_proof_plan(&plan, &schemas).unwrap_err();
+        assert!(matches!(
+            err,
+            PlannerError::AnalyzeError {
+                source: AnalyzeError::InvalidDataType {
+                    expr_type: ColumnType::VarChar
+                },
+            }
+        ));
+    }
+
+    #[test]
+    fn we_can_convert_multi_key_join_with_differently_named_keys() {
+        let schemas = JOIN_SCHEMAS();
+        let plan = inner_join(
+            vec![
+                (df_column("left", "a"), df_column("right", "a")),
+                (df_column("left", "b"), df_column("right", "e")),
+            ],
+            &schemas,
+        );
+        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
+        let join_exec = DynProofPlan::SortMergeJoin(SortMergeJoinExec::new(
+            Box::new(join_table_exec("left", &schemas)),
+            Box::new(join_table_exec("right", &schemas)),
+            vec![0, 1],
+            vec![0, 1],
+            vec!["a".into(), "b".into(), "c".into(), "d".into()],
+        ));
+        let join_output_column = |name: &str, data_type: ColumnType| {
+            DynProofExpr::new_column(ColumnRef::new(
+                TableRef::from_names(None, ""),
+                name.into(),
+                data_type,
+            ))
+        };
+        let expected = DynProofPlan::new_projection(
+            vec![
+                AliasedDynProofExpr {
+                    expr: join_output_column("a", ColumnType::BigInt),
+                    alias: "a".into(),
+                },
+                AliasedDynProofExpr {
+                    expr: join_output_column("b", ColumnType::Int),
+                    alias: "b".into(),
+                },
+                AliasedDynProofExpr {
+                    expr: join_output_column("c", ColumnType::VarChar),
+                    alias: "c".into(),
+                },
+                AliasedDynProofExpr {
+                    expr: join_output_column("d", ColumnType::Boolean),
+                    alias: "d".into(),
+                },
+                AliasedDynProofExpr {
+                    expr: join_output_column("b", ColumnType::Int),
+                    alias: "e".into(),
+                },
+            ],
+            join_exec,
+        );
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn we_can_convert_multi_key_join_with_swapped_key_orientation() {
+        let schemas = JOIN_SCHEMAS();
+        let plan = inner_join(
+            vec![
+                (df_column("right", "a"), df_column("left", "a")),
+                (df_column("right", "e"), df_column("left", "b")),
+            ],
+            &schemas,
+        );
+        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
+        let DynProofPlan::Projection(projection) = result else {
+            panic!("expected a projection over the join");
+        };
+        assert_eq!(
+            projection.input(),
+            &DynProofPlan::SortMergeJoin(SortMergeJoinExec::new(
+                Box::new(join_table_exec("left", &schemas)),
+                Box::new(join_table_exec("right", &schemas)),
+                vec![0, 1],
+                vec![0, 1],
+                vec!["a".into(), "b".into(), "c".into(), "d".into()],
+            ))
+        );
+        assert_eq!(
+            projection
+                .get_column_result_fields()
+                .into_iter()
+                .map(|field| field.name())
+                .collect::<Vec<_>>(),
+            vec![
+                Ident::new("a"),
+                Ident::new("b"),
+                Ident::new("c"),
+                Ident::new("d"),
+                Ident::new("e"),
+            ]
+        );
+    }
+
+    #[test]
+    fn we_can_convert_multi_key_join_with_identically_named_keys() {
+        let schemas = SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+            TableRef::new("", "left") => vec![("a".into(), ColumnType::BigInt),
+                ("b".into(), ColumnType::Int), ("c".into(), ColumnType::VarChar)],
+            TableRef::new("", "right") => vec![("b".into(), ColumnType::Int),
+                ("a".into(), ColumnType::BigInt), ("d".into(), ColumnType::Boolean)],
+        });
+        let plan = inner_join(
+            vec![
+                (df_column("left", "a"), df_column("right", "a")),
+                (df_column("left", "b"), df_column("right", "b")),
+            ],
+            &schemas,
+        );
+        let result = logical_plan_to_proof_plan(&plan, &schemas).unwrap();
+        let expected = DynProofPlan::SortMergeJoin(SortMergeJoinExec::new(
+            Box::new(join_table_exec("left", &schemas)),
+            Box::new(join_table_exec("right", &schemas)),
+            vec![0, 1],
+            vec![1, 0],
+            vec!["a".into(), "b".into(), "c".into(), "d".into()],
+        ));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn we_report_join_predicate_that_is_not_a_column_equality() {
+        let schemas = JOIN_SCHEMAS();
+        let plan = inner_join(
+            vec![
+                (df_column("left", "a"), df_column("right", "a")),
+                (
+                    df_column("left", "b").add(lit(1_i32)),
+                    df_column("right", "e"),
+                ),
+            ],
+            &schemas,
+        );
+        let join_err = logical_plan_to_proof_plan(&plan, &schemas).unwrap_err();
+        assert!(matches!(
+            join_err,
+            PlannerError::UnsupportedJoinPlan {
+                source: JoinPlanError::UnsupportedPredicate { left, right },
+            } if left == "left.b + Int32(1)" && right == "right.e"
+        ));
+    }
+
+    #[test]
+    fn we_report_inequality_join_predicate() {
+        let schemas = JOIN_SCHEMAS();
+        let LogicalPlan::Join(join) = inner_join(
+            vec![(df_column("left", "a"), df_column("right", "a"))],
+            &schemas,
+        ) else {
+            unreachable!()
+        };
+        let plan = LogicalPlan::Join(Join {
+            filter: Some(df_column("left", "b").lt(df_column("right", "e"))),
+            ..join
+        });
+        let join_err = logical_plan_to_proof_plan(&plan, &schemas).unwrap_err();
+        assert!(matches!(
+            join_err,
+            PlannerError::UnsupportedJoinPlan {
+                source: JoinPlanError::UnsupportedFilterPredicate { predicate },
+            } if predicate == "left.b < right.e"
+        ));
+    }
+
+    #[test]
+    fn we_report_join_predicate_of_using_join_with_filter() {
+        let schemas = JOIN_SCHEMAS();
+        let LogicalPlan::Join(join) = inner_join(
+            vec![(df_column("left", "a"), df_column("right", "a"))],
+            &schemas,
+        ) else {
+            unreachable!()
+        };
+        let plan = LogicalPlan::Join(Join {
+            filter: Some(df_column("left", "b").not_eq(df_column("right", "e"))),
+            join_constraint: JoinConstraint::Using,
+            ..join
+        });
+        let join_err = logical_plan_to_proof_plan(&plan, &schemas).unwrap_err();
+        assert!(matches!(
+            join_err,
+            PlannerError::UnsupportedJoinPlan {
+                source: JoinPlanError::UnsupportedFilterPredicate { predicate },
+            } if predicate == "left.b != right.e"
+        ));
+    }
+
+    #[test]
+    fn we_report_join_predicate_on_columns_from_the_same_side() {
+        let schemas = JOIN_SCHEMAS();
+        let plan = inner_join(
+            vec![(df_column("left", "b"), df_column("left", "c"))],
+            &schemas,
+        );
+        let join_err = logical_plan_to_proof_plan(&plan, &schemas).unwrap_err();
+        assert!(matches!(
+            join_err,
+            PlannerError::UnsupportedJoinPlan {
+                source: JoinPlanError::UnsupportedPredicate { left, right },
+            } if left == "left.b" && right == "left.c"
+        ));
+    }
+
+    #[test]
+    fn we_can_resolve_swapped_join_key_by_qualifier() {
+        let schemas = SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+            TableRef::new("", "left") => vec![("a".into(), ColumnType::BigInt),
+                ("b".into(), ColumnType::BigInt)],
+            TableRef::new("", "right") => vec![("b".into(), ColumnType::BigInt),
+                ("a".into(), ColumnType::BigInt)],
+        });
+        let (left_scan, right_scan) = (
+            join_table_scan("left", &schemas),
+            join_table_scan("right", &schemas),
+        );
+        let fields = |plan: &DynProofPlan| {
+            plan.get_column_result_fields()
+                .into_iter()
+                .map(|field| field.name())
+                .collect::<IndexSet<_>>()
+        };
+        let left_fields = fields(&join_table_exec("left", &schemas));
+        let right_fields = fields(&join_table_exec("right", &schemas));
+        // Matching names alone would pair left.a with right.b
+        let resolved = resolve_join_key(
+            &df_column("right", "a"),
+            &df_column("left", "b"),
+            left_scan.schema(),
+            right_scan.schema(),
+            &left_fields,
+            &right_fields,
+        )
+        .unwrap();
+        assert_eq!(resolved, ((1, 1), ("b".into(), "a".into())));
+    }
+
+    #[test]
+    fn we_report_join_predicate_on_columns_from_the_same_side_with_names_on_both_sides() {
+        let schemas = SchemaAccessorImpl::new(indexmap_with_default! {AHasher;
+            TableRef::new("", "left") => vec![("a".into(), ColumnType::BigInt),
+                ("b".into(), ColumnType::BigInt)],
+            TableRef::new("", "right") => vec![("b".into(), ColumnType::BigInt),
+                ("a".into(), ColumnType::BigInt)],
+        });
+        let plan = inner_join(
+            vec![(df_column("left", "a"), df_column("left", "b"))],
+            &schemas,
+        );
+        let join_err = logical_plan_to_proof_plan(&plan, &schemas).unwrap_err();
+        assert!(matches!(
+            join_err,
+            PlannerError::UnsupportedJoinPlan {
+                source: JoinPlanError::UnsupportedPredicate { left, right },
+            } if left == "left.a" && right == "left.b"
+        ));
+    }
+
     // Filter (LogicalPlan::Filter) tests - Happy paths
     #[test]
     fn we_can_convert_simple_nested_filters() {
@@ -2638,6 +4086,110 @@ mod tests {
         assert!(matches!(result, Err(PlannerError::ColumnNotFound)));
     }
 
+    #[test]
+    fn we_can_convert_table_scan_with_constant_filters_without_proving_the_predicate() {
+        let table_scan = |value: bool| {
+            LogicalPlan::TableScan(
+                TableScan::try_new(
+                    "table",
+                    TABLE_SOURCE(),
+                    Some(vec![0, 2]),
+                    vec![Expr::Literal(ScalarValue::Boolean(Some(value)))],
+                    None,
+                )
+                .unwrap(),
+            )
+        };
+        let schemas = SCHEMAS();
+        // The projection onto the scanned columns is an identity and hence removed
+        let table = DynProofPlan::new_table(
+            TABLE_REF_TABLE(),
+            vec![
+                ColumnField::new("a".into(), ColumnType::BigInt),
+                ColumnField::new("c".into(), ColumnType::VarChar),
+            ],
+        );
+        assert_eq!(
+            logical_plan_to_proof_plan(&table_scan(true), &schemas).unwrap(),
+            table
+        );
+        assert_eq!(
+            logical_plan_to_proof_plan(&table_scan(false), &schemas).unwrap(),
+            DynProofPlan::new_slice(table, 0, Some(0))
+        );
+    }
+
+    #[test]
+    fn we_can_convert_constant_filters_without_proving_the_predicate() {
+        let table_scan = LogicalPlan::TableScan(
+            TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 1]), vec![], None).unwrap(),
+        );
+        let filter = |value: bool| {
+            LogicalPlan::Filter(
+                Filter::try_new(
+                    Expr::Literal(ScalarValue::Boolean(Some(value))),
+                    Arc::new(table_scan.clone()),
+                )
+                .unwrap(),
+            )
+        };
+        let schemas = SCHEMAS();
+        let input_plan = logical_plan_to_proof_plan(&table_scan, &schemas).unwrap();
+        assert_eq!(
+            logical_plan_to_proof_plan(&filter(true), &schemas).unwrap(),
+            input_plan
+        );
+        assert_eq!(
+            logical_plan_to_proof_plan(&filter(false), &schemas).unwrap(),
+            DynProofPlan::new_slice(input_plan, 0, Some(0))
+        );
+    }
+
+    #[test]
+    fn we_can_convert_filters_comparing_to_null_into_empty_results() {
+        let null = || Expr::Literal(ScalarValue::Int64(None));
+        let schemas = SCHEMAS();
+        let table = DynProofPlan::new_table(
+            TABLE_REF_TABLE(),
+            vec![
+                ColumnField::new("a".into(), ColumnType::BigInt),
+                ColumnField::new("c".into(), ColumnType::VarChar),
+            ],
+        );
+
+        // a = NULL, pushed down into the table scan
+        let table_scan = LogicalPlan::TableScan(
+            TableScan::try_new(
+                "table",
+                TABLE_SOURCE(),
+                Some(vec![0, 2]),
+                vec![df_column("table", "a").eq(null())],
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            logical_plan_to_proof_plan(&table_scan, &schemas).unwrap(),
+            DynProofPlan::new_slice(table.clone(), 0, Some(0))
+        );
+
+        // NOT (a < NULL), as a separate filter
+        let table_scan = LogicalPlan::TableScan(
+            TableScan::try_new("table", TABLE_SOURCE(), Some(vec![0, 2]), vec![], None).unwrap(),
+        );
+        let filter = LogicalPlan::Filter(
+            Filter::try_new(
+                not(df_column("table", "a").lt(null())),
+                Arc::new(table_scan),
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            logical_plan_to_proof_plan(&filter, &schemas).unwrap(),
+            DynProofPlan::new_slice(table, 0, Some(0))
+        );
+    }
+
     // table_scan_get_required_columns tests
     #[test]
     fn we_can_get_required_columns_from_projection_only() {
@@ -14,6 +14,7 @@ use proof_of_sql::{
     sql::proof_exprs::DynProofExpr,
 };
 use sqlparser::ast::Ident;
+use std::sync::Arc;
 
 /// Parse a placeholder string of the form "$1", "$2", etc. into a `usize`.
 fn parse_placeholder_id(s: &str) -> Option<usize> {
@@ -58,6 +59,9 @@ pub(crate) fn table_reference_to_table_ref(table: &TableReference) -> PlannerRes
 
 /// Convert a [`ScalarValue`] to a [`LiteralValue`]
 ///
+/// Null scalars such as `ScalarValue::Boolean(None)` are rejected with
+/// [`PlannerError::UnsupportedDataType`] since `PoSQL` has no null literals.
+///
 /// TODO: add other types supported in `PoSQL`
 pub(crate) fn scalar_value_to_literal_value(value: ScalarValue) -> PlannerResult<LiteralValue> {
     match value {
@@ -67,28 +71,31 @@ pub(crate) fn scalar_value_to_literal_value(value: ScalarValue) -> PlannerResult
         ScalarValue::Int32(Some(v)) => Ok(LiteralValue::Int(v)),
         ScalarValue::Int64(Some(v)) => Ok(LiteralValue::BigInt(v)),
         ScalarValue::UInt8(Some(v)) => Ok(LiteralValue::Uint8(v)),
-        ScalarValue::Utf8(Some(v)) => Ok(LiteralValue::VarChar(v)),
+        // The string is passed through unchanged, escapes have already been resolved by the parser
+        ScalarValue::Utf8(Some(v)) | ScalarValue::LargeUtf8(Some(v)) => {
+            Ok(LiteralValue::VarChar(v))
+        }
         ScalarValue::Binary(Some(v)) | ScalarValue::LargeBinary(Some(v)) => {
             Ok(LiteralValue::VarBinary(v))
         }
-        ScalarValue::TimestampSecond(Some(v), None) => Ok(LiteralValue::TimeStampTZ(
+        ScalarValue::TimestampSecond(Some(v), ref timezone) => Ok(LiteralValue::TimeStampTZ(
             PoSQLTimeUnit::Second,
-            PoSQLTimeZone::utc(),
+            timezone_to_posql_timezone(timezone, &value)?,
             v,
         )),
-        ScalarValue::TimestampMillisecond(Some(v), None) => Ok(LiteralValue::TimeStampTZ(
+        ScalarValue::TimestampMillisecond(Some(v), ref timezone) => Ok(LiteralValue::TimeStampTZ(
             PoSQLTimeUnit::Millisecond,
-            PoSQLTimeZone::utc(),
+            timezone_to_posql_timezone(timezone, &value)?,
             v,
         )),
-        ScalarValue::TimestampMicrosecond(Some(v), None) => Ok(LiteralValue::TimeStampTZ(
+        ScalarValue::TimestampMicrosecond(Some(v), ref timezone) => Ok(LiteralValue::TimeStampTZ(
             PoSQLTimeUnit::Microsecond,
-            PoSQLTimeZone::utc(),
+            timezone_to_posql_timezone(timezone, &value)?,
             v,
         )),
-        ScalarValue::TimestampNanosecond(Some(v), None) => Ok(LiteralValue::TimeStampTZ(
+        ScalarValue::TimestampNanosecond(Some(v), ref timezone) => Ok(LiteralValue::TimeStampTZ(
             PoSQLTimeUnit::Nanosecond,
-            PoSQLTimeZone::utc(),
+            timezone_to_posql_timezone(timezone, &value)?,
             v,
         )),
         ScalarValue::Decimal128(Some(v), precision, scale) => Ok(LiteralValue::Decimal75(
@@ -107,6 +114,18 @@ pub(crate) fn scalar_value_to_literal_value(value: ScalarValue) -> PlannerResult
     }
 }
 
+/// Convert the timezone of a datafusion timestamp [`ScalarValue`] to a [`PoSQLTimeZone`]
+///
+/// A missing timezone is treated as UTC.
+fn timezone_to_posql_timezone(
+    timezone: &Option<Arc<str>>,
+    value: &ScalarValue,
+) -> PlannerResult<PoSQLTimeZone> {
+    PoSQLTimeZone::try_from(timezone).map_err(|_| PlannerError::UnsupportedDataType {
+        data_type: value.data_type(),
+    })
+}
+
 /// Find a column in a schema and return its info as a [`ColumnRef`]
 ///
 /// Note that the table name must be provided in the column which resolved logical plans do
@@ -131,6 +150,8 @@ pub(crate) fn column_to_column_ref(
 }
 
 /// Convert a Vec<ColumnField> to a Schema
+///
+/// The fields of the schema are in the same order as `column_fields` and carry their metadata.
 #[must_use]
 pub fn column_fields_to_schema(column_fields: Vec<ColumnField>) -> Schema {
     Schema::new(
@@ -138,16 +159,17 @@ pub fn column_fields_to_schema(column_fields: Vec<ColumnField>) -> Schema {
             .into_iter()
             .map(|column_field| {
                 //TODO: Make columns nullable
-                let data_type = (&column_field.data_type()).into();
-                Field::new(column_field.name().value.as_str(), data_type, false)
+                Field::from(&column_field)
             })
             .collect::<Vec<_>>(),
     )
 }
 
-/// Convert a [`DFSchema`] to a Vec<ColumnField>
+/// Convert a schema, as returned by [`SchemaAccessor::lookup_schema`](proof_of_sql::base::database::SchemaAccessor::lookup_schema),
+/// to a Vec<ColumnField>
 ///
-/// Note that this returns an error if any column has an unsupported `DataType`
+/// The fields are in the same order as the columns of `schema`, i.e. declaration order.
+/// EVM serialization refers to columns by position, so this order must not change.
 pub(crate) fn schema_to_column_fields(schema: Vec<(Ident, ColumnType)>) -> Vec<ColumnField> {
     schema
         .into_iter()
@@ -159,6 +181,7 @@ pub(crate) fn schema_to_column_fields(schema: Vec<(Ident, ColumnType)>) -> Vec<C
 mod tests {
     use super::*;
     use arrow::datatypes::DataType;
+    use proof_of_sql::base::math::i256::I256;
 
     // parse_placeholder_id
     #[test]
@@ -368,6 +391,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_convert_timezone_aware_timestamps_to_literal_values() {
+        let value = ScalarValue::TimestampSecond(Some(1_577_836_800_i64), Some("+00:00".into()));
+        assert_eq!(
+            scalar_value_to_literal_value(value).unwrap(),
+            LiteralValue::TimeStampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                1_577_836_800_i64
+            )
+        );
+        let value = ScalarValue::TimestampMillisecond(Some(1_i64), Some("-05:00".into()));
+        assert_eq!(
+            scalar_value_to_literal_value(value).unwrap(),
+            LiteralValue::TimeStampTZ(
+                PoSQLTimeUnit::Millisecond,
+                PoSQLTimeZone::new(-5 * 3600),
+                1_i64
+            )
+        );
+        let value = ScalarValue::TimestampSecond(Some(1_i64), Some("Mars/Olympus".into()));
+        assert!(matches!(
+            scalar_value_to_literal_value(value),
+            Err(PlannerError::UnsupportedDataType { .. })
+        ));
+    }
+
     #[expect(clippy::cast_sign_loss)]
     #[test]
     fn we_can_convert_scalar_value_to_literal_value_for_decimals() {
@@ -459,6 +509,111 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_convert_scalar_values_at_the_extremes_of_each_type_to_literal_values() {
+        let cases = [
+            (
+                ScalarValue::Boolean(Some(false)),
+                LiteralValue::Boolean(false),
+            ),
+            (
+                ScalarValue::Int8(Some(i8::MIN)),
+                LiteralValue::TinyInt(i8::MIN),
+            ),
+            (
+                ScalarValue::Int8(Some(i8::MAX)),
+                LiteralValue::TinyInt(i8::MAX),
+            ),
+            (
+                ScalarValue::Int16(Some(i16::MIN)),
+                LiteralValue::SmallInt(i16::MIN),
+            ),
+            (
+                ScalarValue::Int16(Some(i16::MAX)),
+                LiteralValue::SmallInt(i16::MAX),
+            ),
+            (
+                ScalarValue::Int32(Some(i32::MIN)),
+                LiteralValue::Int(i32::MIN),
+            ),
+            (
+                ScalarValue::Int32(Some(i32::MAX)),
+                LiteralValue::Int(i32::MAX),
+            ),
+            (
+                ScalarValue::Int64(Some(i64::MIN)),
+                LiteralValue::BigInt(i64::MIN),
+            ),
+            (
+                ScalarValue::Int64(Some(i64::MAX)),
+                LiteralValue::BigInt(i64::MAX),
+            ),
+            (
+                ScalarValue::UInt8(Some(u8::MAX)),
+                LiteralValue::Uint8(u8::MAX),
+            ),
+            (
+                ScalarValue::Decimal128(Some(-12_345), 5, 2),
+                LiteralValue::Decimal75(Precision::new(5).unwrap(), 2, I256::from(-12_345_i128)),
+            ),
+            (
+                ScalarValue::Decimal128(Some(i128::MAX), 38, -3),
+                LiteralValue::Decimal75(Precision::new(38).unwrap(), -3, I256::from(i128::MAX)),
+            ),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(scalar_value_to_literal_value(value).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn we_can_convert_strings_with_quotes_escapes_and_multibyte_characters_to_literal_values() {
+        for string in [
+            "O'Malley",
+            "''",
+            "say \"hi\"",
+            "back\\slash\\n",
+            "tab\there\nnewline",
+            "Zoë 猫 🐈",
+            "",
+        ] {
+            for value in [
+                ScalarValue::Utf8(Some(string.to_string())),
+                ScalarValue::LargeUtf8(Some(string.to_string())),
+            ] {
+                assert_eq!(
+                    scalar_value_to_literal_value(value).unwrap(),
+                    LiteralValue::VarChar(string.to_string())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn we_cannot_convert_null_scalar_values_to_literal_values() {
+        let cases = [
+            (ScalarValue::Boolean(None), DataType::Boolean),
+            (ScalarValue::Int8(None), DataType::Int8),
+            (ScalarValue::Int16(None), DataType::Int16),
+            (ScalarValue::Int32(None), DataType::Int32),
+            (ScalarValue::Int64(None), DataType::Int64),
+            (ScalarValue::UInt8(None), DataType::UInt8),
+            (ScalarValue::Utf8(None), DataType::Utf8),
+            (ScalarValue::LargeUtf8(None), DataType::LargeUtf8),
+            (
+                ScalarValue::Decimal128(None, 10, 2),
+                DataType::Decimal128(10, 2),
+            ),
+            (ScalarValue::Null, DataType::Null),
+        ];
+        for (value, expected_data_type) in cases {
+            assert!(matches!(
+                scalar_value_to_literal_value(value),
+                Err(PlannerError::UnsupportedDataType { data_type }) if data_type == expected_data_type
+            ));
+        }
+    }
+
     #[test]
     fn we_cannot_convert_scalar_value_to_literal_value_if_unsupported() {
         // Unsupported
@@ -527,6 +682,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn column_field_metadata_survives_the_schema_round_trip() {
+        let metadata = [("unit".to_string(), "cents".to_string())].into();
+        let column_fields = vec![
+            ColumnField::new("a".into(), ColumnType::BigInt).with_metadata(metadata),
+            ColumnField::new("b".into(), ColumnType::VarChar),
+        ];
+        let schema = column_fields_to_schema(column_fields.clone());
+        assert_eq!(schema.field(0).metadata()["unit"], "cents");
+        assert!(schema.field(1).metadata().is_empty());
+        let round_tripped = schema
+            .fields()
+            .iter()
+            .map(|field| ColumnField::try_from(field.as_ref()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(round_tripped, column_fields);
+        assert_eq!(round_tripped[0].metadata(), column_fields[0].metadata());
+        assert!(round_tripped[1].metadata().is_empty());
+    }
+
     // DFSchema to Vec<ColumnField>
     #[test]
     fn we_can_convert_df_schema_to_column_fields() {
@@ -548,4 +723,50 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn we_can_convert_schemas_with_many_columns_in_declaration_order() {
+        let column_types = [
+            ColumnType::Boolean,
+            ColumnType::BigInt,
+            ColumnType::VarChar,
+            ColumnType::Int128,
+        ];
+        // Names which are not in lexicographic order
+        let schema: Vec<(Ident, ColumnType)> = (0..64)
+            .map(|i| {
+                (
+                    format!("column_{}", (i * 37) % 64).as_str().into(),
+                    column_types[i % column_types.len()],
+                )
+            })
+            .collect();
+
+        let column_fields = schema_to_column_fields(schema.clone());
+        assert_eq!(
+            column_fields
+                .iter()
+                .map(|field| (field.name(), field.data_type()))
+                .collect::<Vec<_>>(),
+            schema
+        );
+        let arrow_schema = column_fields_to_schema(column_fields.clone());
+        assert_eq!(
+            arrow_schema
+                .fields()
+                .iter()
+                .map(|field| field.name().as_str())
+                .collect::<Vec<_>>(),
+            schema
+                .iter()
+                .map(|(name, _)| name.value.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        // Repeated conversions yield the same order
+        for _ in 0..8 {
+            assert_eq!(schema_to_column_fields(schema.clone()), column_fields);
+            assert_eq!(column_fields_to_schema(column_fields.clone()), arrow_schema);
+        }
+    }
 }
@@ -304,6 +304,156 @@ fn test_projection_scaling() {
     );
 }
 
+/// Test negating columns, including the minimum of a signed integer type
+#[test]
+fn test_projection_and_filter_with_negation() {
+    let alloc = Bump::new();
+    let sql = r"SELECT -a as neg_a, -c as neg_c FROM tab;
+    SELECT a FROM tab WHERE -a > b;";
+
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "tab") => table(
+            vec![
+                borrowed_bigint("a", [i64::MIN, -1, 0, 5], &alloc),
+                borrowed_bigint("b", [0_i64, 0, 1, -10], &alloc),
+                borrowed_decimal75("c", 5, 2, [-150, 0, 25, 1], &alloc),
+            ]
+        )
+    };
+
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([
+            decimal75("neg_a", 20, 0, [-i128::from(i64::MIN), 1, 0, -5]),
+            decimal75("neg_c", 6, 2, [150_i64, 0, -25, -1]),
+        ]),
+        owned_table([bigint("a", [i64::MIN, -1, 5])]),
+    ];
+
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+#[test]
+fn test_projection_and_filter_with_abs() {
+    let alloc = Bump::new();
+    let sql = r"SELECT ABS(a) as abs_a, ABS(c) as abs_c FROM tab;
+    SELECT a FROM tab WHERE ABS(a) > 1;";
+
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "tab") => table(
+            vec![
+                borrowed_bigint("a", [i64::MIN, -1, 0, 5], &alloc),
+                borrowed_decimal75("c", 5, 2, [-150, 0, 25, -1], &alloc),
+            ]
+        )
+    };
+
+    // |i64::MIN| does not fit into a BIGINT, so ABS of an integer is a decimal
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([
+            decimal75("abs_a", 19, 0, [-i128::from(i64::MIN), 1, 0, 5]),
+            decimal75("abs_c", 5, 2, [150_i64, 0, 25, 1]),
+        ]),
+        owned_table([bigint("a", [i64::MIN, 5])]),
+    ];
+
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+#[test]
+fn test_projection_with_floor_ceil_and_round() {
+    let alloc = Bump::new();
+    let sql = r"SELECT FLOOR(c) as floor_c, CEIL(c) as ceil_c, ROUND(c, 1) as round_c FROM tab;";
+
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "tab") => table(
+            vec![
+                borrowed_decimal75("c", 5, 2, [-150, -125, 0, 25, 150, 249], &alloc),
+            ]
+        )
+    };
+
+    // Like in SQL, ROUND rounds halves away from zero
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![owned_table([
+        decimal75("floor_c", 4, 0, [-2_i64, -2, 0, 0, 1, 2]),
+        decimal75("ceil_c", 4, 0, [-1_i64, -1, 0, 1, 2, 3]),
+        decimal75("round_c", 5, 1, [-15_i64, -13, 0, 3, 15, 25]),
+    ])];
+
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+/// Comparisons with NULL are UNKNOWN and never hold
+#[test]
+fn test_filter_with_comparisons_with_null() {
+    let alloc = Bump::new();
+    let sql = r"SELECT a FROM tab WHERE a = NULL OR b = 1;
+    SELECT a FROM tab WHERE NOT (a < NULL AND b = 1);";
+
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "tab") => table(
+            vec![
+                borrowed_bigint("a", [1_i64, 2, 3, 4], &alloc),
+                borrowed_bigint("b", [1_i64, 0, 1, 2], &alloc),
+            ]
+        )
+    };
+
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([bigint("a", [1_i64, 3])]),
+        owned_table([bigint("a", [2_i64, 4])]),
+    ];
+
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
 /// Test slicing/limit operation - retrieving only a subset of rows
 #[test]
 fn test_slicing_limit() {
@@ -341,6 +491,43 @@ fn test_slicing_limit() {
     );
 }
 
+/// Test OFFSET without LIMIT at and beyond the number of rows, which returns no rows
+#[test]
+fn test_slicing_offset_at_or_beyond_row_count() {
+    let alloc = Bump::new();
+    let sql = "SELECT * FROM products OFFSET 3;
+    SELECT * FROM products OFFSET 10;
+    SELECT id FROM products OFFSET 2;";
+
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "products") => table(
+            vec![
+                borrowed_int("id", [101, 102, 103], &alloc),
+                borrowed_varchar("name", ["Laptop", "Phone", "Tablet"], &alloc),
+            ]
+        )
+    };
+
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([int("id", [0; 0]), varchar("name", [""; 0])]),
+        owned_table([int("id", [0; 0]), varchar("name", [""; 0])]),
+        owned_table([int("id", [103])]),
+    ];
+
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
 /// Test GROUP BY queries
 #[test]
 fn test_group_by() {
@@ -430,6 +617,121 @@ fn test_group_by() {
     );
 }
 
+/// The output columns of a GROUP BY follow the SELECT list
+#[test]
+fn test_group_by_output_column_order() {
+    let alloc = Bump::new();
+    let sql = "select count(1) as num_cats, sum(weight) as total_weight, human_id from cats group by human_id;
+    select sum(weight) as total_weight, human_id, count(1) as num_cats from cats group by human_id;";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "cats") => table(
+            vec![
+                borrowed_int("id", [1, 2, 3, 4, 5], &alloc),
+                borrowed_int("human_id", [1, 1, 1, 2, 2], &alloc),
+                borrowed_decimal75("weight", 3, 1, [145, 75, 20, 45, 55], &alloc),
+            ]
+        )
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([
+            bigint("num_cats", [3_i64, 2]),
+            decimal75("total_weight", 3, 1, [240, 100]),
+            int("human_id", [1, 2]),
+        ]),
+        owned_table([
+            decimal75("total_weight", 3, 1, [240, 100]),
+            int("human_id", [1, 2]),
+            bigint("num_cats", [3_i64, 2]),
+        ]),
+    ];
+
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+/// Test COUNT(DISTINCT ...) queries
+#[test]
+fn test_count_distinct() {
+    let alloc = Bump::new();
+    let sql = "select count(distinct human_id) as num_humans from cats;
+    select count(distinct human_id) from cats;";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "cats") => table(
+            vec![
+                borrowed_int("id", [1, 2, 3, 4, 5], &alloc),
+                borrowed_int("human_id", [1, 1, 1, 2, 2], &alloc),
+            ]
+        )
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([bigint("num_humans", [2_i64])]),
+        owned_table([bigint("COUNT(DISTINCT cats.human_id)", [2_i64])]),
+    ];
+
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+/// Test SELECT DISTINCT queries
+#[test]
+fn test_distinct() {
+    let alloc = Bump::new();
+    let sql = "select distinct human_id from cats;
+    select distinct human_id, age from cats;
+    select distinct weight from cats where age > 2;";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "cats") => table(
+            vec![
+                borrowed_int("id", [1, 2, 3, 4, 5], &alloc),
+                borrowed_int("human_id", [2, 1, 1, 2, 1], &alloc),
+                borrowed_tinyint("age", [4_i8, 13, 2, 4, 2], &alloc),
+                borrowed_decimal75("weight", 3, 1, [45, 145, 20, 45, 75], &alloc),
+            ]
+        )
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([int("human_id", [1, 2])]),
+        owned_table([int("human_id", [1, 1, 2]), tinyint("age", [2_i8, 13, 4])]),
+        owned_table([decimal75("weight", 3, 1, [45, 145])]),
+    ];
+
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
 #[test]
 fn test_coin() {
     let alloc = Bump::new();
@@ -478,45 +780,170 @@ fn test_coin() {
         &expected_results,
         &prover_setup,
         &verifier_setup,
-        &[LiteralValue::VarChar("0x2".to_string())],
+        &[LiteralValue::VarChar("0x2".to_string())],
+    );
+}
+
+#[test]
+fn test_join() {
+    let alloc = Bump::new();
+    let sql = "SELECT column1, column2, column3 FROM table1 JOIN table2 ON table1.common_column = table2.common_column JOIN table3 on table3.another_column = table2.another_column;
+    SELECT table1.common_column*table2.another_column as product FROM table1 JOIN table2 ON table1.common_column = table2.common_column;
+    SELECT table2.common_column+table3.another_column as sum_result FROM table3 JOIN table2 ON table2.another_column = table3.another_column;";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "table1") => table(
+            vec![
+                borrowed_int("common_column", [1, 2, 3, 4, 5], &alloc),
+                borrowed_varchar("column1", ["Chloe", "Margaret", "Katy", "Lucy", "Prudence"], &alloc),
+            ]
+        ),
+        TableRef::from_names(None, "table2") => table(
+            vec![
+                borrowed_int("common_column", [2, 3, 5, 4], &alloc),
+                borrowed_int("another_column", [7, 8, 10, 10], &alloc),
+                borrowed_varchar("column2", ["Test", "Some", "Creamy", "Chocolate"], &alloc),
+            ]
+        ),
+        TableRef::from_names(None, "table3") => table(
+            vec![
+                borrowed_int("another_column", [8, 10, 6], &alloc),
+                borrowed_varchar("column3", ["Puppy", "Dog", "Eyes"], &alloc),
+            ]
+        )
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([
+            varchar("column1", ["Katy", "Lucy", "Prudence"]),
+            varchar("column2", ["Some", "Chocolate", "Creamy"]),
+            varchar("column3", ["Puppy", "Dog", "Dog"]),
+        ]),
+        owned_table([decimal75("product", 21, 0, [14, 24, 40, 50])]),
+        owned_table([decimal75("sum_result", 11, 0, [11, 15, 14])]),
+    ];
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+#[test]
+fn test_cross_join() {
+    let alloc = Bump::new();
+    let sql = "SELECT * FROM cats CROSS JOIN toys;
+    SELECT name, toy FROM cats CROSS JOIN toys WHERE price > 4;";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "cats") => table(
+            vec![
+                borrowed_bigint("id", [1, 2], &alloc),
+                borrowed_varchar("name", ["Chloe", "Margaret"], &alloc),
+            ]
+        ),
+        TableRef::from_names(None, "toys") => table(
+            vec![
+                borrowed_varchar("toy", ["ball", "mouse", "yarn"], &alloc),
+                borrowed_int("price", [5, 3, 7], &alloc),
+            ]
+        )
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([
+            bigint("id", [1, 1, 1, 2, 2, 2]),
+            varchar(
+                "name",
+                [
+                    "Chloe", "Chloe", "Chloe", "Margaret", "Margaret", "Margaret",
+                ],
+            ),
+            varchar("toy", ["ball", "mouse", "yarn", "ball", "mouse", "yarn"]),
+            int("price", [5, 3, 7, 5, 3, 7]),
+        ]),
+        owned_table([
+            varchar("name", ["Chloe", "Chloe", "Margaret", "Margaret"]),
+            varchar("toy", ["ball", "yarn", "ball", "yarn"]),
+        ]),
+    ];
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+#[test]
+fn test_row_number() {
+    let alloc = Bump::new();
+    let sql = "SELECT name, ROW_NUMBER() OVER (ORDER BY age) AS row_num FROM cats;
+    SELECT id, ROW_NUMBER() OVER (ORDER BY id DESC) AS rank FROM cats WHERE age > 1;";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "cats") => table(
+            vec![
+                borrowed_bigint("id", [1, 2, 3, 4], &alloc),
+                borrowed_varchar("name", ["Chloe", "Margaret", "Prudence", "Lucy"], &alloc),
+                borrowed_int("age", [13, 2, 7, 1], &alloc),
+            ]
+        )
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([
+            varchar("name", ["Lucy", "Margaret", "Prudence", "Chloe"]),
+            bigint("row_num", [1, 2, 3, 4]),
+        ]),
+        owned_table([bigint("id", [3, 2, 1]), bigint("rank", [1, 2, 3])]),
+    ];
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
     );
 }
 
 #[test]
-fn test_join() {
+fn test_in_subquery() {
     let alloc = Bump::new();
-    let sql = "SELECT column1, column2, column3 FROM table1 JOIN table2 ON table1.common_column = table2.common_column JOIN table3 on table3.another_column = table2.another_column;
-    SELECT table1.common_column*table2.another_column as product FROM table1 JOIN table2 ON table1.common_column = table2.common_column;
-    SELECT table2.common_column+table3.another_column as sum_result FROM table3 JOIN table2 ON table2.another_column = table3.another_column;";
+    let sql = "SELECT id, name FROM cats WHERE id IN (SELECT cat_id FROM owners);
+    SELECT name FROM cats WHERE id IN (SELECT cat_id FROM owners WHERE human = 'Cassia') AND id > 1;";
     let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
-        TableRef::from_names(None, "table1") => table(
-            vec![
-                borrowed_int("common_column", [1, 2, 3, 4, 5], &alloc),
-                borrowed_varchar("column1", ["Chloe", "Margaret", "Katy", "Lucy", "Prudence"], &alloc),
-            ]
-        ),
-        TableRef::from_names(None, "table2") => table(
+        TableRef::from_names(None, "cats") => table(
             vec![
-                borrowed_int("common_column", [2, 3, 5, 4], &alloc),
-                borrowed_int("another_column", [7, 8, 10, 10], &alloc),
-                borrowed_varchar("column2", ["Test", "Some", "Creamy", "Chocolate"], &alloc),
+                borrowed_bigint("id", [5, 1, 2, 3, 4], &alloc),
+                borrowed_varchar("name", ["Pepper", "Chloe", "Margaret", "Prudence", "Lucy"], &alloc),
             ]
         ),
-        TableRef::from_names(None, "table3") => table(
+        TableRef::from_names(None, "owners") => table(
             vec![
-                borrowed_int("another_column", [8, 10, 6], &alloc),
-                borrowed_varchar("column3", ["Puppy", "Dog", "Eyes"], &alloc),
+                borrowed_bigint("cat_id", [1, 2, 98, 4, 1, 3], &alloc),
+                borrowed_varchar("human", ["Cassia", "Cassia", "Gretta", "Gretta", "Ian", "Cassia"], &alloc),
             ]
         )
     };
     let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
         owned_table([
-            varchar("column1", ["Katy", "Lucy", "Prudence"]),
-            varchar("column2", ["Some", "Chocolate", "Creamy"]),
-            varchar("column3", ["Puppy", "Dog", "Dog"]),
+            bigint("id", [1, 2, 3, 4]),
+            varchar("name", ["Chloe", "Margaret", "Prudence", "Lucy"]),
         ]),
-        owned_table([decimal75("product", 21, 0, [14, 24, 40, 50])]),
-        owned_table([decimal75("sum_result", 11, 0, [11, 15, 14])]),
+        owned_table([varchar("name", ["Margaret", "Prudence"])]),
     ];
     // Create public parameters for DynamicDoryEvaluationProof
     let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
@@ -636,7 +1063,6 @@ JOIN (
 #[test]
 fn test_union() {
     let alloc = Bump::new();
-    // WE do not yet support regular UNION
     let sql = "SELECT column1 FROM table1 UNION ALL SELECT column2 FROM table2;";
     let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
         TableRef::from_names(None, "table1") => table(
@@ -678,6 +1104,41 @@ fn test_union() {
     );
 }
 
+#[test]
+fn test_union_distinct() {
+    let alloc = Bump::new();
+    let sql = "SELECT a FROM table1 UNION SELECT b FROM table2;
+    SELECT a FROM table1 UNION ALL SELECT b FROM table2;";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "table1") => table(
+            vec![
+                borrowed_bigint("a", [3_i64, 1, 2, 3], &alloc),
+            ]
+        ),
+        TableRef::from_names(None, "table2") => table(
+            vec![
+                borrowed_bigint("b", [2_i64, 4, 1], &alloc),
+            ]
+        ),
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([bigint("a", [1_i64, 2, 3, 4])]),
+        owned_table([bigint("a", [3_i64, 1, 2, 3, 2, 4, 1])]),
+    ];
+    // Create public parameters for DynamicDoryEvaluationProof
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
 #[test]
 fn test_implicit_casts() {
     let alloc = Bump::new();
@@ -840,6 +1301,41 @@ fn test_in_list_long_numeric_list() {
     );
 }
 
+/// Columns are never null, so `IS NOT NULL` keeps every row
+#[test]
+fn test_is_not_null() {
+    let alloc = Bump::new();
+    let sql = "SELECT id, score FROM students WHERE score IS NOT NULL;
+    SELECT id FROM students WHERE score IS NOT NULL AND score > 60;";
+
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "students") => table(
+            vec![
+                borrowed_int("id", [1, 2, 3], &alloc),
+                borrowed_bigint("score", [45_i64, 60, 75], &alloc),
+            ]
+        )
+    };
+
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([int("id", [1, 2, 3]), bigint("score", [45_i64, 60, 75])]),
+        owned_table([int("id", [3])]),
+    ];
+
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
 /// Test BETWEEN and NOT BETWEEN operators, including inclusive boundary behaviour
 #[test]
 fn test_between_operator() {
@@ -880,6 +1376,46 @@ fn test_between_operator() {
     );
 }
 
+/// Test BETWEEN with bounds of a different numeric type than the column
+#[test]
+fn test_between_with_mixed_numeric_types() {
+    let alloc = Bump::new();
+    let sql = "SELECT name_en, price_per_pound FROM sushi WHERE price_per_pound BETWEEN 25 AND 75;
+    SELECT name_en FROM sushi WHERE id NOT BETWEEN 2 AND 4;";
+
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "sushi") => table(
+            vec![
+                borrowed_int("id", [1, 2, 3, 4, 5], &alloc),
+                borrowed_varchar("name_en", ["Tuna", "Salmon", "Eel", "Sea Urchin", "Toro"], &alloc),
+                borrowed_decimal75("price_per_pound", 5, 2, [1850, 2500, 4999, 7500, 7501], &alloc),
+            ]
+        )
+    };
+
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        // Both bounds are inclusive
+        owned_table([
+            varchar("name_en", ["Salmon", "Eel", "Sea Urchin"]),
+            decimal75("price_per_pound", 5, 2, [2500, 4999, 7500]),
+        ]),
+        owned_table([varchar("name_en", ["Tuna", "Toro"])]),
+    ];
+
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
 /// Test BETWEEN combined with AND / OR filters
 #[test]
 fn test_between_combined_with_other_filters() {
@@ -923,3 +1459,226 @@ fn test_between_combined_with_other_filters() {
         &[],
     );
 }
+
+/// Test comparing a date column, stored as timestamps at midnight UTC, against date strings
+#[test]
+fn test_date_comparison() {
+    let alloc = Bump::new();
+    let sql = "SELECT id FROM prices WHERE d > '2020-01-01';
+    SELECT id, d FROM prices WHERE d <= '2020-01-01';";
+
+    // 2019-12-31, 2020-01-01 and 2020-06-15
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "prices") => table(
+            vec![
+                borrowed_bigint("id", [1_i64, 2, 3], &alloc),
+                borrowed_timestamptz(
+                    "d",
+                    PoSQLTimeUnit::Second,
+                    PoSQLTimeZone::utc(),
+                    [1_577_750_400, 1_577_836_800, 1_592_179_200],
+                    &alloc,
+                ),
+            ]
+        )
+    };
+
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([bigint("id", [3_i64])]),
+        owned_table([
+            bigint("id", [1_i64, 2]),
+            timestamptz(
+                "d",
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                [1_577_750_400, 1_577_836_800],
+            ),
+        ]),
+    ];
+
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+/// Test casting numeric string literals, which are parsed when planning
+#[test]
+fn test_cast_of_numeric_string_literals() {
+    let alloc = Bump::new();
+    let sql = "SELECT id FROM prices WHERE price > CAST('150' AS BIGINT);
+    SELECT id, price + CAST('-7' AS BIGINT) AS adjusted FROM prices WHERE price = CAST('100' AS BIGINT);";
+
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "prices") => table(
+            vec![
+                borrowed_bigint("id", [1_i64, 2, 3], &alloc),
+                borrowed_bigint("price", [100_i64, 150, 200], &alloc),
+            ]
+        )
+    };
+
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([bigint("id", [3_i64])]),
+        owned_table([bigint("id", [1_i64]), bigint("adjusted", [93_i64])]),
+    ];
+
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+#[test]
+fn test_varbinary_equality() {
+    let alloc = Bump::new();
+    let sql = "SELECT id FROM txs WHERE tx_hash = X'0a0b';
+    SELECT id, tx_hash FROM txs WHERE tx_hash <> X'0a0b' AND id > 1;";
+
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "txs") => table(
+            vec![
+                borrowed_bigint("id", [1_i64, 2, 3], &alloc),
+                borrowed_varbinary("tx_hash", [[0x0a_u8, 0x0b].as_slice(), &[0xff], &[0x0a, 0x0b]], &alloc),
+            ]
+        )
+    };
+
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([bigint("id", [1_i64, 3])]),
+        owned_table([bigint("id", [2_i64]), varbinary("tx_hash", [[0xff_u8]])]),
+    ];
+
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+#[test]
+fn test_filter_on_strings_with_quotes_and_multibyte_characters() {
+    let alloc = Bump::new();
+    let sql = "SELECT id FROM cats WHERE name = 'O''Malley';
+    SELECT id FROM cats WHERE name = 'Zoë 🐈' OR name = '\"Tiger\"';";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "cats") => table(
+            vec![
+                borrowed_bigint("id", [1_i64, 2, 3, 4], &alloc),
+                borrowed_varchar("name", ["O'Malley", "OMalley", "Zoë 🐈", "\"Tiger\""], &alloc),
+            ]
+        )
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![
+        owned_table([bigint("id", [1_i64])]),
+        owned_table([bigint("id", [3_i64, 4])]),
+    ];
+    let public_parameters = PublicParameters::test_rand(5, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+/// Test proving and verifying with setups that were saved and reloaded
+#[test]
+fn test_queries_with_reloaded_setups() {
+    let alloc = Bump::new();
+    let sql = "select id from cats where age > 2;";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "cats") => table(
+            vec![
+                borrowed_int("id", [1, 2, 3], &alloc),
+                borrowed_tinyint("age", [13_i8, 2, 4], &alloc),
+            ]
+        )
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> = vec![owned_table([int("id", [1, 3])])];
+
+    let mut public_parameters_bytes = Vec::new();
+    PublicParameters::test_rand(5, &mut test_rng())
+        .save_to_writer(&mut public_parameters_bytes)
+        .unwrap();
+    let public_parameters =
+        PublicParameters::load_from_reader(&public_parameters_bytes[..]).unwrap();
+    let mut verifier_setup_bytes = Vec::new();
+    VerifierSetup::from(&public_parameters)
+        .save_to_writer(&mut verifier_setup_bytes)
+        .unwrap();
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::load_from_reader(&verifier_setup_bytes[..]).unwrap();
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
+
+/// Test proving and verifying with setups derived from parameters loaded from a file
+#[test]
+fn test_queries_with_setups_loaded_from_a_file() {
+    let alloc = Bump::new();
+    let sql = "select name from cats where age = 2;";
+    let tables: IndexMap<TableRef, Table<DoryScalar>> = indexmap! {
+        TableRef::from_names(None, "cats") => table(
+            vec![
+                borrowed_varchar("name", ["Chloe", "Margaret"], &alloc),
+                borrowed_tinyint("age", [13_i8, 2], &alloc),
+            ]
+        )
+    };
+    let expected_results: Vec<OwnedTable<DoryScalar>> =
+        vec![owned_table([varchar("name", ["Margaret"])])];
+
+    let file_path =
+        std::env::temp_dir().join(format!("e2e_public_parameters_{}.bin", std::process::id()));
+    PublicParameters::test_rand(5, &mut test_rng())
+        .save_to_file(&file_path)
+        .unwrap();
+    let public_parameters = PublicParameters::load_from_file(&file_path).unwrap();
+    std::fs::remove_file(&file_path).unwrap();
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+
+    posql_end_to_end_test::<DynamicDoryEvaluationProof>(
+        sql,
+        &tables,
+        &expected_results,
+        &prover_setup,
+        &verifier_setup,
+        &[],
+    );
+}
@@ -7,9 +7,11 @@ use crate::base::{
 };
 use arrow::{
     array::{
-        Array, ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, LargeBinaryArray, StringArray, TimestampMicrosecondArray,
-        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt8Array,
+        Array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Decimal128Array, Decimal256Array,
+        GenericBinaryArray, GenericStringArray, Int16Array, Int32Array, Int64Array, Int8Array,
+        LargeBinaryArray, LargeStringArray, OffsetSizeTrait, StringArray,
+        TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+        TimestampSecondArray, UInt8Array,
     },
     datatypes::{i256, DataType, TimeUnit as ArrowTimeUnit},
 };
@@ -59,6 +61,49 @@ pub enum ArrowArrayToColumnConversionError {
     },
 }
 
+/// The number of seconds in a day, used to convert arrow dates to timestamps
+pub(crate) const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Converts a range of an arrow binary array of either offset size into a `VarBinary` column
+fn binary_array_to_column<'a, S: Scalar, O: OffsetSizeTrait>(
+    array: &'a GenericBinaryArray<O>,
+    alloc: &'a Bump,
+    range: &Range<usize>,
+    precomputed_scals: Option<&'a [S]>,
+) -> Column<'a, S> {
+    let vals = alloc.alloc_slice_fill_with(range.end - range.start, |i| -> &'a [u8] {
+        array.value(range.start + i)
+    });
+
+    let scals = if let Some(scals) = precomputed_scals {
+        &scals[range.start..range.end]
+    } else {
+        alloc.alloc_slice_fill_with(vals.len(), |i| S::from_byte_slice_via_hash(vals[i]))
+    };
+
+    Column::VarBinary((vals, scals))
+}
+
+/// Converts a range of an arrow string array of either offset size into a `VarChar` column
+fn string_array_to_column<'a, S: Scalar, O: OffsetSizeTrait>(
+    array: &'a GenericStringArray<O>,
+    alloc: &'a Bump,
+    range: &Range<usize>,
+    precomputed_scals: Option<&'a [S]>,
+) -> Column<'a, S> {
+    let vals = alloc.alloc_slice_fill_with(range.end - range.start, |i| -> &'a str {
+        array.value(range.start + i)
+    });
+
+    let scals = if let Some(scals) = precomputed_scals {
+        &scals[range.start..range.end]
+    } else {
+        alloc.alloc_slice_fill_with(vals.len(), |i| -> S { vals[i].into() })
+    };
+
+    Column::VarChar((vals, scals))
+}
+
 /// This trait is used to provide utility functions to convert [`ArrayRef`]s into proof types (Column, Scalars, etc.)
 pub trait ArrayRefExt {
     /// Convert an [`ArrayRef`] into a Proof of SQL Column type
@@ -96,7 +141,9 @@ impl ArrayRefExt for ArrayRef {
     /// - For `DataType::Int64` and `DataType::Decimal128(38, 0)`, it slices the array
     ///   based on the provided range and returns the corresponding `BigInt` or `Int128` column.
     /// - Decimal256, converts arrow i256 columns into Decimal75(precision, scale) columns.
-    /// - For `DataType::Utf8`, it extracts string values and scalar values (if `precomputed_scals`
+    /// - For `DataType::Date32`, it converts the days since the epoch into a `TimestampTZ` column
+    ///   in seconds at UTC.
+    /// - For `DataType::Utf8` and `DataType::LargeUtf8`, it extracts string values and scalar values (if `precomputed_scals`
     ///   is provided) for the specified range and returns a `VarChar` column.
     ///
     /// # Panics
@@ -269,20 +316,59 @@ impl ArrayRefExt for ArrayRef {
                     }
                 }
             },
+            // Dates are represented as timestamps in seconds at midnight UTC
+            DataType::Date32 => {
+                if let Some(array) = self.as_any().downcast_ref::<Date32Array>() {
+                    let days = &array.values()[range.start..range.end];
+                    Ok(Column::TimestampTZ(
+                        PoSQLTimeUnit::Second,
+                        PoSQLTimeZone::utc(),
+                        alloc.alloc_slice_fill_with(days.len(), |i| {
+                            i64::from(days[i]) * SECONDS_PER_DAY
+                        }),
+                    ))
+                } else {
+                    Err(ArrowArrayToColumnConversionError::UnsupportedType {
+                        datatype: self.data_type().clone(),
+                    })
+                }
+            }
             DataType::Utf8 => {
                 if let Some(array) = self.as_any().downcast_ref::<StringArray>() {
-                    let vals = alloc
-                        .alloc_slice_fill_with(range.end - range.start, |i| -> &'a str {
-                            array.value(range.start + i)
-                        });
-
-                    let scals = if let Some(scals) = precomputed_scals {
-                        &scals[range.start..range.end]
-                    } else {
-                        alloc.alloc_slice_fill_with(vals.len(), |i| -> S { vals[i].into() })
-                    };
-
-                    Ok(Column::VarChar((vals, scals)))
+                    Ok(string_array_to_column(
+                        array,
+                        alloc,
+                        range,
+                        precomputed_scals,
+                    ))
+                } else {
+                    Err(ArrowArrayToColumnConversionError::UnsupportedType {
+                        datatype: self.data_type().clone(),
+                    })
+                }
+            }
+            DataType::LargeUtf8 => {
+                if let Some(array) = self.as_any().downcast_ref::<LargeStringArray>() {
+                    Ok(string_array_to_column(
+                        array,
+                        alloc,
+                        range,
+                        precomputed_scals,
+                    ))
+                } else {
+                    Err(ArrowArrayToColumnConversionError::UnsupportedType {
+                        datatype: self.data_type().clone(),
+                    })
+                }
+            }
+            DataType::Binary => {
+                if let Some(array) = self.as_any().downcast_ref::<BinaryArray>() {
+                    Ok(binary_array_to_column(
+                        array,
+                        alloc,
+                        range,
+                        precomputed_scals,
+                    ))
                 } else {
                     Err(ArrowArrayToColumnConversionError::UnsupportedType {
                         datatype: self.data_type().clone(),
@@ -291,20 +377,12 @@ impl ArrayRefExt for ArrayRef {
             }
             DataType::LargeBinary => {
                 if let Some(array) = self.as_any().downcast_ref::<LargeBinaryArray>() {
-                    let vals = alloc
-                        .alloc_slice_fill_with(range.end - range.start, |i| -> &'a [u8] {
-                            array.value(range.start + i)
-                        });
-
-                    let scals = if let Some(scals) = precomputed_scals {
-                        &scals[range.start..range.end]
-                    } else {
-                        alloc.alloc_slice_fill_with(vals.len(), |i| {
-                            S::from_byte_slice_via_hash(vals[i])
-                        })
-                    };
-
-                    Ok(Column::VarBinary((vals, scals)))
+                    Ok(binary_array_to_column(
+                        array,
+                        alloc,
+                        range,
+                        precomputed_scals,
+                    ))
                 } else {
                     Err(ArrowArrayToColumnConversionError::UnsupportedType {
                         datatype: self.data_type().clone(),
@@ -323,7 +401,10 @@ mod tests {
 
     use super::*;
     use crate::{
-        base::{database::OwnedColumn, scalar::test_scalar::TestScalar},
+        base::{
+            database::{ColumnType, OwnedColumn},
+            scalar::test_scalar::TestScalar,
+        },
         proof_primitive::dory::DoryScalar,
     };
     use alloc::sync::Arc;
@@ -347,6 +428,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_convert_date_array_to_timestamps_at_midnight_utc() {
+        let alloc = Bump::new();
+        // 1970-01-01, 2020-01-01 and 1969-12-31
+        let array: ArrayRef = Arc::new(Date32Array::from(vec![0, 18_262, -1]));
+
+        let result = array.to_column::<TestScalar>(&alloc, &(1..3), None);
+        assert_eq!(
+            result.unwrap(),
+            Column::TimestampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                &[1_577_836_800, -86_400]
+            )
+        );
+        assert_eq!(
+            ColumnType::try_from(array.data_type().clone()),
+            Ok(ColumnType::TimestampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc()
+            ))
+        );
+    }
+
     #[test]
     fn we_can_build_an_empty_column_from_an_empty_range_timestamp() {
         let alloc = Bump::new();
@@ -1017,6 +1122,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_convert_valid_large_string_array_refs_into_valid_columns() {
+        let alloc = Bump::new();
+        let data = vec!["ab", "-f34", "xyz"];
+        let scals: Vec<_> = data.iter().map(core::convert::Into::into).collect();
+        let array: ArrayRef = Arc::new(arrow::array::LargeStringArray::from(data.clone()));
+        assert_eq!(
+            array
+                .to_column::<DoryScalar>(&alloc, &(1..3), None)
+                .unwrap(),
+            Column::VarChar((&data[1..3], &scals[1..3]))
+        );
+        assert_eq!(
+            array
+                .to_column::<DoryScalar>(&alloc, &(0..3), Some(&scals))
+                .unwrap(),
+            Column::VarChar((&data[..], &scals[..]))
+        );
+    }
+
     #[test]
     fn we_can_convert_valid_binary_array_refs_into_valid_columns() {
         let alloc = Bump::new();
@@ -1035,6 +1160,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn we_can_convert_non_large_binary_array_refs_into_valid_columns() {
+        let alloc = Bump::new();
+        let data = vec![b"cd".as_slice(), b"".as_slice(), b"\xff\x00".as_slice()];
+        let scals: Vec<_> = data
+            .iter()
+            .copied()
+            .map(DoryScalar::from_byte_slice_via_hash)
+            .collect();
+        let array: ArrayRef = Arc::new(BinaryArray::from(data.clone()));
+        assert_eq!(
+            array
+                .to_column::<DoryScalar>(&alloc, &(1..3), None)
+                .unwrap(),
+            Column::VarBinary((&data[1..3], &scals[1..3]))
+        );
+        assert_eq!(
+            ColumnType::try_from(array.data_type().clone()),
+            Ok(ColumnType::VarBinary)
+        );
+    }
+
+    #[test]
+    fn we_cannot_convert_binary_array_refs_with_nulls() {
+        let alloc = Bump::new();
+        let array: ArrayRef = Arc::new(BinaryArray::from(vec![Some(b"ab".as_slice()), None]));
+        assert_eq!(
+            array.to_column::<TestScalar>(&alloc, &(0..2), None),
+            Err(ArrowArrayToColumnConversionError::ArrayContainsNulls)
+        );
+    }
+
     #[test]
     fn we_can_convert_valid_boolean_array_refs_into_valid_columns() {
         let alloc = Bump::new();
@@ -65,13 +65,17 @@ impl TryFrom<DataType> for ColumnType {
                     PoSQLTimeZone::try_from(&timezone_option)?,
                 ))
             }
-            DataType::Utf8 => Ok(ColumnType::VarChar),
-            DataType::LargeBinary => Ok(ColumnType::VarBinary),
+            DataType::Date32 => Ok(ColumnType::TimestampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+            )),
+            DataType::Utf8 | DataType::LargeUtf8 => Ok(ColumnType::VarChar),
+            DataType::Binary | DataType::LargeBinary => Ok(ColumnType::VarBinary),
             _ => Err(format!("Unsupported arrow data type {data_type:?}")),
         }
     }
 }
-/// Convert [`ColumnField`] values to arrow Field
+/// Convert [`ColumnField`] values to arrow Field, including their metadata
 impl From<&ColumnField> for Field {
     fn from(column_field: &ColumnField) -> Self {
         Field::new(
@@ -79,6 +83,20 @@ impl From<&ColumnField> for Field {
             (&column_field.data_type()).into(),
             false,
         )
+        .with_metadata(column_field.metadata().clone().into_iter().collect())
+    }
+}
+
+/// Convert arrow Field values to [`ColumnField`], including their metadata
+impl TryFrom<&Field> for ColumnField {
+    type Error = String;
+
+    fn try_from(field: &Field) -> Result<Self, Self::Error> {
+        Ok(ColumnField::new(
+            field.name().as_str().into(),
+            field.data_type().clone().try_into()?,
+        )
+        .with_metadata(field.metadata().clone().into_iter().collect()))
     }
 }
 
@@ -96,4 +114,26 @@ mod tests {
             prop_assert_eq!(actual, column_type);
         }
     }
+
+    #[test]
+    fn we_can_roundtrip_a_column_field_with_metadata_through_an_arrow_field() {
+        let column_field = ColumnField::new("price".into(), ColumnType::BigInt).with_metadata(
+            [
+                ("unit".to_string(), "cents".to_string()),
+                ("description".to_string(), "price of a toy".to_string()),
+            ]
+            .into(),
+        );
+        let field = Field::from(&column_field);
+        assert_eq!(field.metadata()["unit"], "cents");
+        let actual = ColumnField::try_from(&field).unwrap();
+        assert_eq!(actual, column_field);
+        assert_eq!(actual.metadata(), column_field.metadata());
+    }
+
+    #[test]
+    fn we_cannot_convert_an_arrow_field_of_an_unsupported_type_to_a_column_field() {
+        let field = Field::new("a", DataType::Float32, false);
+        assert!(ColumnField::try_from(&field).is_err());
+    }
 }
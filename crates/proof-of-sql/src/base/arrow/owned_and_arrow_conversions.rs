@@ -5,14 +5,22 @@
 //! `Boolean` <-> `Boolean`
 //! `BigInt` <-> `Int64`
 //! `VarChar` <-> `Utf8/String`
+//! `VarChar` <- `LargeUtf8`
 //! `Int128` <-> `Decimal128(38,0)`
 //! `Decimal75` <-> `S`
+//! `TimestampTZ` <-> `Timestamp`
+//! `TimestampTZ(Second, UTC)` <- `Date32`
+//! `VarBinary` <-> `LargeBinary`
+//! `VarBinary` <- `Binary`
 //!
 //! Note: this converts `Int128` values to `Decimal128(38,0)`, which are backed by `i128`.
 //! This is because there is no `Int128` type in Arrow.
 //! This does not check that the values are less than 39 digits.
 //! However, the actual arrow backing `i128` is the correct value.
-use super::scalar_and_i256_conversions::{convert_i256_to_scalar, convert_scalar_to_i256};
+use super::{
+    arrow_array_to_column_conversion::SECONDS_PER_DAY,
+    scalar_and_i256_conversions::{convert_i256_to_scalar, convert_scalar_to_i256},
+};
 use crate::base::{
     database::{OwnedColumn, OwnedTable, OwnedTableError},
     map::IndexMap,
@@ -23,9 +31,10 @@ use crate::base::{
 use alloc::sync::Arc;
 use arrow::{
     array::{
-        ArrayRef, BooleanArray, Decimal128Array, Decimal256Array, Int16Array, Int32Array,
-        Int64Array, Int8Array, LargeBinaryArray, StringArray, TimestampMicrosecondArray,
-        TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt8Array,
+        ArrayRef, BinaryArray, BooleanArray, Date32Array, Decimal128Array, Decimal256Array,
+        Int16Array, Int32Array, Int64Array, Int8Array, LargeBinaryArray, LargeStringArray,
+        StringArray, TimestampMicrosecondArray, TimestampMillisecondArray,
+        TimestampNanosecondArray, TimestampSecondArray, UInt8Array,
     },
     datatypes::{i256, DataType, Schema, SchemaRef, TimeUnit as ArrowTimeUnit},
     error::ArrowError,
@@ -147,6 +156,7 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
     /// - `Decimal128Array` when converting from `DataType::Decimal128(38, 0)`.
     /// - `Decimal256Array` when converting from `DataType::Decimal256` if precision is less than or equal to 75.
     /// - `StringArray` when converting from `DataType::Utf8`.
+    /// - `LargeStringArray` when converting from `DataType::LargeUtf8`.
     fn try_from(value: &ArrayRef) -> Result<Self, Self::Error> {
         match &value.data_type() {
             // Arrow uses a bit-packed representation for booleans.
@@ -230,6 +240,24 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
                     .map(|s| s.unwrap().to_string())
                     .collect(),
             )),
+            DataType::LargeUtf8 => Ok(Self::VarChar(
+                value
+                    .as_any()
+                    .downcast_ref::<LargeStringArray>()
+                    .unwrap()
+                    .iter()
+                    .map(|s| s.unwrap().to_string())
+                    .collect(),
+            )),
+            DataType::Binary => Ok(Self::VarBinary(
+                value
+                    .as_any()
+                    .downcast_ref::<BinaryArray>()
+                    .unwrap()
+                    .iter()
+                    .map(|s| s.map(<[u8]>::to_vec).unwrap())
+                    .collect(),
+            )),
             DataType::LargeBinary => Ok(Self::VarBinary(
                 value
                     .as_any()
@@ -239,6 +267,18 @@ impl<S: Scalar> TryFrom<&ArrayRef> for OwnedColumn<S> {
                     .map(|s| s.map(<[u8]>::to_vec).unwrap())
                     .collect(),
             )),
+            DataType::Date32 => Ok(OwnedColumn::TimestampTZ(
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                value
+                    .as_any()
+                    .downcast_ref::<Date32Array>()
+                    .unwrap()
+                    .values()
+                    .iter()
+                    .map(|&days| i64::from(days) * SECONDS_PER_DAY)
+                    .collect(),
+            )),
             DataType::Timestamp(time_unit, timezone) => match time_unit {
                 ArrowTimeUnit::Second => {
                     let array = value
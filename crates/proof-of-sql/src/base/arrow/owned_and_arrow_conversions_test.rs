@@ -2,13 +2,14 @@ use super::owned_and_arrow_conversions::OwnedArrowConversionError;
 use crate::base::{
     database::{owned_table_utility::*, OwnedColumn, OwnedTable},
     map::IndexMap,
+    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
     scalar::test_scalar::TestScalar,
 };
 use alloc::sync::Arc;
 use arrow::{
     array::{
-        ArrayRef, BooleanArray, Decimal128Array, Float32Array, Int64Array, LargeBinaryArray,
-        StringArray,
+        ArrayRef, BinaryArray, BooleanArray, Date32Array, Decimal128Array, Float32Array,
+        Int64Array, LargeBinaryArray, LargeStringArray, StringArray,
     },
     datatypes::{DataType, Field, Schema},
     record_batch::RecordBatch,
@@ -101,6 +102,52 @@ fn we_get_an_unsupported_type_error_when_trying_to_convert_from_a_float32_array_
     ));
 }
 
+#[test]
+fn we_can_convert_a_date_array_ref_to_a_timestamp_owned_column() {
+    let array_ref: ArrayRef = Arc::new(Date32Array::from(vec![0, 18_262, -1]));
+    assert_eq!(
+        OwnedColumn::<TestScalar>::try_from(array_ref).unwrap(),
+        OwnedColumn::TimestampTZ(
+            PoSQLTimeUnit::Second,
+            PoSQLTimeZone::utc(),
+            vec![0, 1_577_836_800, -86_400]
+        )
+    );
+}
+
+#[test]
+fn we_can_convert_a_binary_array_ref_to_a_varbinary_owned_column() {
+    let data = vec![b"ab".as_slice(), b"".as_slice(), b"\x00".as_slice()];
+    let owned_column =
+        OwnedColumn::<TestScalar>::try_from(Arc::new(BinaryArray::from(data.clone())) as ArrayRef)
+            .unwrap();
+    assert_eq!(
+        owned_column,
+        OwnedColumn::VarBinary(data.iter().copied().map(<[u8]>::to_vec).collect())
+    );
+    assert_eq!(
+        ArrayRef::from(owned_column),
+        Arc::new(LargeBinaryArray::from(data)) as ArrayRef
+    );
+}
+
+#[test]
+fn we_can_convert_a_large_string_array_ref_to_a_varchar_owned_column() {
+    let data = vec!["ab", "", "-f34"];
+    let owned_column = OwnedColumn::<TestScalar>::try_from(Arc::new(LargeStringArray::from(
+        data.clone(),
+    )) as ArrayRef)
+    .unwrap();
+    assert_eq!(
+        owned_column,
+        OwnedColumn::VarChar(data.iter().copied().map(String::from).collect())
+    );
+    assert_eq!(
+        ArrayRef::from(owned_column),
+        Arc::new(StringArray::from(data)) as ArrayRef
+    );
+}
+
 fn we_can_convert_between_owned_table_and_record_batch_impl(
     owned_table: &OwnedTable<TestScalar>,
     record_batch: &RecordBatch,
@@ -0,0 +1,112 @@
+use super::Commitment;
+use crate::base::database::{ColumnRef, CommitmentAccessor};
+use snafu::Snafu;
+
+/// The commitment of a column differs between two accessors.
+#[derive(Snafu, Debug, PartialEq, Eq)]
+#[snafu(display(
+    "commitments of column {}.{} differ between accessors",
+    column_ref.table_ref(),
+    column_ref.column_id()
+))]
+pub struct CommitmentMismatch<C: Commitment> {
+    /// The column whose commitments differ
+    pub column_ref: ColumnRef,
+    /// The commitment of the column in the first accessor
+    pub left: C,
+    /// The commitment of the column in the second accessor
+    pub right: C,
+}
+
+/// Checks that two accessors have equal commitments for each of the given columns.
+///
+/// This can be used to confirm that, e.g., a prover and a verifier are looking at the same data.
+///
+/// # Errors
+/// Returns the first column, in iteration order, whose commitments differ.
+pub fn check_commitments_equal<'a, C: Commitment>(
+    left: &impl CommitmentAccessor<C>,
+    right: &impl CommitmentAccessor<C>,
+    column_refs: impl IntoIterator<Item = &'a ColumnRef>,
+) -> Result<(), CommitmentMismatch<C>> {
+    column_refs.into_iter().try_for_each(|column_ref| {
+        let table_ref = column_ref.table_ref();
+        let column_id = column_ref.column_id();
+        let left = left.get_commitment(&table_ref, &column_id);
+        let right = right.get_commitment(&table_ref, &column_id);
+        if left == right {
+            Ok(())
+        } else {
+            Err(CommitmentMismatch {
+                column_ref: column_ref.clone(),
+                left,
+                right,
+            })
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{
+        commitment::naive_evaluation_proof::NaiveEvaluationProof,
+        database::{owned_table_utility::*, ColumnType, OwnedTableTestAccessor, TableRef},
+    };
+    use alloc::string::ToString;
+
+    fn accessor_with_b(b: [i64; 3]) -> OwnedTableTestAccessor<'static, NaiveEvaluationProof> {
+        let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_empty_with_setup(());
+        accessor.add_table(
+            TableRef::new("sxt", "t"),
+            owned_table([bigint("a", [1, 2, 3]), bigint("b", b)]),
+            0,
+        );
+        accessor.add_table(
+            TableRef::new("sxt", "u"),
+            owned_table([varchar("c", ["x", "y"])]),
+            0,
+        );
+        accessor
+    }
+
+    #[test]
+    fn we_can_check_that_accessors_have_equal_commitments() {
+        let column_refs = [
+            ColumnRef::new(TableRef::new("sxt", "t"), "a".into(), ColumnType::BigInt),
+            ColumnRef::new(TableRef::new("sxt", "t"), "b".into(), ColumnType::BigInt),
+            ColumnRef::new(TableRef::new("sxt", "u"), "c".into(), ColumnType::VarChar),
+        ];
+        let left = accessor_with_b([4, 5, 6]);
+        let right = accessor_with_b([4, 5, 6]);
+        assert_eq!(check_commitments_equal(&left, &right, &column_refs), Ok(()));
+        assert_eq!(check_commitments_equal(&left, &right, &[]), Ok(()));
+    }
+
+    #[test]
+    fn we_can_find_the_first_mismatched_commitment_between_accessors() {
+        let a = ColumnRef::new(TableRef::new("sxt", "t"), "a".into(), ColumnType::BigInt);
+        let b = ColumnRef::new(TableRef::new("sxt", "t"), "b".into(), ColumnType::BigInt);
+        let c = ColumnRef::new(TableRef::new("sxt", "u"), "c".into(), ColumnType::VarChar);
+        let left = accessor_with_b([4, 5, 6]);
+        let right = accessor_with_b([4, 5, 7]);
+
+        let err = check_commitments_equal(&left, &right, [&a, &b, &c]).unwrap_err();
+        assert_eq!(err.column_ref, b);
+        assert_eq!(
+            err.left,
+            left.get_commitment(&TableRef::new("sxt", "t"), &"b".into())
+        );
+        assert_eq!(
+            err.right,
+            right.get_commitment(&TableRef::new("sxt", "t"), &"b".into())
+        );
+        assert_ne!(err.left, err.right);
+        assert_eq!(
+            err.to_string(),
+            "commitments of column sxt.t.b differ between accessors"
+        );
+
+        assert_eq!(check_commitments_equal(&left, &right, [&a, &c]), Ok(()));
+    }
+}
@@ -70,4 +70,9 @@ pub trait CommitmentEvaluationProof {
         table_length: usize,
         setup: &Self::VerifierPublicSetup<'_>,
     ) -> Result<(), Self::Error>;
+    /// Returns the number of rows, including the offset, that a table may have to be proven with `setup`,
+    /// or `None` if the setup does not limit the table size.
+    fn max_rows_for_setup(_setup: &Self::ProverPublicSetup<'_>) -> Option<usize> {
+        None
+    }
 }
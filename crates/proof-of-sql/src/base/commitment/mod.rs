@@ -36,6 +36,9 @@ pub use table_commitment::{
 mod query_commitments;
 pub use query_commitments::{QueryCommitments, QueryCommitmentsExt};
 
+mod commitment_accessor_equality;
+pub use commitment_accessor_equality::{check_commitments_equal, CommitmentMismatch};
+
 /// Module for providing a mock commitment.
 #[cfg(test)]
 pub mod naive_commitment;
@@ -79,6 +79,10 @@ impl<C: Commitment> MetadataAccessor for QueryCommitments<C> {
         let table_commitment = self.get(&table_ref).unwrap();
         table_commitment.range().start
     }
+
+    fn table_row_count(&self, table_ref: &TableRef) -> Option<usize> {
+        self.get(table_ref).map(TableCommitment::num_rows)
+    }
 }
 
 /// # Panics
@@ -120,6 +124,10 @@ impl<C: Commitment> SchemaAccessor for QueryCommitments<C> {
             })
             .collect()
     }
+
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.contains_key(table_ref)
+    }
 }
 
 #[cfg(all(test, feature = "blitzar"))]
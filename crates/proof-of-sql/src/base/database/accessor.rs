@@ -22,6 +22,14 @@ pub trait MetadataAccessor {
     /// If the data span has its first row starting at the ith table row,
     /// this `get_offset` should then return `i`.
     fn get_offset(&self, table_ref: &TableRef) -> usize;
+
+    /// Return the number of rows of the table, or `None` if the table is unknown
+    ///
+    /// Unlike [`MetadataAccessor::get_length`], this does not assume that the table exists.
+    /// The default implementation returns `None`, as accessors can not in general tell whether a table exists.
+    fn table_row_count(&self, _table_ref: &TableRef) -> Option<usize> {
+        None
+    }
 }
 
 /// Access commitments of database columns.
@@ -134,6 +142,17 @@ pub trait SchemaAccessor {
     /// Precondition 1: the table must exist and be tamperproof.
     /// Precondition 2: `table_name` must be lowercase.
     fn lookup_schema(&self, table_ref: &TableRef) -> Vec<(Ident, ColumnType)>;
+
+    /// Check whether the specified table exists
+    ///
+    /// Unlike the other methods this may be called for tables which do not exist,
+    /// e.g. to validate a query before planning it.
+    /// The default implementation assumes that every table exists.
+    ///
+    /// Precondition: `table_ref` must be lowercase.
+    fn has_table(&self, _table_ref: &TableRef) -> bool {
+        true
+    }
 }
 
 /// The simplest implementation of `SchemaAccessor`.
@@ -174,6 +193,10 @@ impl SchemaAccessor for SchemaAccessorImpl {
             .expect("Table does not exist in schema accessor.")
             .clone()
     }
+
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.table_schema_lookup.contains_key(table_ref)
+    }
 }
 
 #[cfg(test)]
@@ -252,4 +275,13 @@ mod tests {
         let not_a_table = TableRef::new("schema", "not_a_table");
         accessor.lookup_schema(&not_a_table);
     }
+
+    #[test]
+    fn test_has_table() {
+        let accessor = sample_schema_accessor();
+        assert!(accessor.has_table(&TableRef::new("schema", "table1")));
+        assert!(accessor.has_table(&TableRef::new("schema", "table2")));
+        assert!(!accessor.has_table(&TableRef::new("schema", "not_a_table")));
+        assert!(!accessor.has_table(&TableRef::new("", "table1")));
+    }
 }
@@ -1,13 +1,46 @@
 //! This module provides utility functions for working with Arrow schemas in the context of Proof of SQL.
 //! It includes functionality to convert Arrow schemas to PoSQL-compatible formats.
 
-use alloc::sync::Arc;
+use super::ColumnType;
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
 use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use core::fmt;
+use snafu::Snafu;
+
+/// A column of an Arrow schema whose data type can not be represented in Proof of SQL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncompatibleColumn {
+    /// The name of the column
+    pub name: String,
+    /// The Arrow data type of the column
+    pub data_type: DataType,
+}
+
+impl fmt::Display for IncompatibleColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.data_type)
+    }
+}
+
+/// Errors from converting an Arrow schema to a PoSQL-compatible schema
+#[derive(Snafu, Debug, Clone, PartialEq, Eq)]
+#[snafu(display(
+    "arrow schema has columns with types unsupported by Proof of SQL: {}",
+    columns.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+))]
+pub struct IncompatibleSchemaError {
+    /// Every incompatible column, in schema order
+    pub columns: Vec<IncompatibleColumn>,
+}
 
 /// Converts an Arrow schema to a PoSQL-compatible schema.
 ///
 /// This function takes an Arrow `SchemaRef` and returns a new `SchemaRef` where
-/// floating-point data types (Float16, Float32, Float64) are converted to Decimal256(75, 30).
+/// floating-point data types (Float16, Float32, Float64) are converted to Decimal256(20, 10).
 /// Other data types remain unchanged.
 ///
 /// # Arguments
@@ -17,8 +50,14 @@ use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
 /// # Returns
 ///
 /// A new `SchemaRef` with PoSQL-compatible data types.
-#[must_use]
-pub fn get_posql_compatible_schema(schema: &SchemaRef) -> SchemaRef {
+///
+/// # Errors
+///
+/// Returns an [`IncompatibleSchemaError`] naming every column whose type still can not be
+/// represented after the conversion, so that callers can drop or cast those columns up front.
+pub fn get_posql_compatible_schema(
+    schema: &SchemaRef,
+) -> Result<SchemaRef, IncompatibleSchemaError> {
     let new_fields: Vec<Field> = schema
         .fields()
         .iter()
@@ -33,5 +72,69 @@ pub fn get_posql_compatible_schema(schema: &SchemaRef) -> SchemaRef {
         })
         .collect();
 
-    Arc::new(Schema::new(new_fields))
+    let columns: Vec<IncompatibleColumn> = new_fields
+        .iter()
+        .filter(|field| ColumnType::try_from(field.data_type().clone()).is_err())
+        .map(|field| IncompatibleColumn {
+            name: field.name().clone(),
+            data_type: field.data_type().clone(),
+        })
+        .collect();
+    if !columns.is_empty() {
+        return Err(IncompatibleSchemaError { columns });
+    }
+
+    Ok(Arc::new(Schema::new(new_fields)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::datatypes::TimeUnit;
+
+    #[test]
+    fn we_can_convert_float_columns_to_decimals() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Float64, false),
+            Field::new("c", DataType::Utf8, false),
+        ]));
+        let expected = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Decimal256(20, 10), false),
+            Field::new("c", DataType::Utf8, false),
+        ]));
+        assert_eq!(get_posql_compatible_schema(&schema), Ok(expected));
+    }
+
+    #[test]
+    fn we_cannot_convert_a_schema_with_unsupported_columns() {
+        let list_type = DataType::List(Arc::new(Field::new("item", DataType::Int64, true)));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("price", DataType::Float32, false),
+            Field::new("tags", list_type.clone(), false),
+            Field::new("id", DataType::Int64, false),
+            Field::new("ratio", DataType::Float16, false),
+            Field::new("time", DataType::Time32(TimeUnit::Second), false),
+        ]));
+        let err = get_posql_compatible_schema(&schema).unwrap_err();
+        assert_eq!(
+            err.columns,
+            vec![
+                IncompatibleColumn {
+                    name: "tags".to_string(),
+                    data_type: list_type,
+                },
+                IncompatibleColumn {
+                    name: "time".to_string(),
+                    data_type: DataType::Time32(TimeUnit::Second),
+                },
+            ]
+        );
+        let message = err.to_string();
+        assert!(message.starts_with(
+            "arrow schema has columns with types unsupported by Proof of SQL: tags (List("
+        ));
+        assert!(message.ends_with(", time (Time32(Second))"));
+    }
 }
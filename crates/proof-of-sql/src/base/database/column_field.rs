@@ -1,22 +1,41 @@
 use super::ColumnType;
+use alloc::{collections::BTreeMap, string::String};
+use core::hash::{Hash, Hasher};
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::Ident;
 
 /// This type is used to represent the metadata
 /// of a column in a table. Namely: it's name and type.
 ///
+/// A field may also carry free-form metadata, e.g. units or descriptions, which is passed through
+/// for display. It has no effect on proofs: it is ignored by equality and hashing and is not
+/// serialized, so it never reaches the transcript.
+///
 /// This is the analog of a `Field` in Apache Arrow.
-#[derive(Debug, PartialEq, Eq, Clone, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColumnField {
     name: Ident,
     data_type: ColumnType,
+    #[serde(skip)]
+    metadata: BTreeMap<String, String>,
 }
 
 impl ColumnField {
     /// Create a new `ColumnField` from a name and a type
     #[must_use]
     pub fn new(name: Ident, data_type: ColumnType) -> ColumnField {
-        ColumnField { name, data_type }
+        ColumnField {
+            name,
+            data_type,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the field with its metadata replaced by `metadata`
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: BTreeMap<String, String>) -> ColumnField {
+        self.metadata = metadata;
+        self
     }
 
     /// Returns the name of the column
@@ -30,4 +49,66 @@ impl ColumnField {
     pub fn data_type(&self) -> ColumnType {
         self.data_type
     }
+
+    /// Returns the metadata of the column
+    #[must_use]
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+}
+
+impl PartialEq for ColumnField {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.data_type == other.data_type
+    }
+}
+
+impl Eq for ColumnField {}
+
+impl Hash for ColumnField {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.data_type.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{map::IndexSet, try_standard_binary_serialization};
+    use alloc::string::ToString;
+
+    fn with_unit(field: ColumnField, unit: &str) -> ColumnField {
+        field.with_metadata(BTreeMap::from([("unit".to_string(), unit.to_string())]))
+    }
+
+    #[test]
+    fn we_can_attach_metadata_to_a_column_field() {
+        let field = ColumnField::new("price".into(), ColumnType::BigInt);
+        assert!(field.metadata().is_empty());
+        let field = with_unit(field, "cents");
+        assert_eq!(field.metadata()["unit"], "cents");
+        assert_eq!(field.name(), Ident::new("price"));
+        assert_eq!(field.data_type(), ColumnType::BigInt);
+    }
+
+    #[test]
+    fn metadata_is_ignored_by_equality_hashing_and_serialization() {
+        let plain = ColumnField::new("price".into(), ColumnType::BigInt);
+        let cents = with_unit(plain.clone(), "cents");
+        let dollars = with_unit(plain.clone(), "dollars");
+        assert_eq!(cents, plain);
+        assert_eq!(cents, dollars);
+        assert_ne!(cents, ColumnField::new("cost".into(), ColumnType::BigInt));
+
+        let fields: IndexSet<_> = [plain.clone(), cents.clone(), dollars]
+            .into_iter()
+            .collect();
+        assert_eq!(fields.len(), 1);
+
+        assert_eq!(
+            try_standard_binary_serialization(&cents).unwrap(),
+            try_standard_binary_serialization(&plain).unwrap()
+        );
+    }
 }
@@ -107,7 +107,8 @@ impl ColumnType {
     }
 
     /// Returns the number of bits in the integer type if it is an integer type. Otherwise, return None.
-    fn to_integer_bits(self) -> Option<usize> {
+    #[must_use]
+    pub fn integer_bit_width(&self) -> Option<u32> {
         match self {
             ColumnType::Uint8 | ColumnType::TinyInt => Some(8),
             ColumnType::SmallInt => Some(16),
@@ -121,7 +122,7 @@ impl ColumnType {
     /// Returns the [`ColumnType`] of the signed integer type with the given number of bits if it is a valid integer type.
     ///
     /// Otherwise, return None.
-    fn from_signed_integer_bits(bits: usize) -> Option<Self> {
+    fn from_signed_integer_bits(bits: u32) -> Option<Self> {
         match bits {
             8 => Some(ColumnType::TinyInt),
             16 => Some(ColumnType::SmallInt),
@@ -135,7 +136,7 @@ impl ColumnType {
     /// Returns the [`ColumnType`] of the unsigned integer type with the given number of bits if it is a valid integer type.
     ///
     /// Otherwise, return None.
-    fn from_unsigned_integer_bits(bits: usize) -> Option<Self> {
+    fn from_unsigned_integer_bits(bits: u32) -> Option<Self> {
         match bits {
             8 => Some(ColumnType::Uint8),
             _ => None,
@@ -151,9 +152,9 @@ impl ColumnType {
         if !self.is_integer() || !other.is_integer() {
             return None;
         }
-        self.to_integer_bits().and_then(|self_bits| {
+        self.integer_bit_width().and_then(|self_bits| {
             other
-                .to_integer_bits()
+                .integer_bit_width()
                 .and_then(|other_bits| Self::from_signed_integer_bits(self_bits.max(other_bits)))
         })
     }
@@ -167,9 +168,9 @@ impl ColumnType {
         if !self.is_integer() || !other.is_integer() {
             return None;
         }
-        self.to_integer_bits().and_then(|self_bits| {
+        self.integer_bit_width().and_then(|self_bits| {
             other
-                .to_integer_bits()
+                .integer_bit_width()
                 .and_then(|other_bits| Self::from_unsigned_integer_bits(self_bits.max(other_bits)))
         })
     }
@@ -644,6 +645,73 @@ mod tests {
         assert_eq!(ColumnType::VarChar.min_scalar::<TestScalar>(), None);
     }
 
+    #[test]
+    fn we_can_get_the_signedness_and_bit_width_of_integer_types() {
+        for (column_type, is_signed, bit_width) in [
+            (ColumnType::Uint8, false, 8),
+            (ColumnType::TinyInt, true, 8),
+            (ColumnType::SmallInt, true, 16),
+            (ColumnType::Int, true, 32),
+            (ColumnType::BigInt, true, 64),
+            (ColumnType::Int128, true, 128),
+        ] {
+            assert!(column_type.is_integer());
+            assert_eq!(column_type.is_signed(), is_signed);
+            assert_eq!(column_type.integer_bit_width(), Some(bit_width));
+        }
+        for column_type in [
+            ColumnType::Boolean,
+            ColumnType::Scalar,
+            ColumnType::VarChar,
+            ColumnType::VarBinary,
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+            ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc()),
+        ] {
+            assert!(!column_type.is_integer());
+            assert_eq!(column_type.integer_bit_width(), None);
+        }
+        assert!(ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc()).is_signed());
+        assert!(!ColumnType::Decimal75(Precision::new(10).unwrap(), 2).is_signed());
+    }
+
+    #[test]
+    fn we_can_get_the_max_integer_type_of_two_integer_types() {
+        let signed = [
+            ColumnType::TinyInt,
+            ColumnType::SmallInt,
+            ColumnType::Int,
+            ColumnType::BigInt,
+            ColumnType::Int128,
+        ];
+        for (i, lhs) in signed.iter().enumerate() {
+            for (j, rhs) in signed.iter().enumerate() {
+                assert_eq!(lhs.max_integer_type(rhs), Some(signed[i.max(j)]));
+            }
+            // Mixing with an unsigned type promotes to a signed type of at least the same width
+            assert_eq!(lhs.max_integer_type(&ColumnType::Uint8), Some(*lhs));
+            assert_eq!(ColumnType::Uint8.max_integer_type(lhs), Some(*lhs));
+            assert_eq!(lhs.max_integer_type(&ColumnType::VarChar), None);
+            assert_eq!(ColumnType::Scalar.max_integer_type(lhs), None);
+        }
+        assert_eq!(
+            ColumnType::Uint8.max_integer_type(&ColumnType::Uint8),
+            Some(ColumnType::TinyInt)
+        );
+        assert_eq!(
+            ColumnType::Uint8.max_unsigned_integer_type(&ColumnType::Uint8),
+            Some(ColumnType::Uint8)
+        );
+        assert_eq!(
+            ColumnType::Uint8.max_unsigned_integer_type(&ColumnType::SmallInt),
+            None
+        );
+        assert_eq!(
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 0)
+                .max_integer_type(&ColumnType::BigInt),
+            None
+        );
+    }
+
     #[test]
     fn we_can_get_sqrt_negative_min() {
         for column_type in [
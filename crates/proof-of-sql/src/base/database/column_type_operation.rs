@@ -7,10 +7,22 @@ use alloc::string::ToString;
 // For decimal type manipulation please refer to
 // https://learn.microsoft.com/en-us/sql/t-sql/data-types/precision-scale-and-length-transact-sql?view=sql-server-ver16
 
+/// Returns the type a boolean operand takes part in arithmetic as
+///
+/// Booleans are treated as the integers `0` and `1`, i.e. as `UINT8`. All other types are unchanged.
+fn promote_boolean_to_integer(column_type: ColumnType) -> ColumnType {
+    match column_type {
+        ColumnType::Boolean => ColumnType::Uint8,
+        _ => column_type,
+    }
+}
+
 /// Determine the output type of an add or subtract operation if it is possible
 /// to add or subtract the two input types. If the types are not compatible, return
 /// an error.
 ///
+/// Boolean operands are promoted to `UINT8`, so that `true` and `false` count as `1` and `0`.
+///
 /// # Panics
 ///
 /// - Panics if `lhs` or `rhs` does not have a precision or scale when they are expected to be numeric types.
@@ -19,20 +31,26 @@ pub fn try_add_subtract_column_types(
     lhs: ColumnType,
     rhs: ColumnType,
 ) -> ColumnOperationResult<ColumnType> {
-    if !lhs.is_numeric() || !rhs.is_numeric() {
+    let left = promote_boolean_to_integer(lhs);
+    let right = promote_boolean_to_integer(rhs);
+    if !left.is_numeric() || !right.is_numeric() {
         return Err(ColumnOperationError::BinaryOperationInvalidColumnType {
             operator: "+/-".to_string(),
             left_type: lhs,
             right_type: rhs,
         });
     }
-    if lhs == ColumnType::Scalar || rhs == ColumnType::Scalar {
+    if left == ColumnType::Scalar || right == ColumnType::Scalar {
         Ok(ColumnType::Scalar)
     } else {
-        let left_precision_value = lhs.precision_value().expect("Numeric types have precision");
-        let right_precision_value = rhs.precision_value().expect("Numeric types have precision");
-        let left_scale = lhs.scale().expect("Numeric types have scale");
-        let right_scale = rhs.scale().expect("Numeric types have scale");
+        let left_precision_value = left
+            .precision_value()
+            .expect("Numeric types have precision");
+        let right_precision_value = right
+            .precision_value()
+            .expect("Numeric types have precision");
+        let left_scale = left.scale().expect("Numeric types have scale");
+        let right_scale = right.scale().expect("Numeric types have scale");
         if left_scale != right_scale {
             return Err(ColumnOperationError::BinaryOperationInvalidColumnType {
                 operator: "+/-".to_string(),
@@ -47,10 +65,74 @@ pub fn try_add_subtract_column_types(
     }
 }
 
+/// Determine the common decimal type two numeric types have to be scaled to before they can be
+/// compared or combined, along with the scale shift needed for each side
+///
+/// The common type has the larger of the two scales and enough precision for the integer digits
+/// of either side. The shifts are the number of digits each side has to be scaled up by, i.e. the
+/// side has to be multiplied by `10^shift`, to reach the common scale. One of them is always zero.
+///
+/// # Errors
+///
+/// - Returns [`ColumnOperationError::BinaryOperationInvalidColumnType`] if either type is not
+///   numeric or is a `SCALAR`, which has no scale to align.
+/// - Returns [`ColumnOperationError::DecimalConversionError`] if the common type would need more
+///   than 75 digits of precision.
+///
+/// # Panics
+///
+/// Panics if `lhs` or `rhs` does not have a precision or scale when they are expected to be numeric types.
+pub fn align_decimal_scales(
+    lhs: ColumnType,
+    rhs: ColumnType,
+) -> ColumnOperationResult<(ColumnType, i8, i8)> {
+    if !lhs.is_numeric()
+        || !rhs.is_numeric()
+        || lhs == ColumnType::Scalar
+        || rhs == ColumnType::Scalar
+    {
+        return Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+            operator: "scale alignment".to_string(),
+            left_type: lhs,
+            right_type: rhs,
+        });
+    }
+    let left_precision_value =
+        i16::from(lhs.precision_value().expect("Numeric types have precision"));
+    let right_precision_value =
+        i16::from(rhs.precision_value().expect("Numeric types have precision"));
+    let left_scale = lhs.scale().expect("Numeric types have scale");
+    let right_scale = rhs.scale().expect("Numeric types have scale");
+    let scale = left_scale.max(right_scale);
+    let precision_value: i16 = i16::from(scale)
+        + (left_precision_value - i16::from(left_scale))
+            .max(right_precision_value - i16::from(right_scale));
+    let precision = u8::try_from(precision_value)
+        .ok()
+        .and_then(|value| Precision::new(value).ok())
+        .ok_or(ColumnOperationError::DecimalConversionError {
+            source: DecimalError::InvalidPrecision {
+                error: precision_value.to_string(),
+            },
+        })?;
+    // Each shift is at most the common precision, so it fits into an `i8`
+    let shift = |from_scale: i8| {
+        i8::try_from(i16::from(scale) - i16::from(from_scale))
+            .expect("Shift is bounded by the precision")
+    };
+    Ok((
+        ColumnType::Decimal75(precision, scale),
+        shift(left_scale),
+        shift(right_scale),
+    ))
+}
+
 /// Determine the output type of an add or subtract operation if it is possible
 /// to add or subtract the two input types. Scaling is allowed. If the types are not compatible, return
 /// an error.
 ///
+/// Both sides are first aligned to a common scale with [`align_decimal_scales`].
+///
 /// # Panics
 ///
 /// - Panics if `lhs` or `rhs` does not have a precision or scale when they are expected to be numeric types.
@@ -69,23 +151,18 @@ pub fn try_add_subtract_column_types_with_scaling(
     if lhs == ColumnType::Scalar || rhs == ColumnType::Scalar {
         Ok(ColumnType::Scalar)
     } else {
-        let left_precision_value =
-            i16::from(lhs.precision_value().expect("Numeric types have precision"));
-        let right_precision_value =
-            i16::from(rhs.precision_value().expect("Numeric types have precision"));
-        let left_scale = lhs.scale().expect("Numeric types have scale");
-        let right_scale = rhs.scale().expect("Numeric types have scale");
-        let scale = left_scale.max(right_scale);
-        let precision_value: i16 = (i16::from(scale)
-            + (left_precision_value - i16::from(left_scale))
-                .max(right_precision_value - i16::from(right_scale))
-            + 1_i16)
-            .min(75_i16);
-        let precision = Precision::new(
-            u8::try_from(precision_value).expect("Precision value should be in range 0-75"),
-        )
-        .expect("Precision value should be in range 0-75");
-        Ok(ColumnType::Decimal75(precision, scale))
+        let (aligned_type, _, _) = align_decimal_scales(lhs, rhs)?;
+        let precision_value = (aligned_type
+            .precision_value()
+            .expect("Decimals have precision")
+            + 1_u8)
+            .min(75_u8);
+        let precision =
+            Precision::new(precision_value).expect("Precision value should be in range 0-75");
+        Ok(ColumnType::Decimal75(
+            precision,
+            aligned_type.scale().expect("Decimals have scale"),
+        ))
     }
 }
 
@@ -317,6 +394,21 @@ pub fn try_inequality_types(lhs: ColumnType, rhs: ColumnType) -> ColumnOperation
     })
 }
 
+/// Determine the output type of a `SUM` aggregate over an expression of type `datatype`
+///
+/// Numeric sums keep the type of their input. Summing a boolean expression counts its `true`
+/// values, so the result is a `BIGINT` just like `COUNT(*)`.
+pub fn try_sum_column_type(datatype: ColumnType) -> ColumnOperationResult<ColumnType> {
+    match datatype {
+        ColumnType::Boolean => Ok(ColumnType::BigInt),
+        _ if datatype.is_numeric() => Ok(datatype),
+        _ => Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+            operator: "SUM".to_string(),
+            operand_type: datatype,
+        }),
+    }
+}
+
 /// Determines the output type of a negation operation
 #[expect(clippy::missing_panics_doc)]
 pub fn try_neg_type(datatype: ColumnType) -> ColumnOperationResult<ColumnType> {
@@ -499,6 +591,95 @@ mod test {
             try_add_subtract_column_types(lhs, rhs),
             Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
         ));
+
+        for (lhs, rhs) in [
+            (ColumnType::BigInt, ColumnType::VarBinary),
+            (ColumnType::VarBinary, ColumnType::Int),
+            (ColumnType::VarBinary, ColumnType::VarBinary),
+        ] {
+            assert!(matches!(
+                try_add_subtract_column_types(lhs, rhs),
+                Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn we_can_add_booleans_as_integers() {
+        let actual =
+            try_add_subtract_column_types(ColumnType::Boolean, ColumnType::Boolean).unwrap();
+        let expected = ColumnType::Decimal75(Precision::new(4).unwrap(), 0);
+        assert_eq!(expected, actual);
+
+        let actual =
+            try_add_subtract_column_types(ColumnType::Boolean, ColumnType::BigInt).unwrap();
+        let expected = ColumnType::Decimal75(Precision::new(20).unwrap(), 0);
+        assert_eq!(expected, actual);
+
+        let actual = try_add_subtract_column_types(
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 0),
+            ColumnType::Boolean,
+        )
+        .unwrap();
+        let expected = ColumnType::Decimal75(Precision::new(11).unwrap(), 0);
+        assert_eq!(expected, actual);
+
+        let actual =
+            try_add_subtract_column_types(ColumnType::Boolean, ColumnType::Scalar).unwrap();
+        assert_eq!(ColumnType::Scalar, actual);
+    }
+
+    #[test]
+    fn we_cannot_add_booleans_to_non_numeric_types_or_decimals_with_nonzero_scale() {
+        assert_eq!(
+            try_add_subtract_column_types(ColumnType::Boolean, ColumnType::VarChar),
+            Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+                operator: "+/-".to_string(),
+                left_type: ColumnType::Boolean,
+                right_type: ColumnType::VarChar,
+            })
+        );
+        assert!(matches!(
+            try_add_subtract_column_types(
+                ColumnType::Boolean,
+                ColumnType::Decimal75(Precision::new(10).unwrap(), 2)
+            ),
+            Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
+        ));
+    }
+
+    #[test]
+    fn we_can_get_the_column_type_of_a_sum() {
+        assert_eq!(
+            try_sum_column_type(ColumnType::Boolean).unwrap(),
+            ColumnType::BigInt
+        );
+        assert_eq!(
+            try_sum_column_type(ColumnType::TinyInt).unwrap(),
+            ColumnType::TinyInt
+        );
+        assert_eq!(
+            try_sum_column_type(ColumnType::Decimal75(Precision::new(10).unwrap(), 2)).unwrap(),
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2)
+        );
+        assert_eq!(
+            try_sum_column_type(ColumnType::Scalar).unwrap(),
+            ColumnType::Scalar
+        );
+    }
+
+    #[test]
+    fn we_cannot_get_the_column_type_of_a_sum_of_non_numeric_types() {
+        for datatype in [
+            ColumnType::VarChar,
+            ColumnType::VarBinary,
+            ColumnType::TimestampTZ(PoSQLTimeUnit::Second, PoSQLTimeZone::utc()),
+        ] {
+            assert!(matches!(
+                try_sum_column_type(datatype),
+                Err(ColumnOperationError::UnaryOperationInvalidColumnType { .. })
+            ));
+        }
     }
 
     #[test]
@@ -642,6 +823,102 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn we_can_align_types_with_equal_scales() {
+        let actual = align_decimal_scales(ColumnType::SmallInt, ColumnType::BigInt).unwrap();
+        let expected = (ColumnType::Decimal75(Precision::new(19).unwrap(), 0), 0, 0);
+        assert_eq!(expected, actual);
+
+        let actual = align_decimal_scales(
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 5),
+            ColumnType::Decimal75(Precision::new(25).unwrap(), 5),
+        )
+        .unwrap();
+        let expected = (ColumnType::Decimal75(Precision::new(25).unwrap(), 5), 0, 0);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn we_can_align_types_with_differing_scales() {
+        // The integer digits of the right side need the extra precision
+        let actual = align_decimal_scales(
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 5),
+            ColumnType::BigInt,
+        )
+        .unwrap();
+        let expected = (ColumnType::Decimal75(Precision::new(24).unwrap(), 5), 0, 5);
+        assert_eq!(expected, actual);
+
+        // Negative scales are shifted up as well
+        let actual = align_decimal_scales(
+            ColumnType::Decimal75(Precision::new(3).unwrap(), -2),
+            ColumnType::Decimal75(Precision::new(75).unwrap(), 10),
+        )
+        .unwrap();
+        let expected = (
+            ColumnType::Decimal75(Precision::new(75).unwrap(), 10),
+            12,
+            0,
+        );
+        assert_eq!(expected, actual);
+
+        let actual = align_decimal_scales(
+            ColumnType::Decimal75(Precision::new(74).unwrap(), -13),
+            ColumnType::Decimal75(Precision::new(15).unwrap(), -14),
+        )
+        .unwrap();
+        let expected = (
+            ColumnType::Decimal75(Precision::new(74).unwrap(), -13),
+            0,
+            1,
+        );
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn we_cannot_align_types_if_the_precision_overflows() {
+        assert_eq!(
+            align_decimal_scales(
+                ColumnType::Decimal75(Precision::new(75).unwrap(), 0),
+                ColumnType::Decimal75(Precision::new(5).unwrap(), 5),
+            ),
+            Err(ColumnOperationError::DecimalConversionError {
+                source: DecimalError::InvalidPrecision {
+                    error: "80".to_string()
+                }
+            })
+        );
+        assert!(matches!(
+            align_decimal_scales(
+                ColumnType::Decimal75(Precision::new(75).unwrap(), -100),
+                ColumnType::Decimal75(Precision::new(75).unwrap(), 100),
+            ),
+            Err(ColumnOperationError::DecimalConversionError { .. })
+        ));
+        assert!(matches!(
+            try_add_subtract_column_types_with_scaling(
+                ColumnType::Decimal75(Precision::new(75).unwrap(), 0),
+                ColumnType::Decimal75(Precision::new(5).unwrap(), 5),
+            ),
+            Err(ColumnOperationError::DecimalConversionError { .. })
+        ));
+    }
+
+    #[test]
+    fn we_cannot_align_non_numeric_or_scalar_types() {
+        for (lhs, rhs) in [
+            (ColumnType::VarChar, ColumnType::BigInt),
+            (ColumnType::BigInt, ColumnType::Boolean),
+            (ColumnType::Scalar, ColumnType::BigInt),
+            (ColumnType::Int, ColumnType::Scalar),
+        ] {
+            assert!(matches!(
+                align_decimal_scales(lhs, rhs),
+                Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
+            ));
+        }
+    }
+
     #[test]
     fn we_cannot_add_non_numeric_types_with_scaling() {
         let lhs = ColumnType::TinyInt;
@@ -793,6 +1070,17 @@ mod test {
             try_add_subtract_column_types_with_scaling(lhs, rhs),
             Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
         ));
+
+        for (lhs, rhs) in [
+            (ColumnType::BigInt, ColumnType::VarBinary),
+            (ColumnType::VarBinary, ColumnType::Int),
+            (ColumnType::VarBinary, ColumnType::VarBinary),
+        ] {
+            assert!(matches!(
+                try_add_subtract_column_types(lhs, rhs),
+                Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
+            ));
+        }
     }
 
     #[test]
@@ -0,0 +1,178 @@
+//! This module provides utilities for loading CSV files into Proof of SQL tables.
+//!
+//! Files are read in record batches, so tables larger than a single batch are supported.
+
+use super::{
+    arrow_schema_utility::{get_posql_compatible_schema, IncompatibleSchemaError},
+    OwnedArrowConversionError, OwnedTable, TableRef, TestAccessor,
+};
+use crate::base::{commitment::Commitment, scalar::Scalar};
+use alloc::{sync::Arc, vec::Vec};
+use arrow::{
+    compute::concat_batches,
+    csv::{reader::Format, ReaderBuilder},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use snafu::Snafu;
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+};
+
+/// The number of rows read from a CSV file at a time
+const DEFAULT_BATCH_SIZE: usize = 1024;
+
+/// Errors from loading a CSV file into an [`OwnedTable`]
+#[derive(Snafu, Debug)]
+pub enum CsvLoadError {
+    /// The file could not be opened or read
+    #[snafu(transparent)]
+    Io {
+        /// The underlying source error
+        source: std::io::Error,
+    },
+    /// The CSV could not be parsed
+    #[snafu(transparent)]
+    Arrow {
+        /// The underlying source error
+        source: ArrowError,
+    },
+    /// The inferred schema has columns which Proof of SQL does not support
+    #[snafu(transparent)]
+    IncompatibleSchema {
+        /// The underlying source error
+        source: IncompatibleSchemaError,
+    },
+    /// The parsed record batch could not be converted to an [`OwnedTable`]
+    #[snafu(transparent)]
+    OwnedArrowConversion {
+        /// The underlying source error
+        source: OwnedArrowConversionError,
+    },
+}
+
+/// Reads a CSV with a header row from `reader`, `batch_size` rows at a time
+///
+/// The schema is inferred from the whole input and made compatible with
+/// Proof of SQL using [`get_posql_compatible_schema`].
+fn read_csv_table<S: Scalar, R: Read + Seek>(
+    mut reader: R,
+    batch_size: usize,
+) -> Result<OwnedTable<S>, CsvLoadError> {
+    let (inferred_schema, _) = Format::default()
+        .with_header(true)
+        .infer_schema(&mut reader, None)?;
+    reader.seek(SeekFrom::Start(0))?;
+    let schema = get_posql_compatible_schema(&Arc::new(inferred_schema))?;
+    let batches = ReaderBuilder::new(schema.clone())
+        .with_header(true)
+        .with_batch_size(batch_size)
+        .build(reader)?
+        .collect::<Result<Vec<RecordBatch>, _>>()?;
+    let batch = concat_batches(&schema, &batches)?;
+    Ok(OwnedTable::try_from(batch)?)
+}
+
+/// Loads a CSV file with a header row into an [`OwnedTable`]
+///
+/// Float columns are converted to decimals as described in [`get_posql_compatible_schema`].
+///
+/// # Errors
+/// Returns an error if the file can not be read or parsed, or if it has columns
+/// whose types are not supported by Proof of SQL.
+pub fn load_csv_table<S: Scalar>(path: impl AsRef<Path>) -> Result<OwnedTable<S>, CsvLoadError> {
+    read_csv_table(File::open(path)?, DEFAULT_BATCH_SIZE)
+}
+
+/// Loads a CSV file with a header row and adds it to `accessor` as `table_ref` at `offset`
+///
+/// # Errors
+/// Returns the same errors as [`load_csv_table`]. The accessor is left unchanged on error.
+pub fn add_csv_table<C, A>(
+    accessor: &mut A,
+    path: impl AsRef<Path>,
+    table_ref: TableRef,
+    offset: usize,
+) -> Result<(), CsvLoadError>
+where
+    C: Commitment,
+    A: TestAccessor<C, Table = OwnedTable<C::Scalar>>,
+{
+    let table = load_csv_table(path)?;
+    accessor.add_table(table_ref, table, offset);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{
+        commitment::naive_evaluation_proof::NaiveEvaluationProof,
+        database::{owned_table_utility::*, MetadataAccessor, OwnedTableTestAccessor},
+        scalar::test_scalar::TestScalar,
+    };
+    use std::io::Cursor;
+
+    const FIXTURE: &[u8] = include_bytes!("csv_test_fixture.csv");
+
+    fn expected_table() -> OwnedTable<TestScalar> {
+        owned_table([
+            bigint("id", [1, 2, 3, 4, 5]),
+            varchar("name", ["ada", "bob", "cy", "di", "ed"]),
+            decimal75(
+                "price",
+                20,
+                10,
+                [
+                    15_000_000_000_i64,
+                    2_500_000_000,
+                    0,
+                    -12_500_000_000,
+                    10_000_000_000,
+                ],
+            ),
+        ])
+    }
+
+    #[test]
+    fn we_can_read_a_csv_spanning_multiple_batches() {
+        for batch_size in [1, 2, 5, DEFAULT_BATCH_SIZE] {
+            let table = read_csv_table::<TestScalar, _>(Cursor::new(FIXTURE), batch_size).unwrap();
+            assert_eq!(table, expected_table());
+        }
+    }
+
+    #[test]
+    fn we_can_read_a_csv_with_only_a_header() {
+        let table = read_csv_table::<TestScalar, _>(Cursor::new(b"id,name\n"), 2).unwrap();
+        assert_eq!(table.num_rows(), 0);
+        assert_eq!(table.num_columns(), 2);
+    }
+
+    #[test]
+    fn we_cannot_read_a_malformed_csv() {
+        let csv = b"id,name\n1,ada\n2\n";
+        assert!(matches!(
+            read_csv_table::<TestScalar, _>(Cursor::new(csv), 2),
+            Err(CsvLoadError::Arrow { .. })
+        ));
+    }
+
+    #[test]
+    fn we_can_load_a_csv_file_into_an_accessor() {
+        let path =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("src/base/database/csv_test_fixture.csv");
+        let table_ref = TableRef::new("sxt", "t");
+        let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_empty_with_setup(());
+        add_csv_table(&mut accessor, &path, table_ref.clone(), 3).unwrap();
+        assert_eq!(accessor.get_length(&table_ref), 5);
+        assert_eq!(accessor.get_offset(&table_ref), 3);
+
+        assert!(matches!(
+            add_csv_table(&mut accessor, path.with_extension("missing"), table_ref, 0),
+            Err(CsvLoadError::Io { .. })
+        ));
+    }
+}
@@ -77,6 +77,12 @@ impl<S: Scalar> MetadataAccessor for DataAccessorImpl<'_, S> {
             .expect("table does not exist")
             .offset
     }
+
+    fn table_row_count(&self, table_ref: &TableRef) -> Option<usize> {
+        self.data_lookup
+            .get(table_ref)
+            .map(|table| table.table_data.len())
+    }
 }
 
 impl<S: Scalar> DataAccessor<S> for DataAccessorImpl<'_, S> {
@@ -6,6 +6,8 @@ use crate::base::{
     standard_serializations::limbs::{deserialize_to_limbs, serialize_limbs},
 };
 use alloc::{string::String, vec::Vec};
+use bigdecimal::BigDecimal;
+use core::fmt::{self, Display, Formatter};
 use serde::{Deserialize, Serialize};
 
 /// Represents a literal value.
@@ -91,6 +93,37 @@ impl LiteralValue {
     }
 }
 
+/// Display the literal the way it would be written in SQL
+impl Display for LiteralValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Boolean(true) => write!(f, "TRUE"),
+            Self::Boolean(false) => write!(f, "FALSE"),
+            Self::Uint8(i) => write!(f, "{i}"),
+            Self::TinyInt(i) => write!(f, "{i}"),
+            Self::SmallInt(i) => write!(f, "{i}"),
+            Self::Int(i) => write!(f, "{i}"),
+            Self::BigInt(i) => write!(f, "{i}"),
+            Self::Int128(i) => write!(f, "{i}"),
+            Self::VarChar(str) => write!(f, "'{}'", str.replace('\'', "''")),
+            Self::VarBinary(bytes) => {
+                write!(f, "X'")?;
+                for byte in bytes {
+                    write!(f, "{byte:02x}")?;
+                }
+                write!(f, "'")
+            }
+            Self::Decimal75(_, scale, i) => write!(
+                f,
+                "{}",
+                BigDecimal::new(i.to_num_bigint(), i64::from(*scale)).to_plain_string()
+            ),
+            Self::Scalar(limbs) => write!(f, "{}", I256::new(*limbs).to_num_bigint()),
+            Self::TimeStampTZ(tu, tz, time) => write!(f, "TIMESTAMP({time}, {tu}, {tz})"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::base::{
@@ -100,6 +133,30 @@ mod tests {
         try_standard_binary_serialization,
     };
 
+    #[test]
+    fn we_can_display_literal_values() {
+        for (literal_value, expected) in [
+            (LiteralValue::Boolean(true), "TRUE"),
+            (LiteralValue::Uint8(2), "2"),
+            (LiteralValue::TinyInt(-3), "-3"),
+            (LiteralValue::BigInt(6), "6"),
+            (LiteralValue::Int128(-7), "-7"),
+            (LiteralValue::VarChar("it's".to_string()), "'it''s'"),
+            (
+                LiteralValue::Decimal75(Precision::new(9).unwrap(), 2, (-7010).into()),
+                "-70.10",
+            ),
+            (
+                LiteralValue::Decimal75(Precision::new(9).unwrap(), -2, 7.into()),
+                "700",
+            ),
+            (LiteralValue::Scalar([1, 0, 0, 0]), "1"),
+            (LiteralValue::VarBinary(vec![0, 255]), "X'00ff'"),
+        ] {
+            assert_eq!(literal_value.to_string(), expected);
+        }
+    }
+
     /// This allows us to reuse code within solidity more safely
     #[test]
     fn literal_value_and_column_type_varaints_should_have_same_data_type_serialization() {
@@ -28,11 +28,11 @@ mod slice_decimal_operation;
 
 mod column_type_operation;
 pub use column_type_operation::{
-    can_and_or_types, can_not_type, try_add_subtract_column_types,
+    align_decimal_scales, can_and_or_types, can_not_type, try_add_subtract_column_types,
     try_add_subtract_column_types_with_scaling, try_cast_types, try_divide_column_types,
     try_equals_types, try_equals_types_with_scaling, try_inequality_types,
     try_inequality_types_with_scaling, try_multiply_column_types, try_neg_type,
-    try_scale_cast_types,
+    try_scale_cast_types, try_sum_column_type,
 };
 
 mod column_arithmetic_operation;
@@ -42,7 +42,7 @@ mod column_comparison_operation;
 pub(super) use column_comparison_operation::{ComparisonOp, EqualOp, GreaterThanOp, LessThanOp};
 
 mod column_index_operation;
-pub(super) use column_index_operation::apply_column_to_indexes;
+pub(crate) use column_index_operation::apply_column_to_indexes;
 
 mod column_repetition_operation;
 pub(super) use column_repetition_operation::{ColumnRepeatOp, ElementwiseRepeatOp, RepetitionOp};
@@ -63,6 +63,8 @@ mod error;
 pub use error::ParseError;
 
 mod table_ref;
+#[cfg(test)]
+mod table_ref_test;
 #[cfg(feature = "arrow")]
 pub use crate::base::arrow::{
     arrow_array_to_column_conversion::{ArrayRefExt, ArrowArrayToColumnConversionError},
@@ -74,6 +76,9 @@ pub use table_ref::TableRef;
 #[cfg(feature = "arrow")]
 pub mod arrow_schema_utility;
 
+#[cfg(feature = "arrow")]
+pub mod csv;
+
 mod owned_column;
 pub use owned_column::OwnedColumn;
 
@@ -90,11 +95,14 @@ pub(crate) mod owned_column_operation;
 
 mod owned_table;
 pub(crate) use owned_table::TableCoercionError;
-pub use owned_table::{OwnedTable, OwnedTableError};
+pub use owned_table::{OwnedTable, OwnedTableBuilder, OwnedTableError};
 #[cfg(test)]
 mod owned_table_test;
 pub mod owned_table_utility;
 
+mod owned_table_json;
+pub use owned_table_json::owned_table_to_json;
+
 mod table;
 #[cfg(test)]
 pub(crate) use table::TableError;
@@ -110,7 +118,7 @@ mod test_accessor;
 pub use test_accessor::TestAccessor;
 
 mod owned_table_test_accessor;
-pub use owned_table_test_accessor::OwnedTableTestAccessor;
+pub use owned_table_test_accessor::{OwnedTableTestAccessor, OwnedTableTestAccessorError};
 #[cfg(all(test, feature = "blitzar"))]
 mod owned_table_test_accessor_test;
 
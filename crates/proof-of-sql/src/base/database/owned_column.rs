@@ -16,13 +16,17 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
+use core::ops::Range;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Clone, Eq, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Eq, Hash, Serialize, Deserialize)]
 #[non_exhaustive]
 #[cfg_attr(test, derive(proptest_derive::Arbitrary))]
 /// Supported types for [`OwnedColumn`]
+///
+/// Equality and hashing take the column type into account, so e.g. `BigInt([1])` and `Int([1])`
+/// are different columns. Use [`OwnedColumn::values_equal_ignoring_type`] to compare values only.
 pub enum OwnedColumn<S: Scalar> {
     /// Boolean columns
     Boolean(Vec<bool>),
@@ -91,6 +95,36 @@ impl<S: Scalar> OwnedColumn<S> {
         }
     }
 
+    /// Returns whether the two columns hold the same values, ignoring differences in integer width
+    ///
+    /// Integer columns are compared by value, e.g. `BigInt([1])` and `Int([1])` are equal.
+    /// Columns of any other type are only equal to columns of the same type with the same values.
+    #[must_use]
+    pub fn values_equal_ignoring_type(&self, other: &Self) -> bool {
+        match (self.integer_values(), other.integer_values()) {
+            (Some(lhs), Some(rhs)) => lhs == rhs,
+            _ => self == other,
+        }
+    }
+
+    /// Returns the values of an integer column widened to `i128`, or `None` for other columns
+    fn integer_values(&self) -> Option<Vec<i128>> {
+        match self {
+            OwnedColumn::Uint8(col) => Some(col.iter().copied().map(i128::from).collect()),
+            OwnedColumn::TinyInt(col) => Some(col.iter().copied().map(i128::from).collect()),
+            OwnedColumn::SmallInt(col) => Some(col.iter().copied().map(i128::from).collect()),
+            OwnedColumn::Int(col) => Some(col.iter().copied().map(i128::from).collect()),
+            OwnedColumn::BigInt(col) => Some(col.iter().copied().map(i128::from).collect()),
+            OwnedColumn::Int128(col) => Some(col.clone()),
+            OwnedColumn::Boolean(_)
+            | OwnedColumn::VarChar(_)
+            | OwnedColumn::Decimal75(..)
+            | OwnedColumn::TimestampTZ(..)
+            | OwnedColumn::Scalar(_)
+            | OwnedColumn::VarBinary(_) => None,
+        }
+    }
+
     /// Returns the column with its entries permutated
     pub fn try_permute(&self, permutation: &Permutation) -> Result<Self, PermutationError> {
         Ok(match self {
@@ -136,6 +170,84 @@ impl<S: Scalar> OwnedColumn<S> {
         }
     }
 
+    /// Returns the rows of the column in `range`.
+    ///
+    /// # Errors
+    /// Returns an error if `range` is out of bounds of the column.
+    pub fn try_slice(&self, range: Range<usize>) -> OwnedColumnResult<Self> {
+        if range.start > range.end || range.end > self.len() {
+            return Err(OwnedColumnError::SliceOutOfBounds {
+                start: range.start,
+                end: range.end,
+                len: self.len(),
+            });
+        }
+        Ok(self.slice(range.start, range.end))
+    }
+
+    /// Returns the concatenation of `columns`.
+    ///
+    /// # Errors
+    /// Returns an error if `columns` is empty or the columns do not all have the same type.
+    pub fn try_concat(columns: &[Self]) -> OwnedColumnResult<Self> {
+        let (first, rest) = columns
+            .split_first()
+            .ok_or(OwnedColumnError::EmptyConcatenation)?;
+        let expected = first.column_type();
+        if let Some(column) = rest.iter().find(|column| column.column_type() != expected) {
+            return Err(OwnedColumnError::ConcatenationTypeMismatch {
+                expected,
+                actual: column.column_type(),
+            });
+        }
+        let mut result = first.clone();
+        for column in rest {
+            result.append(column);
+        }
+        Ok(result)
+    }
+
+    /// Appends the rows of `other` to the column.
+    ///
+    /// # Panics
+    /// Panics if the columns have different types.
+    pub(crate) fn append(&mut self, other: &Self) {
+        assert_eq!(
+            self.column_type(),
+            other.column_type(),
+            "cannot append columns of different types"
+        );
+        match (self, other) {
+            (OwnedColumn::Boolean(col), OwnedColumn::Boolean(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::TinyInt(col), OwnedColumn::TinyInt(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::Uint8(col), OwnedColumn::Uint8(other)) => col.extend_from_slice(other),
+            (OwnedColumn::SmallInt(col), OwnedColumn::SmallInt(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::Int(col), OwnedColumn::Int(other)) => col.extend_from_slice(other),
+            (OwnedColumn::BigInt(col), OwnedColumn::BigInt(other))
+            | (OwnedColumn::TimestampTZ(_, _, col), OwnedColumn::TimestampTZ(_, _, other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::VarChar(col), OwnedColumn::VarChar(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::VarBinary(col), OwnedColumn::VarBinary(other)) => {
+                col.extend_from_slice(other);
+            }
+            (OwnedColumn::Int128(col), OwnedColumn::Int128(other)) => col.extend_from_slice(other),
+            (OwnedColumn::Scalar(col), OwnedColumn::Scalar(other))
+            | (OwnedColumn::Decimal75(_, _, col), OwnedColumn::Decimal75(_, _, other)) => {
+                col.extend_from_slice(other);
+            }
+            _ => unreachable!("column types are equal"),
+        }
+    }
+
     /// Returns true if the column is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -463,6 +575,7 @@ impl<S: Scalar> OwnedColumn<S> {
 mod test {
     use super::*;
     use crate::base::{
+        map::IndexSet,
         math::decimal::Precision,
         scalar::{test_scalar::TestScalar, ScalarExt},
     };
@@ -475,6 +588,74 @@ mod test {
         assert_eq!(col.slice(1, 4), OwnedColumn::Int128(vec![2, 3, 4]));
     }
 
+    #[test]
+    fn we_can_try_slice_bigint_and_varchar_columns() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![1, 2, 3, 4, 5]);
+        assert_eq!(
+            col.try_slice(1..4).unwrap(),
+            OwnedColumn::BigInt(vec![2, 3, 4])
+        );
+        assert_eq!(col.try_slice(5..5).unwrap(), OwnedColumn::BigInt(vec![]));
+
+        let col: OwnedColumn<TestScalar> =
+            OwnedColumn::VarChar(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+        assert_eq!(
+            col.try_slice(0..2).unwrap(),
+            OwnedColumn::VarChar(vec!["a".to_string(), "b".to_string()])
+        );
+    }
+
+    #[test]
+    fn we_cannot_try_slice_a_column_out_of_bounds() {
+        let col: OwnedColumn<TestScalar> = OwnedColumn::BigInt(vec![1, 2, 3]);
+        assert_eq!(
+            col.try_slice(2..4),
+            Err(OwnedColumnError::SliceOutOfBounds {
+                start: 2,
+                end: 4,
+                len: 3
+            })
+        );
+        assert!(matches!(
+            col.try_slice(Range { start: 2, end: 1 }),
+            Err(OwnedColumnError::SliceOutOfBounds { .. })
+        ));
+    }
+
+    #[test]
+    fn we_can_concat_columns_of_the_same_type() {
+        let columns: [OwnedColumn<TestScalar>; 2] = [
+            OwnedColumn::BigInt(vec![1, 2]),
+            OwnedColumn::BigInt(vec![3]),
+        ];
+        assert_eq!(
+            OwnedColumn::try_concat(&columns).unwrap(),
+            OwnedColumn::BigInt(vec![1, 2, 3])
+        );
+
+        let col: OwnedColumn<TestScalar> = OwnedColumn::VarChar(vec!["a".to_string()]);
+        assert_eq!(OwnedColumn::try_concat(&[col.clone()]).unwrap(), col);
+    }
+
+    #[test]
+    fn we_cannot_concat_columns_of_different_types_or_no_columns() {
+        let columns: [OwnedColumn<TestScalar>; 2] = [
+            OwnedColumn::BigInt(vec![1, 2]),
+            OwnedColumn::VarChar(vec!["a".to_string()]),
+        ];
+        assert_eq!(
+            OwnedColumn::try_concat(&columns),
+            Err(OwnedColumnError::ConcatenationTypeMismatch {
+                expected: ColumnType::BigInt,
+                actual: ColumnType::VarChar
+            })
+        );
+        assert_eq!(
+            OwnedColumn::<TestScalar>::try_concat(&[]),
+            Err(OwnedColumnError::EmptyConcatenation)
+        );
+    }
+
     #[test]
     fn we_can_permute_a_column() {
         let col: OwnedColumn<TestScalar> = OwnedColumn::Int128(vec![1, 2, 3, 4, 5]);
@@ -907,4 +1088,53 @@ mod test {
 
         assert_eq!(product, expected);
     }
+
+    #[test]
+    fn we_can_compare_columns_of_the_same_and_different_integer_widths() {
+        let bigint = OwnedColumn::<TestScalar>::BigInt(vec![1, -2, 3]);
+        let int = OwnedColumn::<TestScalar>::Int(vec![1, -2, 3]);
+        let uint8 = OwnedColumn::<TestScalar>::Uint8(vec![1, 2, 3]);
+        let int128 = OwnedColumn::<TestScalar>::Int128(vec![1, -2, 3]);
+
+        // Equality takes the type into account
+        assert_eq!(bigint, OwnedColumn::BigInt(vec![1, -2, 3]));
+        assert_ne!(bigint, int);
+
+        // Values are compared across integer widths
+        assert!(bigint.values_equal_ignoring_type(&bigint));
+        assert!(bigint.values_equal_ignoring_type(&int));
+        assert!(int.values_equal_ignoring_type(&int128));
+        assert!(
+            OwnedColumn::<TestScalar>::SmallInt(vec![1, 2, 3]).values_equal_ignoring_type(&uint8)
+        );
+    }
+
+    #[test]
+    fn we_can_detect_columns_with_different_values() {
+        let bigint = OwnedColumn::<TestScalar>::BigInt(vec![1, 2, 3]);
+        assert!(!bigint.values_equal_ignoring_type(&OwnedColumn::Int(vec![1, 2, 4])));
+        assert!(!bigint.values_equal_ignoring_type(&OwnedColumn::Int(vec![1, 2])));
+        // Non-integer columns never equal integer columns
+        assert!(
+            !bigint.values_equal_ignoring_type(&OwnedColumn::Scalar(vec![
+                TestScalar::from(1),
+                TestScalar::from(2),
+                TestScalar::from(3)
+            ]))
+        );
+        assert!(!OwnedColumn::<TestScalar>::Boolean(vec![true])
+            .values_equal_ignoring_type(&OwnedColumn::TinyInt(vec![1])));
+        assert!(OwnedColumn::<TestScalar>::VarChar(vec!["a".to_string()])
+            .values_equal_ignoring_type(&OwnedColumn::VarChar(vec!["a".to_string()])));
+    }
+
+    #[test]
+    fn we_can_hash_owned_columns() {
+        let mut columns = IndexSet::default();
+        assert!(columns.insert(OwnedColumn::<TestScalar>::BigInt(vec![1, 2, 3])));
+        assert!(!columns.insert(OwnedColumn::BigInt(vec![1, 2, 3])));
+        assert!(columns.insert(OwnedColumn::Int(vec![1, 2, 3])));
+        assert!(columns.insert(OwnedColumn::VarChar(vec!["a".to_string()])));
+        assert_eq!(columns.len(), 3);
+    }
 }
@@ -25,6 +25,29 @@ pub enum OwnedColumnError {
         /// The underlying error
         error: String,
     },
+    /// The range to slice is out of bounds of the column.
+    #[snafu(display("Can not slice rows {start}..{end} of a column of length {len}"))]
+    SliceOutOfBounds {
+        /// The start of the range
+        start: usize,
+        /// The end of the range
+        end: usize,
+        /// The length of the column
+        len: usize,
+    },
+    /// Columns of different types can not be concatenated.
+    #[snafu(display(
+        "Can not concatenate a column of type {actual} to a column of type {expected}"
+    ))]
+    ConcatenationTypeMismatch {
+        /// The type of the first column
+        expected: ColumnType,
+        /// The type of the mismatched column
+        actual: ColumnType,
+    },
+    /// At least one column is required to concatenate columns.
+    #[snafu(display("Can not concatenate an empty list of columns"))]
+    EmptyConcatenation,
 }
 
 /// Errors that can occur when coercing a column.
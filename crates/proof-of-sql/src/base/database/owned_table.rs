@@ -1,9 +1,13 @@
-use super::{ColumnField, OwnedColumn, Table};
+use super::{
+    filter_util::filter_columns, order_by_util::compare_indexes_by_columns, Column, ColumnField,
+    OwnedColumn, Table,
+};
 use crate::base::{
-    database::ColumnCoercionError, map::IndexMap, polynomial::compute_evaluation_vector,
-    scalar::Scalar,
+    database::ColumnCoercionError, map::IndexMap, math::permutation::Permutation,
+    polynomial::compute_evaluation_vector, scalar::Scalar,
 };
 use alloc::{vec, vec::Vec};
+use bumpalo::Bump;
 use itertools::{EitherOrBoth, Itertools};
 use serde::{Deserialize, Serialize};
 use snafu::Snafu;
@@ -15,6 +19,26 @@ pub enum OwnedTableError {
     /// The columns have different lengths.
     #[snafu(display("Columns have different lengths"))]
     ColumnLengthMismatch,
+    /// More than one column has the same name.
+    #[snafu(display("Duplicate column name: {name}"))]
+    DuplicateColumnName {
+        /// The repeated column name
+        name: Ident,
+    },
+    /// The length of a selection mask does not match the number of rows.
+    #[snafu(display("Mask length {mask_length} does not match the number of rows {num_rows}"))]
+    MaskLengthMismatch {
+        /// The length of the mask
+        mask_length: usize,
+        /// The number of rows of the table
+        num_rows: usize,
+    },
+    /// A column with the given name does not exist.
+    #[snafu(display("Column not found: {name}"))]
+    ColumnNotFound {
+        /// The missing column name
+        name: Ident,
+    },
 }
 
 /// Errors that can occur when coercing a table.
@@ -136,6 +160,102 @@ impl<S: Scalar> OwnedTable<S> {
         self.table.get_index(index).map(|(_, v)| v)
     }
 
+    /// Returns `true` if both tables contain the same rows, ignoring row order.
+    ///
+    /// The column names, column order and column types must match exactly.
+    /// Rows are compared as multisets, so a duplicated row must appear
+    /// the same number of times in both tables.
+    #[must_use]
+    pub fn equals_unordered(&self, other: &Self) -> bool {
+        let same_schema = self.num_columns() == other.num_columns()
+            && self.table.iter().zip(other.table.iter()).all(
+                |((name, column), (other_name, other_column))| {
+                    name == other_name && column.column_type() == other_column.column_type()
+                },
+            );
+        same_schema
+            && self.num_rows() == other.num_rows()
+            && self.sorted_by_rows() == other.sorted_by_rows()
+    }
+
+    /// Returns a new table containing only the rows where `mask` is `true`.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableError::MaskLengthMismatch`] if the length of `mask` is not the number of rows.
+    pub fn filter_by_mask(&self, mask: &[bool]) -> Result<Self, OwnedTableError> {
+        if mask.len() != self.num_rows() {
+            return Err(OwnedTableError::MaskLengthMismatch {
+                mask_length: mask.len(),
+                num_rows: self.num_rows(),
+            });
+        }
+        let alloc = Bump::new();
+        let columns = self
+            .table
+            .values()
+            .map(|column| Column::from_owned_column(column, &alloc))
+            .collect::<Vec<_>>();
+        let (filtered_columns, _) = filter_columns(&alloc, &columns, mask);
+        Ok(Self {
+            table: self
+                .table
+                .keys()
+                .cloned()
+                .zip(filtered_columns.iter().map(OwnedColumn::from))
+                .collect(),
+        })
+    }
+
+    /// Returns a new table containing only the named columns, in the order given by `names`.
+    ///
+    /// # Errors
+    /// Returns an [`OwnedTableError`] if:
+    /// - a name in `names` is not a column of the table.
+    /// - a name appears more than once in `names`.
+    pub fn select(&self, names: &[&str]) -> Result<Self, OwnedTableError> {
+        OwnedTableBuilder {
+            columns: names
+                .iter()
+                .map(|&name| {
+                    let name = Ident::new(name);
+                    let column = self
+                        .table
+                        .get(&name)
+                        .ok_or_else(|| OwnedTableError::ColumnNotFound { name: name.clone() })?;
+                    Ok((name, column.clone()))
+                })
+                .collect::<Result<_, _>>()?,
+        }
+        .try_build()
+    }
+
+    /// Returns a copy of the table with its rows sorted lexicographically by all columns.
+    fn sorted_by_rows(&self) -> Self {
+        let alloc = Bump::new();
+        let columns = self
+            .table
+            .values()
+            .map(|column| Column::from_owned_column(column, &alloc))
+            .collect::<Vec<_>>();
+        let permutation = Permutation::unchecked_new_from_cmp(self.num_rows(), |&a, &b| {
+            compare_indexes_by_columns(&columns, a, b)
+        });
+        Self {
+            table: self
+                .table
+                .iter()
+                .map(|(name, column)| {
+                    (
+                        name.clone(),
+                        column
+                            .try_permute(&permutation)
+                            .expect("Permutation length should match the column length"),
+                    )
+                })
+                .collect(),
+        }
+    }
+
     pub(crate) fn mle_evaluations(&self, evaluation_point: &[S]) -> Vec<S> {
         let mut evaluation_vector = vec![S::ZERO; self.num_rows()];
         compute_evaluation_vector(&mut evaluation_vector, evaluation_point);
@@ -146,6 +266,50 @@ impl<S: Scalar> OwnedTable<S> {
     }
 }
 
+/// A builder for assembling an [`OwnedTable`] one column at a time.
+///
+/// Unlike [`OwnedTable::try_from_iter`], where a repeated name silently replaces the earlier
+/// column, [`OwnedTableBuilder::try_build`] rejects duplicate names.
+#[derive(Debug, Clone, Default)]
+pub struct OwnedTableBuilder<S: Scalar> {
+    columns: Vec<(Ident, OwnedColumn<S>)>,
+}
+
+impl<S: Scalar> OwnedTableBuilder<S> {
+    /// Creates a builder with no columns.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+        }
+    }
+
+    /// Appends a column to the table being built.
+    #[must_use]
+    pub fn add_column(mut self, name: impl Into<Ident>, column: OwnedColumn<S>) -> Self {
+        self.columns.push((name.into(), column));
+        self
+    }
+
+    /// Builds the [`OwnedTable`], with columns in the order they were added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OwnedTableError`] if:
+    /// * Two columns have the same name.
+    /// * The columns have different lengths.
+    pub fn try_build(self) -> Result<OwnedTable<S>, OwnedTableError> {
+        let mut table = IndexMap::default();
+        for (name, column) in self.columns {
+            if table.contains_key(&name) {
+                return Err(OwnedTableError::DuplicateColumnName { name });
+            }
+            table.insert(name, column);
+        }
+        OwnedTable::try_new(table)
+    }
+}
+
 impl<S: Scalar> IntoIterator for OwnedTable<S> {
     type Item = (Ident, OwnedColumn<S>);
 
@@ -0,0 +1,141 @@
+use super::{OwnedColumn, OwnedTable};
+use crate::base::scalar::Scalar;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bigdecimal::BigDecimal;
+use core::fmt::Write;
+use num_bigint::BigInt;
+use serde_json::{Map, Value};
+
+/// Converts an [`OwnedTable`] into a JSON array with one object per row, keyed by column name.
+///
+/// Values which may not fit into a JSON number without losing precision are rendered as strings:
+/// * `Int128`, `Decimal75` and `Scalar` values are rendered in base 10, e.g. `"-12.34"` for a decimal
+/// * `VarBinary` values are rendered as lowercase hex
+///
+/// Timestamps are rendered as the raw integer in the time unit of the column.
+#[must_use]
+pub fn owned_table_to_json<S: Scalar>(table: &OwnedTable<S>) -> Value {
+    let columns: Vec<(String, Vec<Value>)> = table
+        .inner_table()
+        .iter()
+        .map(|(name, column)| (name.value.clone(), column_to_json_values(column)))
+        .collect();
+    Value::Array(
+        (0..table.num_rows())
+            .map(|row| {
+                Value::Object(
+                    columns
+                        .iter()
+                        .map(|(name, values)| (name.clone(), values[row].clone()))
+                        .collect::<Map<String, Value>>(),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Converts each entry of the column into a JSON value
+fn column_to_json_values<S: Scalar>(column: &OwnedColumn<S>) -> Vec<Value> {
+    match column {
+        OwnedColumn::Boolean(col) => col.iter().map(|&v| Value::from(v)).collect(),
+        OwnedColumn::Uint8(col) => col.iter().map(|&v| Value::from(v)).collect(),
+        OwnedColumn::TinyInt(col) => col.iter().map(|&v| Value::from(v)).collect(),
+        OwnedColumn::SmallInt(col) => col.iter().map(|&v| Value::from(v)).collect(),
+        OwnedColumn::Int(col) => col.iter().map(|&v| Value::from(v)).collect(),
+        OwnedColumn::BigInt(col) | OwnedColumn::TimestampTZ(_, _, col) => {
+            col.iter().map(|&v| Value::from(v)).collect()
+        }
+        OwnedColumn::Int128(col) => col.iter().map(|v| Value::from(v.to_string())).collect(),
+        OwnedColumn::Decimal75(_, scale, col) => col
+            .iter()
+            .map(|&v| Value::from(BigDecimal::new(v.into(), i64::from(*scale)).to_plain_string()))
+            .collect(),
+        OwnedColumn::Scalar(col) => col
+            .iter()
+            .map(|&v| Value::from(Into::<BigInt>::into(v).to_string()))
+            .collect(),
+        OwnedColumn::VarChar(col) => col.iter().map(|v| Value::from(v.as_str())).collect(),
+        OwnedColumn::VarBinary(col) => col
+            .iter()
+            .map(|bytes| {
+                let mut hex = String::with_capacity(2 * bytes.len());
+                for byte in bytes {
+                    write!(hex, "{byte:02x}").expect("writing to a String cannot fail");
+                }
+                Value::from(hex)
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{
+        database::owned_table_utility::*,
+        posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+        scalar::test_scalar::TestScalar,
+    };
+    use alloc::vec;
+    use serde_json::json;
+
+    #[test]
+    fn we_can_convert_an_owned_table_to_json_rows() {
+        let table = owned_table::<TestScalar>([
+            bigint("a", [1_i64, -2]),
+            varchar("b", ["x", "yz"]),
+            decimal75("c", 10, 2, [1234_i64, -5]),
+            boolean("d", [true, false]),
+            int128("e", [i128::MAX, 0]),
+            varbinary("f", [vec![0_u8, 255], vec![]]),
+            timestamptz(
+                "g",
+                PoSQLTimeUnit::Second,
+                PoSQLTimeZone::utc(),
+                [0_i64, 60],
+            ),
+        ]);
+        assert_eq!(
+            owned_table_to_json(&table),
+            json!([
+                {
+                    "a": 1,
+                    "b": "x",
+                    "c": "12.34",
+                    "d": true,
+                    "e": i128::MAX.to_string(),
+                    "f": "00ff",
+                    "g": 0,
+                },
+                {
+                    "a": -2,
+                    "b": "yz",
+                    "c": "-0.05",
+                    "d": false,
+                    "e": "0",
+                    "f": "",
+                    "g": 60,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn we_can_convert_decimals_with_negative_scale_and_scalars_to_json() {
+        let table =
+            owned_table::<TestScalar>([decimal75("a", 10, -2, [12_i64]), scalar("b", [-7_i64])]);
+        assert_eq!(
+            owned_table_to_json(&table),
+            json!([{ "a": "1200", "b": "-7" }])
+        );
+    }
+
+    #[test]
+    fn we_can_convert_tables_without_rows_to_json() {
+        let table = owned_table::<TestScalar>([bigint("a", [0_i64; 0])]);
+        assert_eq!(owned_table_to_json(&table), json!([]));
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
     base::{
-        database::{owned_table_utility::*, OwnedColumn, OwnedTable, OwnedTableError},
+        database::{
+            owned_table_utility::*, OwnedColumn, OwnedTable, OwnedTableBuilder, OwnedTableError,
+        },
         map::IndexMap,
         posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
         scalar::test_scalar::TestScalar,
@@ -167,3 +169,189 @@ fn we_cannot_create_an_owned_table_with_differing_column_lengths() {
         Err(OwnedTableError::ColumnLengthMismatch)
     ));
 }
+#[test]
+fn we_can_build_an_owned_table_with_columns_of_several_types() {
+    let table = OwnedTableBuilder::<TestScalar>::new()
+        .add_column("bigint", OwnedColumn::BigInt(vec![1, 2]))
+        .add_column(
+            "varchar",
+            OwnedColumn::VarChar(vec!["a".into(), "b".into()]),
+        )
+        .add_column("boolean", OwnedColumn::Boolean(vec![true, false]))
+        .add_column("decimal", OwnedColumn::Int128(vec![-1, i128::MAX]))
+        .try_build()
+        .unwrap();
+    assert_eq!(
+        table,
+        owned_table([
+            bigint("bigint", [1, 2]),
+            varchar("varchar", ["a", "b"]),
+            boolean("boolean", [true, false]),
+            int128("decimal", [-1, i128::MAX]),
+        ])
+    );
+    assert_eq!(
+        OwnedTableBuilder::<TestScalar>::new().try_build(),
+        OwnedTable::try_new(IndexMap::default())
+    );
+}
+#[test]
+fn we_cannot_build_an_owned_table_with_differing_column_lengths() {
+    assert_eq!(
+        OwnedTableBuilder::<TestScalar>::new()
+            .add_column("a", OwnedColumn::BigInt(vec![0, 1]))
+            .add_column("b", OwnedColumn::Boolean(vec![true]))
+            .try_build(),
+        Err(OwnedTableError::ColumnLengthMismatch)
+    );
+}
+#[test]
+fn we_cannot_build_an_owned_table_with_duplicate_column_names() {
+    assert_eq!(
+        OwnedTableBuilder::<TestScalar>::new()
+            .add_column("a", OwnedColumn::BigInt(vec![0]))
+            .add_column("b", OwnedColumn::BigInt(vec![1]))
+            .add_column("a", OwnedColumn::VarChar(vec!["x".into()]))
+            .try_build(),
+        Err(OwnedTableError::DuplicateColumnName { name: "a".into() })
+    );
+}
+#[test]
+fn we_get_unordered_equality_between_tables_with_reordered_rows() {
+    let owned_table_a: OwnedTable<TestScalar> = owned_table([
+        bigint("a", [1, 2, 2, 3]),
+        varchar("b", ["x", "y", "z", "x"]),
+        decimal75("c", 10, 2, [100, -5, 7, 0]),
+        varbinary("d", [vec![1_u8], vec![], vec![2, 3], vec![1]]),
+        boolean("e", [true, false, true, false]),
+    ]);
+    let owned_table_b: OwnedTable<TestScalar> = owned_table([
+        bigint("a", [2, 3, 1, 2]),
+        varchar("b", ["z", "x", "x", "y"]),
+        decimal75("c", 10, 2, [7, 0, 100, -5]),
+        varbinary("d", [vec![2_u8, 3], vec![1], vec![1], vec![]]),
+        boolean("e", [true, false, true, false]),
+    ]);
+    assert_ne!(owned_table_a, owned_table_b);
+    assert!(owned_table_a.equals_unordered(&owned_table_b));
+    assert!(owned_table_b.equals_unordered(&owned_table_a));
+    assert!(owned_table_a.equals_unordered(&owned_table_a));
+}
+#[test]
+fn we_get_unordered_equality_between_empty_tables() {
+    let owned_table_a: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [0; 0]), varchar("b", ["0"; 0])]);
+    let owned_table_b: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [0; 0]), varchar("b", ["0"; 0])]);
+    assert!(owned_table_a.equals_unordered(&owned_table_b));
+    assert!(owned_table::<TestScalar>([]).equals_unordered(&owned_table([])));
+}
+#[test]
+fn we_get_unordered_inequality_between_tables_with_differing_rows() {
+    let owned_table_a: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [1, 2, 3]), varchar("b", ["x", "y", "z"])]);
+    // Same column values, but paired into different rows
+    let owned_table_b: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [1, 2, 3]), varchar("b", ["y", "x", "z"])]);
+    assert!(!owned_table_a.equals_unordered(&owned_table_b));
+    // Same distinct rows, but with different multiplicities
+    let owned_table_c: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [1, 1, 2]), varchar("b", ["x", "x", "y"])]);
+    let owned_table_d: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [1, 2, 2]), varchar("b", ["x", "y", "y"])]);
+    assert!(!owned_table_c.equals_unordered(&owned_table_d));
+    // Different number of rows
+    let owned_table_e: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])]);
+    assert!(!owned_table_a.equals_unordered(&owned_table_e));
+}
+#[test]
+fn we_get_unordered_inequality_between_tables_with_differing_schemas() {
+    let owned_table_a: OwnedTable<TestScalar> =
+        owned_table([bigint("a", [1, 2]), bigint("b", [3, 4])]);
+    let renamed: OwnedTable<TestScalar> = owned_table([bigint("a", [1, 2]), bigint("c", [3, 4])]);
+    let reordered: OwnedTable<TestScalar> = owned_table([bigint("b", [3, 4]), bigint("a", [1, 2])]);
+    let retyped: OwnedTable<TestScalar> = owned_table([bigint("a", [1, 2]), int("b", [3, 4])]);
+    let extra_column: OwnedTable<TestScalar> = owned_table([
+        bigint("a", [1, 2]),
+        bigint("b", [3, 4]),
+        bigint("c", [5, 6]),
+    ]);
+    assert!(!owned_table_a.equals_unordered(&renamed));
+    assert!(!owned_table_a.equals_unordered(&reordered));
+    assert!(!owned_table_a.equals_unordered(&retyped));
+    assert!(!owned_table_a.equals_unordered(&extra_column));
+}
+
+#[test]
+fn we_can_filter_an_owned_table_by_a_mask() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1_i64, 2, 3, 4]),
+        varchar("b", ["w", "x", "y", "z"]),
+        boolean("c", [true, false, true, false]),
+        decimal75("d", 10, 2, [10_i64, 20, 30, 40]),
+    ]);
+    assert_eq!(
+        table.filter_by_mask(&[true, false, false, true]).unwrap(),
+        owned_table([
+            bigint("a", [1_i64, 4]),
+            varchar("b", ["w", "z"]),
+            boolean("c", [true, false]),
+            decimal75("d", 10, 2, [10_i64, 40]),
+        ])
+    );
+    assert_eq!(
+        table.filter_by_mask(&[false; 4]).unwrap(),
+        owned_table([
+            bigint("a", [0_i64; 0]),
+            varchar("b", ["w"; 0]),
+            boolean("c", [true; 0]),
+            decimal75("d", 10, 2, [0_i64; 0]),
+        ])
+    );
+    assert_eq!(table.filter_by_mask(&[true; 4]).unwrap(), table);
+}
+
+#[test]
+fn we_cannot_filter_an_owned_table_by_a_mask_of_the_wrong_length() {
+    let table = owned_table::<TestScalar>([bigint("a", [1_i64, 2, 3])]);
+    assert_eq!(
+        table.filter_by_mask(&[true, false]),
+        Err(OwnedTableError::MaskLengthMismatch {
+            mask_length: 2,
+            num_rows: 3
+        })
+    );
+}
+
+#[test]
+fn we_can_select_and_reorder_the_columns_of_an_owned_table() {
+    let table = owned_table::<TestScalar>([
+        bigint("a", [1_i64, 2]),
+        varchar("b", ["x", "y"]),
+        boolean("c", [true, false]),
+    ]);
+    assert_eq!(
+        table.select(&["c", "a"]).unwrap(),
+        owned_table([boolean("c", [true, false]), bigint("a", [1_i64, 2])])
+    );
+    assert_eq!(
+        table.select(&["b"]).unwrap(),
+        owned_table([varchar("b", ["x", "y"])])
+    );
+    assert_eq!(table.select(&["a", "b", "c"]).unwrap(), table);
+    assert_eq!(table.select(&[]).unwrap().num_columns(), 0);
+}
+
+#[test]
+fn we_cannot_select_missing_or_repeated_columns_of_an_owned_table() {
+    let table = owned_table::<TestScalar>([bigint("a", [1_i64, 2]), varchar("b", ["x", "y"])]);
+    assert_eq!(
+        table.select(&["a", "d"]),
+        Err(OwnedTableError::ColumnNotFound { name: "d".into() })
+    );
+    assert_eq!(
+        table.select(&["a", "a"]),
+        Err(OwnedTableError::DuplicateColumnName { name: "a".into() })
+    );
+}
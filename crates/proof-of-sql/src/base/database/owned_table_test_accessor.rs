@@ -3,17 +3,40 @@ use super::{
     OwnedTable, SchemaAccessor, TableRef, TestAccessor,
 };
 use crate::base::{
-    commitment::{CommitmentEvaluationProof, VecCommitmentExt},
+    commitment::{
+        ColumnCommitmentsMismatch, CommitmentEvaluationProof, TableCommitment, VecCommitmentExt,
+    },
     map::IndexMap,
     scalar::ScalarExt,
 };
 use alloc::{string::String, vec::Vec};
 use bumpalo::Bump;
+use snafu::Snafu;
 use sqlparser::ast::Ident;
+
+/// Errors from adding a table to an [`OwnedTableTestAccessor`]
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum OwnedTableTestAccessorError {
+    /// The table does not fit into the setup of the accessor
+    #[snafu(display(
+        "table {table_ref} needs {required} rows of the setup, but the setup supports {max}"
+    ))]
+    SetupTooSmall {
+        /// The table that does not fit
+        table_ref: TableRef,
+        /// The offset plus the number of rows of the table
+        required: usize,
+        /// The maximum number of rows the setup supports
+        max: usize,
+    },
+}
+
 /// A test accessor that uses [`OwnedTable`] as the underlying table type.
 /// Note: this is intended for testing and examples. It is not optimized for performance, so should not be used for benchmarks or production use-cases.
 pub struct OwnedTableTestAccessor<'a, CP: CommitmentEvaluationProof> {
     tables: IndexMap<TableRef, (OwnedTable<CP::Scalar>, usize)>,
+    /// Commitments of tables which have had rows appended, kept up to date incrementally
+    commitments: IndexMap<TableRef, TableCommitment<CP::Commitment>>,
     alloc: Bump,
     setup: Option<CP::ProverPublicSetup<'a>>,
 }
@@ -22,6 +45,7 @@ impl<CP: CommitmentEvaluationProof> Default for OwnedTableTestAccessor<'_, CP> {
     fn default() -> Self {
         Self {
             tables: IndexMap::default(),
+            commitments: IndexMap::default(),
             alloc: Bump::new(),
             setup: None,
         }
@@ -32,6 +56,7 @@ impl<CP: CommitmentEvaluationProof> Clone for OwnedTableTestAccessor<'_, CP> {
     fn clone(&self) -> Self {
         Self {
             tables: self.tables.clone(),
+            commitments: self.commitments.clone(),
             setup: self.setup,
             ..Default::default()
         }
@@ -48,6 +73,7 @@ impl<CP: CommitmentEvaluationProof> TestAccessor<CP::Commitment>
     }
 
     fn add_table(&mut self, table_ref: TableRef, data: Self::Table, table_offset: usize) {
+        self.commitments.shift_remove(&table_ref);
         self.tables.insert(table_ref, (data, table_offset));
     }
     ///
@@ -70,6 +96,7 @@ impl<CP: CommitmentEvaluationProof> TestAccessor<CP::Commitment>
     ///
     /// Will panic if the `table_ref` is not found in `self.tables`, indicating that an invalid reference was provided.
     fn update_offset(&mut self, table_ref: &TableRef, new_offset: usize) {
+        self.commitments.shift_remove(table_ref);
         self.tables.get_mut(&table_ref).unwrap().1 = new_offset;
     }
 }
@@ -139,6 +166,12 @@ impl<CP: CommitmentEvaluationProof> CommitmentAccessor<CP::Commitment>
     for OwnedTableTestAccessor<'_, CP>
 {
     fn get_commitment(&self, table_ref: &TableRef, column_id: &Ident) -> CP::Commitment {
+        if let Some(table_commitment) = self.commitments.get(table_ref) {
+            return table_commitment
+                .column_commitments()
+                .get_commitment(column_id)
+                .unwrap();
+        }
         let (table, offset) = self.tables.get(table_ref).unwrap();
         let owned_column = table.inner_table().get(column_id).unwrap();
         Vec::<CP::Commitment>::from_columns_with_offset(
@@ -164,6 +197,12 @@ impl<CP: CommitmentEvaluationProof> MetadataAccessor for OwnedTableTestAccessor<
     fn get_offset(&self, table_ref: &TableRef) -> usize {
         self.tables.get(&table_ref).unwrap().1
     }
+
+    fn table_row_count(&self, table_ref: &TableRef) -> Option<usize> {
+        self.tables
+            .get(table_ref)
+            .map(|(table, _)| table.num_rows())
+    }
 }
 impl<CP: CommitmentEvaluationProof> SchemaAccessor for OwnedTableTestAccessor<'_, CP> {
     fn lookup_column(&self, table_ref: &TableRef, column_id: &Ident) -> Option<ColumnType> {
@@ -190,6 +229,10 @@ impl<CP: CommitmentEvaluationProof> SchemaAccessor for OwnedTableTestAccessor<'_
             .map(|(id, col)| (id.clone(), col.column_type()))
             .collect()
     }
+
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.tables.contains_key(table_ref)
+    }
 }
 
 impl<'a, CP: CommitmentEvaluationProof> OwnedTableTestAccessor<'a, CP> {
@@ -211,4 +254,79 @@ impl<'a, CP: CommitmentEvaluationProof> OwnedTableTestAccessor<'a, CP> {
         res.add_table(table_ref, owned_table, offset);
         res
     }
+
+    /// Adds a table after checking that it fits into the setup of the accessor.
+    ///
+    /// All tables share the generators of the setup, so a table fits if its offset plus its
+    /// number of rows is within [`CommitmentEvaluationProof::max_rows_for_setup`]. Otherwise
+    /// proofs involving the table could never verify. The table replaces any table with the
+    /// same `table_ref`.
+    ///
+    /// Columns are committed to independently, so the number of columns is not limited. Note
+    /// that intermediate results of a query, e.g. of a join, may still be longer than the tables.
+    ///
+    /// # Errors
+    /// Returns [`OwnedTableTestAccessorError::SetupTooSmall`], leaving the accessor unchanged,
+    /// if the table does not fit.
+    ///
+    /// # Panics
+    /// Panics if the accessor has no setup.
+    pub fn try_add_table(
+        &mut self,
+        table_ref: TableRef,
+        data: OwnedTable<CP::Scalar>,
+        table_offset: usize,
+    ) -> Result<(), OwnedTableTestAccessorError> {
+        if let Some(max) = CP::max_rows_for_setup(self.setup.as_ref().unwrap()) {
+            let required = table_offset.saturating_add(data.num_rows());
+            if required > max {
+                return Err(OwnedTableTestAccessorError::SetupTooSmall {
+                    table_ref,
+                    required,
+                    max,
+                });
+            }
+        }
+        self.add_table(table_ref, data, table_offset);
+        Ok(())
+    }
+
+    /// Appends `rows` to the end of an existing table.
+    ///
+    /// Rather than recommitting to the whole table, the commitments to the new rows are
+    /// computed with the generators following the existing rows and added to the existing
+    /// column commitments.
+    ///
+    /// # Errors
+    /// Returns an error, leaving the table unchanged, if `rows` does not have the same
+    /// columns in the same order as the table.
+    ///
+    /// # Panics
+    /// Panics if the `table_ref` is not found in `self.tables` or if the accessor has no setup.
+    pub fn append_rows(
+        &mut self,
+        table_ref: &TableRef,
+        rows: &OwnedTable<CP::Scalar>,
+    ) -> Result<(), ColumnCommitmentsMismatch> {
+        let setup = self.setup.as_ref().unwrap();
+        let (table, offset) = self.tables.get_mut(table_ref).unwrap();
+        self.commitments
+            .entry(table_ref.clone())
+            .or_insert_with(|| TableCommitment::from_owned_table_with_offset(table, *offset, setup))
+            .append_owned_table(rows, setup)?;
+        *table = OwnedTable::try_new(
+            table
+                .inner_table()
+                .iter()
+                .zip(rows.inner_table().values())
+                .map(|((id, column), new_rows)| {
+                    let mut column = column.clone();
+                    column.append(new_rows);
+                    (id.clone(), column)
+                })
+                .collect(),
+        )
+        .expect("appending rows of equal length to every column keeps them of equal length");
+        Ok(())
+    }
 }
@@ -1,16 +1,26 @@
 use super::{
     Column, ColumnType, CommitmentAccessor, DataAccessor, MetadataAccessor, OwnedTableTestAccessor,
-    SchemaAccessor, TestAccessor,
+    OwnedTableTestAccessorError, SchemaAccessor, TestAccessor,
 };
-use crate::base::{
-    commitment::{
-        naive_commitment::NaiveCommitment, naive_evaluation_proof::NaiveEvaluationProof,
-        Commitment, CommittableColumn,
+use crate::{
+    base::{
+        commitment::{
+            naive_commitment::NaiveCommitment, naive_evaluation_proof::NaiveEvaluationProof,
+            Commitment, CommittableColumn,
+        },
+        database::{owned_table_utility::*, TableRef},
+        posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
+        scalar::test_scalar::TestScalar,
+    },
+    proof_primitive::dory::{
+        test_rng, DynamicDoryEvaluationProof, ProverSetup, PublicParameters, VerifierSetup,
+    },
+    sql::{
+        proof::VerifiableQueryResult,
+        proof_plans::test_utility::{column_field, sort_merge_join, table_exec},
     },
-    database::{owned_table_utility::*, TableRef},
-    posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
-    scalar::test_scalar::TestScalar,
 };
+use sqlparser::ast::Ident;
 
 #[test]
 fn we_can_query_the_length_of_a_table() {
@@ -30,6 +40,24 @@ fn we_can_query_the_length_of_a_table() {
     assert_eq!(accessor.get_length(&table_ref_2), 4);
 }
 
+#[test]
+fn we_can_query_the_row_count_of_a_table() {
+    let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_empty_with_setup(());
+    let table_ref_1 = TableRef::new("sxt", "test1");
+    let table_ref_2 = TableRef::new("sxt", "test2");
+
+    let data1 = owned_table([bigint("a", [1, 2, 3]), bigint("b", [4, 5, 6])]);
+    accessor.add_table(table_ref_1.clone(), data1, 5_usize);
+
+    assert_eq!(accessor.table_row_count(&table_ref_1), Some(3));
+    assert_eq!(accessor.table_row_count(&table_ref_2), None);
+
+    let data2 = owned_table([bigint("a", [0; 0])]);
+    accessor.add_table(table_ref_2.clone(), data2, 0_usize);
+
+    assert_eq!(accessor.table_row_count(&table_ref_2), Some(0));
+}
+
 #[test]
 fn we_can_access_the_columns_of_a_table() {
     let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_empty_with_setup(());
@@ -241,3 +269,190 @@ fn we_can_correctly_update_offsets() {
     assert_eq!(accessor1.get_offset(&table_ref), offset);
     assert_eq!(accessor2.get_offset(&table_ref), offset);
 }
+
+#[test]
+fn we_can_append_rows_to_a_table_and_update_its_commitments() {
+    let table_ref = TableRef::new("sxt", "test");
+    let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_from_table(
+        table_ref.clone(),
+        owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])]),
+        3,
+        (),
+    );
+    accessor
+        .append_rows(
+            &table_ref,
+            &owned_table([bigint("a", [3, 4, 5]), varchar("b", ["z", "", "w"])]),
+        )
+        .unwrap();
+    accessor
+        .append_rows(
+            &table_ref,
+            &owned_table([bigint("a", [6]), varchar("b", ["v"])]),
+        )
+        .unwrap();
+
+    let expected = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_from_table(
+        table_ref.clone(),
+        owned_table([
+            bigint("a", [1, 2, 3, 4, 5, 6]),
+            varchar("b", ["x", "y", "z", "", "w", "v"]),
+        ]),
+        3,
+        (),
+    );
+    assert_eq!(accessor.get_length(&table_ref), 6);
+    assert_eq!(accessor.get_offset(&table_ref), 3);
+    for column_id in ["a", "b"] {
+        assert_eq!(
+            accessor.get_commitment(&table_ref, &column_id.into()),
+            expected.get_commitment(&table_ref, &column_id.into())
+        );
+    }
+    match accessor.get_column(&table_ref, &"a".into()) {
+        Column::BigInt(col) => assert_eq!(col.to_vec(), vec![1, 2, 3, 4, 5, 6]),
+        _ => panic!("Invalid column type"),
+    }
+
+    // Moving the table invalidates the appended commitments
+    accessor.update_offset(&table_ref, 0);
+    let mut expected = expected;
+    expected.update_offset(&table_ref, 0);
+    assert_eq!(
+        accessor.get_commitment(&table_ref, &"a".into()),
+        expected.get_commitment(&table_ref, &"a".into())
+    );
+}
+
+#[test]
+fn we_cannot_append_rows_with_a_different_schema() {
+    let table_ref = TableRef::new("sxt", "test");
+    let data = owned_table([bigint("a", [1, 2]), varchar("b", ["x", "y"])]);
+    let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_from_table(
+        table_ref.clone(),
+        data.clone(),
+        0,
+        (),
+    );
+    let commitment = accessor.get_commitment(&table_ref, &"a".into());
+
+    assert!(accessor
+        .append_rows(&table_ref, &owned_table([bigint("a", [3])]))
+        .is_err());
+    assert!(accessor
+        .append_rows(
+            &table_ref,
+            &owned_table([varchar("b", ["z"]), bigint("a", [3])])
+        )
+        .is_err());
+    assert!(accessor
+        .append_rows(
+            &table_ref,
+            &owned_table([bigint("a", [3]), bigint("b", [4])])
+        )
+        .is_err());
+
+    assert_eq!(accessor.get_length(&table_ref), 2);
+    assert_eq!(accessor.get_commitment(&table_ref, &"a".into()), commitment);
+}
+
+#[test]
+fn we_can_add_several_tables_sharing_a_setup_and_prove_a_join() {
+    let public_parameters = PublicParameters::test_rand(3, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let verifier_setup = VerifierSetup::from(&public_parameters);
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    let cats = TableRef::new("sxt", "cats");
+    let owners = TableRef::new("sxt", "owners");
+    accessor
+        .try_add_table(
+            cats.clone(),
+            owned_table([
+                bigint("id", [1, 2, 3]),
+                varchar("name", ["Chloe", "Margaret", "Prudence"]),
+            ]),
+            0,
+        )
+        .unwrap();
+    accessor
+        .try_add_table(
+            owners.clone(),
+            owned_table([
+                bigint("id", [2, 3, 3, 4]),
+                varchar("human", ["Cassia", "Gretta", "Ian", "Erik"]),
+            ]),
+            0,
+        )
+        .unwrap();
+    let ast = sort_merge_join(
+        table_exec(
+            cats,
+            vec![
+                column_field("id", ColumnType::BigInt),
+                column_field("name", ColumnType::VarChar),
+            ],
+        ),
+        table_exec(
+            owners,
+            vec![
+                column_field("id", ColumnType::BigInt),
+                column_field("human", ColumnType::VarChar),
+            ],
+        ),
+        vec![0],
+        vec![0],
+        vec![Ident::new("id"), Ident::new("name"), Ident::new("human")],
+    );
+    let res = VerifiableQueryResult::<DynamicDoryEvaluationProof>::new(
+        &ast,
+        &accessor,
+        &&prover_setup,
+        &[],
+    )
+    .unwrap()
+    .verify(&ast, &accessor, &&verifier_setup, &[])
+    .unwrap()
+    .table;
+    let expected_res = owned_table([
+        bigint("id", [2, 3, 3]),
+        varchar("name", ["Margaret", "Prudence", "Prudence"]),
+        varchar("human", ["Cassia", "Gretta", "Ian"]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_add_a_table_which_does_not_fit_into_the_setup() {
+    // A setup with max_nu = 3 supports 32 rows
+    let public_parameters = PublicParameters::test_rand(3, &mut test_rng());
+    let prover_setup = ProverSetup::from(&public_parameters);
+    let mut accessor =
+        OwnedTableTestAccessor::<DynamicDoryEvaluationProof>::new_empty_with_setup(&prover_setup);
+    let table_ref = TableRef::new("sxt", "t");
+
+    accessor
+        .try_add_table(table_ref.clone(), owned_table([bigint("a", 0..32)]), 0)
+        .unwrap();
+    assert_eq!(accessor.get_length(&table_ref), 32);
+
+    let big_table_ref = TableRef::new("sxt", "big");
+    assert_eq!(
+        accessor.try_add_table(big_table_ref.clone(), owned_table([bigint("a", 0..33)]), 0),
+        Err(OwnedTableTestAccessorError::SetupTooSmall {
+            table_ref: big_table_ref.clone(),
+            required: 33,
+            max: 32,
+        })
+    );
+    // The offset counts towards the rows of the setup
+    assert_eq!(
+        accessor.try_add_table(big_table_ref.clone(), owned_table([bigint("a", 0..3)]), 30),
+        Err(OwnedTableTestAccessorError::SetupTooSmall {
+            table_ref: big_table_ref.clone(),
+            required: 33,
+            max: 32,
+        })
+    );
+    assert!(!accessor.has_table(&big_table_ref));
+}
@@ -83,6 +83,22 @@ impl TableRef {
             }),
         }
     }
+
+    /// Parses a `TableRef` from `schema.table` or a bare `table`.
+    ///
+    /// Unlike parsing with [`FromStr`], the components are trimmed and must not be empty.
+    pub fn from_qualified_str(s: &str) -> Result<Self, ParseError> {
+        let components: Vec<_> = s.split('.').map(str::trim).collect();
+        match components.as_slice() {
+            [table] if !table.is_empty() => Ok(Self::from_names(None, table)),
+            [schema, table] if !schema.is_empty() && !table.is_empty() => {
+                Ok(Self::from_names(Some(schema), table))
+            }
+            _ => Err(ParseError::InvalidTableReference {
+                table_reference: s.to_string(),
+            }),
+        }
+    }
 }
 
 /// Creates a `TableRef` from a dot-separated string.
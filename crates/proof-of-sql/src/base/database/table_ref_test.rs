@@ -0,0 +1,47 @@
+use super::{ParseError, TableRef};
+
+#[test]
+fn we_can_parse_qualified_table_refs() {
+    assert_eq!(
+        TableRef::from_qualified_str("sxt.t").unwrap(),
+        TableRef::new("sxt", "t")
+    );
+    assert_eq!(
+        TableRef::from_qualified_str("  sxt . t ").unwrap(),
+        TableRef::new("sxt", "t")
+    );
+}
+
+#[test]
+fn we_can_parse_unqualified_table_refs() {
+    assert_eq!(
+        TableRef::from_qualified_str("t").unwrap(),
+        TableRef::from_names(None, "t")
+    );
+    assert_eq!(
+        TableRef::from_qualified_str(" t ").unwrap(),
+        TableRef::from_names(None, "t")
+    );
+}
+
+#[test]
+fn we_cannot_parse_table_refs_with_more_than_two_components() {
+    assert_eq!(
+        TableRef::from_qualified_str("a.b.c"),
+        Err(ParseError::InvalidTableReference {
+            table_reference: "a.b.c".to_string(),
+        })
+    );
+}
+
+#[test]
+fn we_cannot_parse_table_refs_with_empty_components() {
+    for s in ["", "   ", ".", "sxt.", ".t", " . t", "sxt.  "] {
+        assert_eq!(
+            TableRef::from_qualified_str(s),
+            Err(ParseError::InvalidTableReference {
+                table_reference: s.to_string(),
+            })
+        );
+    }
+}
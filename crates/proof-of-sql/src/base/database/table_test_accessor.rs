@@ -120,6 +120,12 @@ impl<CP: CommitmentEvaluationProof> MetadataAccessor for TableTestAccessor<'_, C
     fn get_offset(&self, table_ref: &TableRef) -> usize {
         self.tables.get(&table_ref).unwrap().1
     }
+
+    fn table_row_count(&self, table_ref: &TableRef) -> Option<usize> {
+        self.tables
+            .get(table_ref)
+            .map(|(table, _)| table.num_rows())
+    }
 }
 impl<CP: CommitmentEvaluationProof> SchemaAccessor for TableTestAccessor<'_, CP> {
     fn lookup_column(&self, table_ref: &TableRef, column_id: &Ident) -> Option<ColumnType> {
@@ -146,6 +152,10 @@ impl<CP: CommitmentEvaluationProof> SchemaAccessor for TableTestAccessor<'_, CP>
             .map(|(id, col)| (id.clone(), col.column_type()))
             .collect()
     }
+
+    fn has_table(&self, table_ref: &TableRef) -> bool {
+        self.tables.contains_key(table_ref)
+    }
 }
 
 impl<'a, CP: CommitmentEvaluationProof> TableTestAccessor<'a, CP> {
@@ -293,6 +293,37 @@ pub fn borrowed_varchar<'a, S: Scalar>(
     (name.into(), Column::VarChar((alloc_strings, alloc_scalars)))
 }
 
+/// Creates a `(Ident, Column)` pair for a varbinary column.
+/// This is primarily intended for use in conjunction with [`table`].
+/// # Example
+/// ```
+/// use bumpalo::Bump;
+/// use proof_of_sql::base::{database::table_utility::*};
+/// # use proof_of_sql::base::scalar::MontScalar;
+/// # pub type MyScalar = MontScalar<ark_curve25519::FrConfig>;
+/// let alloc = Bump::new();
+/// let result = table::<MyScalar>([
+///     borrowed_varbinary("a", [[1_u8, 2].as_slice(), &[3]], &alloc),
+/// ]);
+/// ```
+pub fn borrowed_varbinary<'a, S: Scalar>(
+    name: impl Into<Ident>,
+    data: impl IntoIterator<Item = impl AsRef<[u8]>>,
+    alloc: &'a Bump,
+) -> (Ident, Column<'a, S>) {
+    let bytes: Vec<&'a [u8]> = data
+        .into_iter()
+        .map(|item| alloc.alloc_slice_copy(item.as_ref()) as &'a [u8])
+        .collect();
+    let alloc_bytes = alloc.alloc_slice_clone(&bytes);
+    let scalars: Vec<S> = bytes
+        .iter()
+        .map(|b| S::from_byte_slice_via_hash(b))
+        .collect();
+    let alloc_scalars = alloc.alloc_slice_copy(&scalars);
+    (name.into(), Column::VarBinary((alloc_bytes, alloc_scalars)))
+}
+
 /// Creates a `(Ident, Column)` pair for a decimal75 column.
 /// This is primarily intended for use in conjunction with [`table`].
 /// # Example
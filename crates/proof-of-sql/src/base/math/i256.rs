@@ -2,6 +2,7 @@ use crate::base::{
     scalar::Scalar,
     standard_serializations::limbs::{deserialize_to_limbs, serialize_limbs},
 };
+use alloc::vec::Vec;
 use ark_ff::BigInteger;
 use core::ops::Neg;
 use serde::{Deserialize, Serialize};
@@ -71,6 +72,13 @@ impl I256 {
             num_bigint::Sign::Plus | num_bigint::Sign::NoSign => Self(limbs),
         }
     }
+
+    #[must_use]
+    /// Conversion into a [`num_bigint::BigInt`], interpreting the `I256` as a signed integer.
+    pub fn to_num_bigint(&self) -> num_bigint::BigInt {
+        let bytes: Vec<u8> = self.0.iter().flat_map(|limb| limb.to_le_bytes()).collect();
+        num_bigint::BigInt::from_signed_bytes_le(&bytes)
+    }
 }
 impl From<i32> for I256 {
     fn from(value: i32) -> Self {
@@ -275,6 +283,25 @@ mod tests {
             assert_eq!(I256::from_num_bigint(&y), I256::from_num_bigint(&-y).neg());
         }
     }
+    #[test]
+    fn we_can_convert_i256_to_num_bigint() {
+        assert_eq!(ZERO.to_num_bigint(), BigInt::from(0));
+        assert_eq!(ONE.to_num_bigint(), BigInt::from(1));
+        assert_eq!(NEG_ONE.to_num_bigint(), BigInt::from(-1));
+        assert_eq!(NEG_TWO.to_num_bigint(), BigInt::from(-2));
+        for (value, value_str) in [(A, A_STR), (B, B_STR), (C, C_STR)] {
+            let expected = value_str.parse::<BigInt>().unwrap();
+            assert_eq!(value.to_num_bigint(), expected);
+            assert_eq!(value.neg().to_num_bigint(), -expected);
+        }
+
+        let mut rng = thread_rng();
+        for _ in 0..10 {
+            let x = I256::from(rng.gen::<i128>());
+            assert_eq!(I256::from_num_bigint(&x.to_num_bigint()), x);
+        }
+    }
+
     #[test]
     fn we_can_convert_i256_from_i32() {
         assert_eq!(I256::from(0), ZERO);
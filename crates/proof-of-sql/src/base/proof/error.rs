@@ -86,6 +86,15 @@ pub enum PlaceholderError {
     #[snafu(display("Placeholder id must be greater than 0"))]
     /// Placeholder id is zero
     ZeroPlaceholderId,
+
+    #[snafu(display("Expected {expected} params, got {actual}"))]
+    /// More params were supplied than there are placeholders
+    InvalidParamCount {
+        /// The number of params the placeholders require
+        expected: usize,
+        /// The number of params supplied
+        actual: usize,
+    },
 }
 
 /// Result type for placeholder errors
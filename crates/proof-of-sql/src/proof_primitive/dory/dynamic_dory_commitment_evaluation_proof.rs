@@ -1,6 +1,8 @@
 use super::{
     dynamic_build_vmv_state::{build_dynamic_vmv_prover_state, build_dynamic_vmv_verifier_state},
-    dynamic_dory_helper::{compute_dynamic_T_vec_prime, compute_dynamic_nu, fold_dynamic_tensors},
+    dynamic_dory_helper::{
+        compute_dynamic_T_vec_prime, compute_dynamic_nu, fold_dynamic_tensors, max_rows_for_max_nu,
+    },
     eval_vmv_re_prove, eval_vmv_re_verify, extended_dory_inner_product_prove,
     extended_dory_inner_product_verify, DeferredGT, DoryMessages, DoryScalar,
     DynamicDoryCommitment, ProverSetup, VerifierSetup, F,
@@ -130,4 +132,8 @@ impl CommitmentEvaluationProof for DynamicDoryEvaluationProof {
 
         Ok(())
     }
+
+    fn max_rows_for_setup(setup: &Self::ProverPublicSetup<'_>) -> Option<usize> {
+        Some(max_rows_for_max_nu(setup.max_nu))
+    }
 }
@@ -95,6 +95,34 @@ pub(super) fn compute_dynamic_nu(num_vars: usize) -> usize {
     num_vars / 2 + 1
 }
 
+/// Returns the maximum number of rows a table may have to be proven with a
+/// [`DynamicDoryEvaluationProof`](super::DynamicDoryEvaluationProof) setup of the given `max_nu`.
+///
+/// This is `2^(2*max_nu-1)`, saturating at `usize::MAX`. A setup with `max_nu = 0` can not prove anything.
+#[must_use]
+pub fn max_rows_for_max_nu(max_nu: usize) -> usize {
+    match max_nu {
+        0 => 0,
+        _ => max_nu
+            .checked_mul(2)
+            .and_then(|double_max_nu| u32::try_from(double_max_nu - 1).ok())
+            .and_then(|shift| 1usize.checked_shl(shift))
+            .unwrap_or(usize::MAX),
+    }
+}
+
+/// Returns the smallest `max_nu` of a [`DynamicDoryEvaluationProof`](super::DynamicDoryEvaluationProof)
+/// setup which can prove tables with `rows` rows.
+///
+/// This is the inverse of [`max_rows_for_max_nu`], so callers can pass the length of their largest table.
+#[must_use]
+pub fn min_max_nu_for_rows(rows: usize) -> usize {
+    let num_vars = rows
+        .checked_next_power_of_two()
+        .map_or(usize::BITS, usize::trailing_zeros) as usize;
+    compute_dynamic_nu(num_vars)
+}
+
 /// Folds the `s1` and `s2` tensors:
 ///
 /// This is the analogous function of the non-dynamic folding function [`extended_dory_reduce_verify_fold_s_vecs`](super::extended_dory_reduce_helper::extended_dory_reduce_verify_fold_s_vecs).
@@ -244,4 +272,35 @@ mod tests {
         ];
         assert_eq!(T_vec_prime, expected_T_vec_prime);
     }
+
+    #[test]
+    fn we_can_compute_the_max_rows_for_a_max_nu() {
+        assert_eq!(max_rows_for_max_nu(0), 0);
+        assert_eq!(max_rows_for_max_nu(1), 2);
+        assert_eq!(max_rows_for_max_nu(3), 32);
+        assert_eq!(max_rows_for_max_nu(4), 128);
+        assert_eq!(max_rows_for_max_nu(8), 32_768);
+        assert_eq!(max_rows_for_max_nu(10), 524_288);
+        assert_eq!(max_rows_for_max_nu(15), 536_870_912);
+        assert_eq!(max_rows_for_max_nu(20), 549_755_813_888);
+        assert_eq!(max_rows_for_max_nu(usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn we_can_compute_the_min_max_nu_for_a_number_of_rows() {
+        assert_eq!(min_max_nu_for_rows(0), 1);
+        assert_eq!(min_max_nu_for_rows(1), 1);
+        assert_eq!(min_max_nu_for_rows(2), 1);
+        assert_eq!(min_max_nu_for_rows(3), 2);
+        assert_eq!(min_max_nu_for_rows(32), 3);
+        assert_eq!(min_max_nu_for_rows(33), 4);
+        assert_eq!(min_max_nu_for_rows(32_768), 8);
+        assert_eq!(min_max_nu_for_rows(500_000), 10);
+        for rows in 1..2000 {
+            let max_nu = min_max_nu_for_rows(rows);
+            assert!(rows <= max_rows_for_max_nu(max_nu));
+            assert!(rows > max_rows_for_max_nu(max_nu - 1));
+        }
+        assert_eq!(min_max_nu_for_rows(usize::MAX), 33);
+    }
 }
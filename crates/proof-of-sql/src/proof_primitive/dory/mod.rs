@@ -154,6 +154,7 @@ mod dynamic_dory_helper;
 use dynamic_dory_commitment_helper_cpu::compute_dynamic_dory_commitments;
 #[cfg(feature = "blitzar")]
 use dynamic_dory_commitment_helper_gpu::compute_dynamic_dory_commitments;
+pub use dynamic_dory_helper::{max_rows_for_max_nu, min_max_nu_for_rows};
 mod dynamic_dory_commitment;
 mod dynamic_dory_commitment_evaluation_proof;
 #[cfg(test)]
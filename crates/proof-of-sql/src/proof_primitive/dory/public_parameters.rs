@@ -1,4 +1,5 @@
 use super::{G1Affine, G2Affine};
+use crate::base::impl_serde_for_ark_serde_checked;
 use alloc::vec::Vec;
 use ark_ff::UniformRand;
 use ark_serialize::{
@@ -9,10 +10,17 @@ use core::iter;
 #[cfg(feature = "std")]
 use std::{
     fs::File,
-    io::{BufReader, BufWriter, Error, Read, Write},
+    io::{BufReader, BufWriter, Error, ErrorKind, Read, Write},
     path::Path,
 };
 
+/// The magic number at the start of files written by [`PublicParameters::save_to_file`]
+#[cfg(feature = "std")]
+const FILE_MAGIC: [u8; 8] = *b"PoSQLDry";
+/// The version of the file format written by [`PublicParameters::save_to_file`]
+#[cfg(feature = "std")]
+const FILE_VERSION: u32 = 1;
+
 /// The public parameters for the Dory protocol. See section 5 of <https://eprint.iacr.org/2020/1274.pdf> for details.
 ///
 /// Note: even though `H_1` and `H_2` are marked as blue, they are still needed.
@@ -59,43 +67,103 @@ impl PublicParameters {
         }
     }
     #[cfg(feature = "std")]
+    /// Function to save `PublicParameters` to a writer in binary form
+    pub fn save_to_writer<W: Write>(&self, writer: W) -> std::io::Result<()> {
+        self.serialize_with_mode(writer, Compress::No)
+            .map_err(|e| Error::other(format!("{e}")))
+    }
+    #[cfg(feature = "std")]
+    /// Function to load `PublicParameters` from a reader in binary form
+    ///
+    /// The data is validated, including that `max_nu` is small enough for a setup of
+    /// `2^max_nu` generators.
+    pub fn load_from_reader<R: Read>(reader: R) -> std::io::Result<Self> {
+        PublicParameters::deserialize_with_mode(reader, Compress::No, Validate::Yes)
+            .map_err(|e| Error::other(format!("{e}")))
+    }
+    #[cfg(feature = "std")]
     /// Function to save `PublicParameters` to a file in binary form
+    ///
+    /// The file starts with a header consisting of a magic number, the file format version,
+    /// and `max_nu`, followed by the parameters in the format of
+    /// [`PublicParameters::save_to_writer`].
     pub fn save_to_file(&self, path: &Path) -> std::io::Result<()> {
         // Create or open the file at the specified path
         let file = File::create(path)?;
         let mut writer = BufWriter::new(file);
 
-        // Serialize the PublicParameters struct into the file
-        let mut serialized_data = Vec::new();
-        self.serialize_with_mode(&mut serialized_data, Compress::No)
-            .map_err(|e| Error::other(format!("{e}")))?;
+        // Write the header
+        writer.write_all(&FILE_MAGIC)?;
+        writer.write_all(&FILE_VERSION.to_le_bytes())?;
+        writer.write_all(&(self.max_nu as u64).to_le_bytes())?;
 
-        // Write serialized bytes to the file
-        writer.write_all(&serialized_data)?;
+        // Serialize the PublicParameters struct into the file
+        self.save_to_writer(&mut writer)?;
         writer.flush()?;
         Ok(())
     }
     #[cfg(feature = "std")]
     /// Function to load `PublicParameters` from a file in binary form
+    ///
+    /// Files without the magic number, with an unsupported version, or whose header does not match
+    /// the parameters, are rejected with [`ErrorKind::InvalidData`].
+    /// Use [`PublicParameters::load_from_legacy_file`] for files written before the header was
+    /// introduced.
     pub fn load_from_file(path: &Path) -> std::io::Result<Self> {
         // Open the file at the specified path
         let file = File::open(path)?;
         let mut reader = BufReader::new(file);
 
-        // Read the serialized data from the file
-        let mut serialized_data = Vec::new();
-        reader.read_to_end(&mut serialized_data)?;
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if magic != FILE_MAGIC {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "not a PublicParameters file (missing magic number)",
+            ));
+        }
 
-        // Deserialize the data into a PublicParameters instance
-        PublicParameters::deserialize_with_mode(
-            &mut &serialized_data[..],
-            Compress::No,
-            Validate::Yes,
-        )
-        .map_err(|e| Error::other(format!("{e}")))
+        let mut version = [0u8; 4];
+        reader.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+        if version != FILE_VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "unsupported PublicParameters file version {version}, expected {FILE_VERSION}"
+                ),
+            ));
+        }
+
+        let mut max_nu = [0u8; 8];
+        reader.read_exact(&mut max_nu)?;
+        let max_nu = u64::from_le_bytes(max_nu);
+
+        let public_parameters = Self::load_from_reader(reader)?;
+        if public_parameters.max_nu as u64 != max_nu {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "PublicParameters file header has max_nu = {max_nu}, but the parameters have max_nu = {}",
+                    public_parameters.max_nu
+                ),
+            ));
+        }
+        Ok(public_parameters)
+    }
+    #[cfg(feature = "std")]
+    /// Function to load `PublicParameters` from a file written before
+    /// [`PublicParameters::save_to_file`] added a header
+    ///
+    /// Such files contain only the parameters, in the format of
+    /// [`PublicParameters::save_to_writer`].
+    pub fn load_from_legacy_file(path: &Path) -> std::io::Result<Self> {
+        Self::load_from_reader(BufReader::new(File::open(path)?))
     }
 }
 
+impl_serde_for_ark_serde_checked!(PublicParameters);
+
 impl CanonicalSerialize for PublicParameters {
     fn serialize_with_mode<W: ark_serialize::Write>(
         &self,
@@ -172,14 +240,18 @@ impl CanonicalDeserialize for PublicParameters {
         let max_nu: usize = max_nu_u64
             .try_into()
             .map_err(|_| SerializationError::InvalidData)?;
+        let num_generators = u32::try_from(max_nu)
+            .ok()
+            .and_then(|max_nu| 1usize.checked_shl(max_nu))
+            .ok_or(SerializationError::InvalidData)?;
 
         // Deserialize Gamma_1 (Vec<G1Affine>)
-        let Gamma_1: Vec<G1Affine> = (0..(1 << max_nu))
+        let Gamma_1: Vec<G1Affine> = (0..num_generators)
             .map(|_| G1Affine::deserialize_with_mode(&mut reader, compress, validate))
             .collect::<Result<_, _>>()?;
 
         // Deserialize Gamma_2 (Vec<G2Affine>)
-        let Gamma_2: Vec<G2Affine> = (0..(1 << max_nu))
+        let Gamma_2: Vec<G2Affine> = (0..num_generators)
             .map(|_| G2Affine::deserialize_with_mode(&mut reader, compress, validate))
             .collect::<Result<_, _>>()?;
 
@@ -267,6 +339,152 @@ mod tests {
             .expect("Deserialized parameters are not valid");
     }
 
+    #[test]
+    fn we_can_save_and_load_public_parameters_with_a_writer_and_reader() {
+        let original_params = PublicParameters::test_rand(2, &mut thread_rng());
+
+        let mut bytes = Vec::new();
+        original_params.save_to_writer(&mut bytes).unwrap();
+        let loaded_params = PublicParameters::load_from_reader(&bytes[..]).unwrap();
+
+        assert_eq!(original_params.Gamma_1, loaded_params.Gamma_1);
+        assert_eq!(original_params.Gamma_2, loaded_params.Gamma_2);
+        assert_eq!(original_params.H_1, loaded_params.H_1);
+        assert_eq!(original_params.H_2, loaded_params.H_2);
+        assert_eq!(original_params.Gamma_2_fin, loaded_params.Gamma_2_fin);
+        assert_eq!(original_params.max_nu, loaded_params.max_nu);
+    }
+
+    #[test]
+    fn we_can_serde_round_trip_public_parameters() {
+        let original_params = PublicParameters::test_rand(2, &mut thread_rng());
+
+        let bytes = crate::base::try_standard_binary_serialization(&original_params).unwrap();
+        let (loaded_params, _): (PublicParameters, _) =
+            crate::base::try_standard_binary_deserialization(&bytes).unwrap();
+
+        assert_eq!(original_params.Gamma_1, loaded_params.Gamma_1);
+        assert_eq!(original_params.Gamma_2, loaded_params.Gamma_2);
+        assert_eq!(original_params.max_nu, loaded_params.max_nu);
+    }
+
+    #[test]
+    fn we_cannot_load_public_parameters_with_a_max_nu_that_is_too_large() {
+        let mut bytes = Vec::new();
+        64u64.serialize_with_mode(&mut bytes, Compress::No).unwrap();
+        assert!(PublicParameters::load_from_reader(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn we_cannot_load_truncated_public_parameters() {
+        let mut bytes = Vec::new();
+        PublicParameters::test_rand(2, &mut thread_rng())
+            .save_to_writer(&mut bytes)
+            .unwrap();
+        bytes.pop();
+        assert!(PublicParameters::load_from_reader(&bytes[..]).is_err());
+    }
+
+    fn temp_file_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("{name}_{}.bin", std::process::id()))
+    }
+
+    #[test]
+    fn we_can_load_public_parameters_from_a_legacy_file_without_a_header() {
+        let original_params = PublicParameters::test_rand(2, &mut thread_rng());
+        let file_path = temp_file_path("public_params_without_header");
+        let mut bytes = Vec::new();
+        original_params.save_to_writer(&mut bytes).unwrap();
+        std::fs::write(&file_path, bytes).unwrap();
+
+        let loaded_params = PublicParameters::load_from_legacy_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+        assert_eq!(original_params.Gamma_1, loaded_params.Gamma_1);
+        assert_eq!(original_params.max_nu, loaded_params.max_nu);
+    }
+
+    #[test]
+    fn we_cannot_load_public_parameters_from_a_file_without_a_header() {
+        let file_path = temp_file_path("public_params_missing_header");
+
+        // A legacy file without a header
+        let mut bytes = Vec::new();
+        PublicParameters::test_rand(2, &mut thread_rng())
+            .save_to_writer(&mut bytes)
+            .unwrap();
+        std::fs::write(&file_path, &bytes).unwrap();
+        assert_eq!(
+            PublicParameters::load_from_file(&file_path)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidData
+        );
+
+        // Arbitrary data
+        std::fs::write(&file_path, [0xAB; 64]).unwrap();
+        assert_eq!(
+            PublicParameters::load_from_file(&file_path)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidData
+        );
+        std::fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn we_cannot_load_public_parameters_from_a_truncated_file() {
+        let file_path = temp_file_path("public_params_truncated");
+        PublicParameters::test_rand(2, &mut thread_rng())
+            .save_to_file(&file_path)
+            .unwrap();
+        let mut bytes = std::fs::read(&file_path).unwrap();
+        bytes.truncate(bytes.len() - 1);
+        std::fs::write(&file_path, &bytes).unwrap();
+        assert!(PublicParameters::load_from_file(&file_path).is_err());
+
+        // Truncated within the header
+        std::fs::write(&file_path, &bytes[..10]).unwrap();
+        assert_eq!(
+            PublicParameters::load_from_file(&file_path)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::UnexpectedEof
+        );
+        std::fs::remove_file(&file_path).unwrap();
+    }
+
+    #[test]
+    fn we_cannot_load_public_parameters_from_a_file_with_a_mismatched_header() {
+        let file_path = temp_file_path("public_params_mismatched_header");
+        PublicParameters::test_rand(2, &mut thread_rng())
+            .save_to_file(&file_path)
+            .unwrap();
+        let bytes = std::fs::read(&file_path).unwrap();
+
+        // Unsupported version
+        let mut wrong_version = bytes.clone();
+        wrong_version[8..12].copy_from_slice(&2u32.to_le_bytes());
+        std::fs::write(&file_path, &wrong_version).unwrap();
+        assert_eq!(
+            PublicParameters::load_from_file(&file_path)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidData
+        );
+
+        // Header `max_nu` does not match the parameters
+        let mut wrong_max_nu = bytes;
+        wrong_max_nu[12..20].copy_from_slice(&3u64.to_le_bytes());
+        std::fs::write(&file_path, &wrong_max_nu).unwrap();
+        assert_eq!(
+            PublicParameters::load_from_file(&file_path)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidData
+        );
+        std::fs::remove_file(&file_path).unwrap();
+    }
+
     // 13th Gen Intel® Core™ i9-13900H × 20
     // nu vs proof size & time:
     // nu = 4  |  0.005 MB  | 287.972567ms
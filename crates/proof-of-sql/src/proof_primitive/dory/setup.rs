@@ -20,6 +20,9 @@ use std::{
 /// Note:
 /// We use nu = m and k = m-i or m-j.
 /// This indexing is more convenient for coding because lengths of the arrays used are typically 2^k rather than 2^i or 2^j.
+///
+/// A `ProverSetup` borrows from the [`PublicParameters`] it is created from, so it is persisted by
+/// saving those with [`PublicParameters::save_to_writer`] and converting them after loading.
 pub struct ProverSetup<'a> {
     /// `Gamma_1[k]` = Γ_1,(m-k) in the Dory paper.
     pub(super) Gamma_1: Vec<&'a [G1Affine]>,
@@ -269,6 +272,39 @@ impl VerifierSetup {
         }
     }
 
+    #[cfg(feature = "std")]
+    /// Function to save `VerifierSetup` to a writer in binary form
+    pub fn save_to_writer<W: Write>(&self, writer: W) -> std::io::Result<()> {
+        self.serialize_with_mode(writer, Compress::No)
+            .map_err(|e| Error::other(format!("{e}")))
+    }
+
+    #[cfg(feature = "std")]
+    /// Function to load `VerifierSetup` from a reader in binary form
+    ///
+    /// The group elements are not validated, but `max_nu` must be consistent with the lengths of the loaded vectors.
+    pub fn load_from_reader<R: Read>(reader: R) -> std::io::Result<Self> {
+        let setup = Self::deserialize_with_mode(reader, Compress::No, Validate::No)
+            .map_err(|e| Error::other(format!("{e}")))?;
+        let expected_len = setup.max_nu.checked_add(1);
+        if [
+            &setup.Delta_1L,
+            &setup.Delta_1R,
+            &setup.Delta_2L,
+            &setup.Delta_2R,
+            &setup.chi,
+        ]
+        .into_iter()
+        .any(|v| Some(v.len()) != expected_len)
+        {
+            return Err(Error::other(format!(
+                "VerifierSetup is inconsistent with max_nu = {}",
+                setup.max_nu
+            )));
+        }
+        Ok(setup)
+    }
+
     #[cfg(feature = "std")]
     /// Function to save `VerifierSetup` to a file in binary form
     pub fn save_to_file(&self, path: &Path) -> std::io::Result<()> {
@@ -277,13 +313,8 @@ impl VerifierSetup {
         let file = File::create(path)?;
         let mut writer = BufWriter::new(file);
 
-        // Serialize the PublicParameters struct into the file
-        let mut serialized_data = Vec::new();
-        self.serialize_with_mode(&mut serialized_data, Compress::No)
-            .map_err(|e| Error::other(format!("{e}")))?;
-
-        // Write serialized bytes to the file
-        writer.write_all(&serialized_data)?;
+        // Serialize the VerifierSetup struct into the file
+        self.save_to_writer(&mut writer)?;
         writer.flush()?;
         Ok(())
     }
@@ -294,15 +325,7 @@ impl VerifierSetup {
         // Open the file at the specified path
 
         let file = File::open(path)?;
-        let mut reader = BufReader::new(file);
-
-        // Read the serialized data from the file
-        let mut serialized_data = Vec::new();
-        reader.read_to_end(&mut serialized_data)?;
-
-        // Deserialize the data into a PublicParameters instance
-        Self::deserialize_with_mode(&mut &serialized_data[..], Compress::No, Validate::No)
-            .map_err(|e| Error::other(format!("{e}")))
+        Self::load_from_reader(BufReader::new(file))
     }
 }
 
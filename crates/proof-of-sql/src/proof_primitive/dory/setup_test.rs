@@ -159,3 +159,24 @@ fn we_can_serialize_and_deserialize_verifier_setups() {
         assert_eq!(setup, deserialized);
     }
 }
+
+#[test]
+fn we_can_save_and_load_a_verifier_setup_with_a_writer_and_reader() {
+    let pp = PublicParameters::test_rand(2, &mut test_rng());
+    let setup = VerifierSetup::from(&pp);
+
+    let mut bytes = Vec::new();
+    setup.save_to_writer(&mut bytes).unwrap();
+    assert_eq!(VerifierSetup::load_from_reader(&bytes[..]).unwrap(), setup);
+}
+
+#[test]
+fn we_cannot_load_a_verifier_setup_that_is_inconsistent_with_its_max_nu() {
+    let pp = PublicParameters::test_rand(2, &mut test_rng());
+    let mut setup = VerifierSetup::from(&pp);
+    setup.max_nu = 3;
+
+    let mut bytes = Vec::new();
+    setup.save_to_writer(&mut bytes).unwrap();
+    assert!(VerifierSetup::load_from_reader(&bytes[..]).is_err());
+}
@@ -2,7 +2,8 @@ use super::{BNScalar, HyperKZGPublicSetup};
 #[cfg(any(not(feature = "blitzar"), test))]
 use crate::base::if_rayon;
 use crate::base::{
-    commitment::{Commitment, CommittableColumn},
+    commitment::{Commitment, CommittableColumn, TableCommitment},
+    database::{OwnedColumn, OwnedTable},
     scalar::Scalar,
     slice_ops,
 };
@@ -108,6 +109,40 @@ impl Sub for HyperKZGCommitment {
     }
 }
 
+impl HyperKZGCommitment {
+    /// Computes the commitment to an [`OwnedColumn`] whose first row is at `offset`.
+    ///
+    /// Only the public setup is needed, so this is available in `no_std` builds without the `hyperkzg_proof` feature.
+    ///
+    /// # Panics
+    /// Panics if `offset + column.len()` exceeds the length of the setup.
+    #[must_use]
+    pub fn from_owned_column<S: Scalar>(
+        column: &OwnedColumn<S>,
+        offset: usize,
+        setup: &HyperKZGPublicSetup<'_>,
+    ) -> Self {
+        Self::compute_commitments(&[CommittableColumn::from(column)], offset, setup)
+            .pop()
+            .expect("one commitment is computed per column")
+    }
+
+    /// Computes the [`TableCommitment`] to an [`OwnedTable`] whose first row is at `offset`.
+    ///
+    /// Like [`HyperKZGCommitment::from_owned_column`], this does not require the `hyperkzg_proof` feature.
+    ///
+    /// # Panics
+    /// Panics if `offset` plus the number of rows of the table exceeds the length of the setup.
+    #[must_use]
+    pub fn table_commitment_from_owned_table<S: Scalar>(
+        table: &OwnedTable<S>,
+        offset: usize,
+        setup: &HyperKZGPublicSetup<'_>,
+    ) -> TableCommitment<Self> {
+        TableCommitment::from_owned_table_with_offset(table, offset, setup)
+    }
+}
+
 #[cfg(any(not(feature = "blitzar"), test))]
 #[tracing::instrument(
     name = "compute_commitment_generic_impl (cpu)",
@@ -216,9 +251,10 @@ impl Commitment for HyperKZGCommitment {
 #[cfg(test)]
 mod tests {
     use super::*;
-    #[cfg(feature = "hyperkzg_proof")]
-    use crate::base::database::OwnedColumn;
-    use crate::base::{try_standard_binary_deserialization, try_standard_binary_serialization};
+    use crate::base::{
+        database::owned_table_utility::{bigint, owned_table, varchar},
+        try_standard_binary_deserialization, try_standard_binary_serialization,
+    };
     #[cfg(feature = "hyperkzg_proof")]
     use crate::proof_primitive::hyperkzg::nova_commitment_key_to_hyperkzg_public_setup;
     #[cfg(feature = "hyperkzg_proof")]
@@ -295,4 +331,32 @@ mod tests {
             assert_eq!(deserialized_commitment.commitment, commitment.commitment);
         }
     }
+
+    #[test]
+    fn we_can_compute_commitments_to_owned_columns_and_tables_without_the_proof_machinery() {
+        use ark_std::UniformRand;
+
+        let mut rng = ark_std::test_rng();
+        let setup: Vec<G1Affine> = (0..8).map(|_| G1Affine::rand(&mut rng)).collect();
+        let table =
+            owned_table::<BNScalar>([bigint("a", [1, -2, 3]), varchar("b", ["x", "yy", "zzz"])]);
+
+        let table_commitment =
+            HyperKZGCommitment::table_commitment_from_owned_table(&table, 2, &&setup[..]);
+        assert_eq!(table_commitment.range(), &(2..5));
+
+        let committable_columns: Vec<CommittableColumn> =
+            table.inner_table().values().map(Into::into).collect();
+        let expected = compute_commitments_impl(&committable_columns, 2, &&setup[..]);
+        assert_eq!(
+            table_commitment.column_commitments().commitments(),
+            &expected
+        );
+        for (column, expected_commitment) in table.inner_table().values().zip(&expected) {
+            assert_eq!(
+                HyperKZGCommitment::from_owned_column(column, 2, &&setup[..]),
+                *expected_commitment
+            );
+        }
+    }
 }
@@ -5,6 +5,10 @@
 //! While the `Commitment` for this commitment scheme is always available, the corresponding
 //! `CommitmentEvaluationProof` is gated behind the `hyperkzg_proof` feature flag.
 //! This is done to preserve `no_std` compatibility for `no_std` commitment generation apps.
+//!
+//! Such apps only need a [`HyperKZGPublicSetup`], e.g. one loaded with
+//! [`deserialize_flat_compressed_hyperkzg_public_setup_from_slice`], and can then use
+//! [`HyperKZGCommitment::from_owned_column`] or [`HyperKZGCommitment::table_commitment_from_owned_table`].
 
 mod scalar;
 pub use scalar::BNScalar;
@@ -2,8 +2,17 @@
 pub mod curve_25519_scalar;
 #[cfg(test)]
 mod curve_25519_tests;
-/// TODO: add doc
+/// Inner product argument over Ristretto points, computed with blitzar
+///
+/// This proof can not be verified on the EVM: there are no precompiles for Ristretto or
+/// Curve25519 and the Solidity verifier only accepts `HyperKZG` proofs over BN254.
+/// Use [`HyperKZGCommitmentEvaluationProof`](crate::proof_primitive::hyperkzg) for on-chain verification.
 #[cfg(feature = "blitzar")]
 pub mod inner_product_proof;
 /// TODO: add doc
 pub mod ristretto_point;
+/// Inner product proofs for vectors of differing lengths
+#[cfg(feature = "blitzar")]
+pub mod variable_length_inner_product_proof;
+#[cfg(all(test, feature = "blitzar"))]
+mod variable_length_inner_product_proof_test;
@@ -0,0 +1,129 @@
+use super::curve_25519_scalar::Curve25519Scalar;
+use crate::base::{proof::Transcript, slice_ops};
+use alloc::vec::Vec;
+use blitzar::proof::InnerProductProof;
+use curve25519_dalek::RistrettoPoint;
+use serde::{Deserialize, Serialize};
+use snafu::Snafu;
+
+/// Errors that can occur when verifying a [`VariableLengthInnerProductProof`]
+#[derive(Snafu, Debug, PartialEq, Eq)]
+pub enum VariableLengthInnerProductError {
+    /// The length the prover padded to is inconsistent with the committed length
+    #[snafu(display(
+        "the proof is padded to length {padded_length}, but the committed length requires {expected_length}"
+    ))]
+    PaddingMismatch {
+        /// The length the proof claims both vectors were padded to
+        padded_length: u64,
+        /// The length implied by the committed vector and the public vector
+        expected_length: u64,
+    },
+    /// The underlying inner product proof is invalid
+    #[snafu(display("the inner product proof is invalid"))]
+    InvalidInnerProductProof,
+}
+
+/// An inner product proof for vectors of differing lengths
+///
+/// The prover commits to `a` and proves `<a, b>` for a public `b`. The shorter of the two
+/// vectors is padded with zeros to the length of the longer one. Padding `a` does not change
+/// its commitment, so the verifier only needs the committed length of `a` to recompute the
+/// padded length and check it against the one the proof was created for.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VariableLengthInnerProductProof {
+    padded_length: u64,
+    proof: InnerProductProof,
+}
+
+/// Returns the length both vectors are padded to
+///
+/// This is the length of the longer vector, but at least 1, since an inner product proof
+/// can't be created for empty vectors.
+fn padded_length(a_length: usize, b_length: usize) -> usize {
+    a_length.max(b_length).max(1)
+}
+
+/// Returns `values` cast to `curve25519_dalek` scalars and padded with zeros to `length`
+fn pad_and_cast(values: &[Curve25519Scalar], length: usize) -> Vec<curve25519_dalek::Scalar> {
+    let mut padded: Vec<curve25519_dalek::Scalar> = slice_ops::slice_cast(values);
+    padded.resize(length, curve25519_dalek::Scalar::ZERO);
+    padded
+}
+
+impl VariableLengthInnerProductProof {
+    /// Creates a proof of `<a, b>`, where the shorter of `a` and `b` is padded with zeros
+    ///
+    /// `a` is the committed vector, committed to with the generators starting at `generators_offset`.
+    pub fn new(
+        transcript: &mut impl Transcript,
+        a: &[Curve25519Scalar],
+        b: &[Curve25519Scalar],
+        generators_offset: u64,
+    ) -> Self {
+        let length = padded_length(a.len(), b.len());
+        let padded_length = length as u64;
+        transcript.extend_as_le([padded_length]);
+        // The InnerProductProof from blitzar only works with the merlin Transcript.
+        // So, we wrap the call to it.
+        let proof = transcript.wrap_transcript(|transcript| {
+            InnerProductProof::create(
+                transcript,
+                &pad_and_cast(a, length),
+                &pad_and_cast(b, length),
+                generators_offset,
+            )
+        });
+        Self {
+            padded_length,
+            proof,
+        }
+    }
+
+    /// Returns the length both vectors were padded to
+    #[must_use]
+    pub fn padded_length(&self) -> u64 {
+        self.padded_length
+    }
+
+    /// Verifies that `product` is the inner product of the vector committed to by `a_commit`,
+    /// which has `committed_length` elements, and `b`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VariableLengthInnerProductError::PaddingMismatch`] if the proof was not padded
+    /// to the longer of `committed_length` and `b.len()`, and
+    /// [`VariableLengthInnerProductError::InvalidInnerProductProof`] if the proof itself is invalid.
+    pub fn verify(
+        &self,
+        transcript: &mut impl Transcript,
+        a_commit: &RistrettoPoint,
+        committed_length: usize,
+        product: &Curve25519Scalar,
+        b: &[Curve25519Scalar],
+        generators_offset: u64,
+    ) -> Result<(), VariableLengthInnerProductError> {
+        let length = padded_length(committed_length, b.len());
+        let expected_length = length as u64;
+        if self.padded_length != expected_length {
+            return Err(VariableLengthInnerProductError::PaddingMismatch {
+                padded_length: self.padded_length,
+                expected_length,
+            });
+        }
+        transcript.extend_as_le([self.padded_length]);
+        // The InnerProductProof from blitzar only works with the merlin Transcript.
+        // So, we wrap the call to it.
+        transcript
+            .wrap_transcript(|transcript| {
+                self.proof.verify(
+                    transcript,
+                    a_commit,
+                    &product.into(),
+                    &pad_and_cast(b, length),
+                    generators_offset,
+                )
+            })
+            .map_err(|_| VariableLengthInnerProductError::InvalidInnerProductProof)
+    }
+}
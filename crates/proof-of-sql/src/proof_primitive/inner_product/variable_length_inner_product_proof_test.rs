@@ -0,0 +1,124 @@
+use super::{
+    curve_25519_scalar::Curve25519Scalar,
+    variable_length_inner_product_proof::{
+        VariableLengthInnerProductError, VariableLengthInnerProductProof,
+    },
+};
+use crate::base::{commitment::VecCommitmentExt, database::Column, slice_ops};
+use ark_std::UniformRand;
+use curve25519_dalek::RistrettoPoint;
+use merlin::Transcript;
+
+fn random_scalars(length: usize) -> Vec<Curve25519Scalar> {
+    let mut rng = ark_std::test_rng();
+    core::iter::repeat_with(|| Curve25519Scalar::rand(&mut rng))
+        .take(length)
+        .collect()
+}
+
+fn commit(a: &[Curve25519Scalar], generators_offset: u64) -> RistrettoPoint {
+    Vec::<RistrettoPoint>::from_columns_with_offset(
+        [Column::Scalar(a)],
+        usize::try_from(generators_offset).unwrap(),
+        &(),
+    )[0]
+}
+
+fn prove_and_verify(
+    a_length: usize,
+    b_length: usize,
+    generators_offset: u64,
+) -> Result<(), VariableLengthInnerProductError> {
+    let a = random_scalars(a_length);
+    let b = random_scalars(b_length);
+    let proof = VariableLengthInnerProductProof::new(
+        &mut Transcript::new(b"variable_length_ipa"),
+        &a,
+        &b,
+        generators_offset,
+    );
+    assert_eq!(proof.padded_length(), a_length.max(b_length) as u64);
+    proof.verify(
+        &mut Transcript::new(b"variable_length_ipa"),
+        &commit(&a, generators_offset),
+        a_length,
+        &slice_ops::inner_product(&a, &b),
+        &b,
+        generators_offset,
+    )
+}
+
+#[test]
+fn we_can_prove_the_inner_product_of_vectors_with_equal_lengths() {
+    assert_eq!(prove_and_verify(1, 1, 0), Ok(()));
+    assert_eq!(prove_and_verify(4, 4, 0), Ok(()));
+    assert_eq!(prove_and_verify(5, 5, 10), Ok(()));
+}
+
+#[test]
+fn we_can_prove_the_inner_product_when_the_committed_vector_is_shorter() {
+    assert_eq!(prove_and_verify(1, 2, 0), Ok(()));
+    assert_eq!(prove_and_verify(3, 7, 0), Ok(()));
+    assert_eq!(prove_and_verify(5, 16, 3), Ok(()));
+}
+
+#[test]
+fn we_can_prove_the_inner_product_when_the_public_vector_is_shorter() {
+    assert_eq!(prove_and_verify(2, 1, 0), Ok(()));
+    assert_eq!(prove_and_verify(7, 3, 0), Ok(()));
+    assert_eq!(prove_and_verify(16, 5, 3), Ok(()));
+}
+
+#[test]
+fn we_cannot_verify_a_proof_whose_padding_disagrees_with_the_committed_length() {
+    let a = random_scalars(7);
+    let b = random_scalars(3);
+    let product = slice_ops::inner_product(&a, &b);
+    let proof = VariableLengthInnerProductProof::new(
+        &mut Transcript::new(b"variable_length_ipa"),
+        &a,
+        &b,
+        0,
+    );
+    let a_commit = commit(&a, 0);
+    for committed_length in [3, 6, 8] {
+        assert_eq!(
+            proof.verify(
+                &mut Transcript::new(b"variable_length_ipa"),
+                &a_commit,
+                committed_length,
+                &product,
+                &b,
+                0,
+            ),
+            Err(VariableLengthInnerProductError::PaddingMismatch {
+                padded_length: 7,
+                expected_length: committed_length.max(3) as u64,
+            })
+        );
+    }
+}
+
+#[test]
+fn we_cannot_verify_a_proof_with_a_wrong_product() {
+    let a = random_scalars(3);
+    let b = random_scalars(7);
+    let product = slice_ops::inner_product(&a, &b) + Curve25519Scalar::from(1_u64);
+    let proof = VariableLengthInnerProductProof::new(
+        &mut Transcript::new(b"variable_length_ipa"),
+        &a,
+        &b,
+        0,
+    );
+    assert_eq!(
+        proof.verify(
+            &mut Transcript::new(b"variable_length_ipa"),
+            &commit(&a, 0),
+            3,
+            &product,
+            &b,
+            0,
+        ),
+        Err(VariableLengthInnerProductError::InvalidInnerProductProof)
+    );
+}
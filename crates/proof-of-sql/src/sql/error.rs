@@ -53,6 +53,13 @@ pub enum AnalyzeError {
     #[snafu(display("Not enough input plans"))]
     /// Error for when there are not enough input plans (for a union for example)
     NotEnoughInputPlans,
+
+    #[snafu(display("Column name {name} appears more than once in the output"))]
+    /// Error for when the output of a plan would have two columns with the same name
+    DuplicateColumnName {
+        /// The duplicated column name
+        name: String,
+    },
 }
 
 impl From<AnalyzeError> for String {
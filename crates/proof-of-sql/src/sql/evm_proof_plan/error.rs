@@ -1,6 +1,21 @@
-use crate::sql::AnalyzeError;
+use crate::{
+    base::database::{ColumnRef, TableRef},
+    sql::AnalyzeError,
+};
+use alloc::string::String;
 use snafu::Snafu;
 
+/// A column or table of a plan which is referred to either directly or by its index
+///
+/// Serializing a plan looks up references, while deserializing looks up indexes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum EVMLookup<T> {
+    /// Looked up by reference
+    Reference(T),
+    /// Looked up by index
+    Index(usize),
+}
+
 /// Represents errors that can occur in the EVM proof plan module.
 #[derive(Snafu, Debug, PartialEq)]
 pub(crate) enum EVMProofPlanError {
@@ -8,17 +23,28 @@ pub(crate) enum EVMProofPlanError {
     #[snafu(display("plan not yet supported"))]
     NotSupported,
     /// Error indicating that the column was not found.
-    #[snafu(display("column not found"))]
-    ColumnNotFound,
+    #[snafu(display("column not found: {column:?}"))]
+    ColumnNotFound {
+        /// The column which was not found
+        column: EVMLookup<ColumnRef>,
+    },
     /// Error indicating that the table was not found.
-    #[snafu(display("table not found"))]
-    TableNotFound,
+    #[snafu(display("table not found: {table:?}"))]
+    TableNotFound {
+        /// The table which was not found
+        table: EVMLookup<TableRef>,
+    },
     /// Error indicating that table name can not be parsed into `TableRef`.
     #[snafu(display("table name can not be parsed into TableRef"))]
     InvalidTableName,
     /// Error indicating that the output column name is invalid or missing.
-    #[snafu(display("invalid or missing output column name"))]
-    InvalidOutputColumnName,
+    #[snafu(display("invalid or missing output column name at index {index}: {name:?}"))]
+    InvalidOutputColumnName {
+        /// The index of the output column
+        index: usize,
+        /// The name of the output column, or `None` if it is missing
+        name: Option<String>,
+    },
     /// Error indicating that the column counts in group by plans are inconsistent.
     #[snafu(display("column counts in group by plans are inconsistent"))]
     InconsistentGroupByColumnCounts,
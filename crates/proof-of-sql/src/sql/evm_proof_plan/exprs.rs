@@ -1,4 +1,4 @@
-use super::{EVMProofPlanError, EVMProofPlanResult};
+use super::{EVMLookup, EVMProofPlanError, EVMProofPlanResult};
 use crate::{
     base::{
         database::{ColumnRef, ColumnType, LiteralValue, TableRef},
@@ -78,6 +78,11 @@ impl EVMDynProofExpr {
             DynProofExpr::Placeholder(placeholder_expr) => Ok(Self::Placeholder(
                 EVMPlaceholderExpr::from_proof_expr(placeholder_expr),
             )),
+            DynProofExpr::Round(_)
+            | DynProofExpr::Abs(_)
+            | DynProofExpr::Lag(_)
+            | DynProofExpr::Floor(_)
+            | DynProofExpr::Ceil(_) => Err(EVMProofPlanError::NotSupported),
         }
     }
 
@@ -156,7 +161,9 @@ impl EVMColumnExpr {
                         expr.data_type(),
                     ))
                 })
-                .ok_or(EVMProofPlanError::ColumnNotFound)?,
+                .ok_or_else(|| EVMProofPlanError::ColumnNotFound {
+                    column: EVMLookup::Reference(expr.column_ref().clone()),
+                })?,
         })
     }
 
@@ -167,7 +174,9 @@ impl EVMColumnExpr {
         Ok(ColumnExpr::new(
             column_refs
                 .get_index(self.column_number)
-                .ok_or(EVMProofPlanError::ColumnNotFound)?
+                .ok_or(EVMProofPlanError::ColumnNotFound {
+                    column: EVMLookup::Index(self.column_number),
+                })?
                 .clone(),
         ))
     }
@@ -709,7 +718,9 @@ mod tests {
 
         assert_eq!(
             EVMColumnExpr::try_from_proof_expr(&ColumnExpr::new(column_ref.clone()), &indexset! {}),
-            Err(EVMProofPlanError::ColumnNotFound)
+            Err(EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Reference(column_ref)
+            })
         );
     }
 
@@ -721,7 +732,9 @@ mod tests {
             evm_column_expr
                 .try_into_proof_expr(&column_refs)
                 .unwrap_err(),
-            EVMProofPlanError::ColumnNotFound
+            EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Index(0)
+            }
         );
     }
 
@@ -1077,7 +1090,9 @@ mod tests {
             evm_column_expr
                 .try_into_proof_expr(&column_refs)
                 .unwrap_err(),
-            EVMProofPlanError::ColumnNotFound
+            EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Index(0)
+            }
         );
     }
 
@@ -1125,7 +1140,9 @@ mod tests {
         let column_refs = IndexSet::<ColumnRef>::default();
         assert_eq!(
             evm_and_expr.try_into_proof_expr(&column_refs).unwrap_err(),
-            EVMProofPlanError::ColumnNotFound
+            EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Index(0)
+            }
         );
     }
 
@@ -1173,7 +1190,9 @@ mod tests {
         let column_refs = IndexSet::<ColumnRef>::default();
         assert_eq!(
             evm_or_expr.try_into_proof_expr(&column_refs).unwrap_err(),
-            EVMProofPlanError::ColumnNotFound
+            EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Index(0)
+            }
         );
     }
 
@@ -1209,7 +1228,9 @@ mod tests {
         let column_refs = IndexSet::<ColumnRef>::default();
         assert_eq!(
             evm_not_expr.try_into_proof_expr(&column_refs).unwrap_err(),
-            EVMProofPlanError::ColumnNotFound
+            EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Index(0)
+            }
         );
     }
 
@@ -1257,7 +1278,9 @@ mod tests {
         let column_refs = IndexSet::<ColumnRef>::default();
         assert_eq!(
             evm_cast_expr.try_into_proof_expr(&column_refs).unwrap_err(),
-            EVMProofPlanError::ColumnNotFound
+            EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Index(0)
+            }
         );
     }
 
@@ -1391,7 +1414,9 @@ mod tests {
             evm_scaling_cast_expr
                 .try_into_proof_expr(&column_refs)
                 .unwrap_err(),
-            EVMProofPlanError::ColumnNotFound
+            EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Index(0)
+            }
         );
     }
 
@@ -1620,6 +1645,93 @@ mod tests {
         assert_eq!(evm.try_into_proof_expr(&indexset! { c }).unwrap(), expr);
     }
 
+    #[test]
+    fn we_can_put_into_evm_a_dyn_proof_expr_with_nested_logical_exprs() {
+        let table_ref = TableRef::try_from("namespace.table").unwrap();
+        let a = ColumnRef::new(table_ref.clone(), "a".into(), ColumnType::BigInt);
+        let b = ColumnRef::new(table_ref.clone(), "b".into(), ColumnType::BigInt);
+        let c = ColumnRef::new(table_ref.clone(), "c".into(), ColumnType::Boolean);
+        let column_refs = indexset! { a.clone(), b.clone(), c.clone() };
+
+        // (a > 5 AND b = 3) OR NOT c
+        let expr = or(
+            and(
+                gt(DynProofExpr::new_column(a), const_bigint(5)),
+                equal(DynProofExpr::new_column(b), const_bigint(3)),
+            ),
+            not(DynProofExpr::new_column(c)),
+        );
+        let evm = EVMDynProofExpr::try_from_proof_expr(&expr, &column_refs).unwrap();
+        let expected = EVMDynProofExpr::Or(EVMOrExpr::new(
+            EVMDynProofExpr::And(EVMAndExpr::new(
+                EVMDynProofExpr::Inequality(EVMInequalityExpr::new(
+                    EVMDynProofExpr::Column(EVMColumnExpr { column_number: 0 }),
+                    EVMDynProofExpr::Literal(EVMLiteralExpr(LiteralValue::BigInt(5))),
+                    false,
+                )),
+                EVMDynProofExpr::Equals(EVMEqualsExpr::new(
+                    EVMDynProofExpr::Column(EVMColumnExpr { column_number: 1 }),
+                    EVMDynProofExpr::Literal(EVMLiteralExpr(LiteralValue::BigInt(3))),
+                )),
+            )),
+            EVMDynProofExpr::Not(EVMNotExpr::new(EVMDynProofExpr::Column(EVMColumnExpr {
+                column_number: 2,
+            }))),
+        ));
+        assert_eq!(evm, expected);
+        assert_eq!(evm.try_into_proof_expr(&column_refs).unwrap(), expr);
+
+        // A column missing from the column refs is reported at any depth
+        let column_refs = column_refs.into_iter().take(2).collect::<IndexSet<_>>();
+        assert_eq!(
+            evm.try_into_proof_expr(&column_refs).unwrap_err(),
+            EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Index(2)
+            }
+        );
+    }
+
+    #[test]
+    fn we_can_roundtrip_a_decimal_literal_in_an_equals_expr_through_bytes() {
+        use crate::base::try_standard_binary_deserialization;
+
+        let table_ref = TableRef::try_from("namespace.table").unwrap();
+        let decimal_type = ColumnType::Decimal75(Precision::new(75).unwrap(), -3);
+        let c = ColumnRef::new(table_ref, "c".into(), decimal_type);
+        // A negative value which needs more than 128 bits
+        let value = I256::from_num_bigint(&-((num_bigint::BigInt::from(1) << 200) + 0x1234));
+        let literal = LiteralValue::Decimal75(Precision::new(75).unwrap(), -3, value);
+
+        let expr = equal(
+            DynProofExpr::new_column(c.clone()),
+            DynProofExpr::new_literal(literal.clone()),
+        );
+        let evm = EVMDynProofExpr::try_from_proof_expr(&expr, &indexset! { c.clone() }).unwrap();
+        let bytes = try_standard_binary_serialization(&evm).unwrap();
+        let (deserialized, _) =
+            try_standard_binary_deserialization::<EVMDynProofExpr>(&bytes).unwrap();
+        assert_eq!(deserialized, evm);
+
+        let EVMDynProofExpr::Equals(equals_expr) = &deserialized else {
+            panic!("Expected an equals expression");
+        };
+        let EVMDynProofExpr::Literal(EVMLiteralExpr(deserialized_literal)) = &*equals_expr.rhs
+        else {
+            panic!("Expected a literal expression");
+        };
+        assert_eq!(deserialized_literal, &literal);
+        assert_eq!(deserialized_literal.column_type(), decimal_type);
+        assert_eq!(
+            try_standard_binary_serialization(deserialized_literal).unwrap(),
+            try_standard_binary_serialization(&literal).unwrap()
+        );
+
+        assert_eq!(
+            deserialized.try_into_proof_expr(&indexset! { c }).unwrap(),
+            expr
+        );
+    }
+
     #[test]
     fn we_can_catch_evm_literal_expr_serialization_change() {
         let literal_values = vec![
@@ -1,5 +1,5 @@
 mod error;
-pub(crate) use error::{EVMProofPlanError, EVMProofPlanResult};
+pub(crate) use error::{EVMLookup, EVMProofPlanError, EVMProofPlanResult};
 mod exprs;
 pub(crate) use exprs::EVMDynProofExpr;
 mod plans;
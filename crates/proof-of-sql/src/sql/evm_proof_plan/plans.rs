@@ -1,4 +1,4 @@
-use super::{EVMDynProofExpr, EVMProofPlanError, EVMProofPlanResult};
+use super::{EVMDynProofExpr, EVMLookup, EVMProofPlanError, EVMProofPlanResult};
 use crate::{
     base::{
         database::{ColumnField, ColumnRef, TableRef},
@@ -87,6 +87,10 @@ impl EVMDynProofPlan {
                 EVMAggregateExec::try_from_proof_plan(aggregate_exec, table_refs, column_refs)
                     .map(Self::Aggregate)
             }
+            DynProofPlan::NoMatch(_)
+            | DynProofPlan::SemiJoin(_)
+            | DynProofPlan::CrossJoin(_)
+            | DynProofPlan::RowNumber(_) => Err(EVMProofPlanError::NotSupported),
         }
     }
 
@@ -168,9 +172,11 @@ impl EVMTableExec {
         column_refs: &IndexSet<ColumnRef>,
     ) -> EVMProofPlanResult<Self> {
         Ok(Self {
-            table_number: table_refs
-                .get_index_of(plan.table_ref())
-                .ok_or(EVMProofPlanError::TableNotFound)?,
+            table_number: table_refs.get_index_of(plan.table_ref()).ok_or_else(|| {
+                EVMProofPlanError::TableNotFound {
+                    table: EVMLookup::Reference(plan.table_ref().clone()),
+                }
+            })?,
             column_numbers: column_refs
                 .iter()
                 .enumerate()
@@ -184,10 +190,11 @@ impl EVMTableExec {
         table_refs: &IndexSet<TableRef>,
         column_refs: &IndexSet<ColumnRef>,
     ) -> EVMProofPlanResult<TableExec> {
-        let table_ref = table_refs
-            .get_index(self.table_number)
-            .cloned()
-            .ok_or(EVMProofPlanError::TableNotFound)?;
+        let table_ref = table_refs.get_index(self.table_number).cloned().ok_or(
+            EVMProofPlanError::TableNotFound {
+                table: EVMLookup::Index(self.table_number),
+            },
+        )?;
 
         // Extract column fields for this table reference
         let schema = column_refs
@@ -207,7 +214,10 @@ fn try_unwrap_output_column_names(
     let output_column_names = match output_column_names {
         Some(output_column_names) => {
             if length > output_column_names.len() {
-                return Err(EVMProofPlanError::InvalidOutputColumnName);
+                return Err(EVMProofPlanError::InvalidOutputColumnName {
+                    index: output_column_names.len(),
+                    name: None,
+                });
             }
             output_column_names.clone()
         }
@@ -224,7 +234,10 @@ fn try_unwrap_output_column_names_with_count_alias(
     let output_column_names = match output_column_names {
         Some(output_column_names) => {
             if length > output_column_names.len() {
-                return Err(EVMProofPlanError::InvalidOutputColumnName);
+                return Err(EVMProofPlanError::InvalidOutputColumnName {
+                    index: output_column_names.len(),
+                    name: None,
+                });
             }
             output_column_names.clone()
         }
@@ -256,7 +269,9 @@ impl EVMLegacyFilterExec {
         Ok(Self {
             table_number: table_refs
                 .get_index_of(&plan.table().table_ref)
-                .ok_or(EVMProofPlanError::TableNotFound)?,
+                .ok_or_else(|| EVMProofPlanError::TableNotFound {
+                    table: EVMLookup::Reference(plan.table().table_ref.clone()),
+                })?,
             results: plan
                 .aliased_results()
                 .iter()
@@ -286,10 +301,11 @@ impl EVMLegacyFilterExec {
                 })
                 .collect::<EVMProofPlanResult<Vec<_>>>()?,
             TableExpr {
-                table_ref: table_refs
-                    .get_index(self.table_number)
-                    .cloned()
-                    .ok_or(EVMProofPlanError::TableNotFound)?,
+                table_ref: table_refs.get_index(self.table_number).cloned().ok_or(
+                    EVMProofPlanError::TableNotFound {
+                        table: EVMLookup::Index(self.table_number),
+                    },
+                )?,
             },
             self.where_clause.try_into_proof_expr(column_refs)?,
         ))
@@ -488,15 +504,19 @@ impl EVMGroupByExec {
             .iter()
             .map(|col_expr| {
                 column_refs
-                    .get_index_of(&col_expr.get_column_reference())
-                    .ok_or(EVMProofPlanError::ColumnNotFound)
+                    .get_index_of(col_expr.column_ref())
+                    .ok_or_else(|| EVMProofPlanError::ColumnNotFound {
+                        column: EVMLookup::Reference(col_expr.get_column_reference()),
+                    })
             })
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(Self {
             table_number: table_refs
                 .get_index_of(&plan.table().table_ref)
-                .ok_or(EVMProofPlanError::TableNotFound)?,
+                .ok_or_else(|| EVMProofPlanError::TableNotFound {
+                    table: EVMLookup::Reference(plan.table().table_ref.clone()),
+                })?,
             group_by_exprs: group_by_exprs.clone(),
             sum_expr: plan
                 .sum_expr()
@@ -525,7 +545,9 @@ impl EVMGroupByExec {
         let output_column_names =
             try_unwrap_output_column_names(output_column_names, required_alias_count)?;
         if grouping_column_count > column_refs.len() {
-            Err(EVMProofPlanError::ColumnNotFound)?;
+            Err(EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Index(column_refs.len()),
+            })?;
         }
         // Convert indices back to ColumnExpr objects
         let group_by_exprs = column_refs
@@ -553,12 +575,14 @@ impl EVMGroupByExec {
             .collect::<Result<Vec<_>, _>>()?;
 
         // For safety, check if the provided count_alias_name matches
-        if &self.count_alias_name
-            != output_column_names
-                .next()
-                .expect("Value confirmed to exist")
-        {
-            Err(EVMProofPlanError::InvalidOutputColumnName)?;
+        let count_alias = output_column_names
+            .next()
+            .expect("Value confirmed to exist");
+        if &self.count_alias_name != count_alias {
+            Err(EVMProofPlanError::InvalidOutputColumnName {
+                index: required_alias_count - 1,
+                name: Some(count_alias.clone()),
+            })?;
         }
 
         GroupByExec::try_new(
@@ -566,10 +590,11 @@ impl EVMGroupByExec {
             sum_expr,
             Ident::new(&self.count_alias_name),
             TableExpr {
-                table_ref: table_refs
-                    .get_index(self.table_number)
-                    .cloned()
-                    .ok_or(EVMProofPlanError::TableNotFound)?,
+                table_ref: table_refs.get_index(self.table_number).cloned().ok_or(
+                    EVMProofPlanError::TableNotFound {
+                        table: EVMLookup::Index(self.table_number),
+                    },
+                )?,
             },
             self.where_clause.try_into_proof_expr(column_refs)?,
         )
@@ -679,12 +704,14 @@ impl EVMAggregateExec {
             .collect::<EVMProofPlanResult<Vec<_>>>()?;
 
         // For safety, check if the provided count_alias_name matches
-        if &self.count_alias_name
-            != output_column_names
-                .next()
-                .expect("Value confirmed to exist")
-        {
-            Err(EVMProofPlanError::InvalidOutputColumnName)?;
+        let count_alias = output_column_names
+            .next()
+            .expect("Value confirmed to exist");
+        if &self.count_alias_name != count_alias {
+            Err(EVMProofPlanError::InvalidOutputColumnName {
+                index: required_alias_count - 1,
+                name: Some(count_alias.clone()),
+            })?;
         }
 
         AggregateExec::try_new(
@@ -906,7 +933,7 @@ mod tests {
                 Some(&indexset![]),
             )
             .unwrap_err(),
-            EVMProofPlanError::InvalidOutputColumnName
+            EVMProofPlanError::InvalidOutputColumnName { .. }
         ));
     }
 
@@ -1043,7 +1070,10 @@ mod tests {
 
         let result = EVMTableExec::try_from_proof_plan(&table_exec, &indexset![], &indexset![]);
 
-        assert!(matches!(result, Err(EVMProofPlanError::TableNotFound)));
+        assert!(matches!(
+            result,
+            Err(EVMProofPlanError::TableNotFound { .. })
+        ));
     }
 
     #[test]
@@ -1056,7 +1086,10 @@ mod tests {
         // Use an empty table_refs to trigger TableNotFound
         let result = EVMTableExec::try_into_proof_plan(&evm_table_exec, &indexset![], &indexset![]);
 
-        assert!(matches!(result, Err(EVMProofPlanError::TableNotFound)));
+        assert!(matches!(
+            result,
+            Err(EVMProofPlanError::TableNotFound { .. })
+        ));
     }
 
     #[test]
@@ -1124,7 +1157,7 @@ mod tests {
                 Some(&indexset![]),
             )
             .unwrap_err(),
-            EVMProofPlanError::InvalidOutputColumnName
+            EVMProofPlanError::InvalidOutputColumnName { .. }
         ));
     }
 
@@ -1232,7 +1265,7 @@ mod tests {
 
         // Create a group by exec with a column that doesn't exist in column_refs
         let group_by_exec = GroupByExec::try_new(
-            vec![ColumnExpr::new(missing_column)],
+            vec![ColumnExpr::new(missing_column.clone())],
             vec![AliasedDynProofExpr {
                 expr: DynProofExpr::Column(ColumnExpr::new(column_ref_b.clone())),
                 alias: Ident::new(sum_alias),
@@ -1259,7 +1292,12 @@ mod tests {
             &indexset![column_ref_a, column_ref_b],
         );
 
-        assert!(matches!(result, Err(EVMProofPlanError::ColumnNotFound)));
+        assert_eq!(
+            result.unwrap_err(),
+            EVMProofPlanError::ColumnNotFound {
+                column: EVMLookup::Reference(missing_column),
+            }
+        );
     }
 
     #[test]
@@ -1283,7 +1321,7 @@ mod tests {
             }],
             Ident::new(count_alias),
             TableExpr {
-                table_ref: missing_table_ref,
+                table_ref: missing_table_ref.clone(),
             },
             DynProofExpr::Equals(
                 EqualsExpr::try_new(
@@ -1303,7 +1341,12 @@ mod tests {
             &indexset![column_ref_a, column_ref_b],
         );
 
-        assert!(matches!(result, Err(EVMProofPlanError::TableNotFound)));
+        assert_eq!(
+            result.unwrap_err(),
+            EVMProofPlanError::TableNotFound {
+                table: EVMLookup::Reference(missing_table_ref),
+            }
+        );
     }
 
     #[test]
@@ -1359,7 +1402,10 @@ mod tests {
             ]),
         );
 
-        assert!(matches!(result, Err(EVMProofPlanError::ColumnNotFound)));
+        assert!(matches!(
+            result,
+            Err(EVMProofPlanError::ColumnNotFound { .. })
+        ));
     }
 
     #[test]
@@ -1415,7 +1461,10 @@ mod tests {
             ]),
         );
 
-        assert!(matches!(result, Err(EVMProofPlanError::TableNotFound)));
+        assert!(matches!(
+            result,
+            Err(EVMProofPlanError::TableNotFound { .. })
+        ));
     }
 
     #[test]
@@ -1467,10 +1516,13 @@ mod tests {
             Some(&indexset![ident_a.value.clone(), sum_alias.clone()]), // Missing count_alias
         );
 
-        assert!(matches!(
-            result,
-            Err(EVMProofPlanError::InvalidOutputColumnName)
-        ));
+        assert_eq!(
+            result.unwrap_err(),
+            EVMProofPlanError::InvalidOutputColumnName {
+                index: 2,
+                name: None,
+            }
+        );
 
         // Try with wrong count alias name
         let wrong_count_alias = "wrong_count".to_string();
@@ -1481,14 +1533,17 @@ mod tests {
             Some(&indexset![
                 ident_a.value.clone(),
                 sum_alias.clone(),
-                wrong_count_alias
+                wrong_count_alias.clone()
             ]),
         );
 
-        assert!(matches!(
-            result,
-            Err(EVMProofPlanError::InvalidOutputColumnName)
-        ));
+        assert_eq!(
+            result.unwrap_err(),
+            EVMProofPlanError::InvalidOutputColumnName {
+                index: 2,
+                name: Some(wrong_count_alias),
+            }
+        );
     }
 
     #[test]
@@ -1822,7 +1877,7 @@ mod tests {
             evm_filter_exec
                 .try_into_proof_plan(&indexset![], &indexset![], Some(&indexset![]),)
                 .unwrap_err(),
-            EVMProofPlanError::InvalidOutputColumnName
+            EVMProofPlanError::InvalidOutputColumnName { .. }
         ));
     }
 
@@ -2045,7 +2100,10 @@ mod tests {
             &indexset![column_ref_a, column_ref_b],
         );
 
-        assert!(matches!(result, Err(EVMProofPlanError::ColumnNotFound)));
+        assert!(matches!(
+            result,
+            Err(EVMProofPlanError::ColumnNotFound { .. })
+        ));
     }
 
     #[test]
@@ -2092,7 +2150,10 @@ mod tests {
             &indexset![column_ref_a, column_ref_b],
         );
 
-        assert!(matches!(result, Err(EVMProofPlanError::ColumnNotFound)));
+        assert!(matches!(
+            result,
+            Err(EVMProofPlanError::ColumnNotFound { .. })
+        ));
     }
 
     #[test]
@@ -2137,7 +2198,10 @@ mod tests {
             &indexset![column_ref_a, column_ref_b],
         );
 
-        assert!(matches!(result, Err(EVMProofPlanError::TableNotFound)));
+        assert!(matches!(
+            result,
+            Err(EVMProofPlanError::TableNotFound { .. })
+        ));
     }
 
     #[test]
@@ -2399,7 +2463,10 @@ mod tests {
             &indexset![column_ref_a, column_ref_b],
         );
 
-        assert!(matches!(result, Err(EVMProofPlanError::TableNotFound)));
+        assert!(matches!(
+            result,
+            Err(EVMProofPlanError::TableNotFound { .. })
+        ));
     }
 
     #[test]
@@ -2465,7 +2532,7 @@ mod tests {
 
         assert!(matches!(
             result,
-            Err(EVMProofPlanError::InvalidOutputColumnName)
+            Err(EVMProofPlanError::InvalidOutputColumnName { .. })
         ));
 
         // Try with wrong count alias name
@@ -2479,7 +2546,7 @@ mod tests {
 
         assert!(matches!(
             result,
-            Err(EVMProofPlanError::InvalidOutputColumnName)
+            Err(EVMProofPlanError::InvalidOutputColumnName { .. })
         ));
     }
 
@@ -2519,6 +2586,9 @@ mod tests {
         )
         .unwrap_err();
 
-        assert!(matches!(err, EVMProofPlanError::InvalidOutputColumnName));
+        assert!(matches!(
+            err,
+            EVMProofPlanError::InvalidOutputColumnName { .. }
+        ));
     }
 }
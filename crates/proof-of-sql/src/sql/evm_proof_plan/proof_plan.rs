@@ -1,4 +1,4 @@
-use super::{plans::EVMDynProofPlan, EVMProofPlanError, EVMProofPlanResult};
+use super::{plans::EVMDynProofPlan, EVMLookup, EVMProofPlanError, EVMProofPlanResult};
 use crate::{
     base::{
         database::{
@@ -7,6 +7,7 @@ use crate::{
         map::{IndexMap, IndexSet},
         proof::{PlaceholderResult, ProofError},
         scalar::Scalar,
+        try_standard_binary_deserialization, try_standard_binary_serialization,
     },
     sql::{
         proof::{
@@ -19,6 +20,7 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
+use bincode::error::{DecodeError, EncodeError};
 use bumpalo::Bump;
 use core::str::FromStr;
 use itertools::Itertools;
@@ -50,6 +52,17 @@ impl EVMProofPlan {
     pub fn inner(&self) -> &DynProofPlan {
         &self.inner
     }
+    /// Serialize the plan into its compact binary form.
+    ///
+    /// The tables and typed columns referenced by the plan are embedded in the bytes,
+    /// so the plan can be rebuilt by [`Self::try_from_bytes`] without any external type information.
+    pub fn try_to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        try_standard_binary_serialization(self)
+    }
+    /// Deserialize a plan from the compact binary form produced by [`Self::try_to_bytes`].
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        try_standard_binary_deserialization(bytes).map(|(plan, _)| plan)
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -78,7 +91,9 @@ impl TryFrom<&EVMProofPlan> for CompactPlan {
             .map(|column_ref| -> EVMProofPlanResult<_> {
                 let table_index = table_refs
                     .get_index_of(&column_ref.table_ref())
-                    .ok_or(EVMProofPlanError::TableNotFound)?;
+                    .ok_or_else(|| EVMProofPlanError::TableNotFound {
+                        table: EVMLookup::Reference(column_ref.table_ref()),
+                    })?;
                 Ok((
                     table_index,
                     column_ref.column_id().to_string(),
@@ -111,10 +126,11 @@ impl TryFrom<CompactPlan> for EVMProofPlan {
             .columns
             .iter()
             .map(|(i, ident, column_type)| -> EVMProofPlanResult<_> {
-                let table_ref = table_refs_clone
-                    .get_index(*i)
-                    .cloned()
-                    .ok_or(EVMProofPlanError::TableNotFound)?;
+                let table_ref = table_refs_clone.get_index(*i).cloned().ok_or(
+                    EVMProofPlanError::TableNotFound {
+                        table: EVMLookup::Index(*i),
+                    },
+                )?;
                 Ok(ColumnRef::new(table_ref, Ident::new(ident), *column_type))
             })
             .try_collect()?;
@@ -1,15 +1,18 @@
 use crate::{
     base::{
         database::{ColumnField, ColumnRef, ColumnType, LiteralValue, TableRef},
+        math::decimal::Precision,
         try_standard_binary_deserialization, try_standard_binary_serialization,
     },
     sql::{
         evm_proof_plan::EVMProofPlan,
-        proof_exprs::{AliasedDynProofExpr, ColumnExpr, DynProofExpr, EqualsExpr, LiteralExpr},
-        proof_plans::{DynProofPlan, FilterExec, TableExec},
+        proof_exprs::{
+            AliasedDynProofExpr, ColumnExpr, DynProofExpr, EqualsExpr, LiteralExpr, TableExpr,
+        },
+        proof_plans::{DynProofPlan, FilterExec, GroupByExec, TableExec},
     },
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::ToString};
 use core::iter;
 use sqlparser::ast::Ident;
 use std::sync::LazyLock;
@@ -141,3 +144,85 @@ fn we_can_deserialize_proof_plan_for_simple_filter() {
     let plan = deserialized.0.inner();
     assert_eq!(plan, &expected_plan);
 }
+
+#[test]
+fn we_can_roundtrip_a_group_by_plan_through_bytes_without_a_type_map() {
+    let table_ref: TableRef = "namespace.table".parse().unwrap();
+    let a = ColumnRef::new(table_ref.clone(), "a".into(), ColumnType::BigInt);
+    let b = ColumnRef::new(
+        table_ref.clone(),
+        "b".into(),
+        ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+    );
+    let c = ColumnRef::new(table_ref.clone(), "c".into(), ColumnType::VarChar);
+
+    let plan = DynProofPlan::GroupBy(
+        GroupByExec::try_new(
+            vec![ColumnExpr::new(a)],
+            vec![AliasedDynProofExpr {
+                expr: DynProofExpr::new_column(b),
+                alias: "sum_b".into(),
+            }],
+            "__count__".into(),
+            TableExpr { table_ref },
+            DynProofExpr::Equals(
+                EqualsExpr::try_new(
+                    Box::new(DynProofExpr::new_column(c)),
+                    Box::new(DynProofExpr::new_literal(LiteralValue::VarChar(
+                        "x".to_string(),
+                    ))),
+                )
+                .unwrap(),
+            ),
+        )
+        .unwrap(),
+    );
+
+    let bytes = EVMProofPlan::new(plan.clone()).try_to_bytes().unwrap();
+    let deserialized = EVMProofPlan::try_from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized.inner(), &plan);
+}
+
+#[test]
+fn we_can_roundtrip_a_plan_with_cast_and_scaling_cast_exprs_through_bytes() {
+    let table_ref: TableRef = "namespace.table".parse().unwrap();
+    let a = ColumnRef::new(table_ref.clone(), "a".into(), ColumnType::BigInt);
+    let b = ColumnRef::new(
+        table_ref.clone(),
+        "b".into(),
+        ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+    );
+    let d = ColumnRef::new(table_ref.clone(), "d".into(), ColumnType::Int);
+
+    // SELECT a, SUM(CAST(d AS BIGINT)) AS sum_d, COUNT(*) FROM namespace.table
+    // WHERE d = b GROUP BY a, where d is scaled to compare it with b
+    let plan = DynProofPlan::GroupBy(
+        GroupByExec::try_new(
+            vec![ColumnExpr::new(a)],
+            vec![AliasedDynProofExpr {
+                expr: DynProofExpr::try_new_cast(
+                    DynProofExpr::new_column(d.clone()),
+                    ColumnType::BigInt,
+                )
+                .unwrap(),
+                alias: "sum_d".into(),
+            }],
+            "__count__".into(),
+            TableExpr { table_ref },
+            DynProofExpr::try_new_equals(
+                DynProofExpr::try_new_scaling_cast(
+                    DynProofExpr::new_column(d),
+                    ColumnType::Decimal75(Precision::new(12).unwrap(), 2),
+                )
+                .unwrap(),
+                DynProofExpr::new_column(b),
+            )
+            .unwrap(),
+        )
+        .unwrap(),
+    );
+
+    let bytes = EVMProofPlan::new(plan.clone()).try_to_bytes().unwrap();
+    let deserialized = EVMProofPlan::try_from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized.inner(), &plan);
+}
@@ -39,7 +39,7 @@ pub use proof_plan::ProofPlan;
 pub(crate) use proof_plan::{HonestProver, ProverEvaluate, ProverHonestyMarker};
 
 mod query_proof;
-pub use query_proof::QueryProof;
+pub use query_proof::{ProofSizeBreakdown, QueryProof};
 #[cfg(all(test, feature = "blitzar"))]
 mod query_proof_test;
 
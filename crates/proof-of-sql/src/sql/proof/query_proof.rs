@@ -15,6 +15,7 @@ use crate::{
         math::log2_up,
         polynomial::{compute_evaluation_vector, MultilinearExtension},
         proof::{Keccak256Transcript, PlaceholderResult, ProofError, Transcript},
+        try_standard_binary_serialization,
     },
     proof_primitive::sumcheck::SumcheckProof,
     utils::log,
@@ -84,6 +85,37 @@ pub struct QueryProofPCSProofEvaluations<S> {
     pub final_round: Vec<S>,
 }
 
+/// The sizes in bytes of the components of a serialized [`QueryProof`].
+///
+/// Sizes are measured with [`try_standard_binary_serialization`], whose fixed-width encoding
+/// concatenates the fields of a struct, so the components add up to the size of the whole proof.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProofSizeBreakdown {
+    /// The commitments of the first and final rounds along with their metadata
+    pub commitments: usize,
+    /// The sumcheck proof
+    pub sumcheck: usize,
+    /// The claimed evaluations of the MLEs at the sumcheck point
+    pub mle_evaluations: usize,
+    /// The commitment evaluation proof of the MLE evaluations
+    pub evaluation_proof: usize,
+}
+
+impl ProofSizeBreakdown {
+    /// The total size in bytes of the serialized proof
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.commitments + self.sumcheck + self.mle_evaluations + self.evaluation_proof
+    }
+}
+
+/// The size in bytes of `value` serialized with [`try_standard_binary_serialization`]
+fn serialized_size(value: impl Serialize) -> usize {
+    try_standard_binary_serialization(value)
+        .expect("Proof components should always be serializable")
+        .len()
+}
+
 /// The proof for a query.
 ///
 /// Note: Because the class is deserialized from untrusted data, it
@@ -101,10 +133,22 @@ pub struct QueryProof<CP: CommitmentEvaluationProof> {
 }
 
 impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
-    /// Create a new `QueryProof`.
+    /// Create a new `QueryProof` using a [`Keccak256Transcript`].
+    pub fn new(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<(Self, OwnedTable<CP::Scalar>)> {
+        Self::new_with_transcript::<Keccak256Transcript>(expr, accessor, setup, params)
+    }
+
+    /// Create a new `QueryProof` using the transcript `T`.
+    ///
+    /// The proof can only be verified with [`Self::verify_with_transcript`] using the same `T`.
     #[tracing::instrument(name = "QueryProof::new", level = "debug", skip_all)]
     #[expect(clippy::too_many_lines)]
-    pub fn new(
+    pub fn new_with_transcript<T: Transcript>(
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
@@ -149,7 +193,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             first_round_builder.commit_intermediate_mles(min_row_num, setup);
 
         // construct a transcript for the proof
-        let mut transcript: Keccak256Transcript = Transcript::new();
+        let mut transcript = T::new();
         transcript.extend_as_le([SETUP_HASH]);
         transcript.challenge_as_le();
         transcript.extend_serialize_as_le(expr);
@@ -323,10 +367,22 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         Ok((proof, provable_result))
     }
 
+    /// Verify a `QueryProof` created with a [`Keccak256Transcript`]. Note: This does NOT transform the result!
+    pub fn verify(
+        self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        result: OwnedTable<CP::Scalar>,
+        setup: &CP::VerifierPublicSetup<'_>,
+        params: &[LiteralValue],
+    ) -> QueryResult<CP::Scalar> {
+        self.verify_with_transcript::<Keccak256Transcript>(expr, accessor, result, setup, params)
+    }
+
     #[tracing::instrument(name = "QueryProof::verify", level = "debug", skip_all, err)]
     #[expect(clippy::too_many_lines)]
-    /// Verify a `QueryProof`. Note: This does NOT transform the result!
-    pub fn verify(
+    /// Verify a `QueryProof` created with the transcript `T`. Note: This does NOT transform the result!
+    pub fn verify_with_transcript<T: Transcript>(
         self,
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl CommitmentAccessor<CP::Commitment>,
@@ -357,7 +413,7 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
         let column_references = expr.get_column_references();
 
         // construct a transcript for the proof
-        let mut transcript: Keccak256Transcript = Transcript::new();
+        let mut transcript = T::new();
         transcript.extend_as_le([SETUP_HASH]);
         transcript.challenge_as_le();
         transcript.extend_serialize_as_le(expr);
@@ -552,4 +608,19 @@ impl<CP: CommitmentEvaluationProof> QueryProof<CP> {
             verification_hash,
         })
     }
+
+    /// Get the sizes in bytes of the components of this proof once serialized
+    ///
+    /// # Panics
+    /// Panics if a component of the proof can not be serialized, which should never happen.
+    #[must_use]
+    pub fn size_breakdown(&self) -> ProofSizeBreakdown {
+        ProofSizeBreakdown {
+            commitments: serialized_size(&self.first_round_message)
+                + serialized_size(&self.final_round_message),
+            sumcheck: serialized_size(&self.sumcheck_proof),
+            mle_evaluations: serialized_size(&self.pcs_proof_evaluations),
+            evaluation_proof: serialized_size(&self.evaluation_proof),
+        }
+    }
 }
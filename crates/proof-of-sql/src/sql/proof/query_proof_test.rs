@@ -960,3 +960,56 @@ fn verify_fails_if_the_commitment_doesnt_match() {
     let (proof, result) = QueryProof::<InnerProductProof>::new(&expr, &accessor, &(), &[]).unwrap();
     assert!(proof.verify(&expr, &accessor, result, &(), &[]).is_err());
 }
+
+#[test]
+fn the_plan_type_separates_the_transcripts_of_structurally_similar_plans() {
+    use crate::{
+        base::try_standard_binary_serialization,
+        sql::{
+            proof_exprs::test_utility::{cols_expr_plan, column, const_bigint, equal, tab},
+            proof_plans::test_utility::{column_field, filter, legacy_filter, table_exec},
+        },
+    };
+
+    let t = TableRef::new("sxt", "t");
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t.clone(),
+        owned_table([bigint("a", [1_i64, 2, 3, 2]), bigint("b", [5_i64, 6, 7, 8])]),
+        0,
+        (),
+    );
+    let where_clause = equal(column(&t, "a", &accessor), const_bigint(2));
+    let legacy_filter_plan = legacy_filter(
+        cols_expr_plan(&t, &["b"], &accessor),
+        tab(&t),
+        where_clause.clone(),
+    );
+    let filter_plan = filter(
+        cols_expr_plan(&t, &["b"], &accessor),
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::BigInt),
+                column_field("b", ColumnType::BigInt),
+            ],
+        ),
+        where_clause,
+    );
+
+    // The serialized plan, which is appended to the transcript, starts with the plan type
+    let legacy_filter_bytes = try_standard_binary_serialization(&legacy_filter_plan).unwrap();
+    let filter_bytes = try_standard_binary_serialization(&filter_plan).unwrap();
+    assert_ne!(legacy_filter_bytes[..4], filter_bytes[..4]);
+
+    // Both plans compute the same result, but a proof of one is not a proof of the other
+    let (proof, result) =
+        QueryProof::<InnerProductProof>::new(&legacy_filter_plan, &accessor, &(), &[]).unwrap();
+    let QueryData { table, .. } = proof
+        .clone()
+        .verify(&legacy_filter_plan, &accessor, result.clone(), &(), &[])
+        .unwrap();
+    assert_eq!(table, owned_table([bigint("b", [6_i64, 8])]));
+    assert!(proof
+        .verify(&filter_plan, &accessor, result, &(), &[])
+        .is_err());
+}
@@ -1,12 +1,13 @@
-use super::{ProofPlan, QueryData, QueryProof, QueryResult};
+use super::{ProofPlan, ProofSizeBreakdown, QueryData, QueryProof, QueryResult};
 use crate::{
     base::{
         commitment::CommitmentEvaluationProof,
         database::{CommitmentAccessor, DataAccessor, LiteralValue, OwnedTable},
-        proof::PlaceholderResult,
+        proof::{Keccak256Transcript, PlaceholderResult, Transcript},
     },
     utils::log,
 };
+use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 /// The result of an sql query along with a proof that the query is valid. The
@@ -78,19 +79,53 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
     ///
     /// This function both computes the result of a query and constructs a proof of the results
     /// validity.
-    #[tracing::instrument(name = "VerifiableQueryResult::new", level = "info", skip_all)]
+    ///
+    /// Proof generation is deterministic: there is no prover randomness and all challenges are
+    /// derived from the transcript, so the same plan, data, setup and parameters always produce
+    /// byte-identical proofs.
     pub fn new(
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl DataAccessor<CP::Scalar>,
         setup: &CP::ProverPublicSetup<'_>,
         params: &[LiteralValue],
+    ) -> PlaceholderResult<Self> {
+        Self::new_with_transcript::<Keccak256Transcript>(expr, accessor, setup, params)
+    }
+
+    /// Form a `VerifiableQueryResult` from a query expression, using the transcript `T`.
+    ///
+    /// [`Self::new`] uses a [`Keccak256Transcript`], which is what the EVM verifier expects.
+    /// The result must be verified with [`Self::verify_with_transcript`] using the same `T`.
+    #[tracing::instrument(name = "VerifiableQueryResult::new", level = "info", skip_all)]
+    pub fn new_with_transcript<T: Transcript>(
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl DataAccessor<CP::Scalar>,
+        setup: &CP::ProverPublicSetup<'_>,
+        params: &[LiteralValue],
     ) -> PlaceholderResult<Self> {
         log::log_memory_usage("Start");
-        let (proof, res) = QueryProof::new(expr, accessor, setup, params)?;
+        let (proof, res) = QueryProof::new_with_transcript::<T>(expr, accessor, setup, params)?;
         log::log_memory_usage("End");
         Ok(Self { result: res, proof })
     }
 
+    /// Get the size in bytes of the serialized proof, excluding the query result.
+    ///
+    /// # Panics
+    /// Panics if a component of the proof can not be serialized, which should never happen.
+    #[must_use]
+    pub fn proof_size_bytes(&self) -> usize {
+        self.proof_size_breakdown().total()
+    }
+
+    /// Get the sizes in bytes of the components of the serialized proof.
+    ///
+    /// See [`QueryProof::size_breakdown`].
+    #[must_use]
+    pub fn proof_size_breakdown(&self) -> ProofSizeBreakdown {
+        self.proof.size_breakdown()
+    }
+
     /// Verify a `VerifiableQueryResult`. Upon success, this function returns the finalized form of
     /// the query result.
     ///
@@ -98,13 +133,26 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
     /// error.
     ///
     /// Note: This does NOT transform the result!
-    #[tracing::instrument(name = "VerifiableQueryResult::verify", level = "info", skip_all)]
     pub fn verify(
         self,
         expr: &(impl ProofPlan + Serialize),
         accessor: &impl CommitmentAccessor<CP::Commitment>,
         setup: &CP::VerifierPublicSetup<'_>,
         params: &[LiteralValue],
+    ) -> QueryResult<CP::Scalar> {
+        self.verify_with_transcript::<Keccak256Transcript>(expr, accessor, setup, params)
+    }
+
+    /// Verify a `VerifiableQueryResult` formed with the transcript `T`.
+    ///
+    /// See [`Self::verify`]. Verification fails if the result was formed with a different transcript.
+    #[tracing::instrument(name = "VerifiableQueryResult::verify", level = "info", skip_all)]
+    pub fn verify_with_transcript<T: Transcript>(
+        self,
+        expr: &(impl ProofPlan + Serialize),
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+        params: &[LiteralValue],
     ) -> QueryResult<CP::Scalar> {
         log::log_memory_usage("Start");
         let QueryData {
@@ -112,10 +160,27 @@ impl<CP: CommitmentEvaluationProof> VerifiableQueryResult<CP> {
             verification_hash,
         } = self
             .proof
-            .verify(expr, accessor, self.result, setup, params)?;
+            .verify_with_transcript::<T>(expr, accessor, self.result, setup, params)?;
         Ok(QueryData {
             table: table.try_coerce_with_fields(expr.get_column_result_fields())?,
             verification_hash,
         })
     }
+
+    /// Verify a batch of `VerifiableQueryResult`s against the same commitments and setup.
+    ///
+    /// Each item is a result along with the query expression and params it was formed with.
+    /// The items are verified as with [`Self::verify`] and the results are returned in the same
+    /// order, so an invalid proof only fails its own entry. To reject the whole batch if any
+    /// proof is invalid, collect the results into a `Result<Vec<_>, _>`.
+    pub fn verify_batch<'a, P: ProofPlan + Serialize + 'a>(
+        results: impl IntoIterator<Item = (Self, &'a P, &'a [LiteralValue])>,
+        accessor: &impl CommitmentAccessor<CP::Commitment>,
+        setup: &CP::VerifierPublicSetup<'_>,
+    ) -> Vec<QueryResult<CP::Scalar>> {
+        results
+            .into_iter()
+            .map(|(result, expr, params)| result.verify(expr, accessor, setup, params))
+            .collect()
+    }
 }
@@ -11,7 +11,7 @@ use crate::{
             TableEvaluation, TableRef,
         },
         map::{indexset, IndexMap, IndexSet},
-        proof::{PlaceholderResult, ProofError},
+        proof::{Keccak256Transcript, PlaceholderResult, ProofError},
         scalar::Scalar,
     },
     sql::proof::{FirstRoundBuilder, QueryData},
@@ -114,3 +114,215 @@ fn we_can_verify_queries_on_an_empty_table() {
     let expected_res = owned_table([bigint("a1", [0; 0])]);
     assert_eq!(table, expected_res);
 }
+
+#[test]
+fn we_can_only_verify_queries_with_the_transcript_they_were_proven_with() {
+    let expr = EmptyTestQueryExpr {
+        length: 2,
+        columns: 1,
+    };
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        TableRef::new("sxt", "test"),
+        owned_table([bigint("a1", [0_i64; 2])]),
+        0,
+        (),
+    );
+    let expected_res = owned_table([bigint("a1", [0; 2])]);
+
+    let keccak_res = VerifiableQueryResult::<InnerProductProof>::new_with_transcript::<
+        Keccak256Transcript,
+    >(&expr, &accessor, &(), &[])
+    .unwrap();
+    let merlin_res = VerifiableQueryResult::<InnerProductProof>::new_with_transcript::<
+        merlin::Transcript,
+    >(&expr, &accessor, &(), &[])
+    .unwrap();
+
+    let keccak_data = keccak_res
+        .clone()
+        .verify_with_transcript::<Keccak256Transcript>(&expr, &accessor, &(), &[])
+        .unwrap();
+    let merlin_data = merlin_res
+        .clone()
+        .verify_with_transcript::<merlin::Transcript>(&expr, &accessor, &(), &[])
+        .unwrap();
+    assert_eq!(keccak_data.table, expected_res);
+    assert_eq!(merlin_data.table, expected_res);
+    assert_ne!(keccak_data.verification_hash, merlin_data.verification_hash);
+
+    // The default transcript is keccak256
+    let default_data = keccak_res
+        .clone()
+        .verify(&expr, &accessor, &(), &[])
+        .unwrap();
+    assert_eq!(
+        default_data.verification_hash,
+        keccak_data.verification_hash
+    );
+
+    assert!(keccak_res
+        .verify_with_transcript::<merlin::Transcript>(&expr, &accessor, &(), &[])
+        .is_err());
+    assert!(merlin_res
+        .verify_with_transcript::<Keccak256Transcript>(&expr, &accessor, &(), &[])
+        .is_err());
+}
+
+#[test]
+fn we_can_verify_a_batch_of_queries() {
+    use crate::sql::{
+        proof_exprs::test_utility::{cols_expr_plan, column, const_bigint, equal, tab},
+        proof_plans::{test_utility::legacy_filter, DynProofPlan},
+    };
+
+    let t = TableRef::new("sxt", "t");
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t.clone(),
+        owned_table([bigint("a", [1_i64, 2, 3, 2]), bigint("b", [5_i64, 6, 7, 8])]),
+        0,
+        (),
+    );
+    let plans: Vec<DynProofPlan> = [2_i64, 3, 4]
+        .into_iter()
+        .map(|a| {
+            legacy_filter(
+                cols_expr_plan(&t, &["b"], &accessor),
+                tab(&t),
+                equal(column(&t, "a", &accessor), const_bigint(a)),
+            )
+        })
+        .collect();
+    let results: Vec<_> = plans
+        .iter()
+        .map(|plan| {
+            VerifiableQueryResult::<InnerProductProof>::new(plan, &accessor, &(), &[]).unwrap()
+        })
+        .collect();
+
+    let verified = VerifiableQueryResult::verify_batch(
+        results
+            .iter()
+            .cloned()
+            .zip(&plans)
+            .map(|(result, plan)| (result, plan, &[][..])),
+        &accessor,
+        &(),
+    );
+    let tables: Vec<_> = verified
+        .into_iter()
+        .map(|query_data| query_data.unwrap().table)
+        .collect();
+    assert_eq!(
+        tables,
+        vec![
+            owned_table([bigint("b", [6_i64, 8])]),
+            owned_table([bigint("b", [7_i64])]),
+            owned_table([bigint("b", [0_i64; 0])]),
+        ]
+    );
+
+    // Tamper with the result of the second query
+    let mut tampered_results = results;
+    tampered_results[1].result = owned_table([bigint("b", [8_i64])]);
+    let verified = VerifiableQueryResult::verify_batch(
+        tampered_results
+            .into_iter()
+            .zip(&plans)
+            .map(|(result, plan)| (result, plan, &[][..])),
+        &accessor,
+        &(),
+    );
+    assert!(verified[0].is_ok());
+    assert!(verified[1].is_err());
+    assert!(verified[2].is_ok());
+    assert!(verified.into_iter().collect::<Result<Vec<_>, _>>().is_err());
+}
+
+#[test]
+fn we_can_get_the_serialized_size_of_proofs() {
+    use crate::{
+        base::try_standard_binary_serialization,
+        sql::{
+            proof_exprs::test_utility::{cols_expr_plan, column, const_bigint, equal, tab},
+            proof_plans::test_utility::{column_field, legacy_filter, projection, table_exec},
+        },
+    };
+
+    let t = TableRef::new("sxt", "t");
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t.clone(),
+        owned_table([bigint("a", [1_i64, 2, 3, 2]), bigint("b", [5_i64, 6, 7, 8])]),
+        0,
+        (),
+    );
+    let filter = legacy_filter(
+        cols_expr_plan(&t, &["b"], &accessor),
+        tab(&t),
+        equal(column(&t, "a", &accessor), const_bigint(2)),
+    );
+    let projection_plan = projection(
+        cols_expr_plan(&t, &["a", "b"], &accessor),
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::BigInt),
+                column_field("b", ColumnType::BigInt),
+            ],
+        ),
+    );
+    for plan in [filter, projection_plan] {
+        let res =
+            VerifiableQueryResult::<InnerProductProof>::new(&plan, &accessor, &(), &[]).unwrap();
+        let breakdown = res.proof_size_breakdown();
+        assert!(breakdown.commitments > 0);
+        assert!(breakdown.sumcheck > 0);
+        assert!(breakdown.mle_evaluations > 0);
+        assert!(breakdown.evaluation_proof > 0);
+        assert_eq!(
+            res.proof_size_bytes(),
+            try_standard_binary_serialization(&res.proof).unwrap().len()
+        );
+        assert_eq!(res.proof_size_bytes(), breakdown.total());
+    }
+}
+
+#[test]
+fn we_get_byte_identical_proofs_when_proving_the_same_query_twice() {
+    use crate::{
+        base::try_standard_binary_serialization,
+        sql::{
+            proof_exprs::test_utility::{cols_expr_plan, column, const_bigint, gt, tab},
+            proof_plans::test_utility::legacy_filter,
+        },
+    };
+
+    let t = TableRef::new("sxt", "t");
+    let accessor = OwnedTableTestAccessor::<InnerProductProof>::new_from_table(
+        t.clone(),
+        owned_table([bigint("a", [1_i64, 2, 3, 2]), bigint("b", [5_i64, 6, 7, 8])]),
+        0,
+        (),
+    );
+    let plan = legacy_filter(
+        cols_expr_plan(&t, &["a", "b"], &accessor),
+        tab(&t),
+        gt(column(&t, "b", &accessor), const_bigint(5)),
+    );
+    let prove = || {
+        let res =
+            VerifiableQueryResult::<InnerProductProof>::new(&plan, &accessor, &(), &[]).unwrap();
+        try_standard_binary_serialization(&res).unwrap()
+    };
+    let first = prove();
+    assert_eq!(first, prove());
+
+    // A different query yields a different proof
+    let other_plan = legacy_filter(
+        cols_expr_plan(&t, &["a", "b"], &accessor),
+        tab(&t),
+        gt(column(&t, "b", &accessor), const_bigint(6)),
+    );
+    let other =
+        VerifiableQueryResult::<InnerProductProof>::new(&other_plan, &accessor, &(), &[]).unwrap();
+    assert_ne!(first, try_standard_binary_serialization(&other).unwrap());
+}
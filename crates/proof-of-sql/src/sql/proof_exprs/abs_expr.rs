@@ -0,0 +1,172 @@
+use super::{DecimalProofExpr, DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, LiteralValue, Table},
+        map::{IndexMap, IndexSet},
+        math::decimal::Precision,
+        proof::{PlaceholderResult, ProofError},
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{
+            FinalRoundBuilder, FirstRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder,
+        },
+        proof_gadgets::{
+            final_round_evaluate_sign, first_round_evaluate_sign, verifier_evaluate_sign,
+        },
+        AnalyzeError, AnalyzeResult,
+    },
+};
+use alloc::{boxed::Box, vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::Ident;
+
+/// Provable ABS expression for signed integers and decimals
+///
+/// If `x` is the input and `s` its sign bit, i.e. `s = 1` exactly when `x < 0`,
+/// then `|x| = x - 2 * s * x`. The prover commits to `|x|`, proves `s` with the sign gadget
+/// and proves `|x| - x + 2 * s * x = 0` row by row.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AbsExpr {
+    from_expr: Box<DynProofExpr>,
+}
+
+impl AbsExpr {
+    /// Creates a new `AbsExpr` taking the absolute value of `from_expr`
+    pub fn try_new(from_expr: Box<DynProofExpr>) -> AnalyzeResult<Self> {
+        let from_datatype = from_expr.data_type();
+        try_abs_type(from_datatype)
+            .map(|_| Self { from_expr })
+            .ok_or(AnalyzeError::InvalidDataType {
+                expr_type: from_datatype,
+            })
+    }
+
+    /// Returns the from expression
+    pub fn get_from_expr(&self) -> &DynProofExpr {
+        &self.from_expr
+    }
+
+    /// Negates each element of `from_values` whose sign bit is set and returns the resulting
+    /// decimal column
+    fn abs_column<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        from_values: &[S],
+        signs: &[bool],
+    ) -> Column<'a, S> {
+        Column::Decimal75(
+            DecimalProofExpr::precision(self),
+            DecimalProofExpr::scale(self),
+            alloc.alloc_slice_fill_with(from_values.len(), |i| {
+                if signs[i] {
+                    -from_values[i]
+                } else {
+                    from_values[i]
+                }
+            }),
+        )
+    }
+}
+
+/// Returns the type of the absolute value of an expression of type `from_type`,
+/// or `None` if `from_type` is not a signed integer or a decimal
+///
+/// The magnitude of the minimum of a signed integer type does not fit into the type itself,
+/// e.g. `|-128| = 128` for a `TINYINT`, so integers become decimals with the same number of digits.
+pub(crate) fn try_abs_type(from_type: ColumnType) -> Option<ColumnType> {
+    match from_type {
+        ColumnType::TinyInt
+        | ColumnType::SmallInt
+        | ColumnType::Int
+        | ColumnType::BigInt
+        | ColumnType::Int128 => Some(ColumnType::Decimal75(
+            Precision::new(from_type.precision_value()?).ok()?,
+            0,
+        )),
+        ColumnType::Decimal75(_, _) => Some(from_type),
+        _ => None,
+    }
+}
+
+impl ProofExpr for AbsExpr {
+    fn data_type(&self) -> ColumnType {
+        try_abs_type(self.from_expr.data_type())
+            .expect("Abs expressions are validated on construction")
+    }
+
+    fn first_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Column<'a, S>> {
+        let from_column = self
+            .from_expr
+            .first_round_evaluate(builder, alloc, table, params)?;
+        let from_values: &'a [S] = alloc.alloc_slice_copy(&from_column.to_scalar());
+        let signs = first_round_evaluate_sign(table.num_rows(), alloc, from_values);
+        Ok(self.abs_column(alloc, from_values, signs))
+    }
+
+    #[tracing::instrument(name = "AbsExpr::final_round_evaluate", level = "debug", skip_all)]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Column<'a, S>> {
+        let from_column = self
+            .from_expr
+            .final_round_evaluate(builder, alloc, table, params)?;
+        let from_values: &'a [S] = alloc.alloc_slice_copy(&from_column.to_scalar());
+        let signs = final_round_evaluate_sign(builder, alloc, from_values);
+        let result = self.abs_column(alloc, from_values, signs);
+        let abs_values = result
+            .as_decimal75()
+            .expect("Result of an abs expression is a decimal");
+        builder.produce_intermediate_mle(abs_values);
+
+        // subpolynomial: |x| - x + 2 * sign(x) * x
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::one(), vec![Box::new(abs_values)]),
+                (-S::one(), vec![Box::new(from_values)]),
+                (S::TWO, vec![Box::new(signs), Box::new(from_values)]),
+            ],
+        );
+        Ok(result)
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut impl VerificationBuilder<S>,
+        accessor: &IndexMap<Ident, S>,
+        chi_eval: S,
+        params: &[LiteralValue],
+    ) -> Result<S, ProofError> {
+        let from_eval = self
+            .from_expr
+            .verifier_evaluate(builder, accessor, chi_eval, params)?;
+        let sign_eval = verifier_evaluate_sign(builder, from_eval, chi_eval, None)?;
+        let abs_eval = builder.try_consume_final_round_mle_evaluation()?;
+
+        // subpolynomial: |x| - x + 2 * sign(x) * x
+        builder.try_produce_sumcheck_subpolynomial_evaluation(
+            SumcheckSubpolynomialType::Identity,
+            abs_eval - from_eval + S::TWO * sign_eval * from_eval,
+            2,
+        )?;
+        Ok(abs_eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.from_expr.get_column_references(columns);
+    }
+}
+
+impl DecimalProofExpr for AbsExpr {}
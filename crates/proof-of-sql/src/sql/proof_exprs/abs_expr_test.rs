@@ -0,0 +1,145 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::{
+                bigint, decimal75, int128, owned_table, tinyint, uint8, varchar,
+            },
+            ColumnType, LiteralValue, OwnedTableTestAccessor, TableRef,
+        },
+        math::decimal::Precision,
+    },
+    sql::{
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::test_utility::{abs, aliased_plan, column, const_bool, const_decimal75, gt},
+        proof_plans::test_utility::{column_field, filter, table_exec},
+        AnalyzeError,
+    },
+};
+use blitzar::proof::InnerProductProof;
+
+#[test]
+fn we_can_prove_abs_expr_including_the_minimum_of_signed_types() {
+    let data = owned_table([
+        bigint("a", [i64::MIN, -5, -1, 0, 1, i64::MAX]),
+        tinyint("b", [i8::MIN, -2, 0, 3, i8::MAX, -1]),
+        decimal75("c", 6, 3, [-1234, 1234, 0, -1, 999_999, -999_999]),
+    ]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(abs(column(&t, "a", &accessor)), "abs_a"),
+            aliased_plan(abs(column(&t, "b", &accessor)), "abs_b"),
+            aliased_plan(abs(column(&t, "c", &accessor)), "abs_c"),
+        ],
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::BigInt),
+                column_field("b", ColumnType::TinyInt),
+                column_field("c", ColumnType::Decimal75(Precision::new(6).unwrap(), 3)),
+            ],
+        ),
+        const_bool(true),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([
+        decimal75(
+            "abs_a",
+            19,
+            0,
+            [-i128::from(i64::MIN), 5, 1, 0, 1, i128::from(i64::MAX)],
+        ),
+        decimal75("abs_b", 3, 0, [128, 2, 0, 3, 127, 1]),
+        decimal75("abs_c", 6, 3, [1234, 1234, 0, 1, 999_999, 999_999]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_abs_expr_in_a_filter_predicate() {
+    let data = owned_table([
+        decimal75("a", 5, 2, [-350, 150, 250, -100, 301]),
+        bigint("b", [1, 2, 3, 4, 5]),
+    ]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    // ABS(a) > 2.50
+    let ast = filter(
+        vec![aliased_plan(column(&t, "b", &accessor), "b")],
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::Decimal75(Precision::new(5).unwrap(), 2)),
+                column_field("b", ColumnType::BigInt),
+            ],
+        ),
+        gt(abs(column(&t, "a", &accessor)), const_decimal75(5, 2, 250)),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([bigint("b", [1, 5])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_compute_the_data_type_of_an_abs_expr() {
+    let t = TableRef::new("sxt", "t");
+    let data = owned_table([
+        tinyint("a", [0_i8]),
+        int128("b", [0_i128]),
+        decimal75("c", 10, -2, [0]),
+    ]);
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    assert_eq!(
+        abs(column(&t, "a", &accessor)).data_type(),
+        ColumnType::Decimal75(Precision::new(3).unwrap(), 0)
+    );
+    assert_eq!(
+        abs(column(&t, "b", &accessor)).data_type(),
+        ColumnType::Decimal75(Precision::new(39).unwrap(), 0)
+    );
+    assert_eq!(
+        abs(column(&t, "c", &accessor)).data_type(),
+        ColumnType::Decimal75(Precision::new(10).unwrap(), -2)
+    );
+}
+
+#[test]
+fn we_cannot_take_the_abs_of_unsigned_or_non_numeric_expressions() {
+    let t = TableRef::new("sxt", "t");
+    let data = owned_table([uint8("a", [0_u8]), varchar("b", ["x"])]);
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_abs(column(&t, "a", &accessor)),
+        Err(AnalyzeError::InvalidDataType {
+            expr_type: ColumnType::Uint8
+        })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_abs(column(&t, "b", &accessor)),
+        Err(AnalyzeError::InvalidDataType {
+            expr_type: ColumnType::VarChar
+        })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_abs(DynProofExpr::new_literal(LiteralValue::Boolean(true))),
+        Err(AnalyzeError::InvalidDataType {
+            expr_type: ColumnType::Boolean
+        })
+    ));
+}
@@ -9,7 +9,7 @@ use crate::{
         scalar::Scalar,
     },
     sql::{
-        proof::{FinalRoundBuilder, VerificationBuilder},
+        proof::{FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder},
         AnalyzeError, AnalyzeResult,
     },
     utils::log,
@@ -58,12 +58,17 @@ impl ProofExpr for AddExpr {
 
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         params: &[LiteralValue],
     ) -> PlaceholderResult<Column<'a, S>> {
-        let lhs_column: Column<'a, S> = self.lhs.first_round_evaluate(alloc, table, params)?;
-        let rhs_column: Column<'a, S> = self.rhs.first_round_evaluate(alloc, table, params)?;
+        let lhs_column: Column<'a, S> = self
+            .lhs
+            .first_round_evaluate(builder, alloc, table, params)?;
+        let rhs_column: Column<'a, S> = self
+            .rhs
+            .first_round_evaluate(builder, alloc, table, params)?;
         let res = add_subtract_columns(lhs_column, rhs_column, alloc, false);
         Ok(Column::Decimal75(self.precision(), self.scale(), res))
     }
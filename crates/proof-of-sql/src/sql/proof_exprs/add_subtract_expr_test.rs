@@ -7,10 +7,11 @@ use crate::{
             TableTestAccessor,
         },
         math::decimal::Precision,
+        scalar::Scalar,
     },
     proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, FirstRoundBuilder, VerifiableQueryResult},
         proof_exprs::{test_utility::*, DynProofExpr, ProofExpr},
         proof_plans::test_utility::*,
         AnalyzeError,
@@ -142,6 +143,70 @@ fn we_can_prove_a_typical_add_subtract_query_with_decimals() {
     assert_eq!(res, expected_res);
 }
 
+// select a + b as s, a - b as d from sxt.t
+#[test]
+fn we_can_prove_add_subtract_of_int128_columns_without_overflow() {
+    let data = owned_table([
+        int128("a", [i128::MAX, i128::MIN, 5]),
+        int128("b", [i128::MAX, 1, -7]),
+    ]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(
+                add(column(&t, "a", &accessor), column(&t, "b", &accessor)),
+                "s",
+            ),
+            aliased_plan(
+                subtract(column(&t, "a", &accessor), column(&t, "b", &accessor)),
+                "d",
+            ),
+        ],
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::Int128),
+                column_field("b", ColumnType::Int128),
+            ],
+        ),
+        const_bool(true),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    // As with the other integer widths, the result is widened to a decimal rather than wrapping
+    let max = Curve25519Scalar::from(i128::MAX);
+    let min = Curve25519Scalar::from(i128::MIN);
+    let expected_res = owned_table([
+        decimal75(
+            "s",
+            40,
+            0,
+            [
+                max + max,
+                min + Curve25519Scalar::ONE,
+                Curve25519Scalar::from(-2_i64),
+            ],
+        ),
+        decimal75(
+            "d",
+            40,
+            0,
+            [
+                Curve25519Scalar::ZERO,
+                min - Curve25519Scalar::ONE,
+                Curve25519Scalar::from(12_i64),
+            ],
+        ),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
 fn test_random_tables_with_given_offset(offset: usize) {
     let dist = Uniform::new(-3, 4);
     let mut rng = StdRng::from_seed([0u8; 32]);
@@ -260,8 +325,9 @@ fn we_can_compute_the_correct_output_of_an_add_subtract_expr_using_first_round_e
         column(&t, "b", &accessor),
         subtract(column(&t, "a", &accessor), const_bigint(1)),
     );
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
     let res = add_subtract_expr
-        .first_round_evaluate(&alloc, &data, &[])
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
         .unwrap();
     let expected_res = borrowed_decimal75("res", 21, 0, [0_i64, 2, 2, 4], &alloc).1;
     assert_eq!(res, expected_res);
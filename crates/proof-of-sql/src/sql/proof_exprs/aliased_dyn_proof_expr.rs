@@ -1,4 +1,5 @@
 use super::DynProofExpr;
+use core::fmt::{self, Display, Formatter};
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::Ident;
 
@@ -10,3 +11,15 @@ pub struct AliasedDynProofExpr {
     /// The alias for the expression.
     pub alias: Ident,
 }
+
+/// Display the expression followed by its alias, omitting the alias of a column of the same name
+impl Display for AliasedDynProofExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.expr {
+            DynProofExpr::Column(column) if column.column_id() == self.alias => {
+                write!(f, "{}", self.alias.value)
+            }
+            expr => write!(f, "{expr} AS {}", self.alias.value),
+        }
+    }
+}
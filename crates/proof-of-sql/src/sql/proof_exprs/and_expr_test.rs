@@ -214,7 +214,10 @@ fn we_can_compute_the_correct_output_of_an_and_expr_using_first_round_evaluate()
         equal(column(&t, "b", &accessor), const_int128(1)),
         equal(column(&t, "d", &accessor), const_varchar("t")),
     );
-    let res = and_expr.first_round_evaluate(&alloc, &data, &[]).unwrap();
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
+    let res = and_expr
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
+        .unwrap();
     let expected_res = Column::Boolean(&[false, true, false, false]);
     assert_eq!(res, expected_res);
 }
@@ -1,12 +1,12 @@
 use super::ProofExpr;
 use crate::{
     base::{
-        database::{Column, ColumnField, ColumnRef, ColumnType, LiteralValue, Table},
+        database::{Column, ColumnField, ColumnRef, ColumnType, LiteralValue, Table, TableRef},
         map::{IndexMap, IndexSet},
         proof::{PlaceholderResult, ProofError},
         scalar::Scalar,
     },
-    sql::proof::{FinalRoundBuilder, VerificationBuilder},
+    sql::proof::{FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder},
 };
 use bumpalo::Bump;
 use serde::{Deserialize, Serialize};
@@ -38,6 +38,20 @@ impl ColumnExpr {
         &self.column_ref
     }
 
+    /// Returns the column expression with its table replaced by `to` if it is `from`
+    #[must_use]
+    pub fn rename_table(&self, from: &TableRef, to: &TableRef) -> Self {
+        if self.column_ref.table_ref() == *from {
+            Self::new(ColumnRef::new(
+                to.clone(),
+                self.column_ref.column_id(),
+                *self.column_ref.column_type(),
+            ))
+        } else {
+            self.clone()
+        }
+    }
+
     /// Wrap the column output name and its type within the [`ColumnField`]
     #[must_use]
     pub fn get_column_field(&self) -> ColumnField {
@@ -74,6 +88,7 @@ impl ProofExpr for ColumnExpr {
     /// add the result to the [`FirstRoundBuilder`](crate::sql::proof::FirstRoundBuilder)
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        _builder: &mut FirstRoundBuilder<'a, S>,
         _alloc: &'a Bump,
         table: &Table<'a, S>,
         _params: &[LiteralValue],
@@ -1,11 +1,19 @@
 use super::{
-    AddExpr, AndExpr, CastExpr, ColumnExpr, EqualsExpr, InequalityExpr, LiteralExpr, MultiplyExpr,
-    NotExpr, OrExpr, PlaceholderExpr, ProofExpr, ScalingCastExpr, SubtractExpr,
+    abs_expr::try_abs_type, numerical_util::try_get_scaling_factor_with_precision_and_scale,
+    round_expr::try_round_type, AbsExpr, AddExpr, AndExpr, CastExpr, CeilExpr, ColumnExpr,
+    EqualsExpr, FloorExpr, InequalityExpr, LagExpr, LiteralExpr, MultiplyExpr, NotExpr, OrExpr,
+    PlaceholderExpr, ProofExpr, RoundExpr, ScalingCastExpr, SubtractExpr,
 };
 use crate::{
     base::{
-        database::{Column, ColumnRef, ColumnType, LiteralValue, Table},
+        database::{
+            can_and_or_types, can_not_type, try_add_subtract_column_types, try_cast_types,
+            try_equals_types, try_inequality_types, try_multiply_column_types, Column,
+            ColumnOperationError, ColumnOperationResult, ColumnRef, ColumnType, LiteralValue,
+            Table, TableRef,
+        },
         map::{IndexMap, IndexSet},
+        math::i256::I256,
         proof::{PlaceholderResult, ProofError},
         scalar::Scalar,
     },
@@ -14,9 +22,9 @@ use crate::{
         AnalyzeResult,
     },
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
 use bumpalo::Bump;
-use core::fmt::Debug;
+use core::fmt::{self, Debug, Display, Formatter};
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::Ident;
 
@@ -50,6 +58,16 @@ pub enum DynProofExpr {
     Cast(CastExpr),
     /// Provable expression for casting numeric expressions to decimal expressions
     ScalingCast(ScalingCastExpr),
+    /// Provable ROUND expression for decimals
+    Round(RoundExpr),
+    /// Provable ABS expression for signed integers and decimals
+    Abs(AbsExpr),
+    /// Provable LAG expression for numeric expressions
+    Lag(LagExpr),
+    /// Provable FLOOR expression for decimals
+    Floor(FloorExpr),
+    /// Provable CEIL expression for decimals
+    Ceil(CeilExpr),
 }
 impl DynProofExpr {
     /// Create column expression
@@ -121,4 +139,425 @@ impl DynProofExpr {
     ) -> AnalyzeResult<Self> {
         ScalingCastExpr::try_new(Box::new(from_expr), to_datatype).map(DynProofExpr::ScalingCast)
     }
+
+    /// Create a new decimal round expression
+    pub fn try_new_round(from_expr: DynProofExpr, scale: i8) -> AnalyzeResult<Self> {
+        RoundExpr::try_new(Box::new(from_expr), scale).map(DynProofExpr::Round)
+    }
+
+    /// Create a new decimal floor expression
+    pub fn try_new_floor(from_expr: DynProofExpr, scale: i8) -> AnalyzeResult<Self> {
+        FloorExpr::try_new(Box::new(from_expr), scale).map(DynProofExpr::Floor)
+    }
+
+    /// Create a new decimal ceil expression
+    pub fn try_new_ceil(from_expr: DynProofExpr, scale: i8) -> AnalyzeResult<Self> {
+        CeilExpr::try_new(Box::new(from_expr), scale).map(DynProofExpr::Ceil)
+    }
+
+    /// Create a new absolute value expression
+    pub fn try_new_abs(from_expr: DynProofExpr) -> AnalyzeResult<Self> {
+        AbsExpr::try_new(Box::new(from_expr)).map(DynProofExpr::Abs)
+    }
+
+    /// Create a new lag expression
+    pub fn try_new_lag(from_expr: DynProofExpr) -> AnalyzeResult<Self> {
+        LagExpr::try_new(Box::new(from_expr)).map(DynProofExpr::Lag)
+    }
+
+    /// Returns the type of the expression if the columns it references had the types in
+    /// `column_type_map`.
+    ///
+    /// Columns missing from `column_type_map` keep the type of their [`ColumnRef`].
+    /// This applies the same type rules as the constructors, e.g. [`try_add_subtract_column_types`],
+    /// and returns an error if some subexpression is not well-typed.
+    pub fn data_type_with_column_types(
+        &self,
+        column_type_map: &IndexMap<ColumnRef, ColumnType>,
+    ) -> ColumnOperationResult<ColumnType> {
+        let data_type = |expr: &DynProofExpr| expr.data_type_with_column_types(column_type_map);
+        let and_or_type = |lhs: &DynProofExpr, rhs: &DynProofExpr| {
+            let (left_type, right_type) = (data_type(lhs)?, data_type(rhs)?);
+            if can_and_or_types(left_type, right_type) {
+                Ok(ColumnType::Boolean)
+            } else {
+                Err(ColumnOperationError::BinaryOperationInvalidColumnType {
+                    operator: "AND/OR".to_string(),
+                    left_type,
+                    right_type,
+                })
+            }
+        };
+        match self {
+            DynProofExpr::Column(expr) => Ok(column_type_map
+                .get(expr.column_ref())
+                .copied()
+                .unwrap_or(*expr.column_ref().column_type())),
+            DynProofExpr::Literal(expr) => Ok(expr.value().column_type()),
+            DynProofExpr::Placeholder(expr) => Ok(expr.column_type()),
+            DynProofExpr::And(expr) => and_or_type(expr.lhs(), expr.rhs()),
+            DynProofExpr::Or(expr) => and_or_type(expr.lhs(), expr.rhs()),
+            DynProofExpr::Not(expr) => {
+                let operand_type = data_type(expr.input())?;
+                if can_not_type(operand_type) {
+                    Ok(ColumnType::Boolean)
+                } else {
+                    Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                        operator: "NOT".to_string(),
+                        operand_type,
+                    })
+                }
+            }
+            DynProofExpr::Equals(expr) => {
+                try_equals_types(data_type(expr.lhs())?, data_type(expr.rhs())?)?;
+                Ok(ColumnType::Boolean)
+            }
+            DynProofExpr::Inequality(expr) => {
+                try_inequality_types(data_type(expr.lhs())?, data_type(expr.rhs())?)?;
+                Ok(ColumnType::Boolean)
+            }
+            DynProofExpr::Add(expr) => {
+                try_add_subtract_column_types(data_type(expr.lhs())?, data_type(expr.rhs())?)
+            }
+            DynProofExpr::Subtract(expr) => {
+                try_add_subtract_column_types(data_type(expr.lhs())?, data_type(expr.rhs())?)
+            }
+            DynProofExpr::Multiply(expr) => {
+                try_multiply_column_types(data_type(expr.lhs())?, data_type(expr.rhs())?)
+            }
+            DynProofExpr::Cast(expr) => {
+                try_cast_types(data_type(expr.get_from_expr())?, *expr.to_type())?;
+                Ok(*expr.to_type())
+            }
+            DynProofExpr::ScalingCast(expr) => {
+                try_get_scaling_factor_with_precision_and_scale(
+                    data_type(expr.get_from_expr())?,
+                    *expr.to_type(),
+                )?;
+                Ok(*expr.to_type())
+            }
+            DynProofExpr::Round(expr) => {
+                let operand_type = data_type(expr.get_from_expr())?;
+                try_round_type(operand_type, expr.scale()).ok_or_else(|| {
+                    ColumnOperationError::UnaryOperationInvalidColumnType {
+                        operator: "ROUND".to_string(),
+                        operand_type,
+                    }
+                })
+            }
+            DynProofExpr::Abs(expr) => {
+                let operand_type = data_type(expr.get_from_expr())?;
+                try_abs_type(operand_type).ok_or_else(|| {
+                    ColumnOperationError::UnaryOperationInvalidColumnType {
+                        operator: "ABS".to_string(),
+                        operand_type,
+                    }
+                })
+            }
+            DynProofExpr::Lag(expr) => {
+                let operand_type = data_type(expr.get_from_expr())?;
+                if operand_type.is_numeric() {
+                    Ok(operand_type)
+                } else {
+                    Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+                        operator: "LAG".to_string(),
+                        operand_type,
+                    })
+                }
+            }
+            DynProofExpr::Floor(expr) => {
+                let operand_type = data_type(expr.get_from_expr())?;
+                try_round_type(operand_type, expr.scale()).ok_or_else(|| {
+                    ColumnOperationError::UnaryOperationInvalidColumnType {
+                        operator: "FLOOR".to_string(),
+                        operand_type,
+                    }
+                })
+            }
+            DynProofExpr::Ceil(expr) => {
+                let operand_type = data_type(expr.get_from_expr())?;
+                try_round_type(operand_type, expr.scale()).ok_or_else(|| {
+                    ColumnOperationError::UnaryOperationInvalidColumnType {
+                        operator: "CEIL".to_string(),
+                        operand_type,
+                    }
+                })
+            }
+        }
+    }
+
+    /// Replaces every placeholder with a literal of its value in `params`.
+    ///
+    /// `num_params_required` is raised to the number of params needed by the placeholders seen.
+    pub(crate) fn try_bind_placeholders(
+        &self,
+        params: &[LiteralValue],
+        num_params_required: &mut usize,
+    ) -> PlaceholderResult<Self> {
+        let mut bind =
+            |expr: &DynProofExpr| expr.try_bind_placeholders(params, num_params_required);
+        let bound = match self {
+            DynProofExpr::Column(_) | DynProofExpr::Literal(_) => return Ok(self.clone()),
+            DynProofExpr::Placeholder(placeholder) => {
+                *num_params_required = (*num_params_required).max(placeholder.index() + 1);
+                return Ok(Self::new_literal(placeholder.interpolate(params)?.clone()));
+            }
+            DynProofExpr::And(expr) => Self::try_new_and(bind(expr.lhs())?, bind(expr.rhs())?),
+            DynProofExpr::Or(expr) => Self::try_new_or(bind(expr.lhs())?, bind(expr.rhs())?),
+            DynProofExpr::Not(expr) => Self::try_new_not(bind(expr.input())?),
+            DynProofExpr::Equals(expr) => {
+                Self::try_new_equals(bind(expr.lhs())?, bind(expr.rhs())?)
+            }
+            DynProofExpr::Inequality(expr) => {
+                Self::try_new_inequality(bind(expr.lhs())?, bind(expr.rhs())?, expr.is_lt())
+            }
+            DynProofExpr::Add(expr) => Self::try_new_add(bind(expr.lhs())?, bind(expr.rhs())?),
+            DynProofExpr::Subtract(expr) => {
+                Self::try_new_subtract(bind(expr.lhs())?, bind(expr.rhs())?)
+            }
+            DynProofExpr::Multiply(expr) => {
+                Self::try_new_multiply(bind(expr.lhs())?, bind(expr.rhs())?)
+            }
+            DynProofExpr::Cast(expr) => {
+                Self::try_new_cast(bind(expr.get_from_expr())?, *expr.to_type())
+            }
+            DynProofExpr::ScalingCast(expr) => {
+                Self::try_new_scaling_cast(bind(expr.get_from_expr())?, *expr.to_type())
+            }
+            DynProofExpr::Round(expr) => {
+                Self::try_new_round(bind(expr.get_from_expr())?, expr.scale())
+            }
+            DynProofExpr::Abs(expr) => Self::try_new_abs(bind(expr.get_from_expr())?),
+            DynProofExpr::Lag(expr) => Self::try_new_lag(bind(expr.get_from_expr())?),
+            DynProofExpr::Floor(expr) => {
+                Self::try_new_floor(bind(expr.get_from_expr())?, expr.scale())
+            }
+            DynProofExpr::Ceil(expr) => {
+                Self::try_new_ceil(bind(expr.get_from_expr())?, expr.scale())
+            }
+        };
+        Ok(bound.expect("binding placeholders preserves the types of expressions"))
+    }
+
+    /// Returns the expression with every column of table `from` replaced by the same column of table `to`
+    #[must_use]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "Renaming a table preserves the types of columns"
+    )]
+    pub fn rename_table(&self, from: &TableRef, to: &TableRef) -> Self {
+        let rename = |expr: &DynProofExpr| expr.rename_table(from, to);
+        let renamed = match self {
+            DynProofExpr::Column(expr) => return Self::Column(expr.rename_table(from, to)),
+            DynProofExpr::Literal(_) | DynProofExpr::Placeholder(_) => return self.clone(),
+            DynProofExpr::And(expr) => Self::try_new_and(rename(expr.lhs()), rename(expr.rhs())),
+            DynProofExpr::Or(expr) => Self::try_new_or(rename(expr.lhs()), rename(expr.rhs())),
+            DynProofExpr::Not(expr) => Self::try_new_not(rename(expr.input())),
+            DynProofExpr::Equals(expr) => {
+                Self::try_new_equals(rename(expr.lhs()), rename(expr.rhs()))
+            }
+            DynProofExpr::Inequality(expr) => {
+                Self::try_new_inequality(rename(expr.lhs()), rename(expr.rhs()), expr.is_lt())
+            }
+            DynProofExpr::Add(expr) => Self::try_new_add(rename(expr.lhs()), rename(expr.rhs())),
+            DynProofExpr::Subtract(expr) => {
+                Self::try_new_subtract(rename(expr.lhs()), rename(expr.rhs()))
+            }
+            DynProofExpr::Multiply(expr) => {
+                Self::try_new_multiply(rename(expr.lhs()), rename(expr.rhs()))
+            }
+            DynProofExpr::Cast(expr) => {
+                Self::try_new_cast(rename(expr.get_from_expr()), *expr.to_type())
+            }
+            DynProofExpr::ScalingCast(expr) => {
+                Self::try_new_scaling_cast(rename(expr.get_from_expr()), *expr.to_type())
+            }
+            DynProofExpr::Round(expr) => {
+                Self::try_new_round(rename(expr.get_from_expr()), expr.scale())
+            }
+            DynProofExpr::Abs(expr) => Self::try_new_abs(rename(expr.get_from_expr())),
+            DynProofExpr::Lag(expr) => Self::try_new_lag(rename(expr.get_from_expr())),
+            DynProofExpr::Floor(expr) => {
+                Self::try_new_floor(rename(expr.get_from_expr()), expr.scale())
+            }
+            DynProofExpr::Ceil(expr) => {
+                Self::try_new_ceil(rename(expr.get_from_expr()), expr.scale())
+            }
+        };
+        renamed.expect("renaming a table preserves the types of expressions")
+    }
+
+    /// Folds comparisons whose outcome is decided by the types of the expressions involved.
+    ///
+    /// `expr < 0` and `0 > expr` become `false` when `expr` is known to be non-negative, i.e. it is
+    /// a `Uint8` column, an `ABS` expression, or a numeric cast of either. Boolean literals produced
+    /// this way are then folded through `NOT`, `AND` and `OR`.
+    ///
+    /// Only facts the proof establishes are used, so the values of signed columns are never assumed
+    /// to be non-negative.
+    #[must_use]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "Folding comparisons into boolean literals preserves the types of expressions"
+    )]
+    pub fn simplify_comparisons(&self) -> Self {
+        let simplify = DynProofExpr::simplify_comparisons;
+        let simplified = match self {
+            DynProofExpr::Inequality(expr) => {
+                let (lhs, rhs) = (simplify(expr.lhs()), simplify(expr.rhs()));
+                let (value, zero) = if expr.is_lt() {
+                    (&lhs, &rhs)
+                } else {
+                    (&rhs, &lhs)
+                };
+                if zero.is_zero_literal() && value.is_non_negative() {
+                    return Self::new_literal(LiteralValue::Boolean(false));
+                }
+                Self::try_new_inequality(lhs, rhs, expr.is_lt())
+            }
+            DynProofExpr::Not(expr) => {
+                let input = simplify(expr.input());
+                if let Some(value) = input.as_boolean_literal() {
+                    return Self::new_literal(LiteralValue::Boolean(!value));
+                }
+                Self::try_new_not(input)
+            }
+            DynProofExpr::And(expr) => {
+                let (lhs, rhs) = (simplify(expr.lhs()), simplify(expr.rhs()));
+                match (lhs.as_boolean_literal(), rhs.as_boolean_literal()) {
+                    (Some(false), _) | (_, Some(true)) => return lhs,
+                    (_, Some(false)) | (Some(true), _) => return rhs,
+                    _ => Self::try_new_and(lhs, rhs),
+                }
+            }
+            DynProofExpr::Or(expr) => {
+                let (lhs, rhs) = (simplify(expr.lhs()), simplify(expr.rhs()));
+                match (lhs.as_boolean_literal(), rhs.as_boolean_literal()) {
+                    (Some(true), _) | (_, Some(false)) => return lhs,
+                    (_, Some(true)) | (Some(false), _) => return rhs,
+                    _ => Self::try_new_or(lhs, rhs),
+                }
+            }
+            _ => return self.clone(),
+        };
+        simplified.expect("simplifying comparisons preserves the types of expressions")
+    }
+
+    /// Returns the value of the expression if it is a boolean literal.
+    fn as_boolean_literal(&self) -> Option<bool> {
+        match self {
+            DynProofExpr::Literal(literal) => match literal.value() {
+                LiteralValue::Boolean(value) => Some(*value),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression is a numeric literal equal to zero.
+    fn is_zero_literal(&self) -> bool {
+        let DynProofExpr::Literal(literal) = self else {
+            return false;
+        };
+        match literal.value() {
+            LiteralValue::Uint8(value) => *value == 0,
+            LiteralValue::TinyInt(value) => *value == 0,
+            LiteralValue::SmallInt(value) => *value == 0,
+            LiteralValue::Int(value) => *value == 0,
+            LiteralValue::BigInt(value) => *value == 0,
+            LiteralValue::Int128(value) => *value == 0,
+            LiteralValue::Decimal75(_, _, value) => *value == I256::new([0; 4]),
+            LiteralValue::Scalar(limbs) => *limbs == [0; 4],
+            LiteralValue::Boolean(_)
+            | LiteralValue::VarChar(_)
+            | LiteralValue::TimeStampTZ(_, _, _)
+            | LiteralValue::VarBinary(_) => false,
+        }
+    }
+
+    /// Returns true if the expression is known never to be negative.
+    fn is_non_negative(&self) -> bool {
+        match self {
+            DynProofExpr::Column(expr) => expr.column_ref().column_type() == &ColumnType::Uint8,
+            DynProofExpr::Cast(expr) => {
+                expr.to_type().is_numeric() && expr.get_from_expr().is_non_negative()
+            }
+            DynProofExpr::ScalingCast(expr) => expr.get_from_expr().is_non_negative(),
+            DynProofExpr::Abs(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Appends the index and type of every placeholder in the expression to `placeholders`.
+    pub(crate) fn collect_placeholders(&self, placeholders: &mut Vec<(usize, ColumnType)>) {
+        let children = match self {
+            DynProofExpr::Column(_) | DynProofExpr::Literal(_) => vec![],
+            DynProofExpr::Placeholder(placeholder) => {
+                placeholders.push((placeholder.index(), placeholder.column_type()));
+                vec![]
+            }
+            DynProofExpr::And(expr) => vec![expr.lhs(), expr.rhs()],
+            DynProofExpr::Or(expr) => vec![expr.lhs(), expr.rhs()],
+            DynProofExpr::Equals(expr) => vec![expr.lhs(), expr.rhs()],
+            DynProofExpr::Inequality(expr) => vec![expr.lhs(), expr.rhs()],
+            DynProofExpr::Add(expr) => vec![expr.lhs(), expr.rhs()],
+            DynProofExpr::Subtract(expr) => vec![expr.lhs(), expr.rhs()],
+            DynProofExpr::Multiply(expr) => vec![expr.lhs(), expr.rhs()],
+            DynProofExpr::Not(expr) => vec![expr.input()],
+            DynProofExpr::Cast(expr) => vec![expr.get_from_expr()],
+            DynProofExpr::ScalingCast(expr) => vec![expr.get_from_expr()],
+            DynProofExpr::Round(expr) => vec![expr.get_from_expr()],
+            DynProofExpr::Abs(expr) => vec![expr.get_from_expr()],
+            DynProofExpr::Lag(expr) => vec![expr.get_from_expr()],
+            DynProofExpr::Floor(expr) => vec![expr.get_from_expr()],
+            DynProofExpr::Ceil(expr) => vec![expr.get_from_expr()],
+        };
+        for child in children {
+            child.collect_placeholders(placeholders);
+        }
+    }
+}
+
+/// Display the expression in SQL-like infix notation, parenthesizing every binary operation
+impl Display for DynProofExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DynProofExpr::Column(expr) => write!(f, "{}", expr.column_id().value),
+            DynProofExpr::Literal(expr) => write!(f, "{}", expr.value()),
+            DynProofExpr::Placeholder(expr) => write!(f, "${}", expr.index() + 1),
+            DynProofExpr::And(expr) => write!(f, "({} AND {})", expr.lhs(), expr.rhs()),
+            DynProofExpr::Or(expr) => write!(f, "({} OR {})", expr.lhs(), expr.rhs()),
+            DynProofExpr::Not(expr) => write!(f, "NOT {}", expr.input()),
+            DynProofExpr::Equals(expr) => write!(f, "({} = {})", expr.lhs(), expr.rhs()),
+            DynProofExpr::Inequality(expr) => {
+                let op = if expr.is_lt() { "<" } else { ">" };
+                write!(f, "({} {op} {})", expr.lhs(), expr.rhs())
+            }
+            DynProofExpr::Add(expr) => write!(f, "({} + {})", expr.lhs(), expr.rhs()),
+            DynProofExpr::Subtract(expr) => write!(f, "({} - {})", expr.lhs(), expr.rhs()),
+            DynProofExpr::Multiply(expr) => write!(f, "({} * {})", expr.lhs(), expr.rhs()),
+            DynProofExpr::Cast(expr) => {
+                write!(f, "CAST({} AS {})", expr.get_from_expr(), expr.to_type())
+            }
+            DynProofExpr::ScalingCast(expr) => {
+                write!(
+                    f,
+                    "SCALING_CAST({} AS {})",
+                    expr.get_from_expr(),
+                    expr.to_type()
+                )
+            }
+            DynProofExpr::Round(expr) => {
+                write!(f, "ROUND({}, {})", expr.get_from_expr(), expr.scale())
+            }
+            DynProofExpr::Abs(expr) => write!(f, "ABS({})", expr.get_from_expr()),
+            DynProofExpr::Lag(expr) => write!(f, "LAG({})", expr.get_from_expr()),
+            DynProofExpr::Floor(expr) => {
+                write!(f, "FLOOR({}, {})", expr.get_from_expr(), expr.scale())
+            }
+            DynProofExpr::Ceil(expr) => {
+                write!(f, "CEIL({}, {})", expr.get_from_expr(), expr.scale())
+            }
+        }
+    }
 }
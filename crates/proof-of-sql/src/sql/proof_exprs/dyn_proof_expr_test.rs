@@ -0,0 +1,253 @@
+use super::{test_utility::*, DynProofExpr, ProofExpr};
+use crate::base::{
+    commitment::naive_evaluation_proof::NaiveEvaluationProof,
+    database::{
+        owned_table_utility::*, ColumnOperationError, ColumnRef, ColumnType,
+        OwnedTableTestAccessor, TableRef, TestAccessor,
+    },
+    map::{indexmap, IndexMap},
+    math::decimal::Precision,
+};
+
+fn column_ref(name: &str, column_type: ColumnType) -> ColumnRef {
+    ColumnRef::new(TableRef::new("sxt", "t"), name.into(), column_type)
+}
+
+#[test]
+fn we_can_get_the_data_type_of_an_expression_without_overriding_column_types() {
+    let a = column_ref("a", ColumnType::BigInt);
+    let b = column_ref("b", ColumnType::Int);
+    // (a + b) * 2 = a
+    let expr = equal(
+        multiply(
+            add(
+                DynProofExpr::new_column(a.clone()),
+                DynProofExpr::new_column(b),
+            ),
+            const_bigint(2),
+        ),
+        DynProofExpr::new_column(a),
+    );
+    let column_type_map = IndexMap::default();
+    assert_eq!(
+        expr.data_type_with_column_types(&column_type_map),
+        Ok(expr.data_type())
+    );
+    let DynProofExpr::Equals(equals_expr) = &expr else {
+        panic!("Expected an equals expression");
+    };
+    assert_eq!(
+        equals_expr
+            .lhs()
+            .data_type_with_column_types(&column_type_map),
+        Ok(equals_expr.lhs().data_type())
+    );
+}
+
+#[test]
+fn we_can_get_the_data_type_of_a_nested_expression_with_overridden_column_types() {
+    let a = column_ref("a", ColumnType::TinyInt);
+    let b = column_ref("b", ColumnType::SmallInt);
+    let c = column_ref("c", ColumnType::Int);
+    // (a + b) * c
+    let expr = multiply(
+        add(
+            DynProofExpr::new_column(a.clone()),
+            DynProofExpr::new_column(b),
+        ),
+        DynProofExpr::new_column(c.clone()),
+    );
+    assert_eq!(
+        expr.data_type(),
+        ColumnType::Decimal75(Precision::new(17).unwrap(), 0)
+    );
+    let column_type_map = indexmap! {
+        a => ColumnType::BigInt,
+        c => ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+    };
+    // BigInt + SmallInt has precision 20, times a Decimal(10, 2) has precision 31 and scale 2
+    assert_eq!(
+        expr.data_type_with_column_types(&column_type_map),
+        Ok(ColumnType::Decimal75(Precision::new(31).unwrap(), 2))
+    );
+    assert_eq!(
+        equal(expr, const_bigint(0)).data_type_with_column_types(&column_type_map),
+        Ok(ColumnType::Boolean)
+    );
+}
+
+#[test]
+fn we_cannot_get_the_data_type_of_an_expression_which_is_ill_typed_with_overridden_column_types() {
+    let a = column_ref("a", ColumnType::BigInt);
+    let b = column_ref("b", ColumnType::BigInt);
+    let expr = equal(
+        add(
+            DynProofExpr::new_column(a.clone()),
+            DynProofExpr::new_column(b.clone()),
+        ),
+        const_bigint(1),
+    );
+    let column_type_map = indexmap! {
+        b.clone() => ColumnType::VarChar,
+    };
+    assert!(matches!(
+        expr.data_type_with_column_types(&column_type_map),
+        Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
+    ));
+
+    let expr = not(equal(
+        DynProofExpr::new_column(a),
+        DynProofExpr::new_column(b.clone()),
+    ));
+    let column_type_map = indexmap! {
+        b => ColumnType::Boolean,
+    };
+    assert!(matches!(
+        expr.data_type_with_column_types(&column_type_map),
+        Err(ColumnOperationError::BinaryOperationInvalidColumnType { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_get_the_data_type_of_a_round_expression_if_the_input_is_no_longer_a_decimal() {
+    let a = column_ref("a", ColumnType::Decimal75(Precision::new(10).unwrap(), 2));
+    let expr = round(DynProofExpr::new_column(a.clone()), 0);
+    assert_eq!(
+        expr.data_type_with_column_types(&IndexMap::default()),
+        Ok(ColumnType::Decimal75(Precision::new(9).unwrap(), 0))
+    );
+    let column_type_map = indexmap! {
+        a => ColumnType::BigInt,
+    };
+    assert_eq!(
+        expr.data_type_with_column_types(&column_type_map),
+        Err(ColumnOperationError::UnaryOperationInvalidColumnType {
+            operator: "ROUND".into(),
+            operand_type: ColumnType::BigInt,
+        })
+    );
+}
+
+fn accessor_with_unsigned_column() -> (TableRef, OwnedTableTestAccessor<NaiveEvaluationProof>) {
+    let t = TableRef::new("sxt", "t");
+    let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t.clone(),
+        owned_table([
+            bigint("a", [1, 2, 3]),
+            bigint("b", [-1, 0, 1]),
+            uint8("c", [0_u8, 1, 2]),
+        ]),
+        0,
+    );
+    (t, accessor)
+}
+
+#[test]
+fn we_can_fold_comparisons_of_non_negative_expressions_against_zero() {
+    let (t, accessor) = accessor_with_unsigned_column();
+    let b = || column(&t, "b", &accessor);
+    let c = || column(&t, "c", &accessor);
+    for (expr, expected) in [
+        (lt(c(), const_bigint(0)), false),
+        (gt(const_bigint(0), c()), false),
+        (gte(c(), const_bigint(0)), true),
+        (lte(const_int(0), c()), true),
+        (lt(abs(b()), const_bigint(0)), false),
+        (gte(cast(c(), ColumnType::Int128), const_int128(0)), true),
+        (
+            lt(
+                scaling_cast(c(), ColumnType::Decimal75(Precision::new(25).unwrap(), 2)),
+                const_decimal75(25, 2, 0),
+            ),
+            false,
+        ),
+    ] {
+        assert_eq!(expr.simplify_comparisons(), const_bool(expected));
+    }
+}
+
+#[test]
+fn we_can_fold_boolean_connectives_after_folding_comparisons() {
+    let (t, accessor) = accessor_with_unsigned_column();
+    let c = || column(&t, "c", &accessor);
+    let b_positive = || gt(column(&t, "b", &accessor), const_bigint(0));
+    let c_negative = || lt(c(), const_bigint(0));
+    assert_eq!(
+        and(c_negative(), b_positive()).simplify_comparisons(),
+        const_bool(false)
+    );
+    assert_eq!(
+        and(b_positive(), not(c_negative())).simplify_comparisons(),
+        b_positive()
+    );
+    assert_eq!(
+        or(c_negative(), b_positive()).simplify_comparisons(),
+        b_positive()
+    );
+    assert_eq!(
+        or(b_positive(), not(c_negative())).simplify_comparisons(),
+        const_bool(true)
+    );
+    assert_eq!(
+        not(and(c_negative(), b_positive())).simplify_comparisons(),
+        const_bool(true)
+    );
+}
+
+#[test]
+fn we_do_not_fold_comparisons_which_types_do_not_decide() {
+    let (t, accessor) = accessor_with_unsigned_column();
+    let a = || column(&t, "a", &accessor);
+    let b = || column(&t, "b", &accessor);
+    let c = || column(&t, "c", &accessor);
+    for expr in [
+        // Signed columns may be negative even if their values happen not to be
+        lt(a(), const_bigint(0)),
+        gte(a(), const_bigint(0)),
+        lt(b(), const_bigint(0)),
+        // The comparison is not against zero
+        lt(c(), const_bigint(1)),
+        // The non-negative column is on the wrong side
+        gt(c(), const_bigint(0)),
+        lt(const_bigint(0), c()),
+        // Arithmetic may be negative
+        lt(subtract(c(), b()), const_bigint(0)),
+        equal(c(), const_bigint(0)),
+    ] {
+        assert_eq!(expr.simplify_comparisons(), expr);
+    }
+}
+
+#[test]
+fn we_can_display_expressions() {
+    let a = || DynProofExpr::new_column(column_ref("a", ColumnType::BigInt));
+    let b = || {
+        DynProofExpr::new_column(column_ref(
+            "b",
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+        ))
+    };
+    for (expr, expected) in [
+        (
+            or(equal(a(), const_bigint(-1)), not(lt(a(), const_bigint(3)))),
+            "((a = -1) OR NOT (a < 3))",
+        ),
+        (
+            multiply(subtract(a(), const_int(1)), const_decimal75(5, 2, 150)),
+            "((a - 1) * 1.50)",
+        ),
+        (cast(a(), ColumnType::Int128), "CAST(a AS DECIMAL)"),
+        (round(b(), 0), "ROUND(b, 0)"),
+        (
+            equal(
+                a(),
+                DynProofExpr::try_new_placeholder(2, ColumnType::BigInt).unwrap(),
+            ),
+            "(a = $2)",
+        ),
+        (const_varchar("it's"), "'it''s'"),
+    ] {
+        assert_eq!(expr.to_string(), expected);
+    }
+}
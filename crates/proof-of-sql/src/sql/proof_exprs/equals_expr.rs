@@ -8,7 +8,9 @@ use crate::{
         slice_ops,
     },
     sql::{
-        proof::{FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+        proof::{
+            FinalRoundBuilder, FirstRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder,
+        },
         AnalyzeError, AnalyzeResult,
     },
     utils::log,
@@ -57,14 +59,19 @@ impl ProofExpr for EqualsExpr {
     #[tracing::instrument(name = "EqualsExpr::first_round_evaluate", level = "debug", skip_all)]
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         params: &[LiteralValue],
     ) -> PlaceholderResult<Column<'a, S>> {
         log::log_memory_usage("Start");
 
-        let lhs_column = self.lhs.first_round_evaluate(alloc, table, params)?;
-        let rhs_column = self.rhs.first_round_evaluate(alloc, table, params)?;
+        let lhs_column = self
+            .lhs
+            .first_round_evaluate(builder, alloc, table, params)?;
+        let rhs_column = self
+            .rhs
+            .first_round_evaluate(builder, alloc, table, params)?;
         let res = add_subtract_columns(lhs_column, rhs_column, alloc, true);
         let res = Column::Boolean(first_round_evaluate_equals_zero(
             table.num_rows(),
@@ -10,7 +10,7 @@ use crate::{
     },
     proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, FirstRoundBuilder, VerifiableQueryResult},
         proof_exprs::{test_utility::*, DynProofExpr, EqualsExpr, ProofExpr},
         proof_plans::test_utility::*,
         AnalyzeError,
@@ -552,8 +552,9 @@ fn we_can_compute_the_correct_output_of_an_equals_expr_using_first_round_evaluat
         column(&t, "e", &accessor),
         const_scalar::<Curve25519Scalar, _>(Curve25519Scalar::ZERO),
     );
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
     let res = equals_expr
-        .first_round_evaluate(&alloc, &data, &[])
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
         .unwrap();
     let expected_res = Column::Boolean(&[true, false, true, false]);
     assert_eq!(res, expected_res);
@@ -599,6 +600,39 @@ fn we_can_query_with_varbinary_equality() {
     assert_eq!(res, expected_res);
 }
 
+#[test]
+fn we_can_query_with_varbinary_equality_on_empty_values() {
+    let data: OwnedTable<Curve25519Scalar> = owned_table([
+        bigint("a", [1, 2, 3]),
+        varbinary("b", [b"".as_slice(), b"\x00", b""]),
+    ]);
+    let t = TableRef::new("sxt", "table");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+
+    // SELECT a, b FROM table WHERE b = []
+    let ast = filter(
+        cols_expr_plan(&t, &["a", "b"], &accessor),
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::BigInt),
+                column_field("b", ColumnType::VarBinary),
+            ],
+        ),
+        equal(column(&t, "b", &accessor), const_varbinary(&[])),
+    );
+    let verifiable_res =
+        VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([bigint("a", [1, 3]), varbinary("b", [b"".as_slice(), b""])]);
+    assert_eq!(res, expected_res);
+}
+
 #[test]
 fn we_cannot_equals_mismatching_types() {
     let alloc = Bump::new();
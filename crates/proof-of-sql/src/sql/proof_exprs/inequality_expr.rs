@@ -7,7 +7,7 @@ use crate::{
         scalar::Scalar,
     },
     sql::{
-        proof::{FinalRoundBuilder, VerificationBuilder},
+        proof::{FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder},
         proof_gadgets::{
             final_round_evaluate_sign, first_round_evaluate_sign, verifier_evaluate_sign,
         },
@@ -73,14 +73,19 @@ impl ProofExpr for InequalityExpr {
     )]
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         params: &[LiteralValue],
     ) -> PlaceholderResult<Column<'a, S>> {
         log::log_memory_usage("Start");
 
-        let lhs_column = self.lhs.first_round_evaluate(alloc, table, params)?;
-        let rhs_column = self.rhs.first_round_evaluate(alloc, table, params)?;
+        let lhs_column = self
+            .lhs
+            .first_round_evaluate(builder, alloc, table, params)?;
+        let rhs_column = self
+            .rhs
+            .first_round_evaluate(builder, alloc, table, params)?;
         let table_length = table.num_rows();
         let diff = if self.is_lt {
             add_subtract_columns(lhs_column, rhs_column, alloc, true)
@@ -11,7 +11,7 @@ use crate::{
     },
     proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, FirstRoundBuilder, VerifiableQueryResult},
         proof_exprs::{inequality_expr::InequalityExpr, test_utility::*, DynProofExpr, ProofExpr},
         proof_plans::test_utility::*,
         AnalyzeError,
@@ -805,7 +805,10 @@ fn we_can_compute_the_correct_output_of_a_lte_inequality_expr_using_first_round_
     let lhs_expr: DynProofExpr = column(&t, "a", &accessor);
     let rhs_expr = column(&t, "b", &accessor);
     let lte_expr = lte(lhs_expr, rhs_expr);
-    let res = lte_expr.first_round_evaluate(&alloc, &data, &[]).unwrap();
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
+    let res = lte_expr
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
+        .unwrap();
     let expected_res = Column::Boolean(&[true, false, true]);
     assert_eq!(res, expected_res);
 }
@@ -823,7 +826,10 @@ fn we_can_compute_the_correct_output_of_a_gte_inequality_expr_using_first_round_
     let col_expr: DynProofExpr = column(&t, "a", &accessor);
     let lit_expr = const_bigint(1);
     let gte_expr = gte(col_expr, lit_expr);
-    let res = gte_expr.first_round_evaluate(&alloc, &data, &[]).unwrap();
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
+    let res = gte_expr
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
+        .unwrap();
     let expected_res = Column::Boolean(&[false, true, true]);
     assert_eq!(res, expected_res);
 }
@@ -0,0 +1,167 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, LiteralValue, Table},
+        map::{IndexMap, IndexSet},
+        proof::{PlaceholderResult, ProofError},
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{
+            FinalRoundBuilder, FirstRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder,
+        },
+        proof_gadgets::{final_round_evaluate_shift, first_round_evaluate_shift, verify_shift},
+        AnalyzeError, AnalyzeResult,
+    },
+};
+use alloc::{boxed::Box, vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::Ident;
+
+/// Provable LAG expression for numeric expressions
+///
+/// Row `i` of the output is row `i - 1` of the input, and the first row of the output is zero.
+///
+/// If `x` is the input of length `n`, the shift gadget proves that `s = [0, x_0, ..., x_{n-1}]`,
+/// which has length `n + 1`, is the downward shift of `x`. The prover commits to the output `y`
+/// and proves `y - chi_n * s = 0` row by row, i.e. `y` is `s` with its last row dropped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LagExpr {
+    from_expr: Box<DynProofExpr>,
+}
+
+impl LagExpr {
+    /// Creates a new `LagExpr` lagging `from_expr` by one row
+    pub fn try_new(from_expr: Box<DynProofExpr>) -> AnalyzeResult<Self> {
+        let from_datatype = from_expr.data_type();
+        if from_datatype.is_numeric() {
+            Ok(Self { from_expr })
+        } else {
+            Err(AnalyzeError::InvalidDataType {
+                expr_type: from_datatype,
+            })
+        }
+    }
+
+    /// Returns the from expression
+    pub fn get_from_expr(&self) -> &DynProofExpr {
+        &self.from_expr
+    }
+}
+
+/// Returns `values` shifted down by one row, with zero in the first row
+fn lag_slice<'a, T: Copy + Default>(alloc: &'a Bump, values: &[T]) -> &'a [T] {
+    alloc.alloc_slice_fill_with(values.len(), |i| {
+        if i == 0 {
+            T::default()
+        } else {
+            values[i - 1]
+        }
+    })
+}
+
+/// Returns `column` shifted down by one row, with zero in the first row
+fn lag_column<'a, S: Scalar>(alloc: &'a Bump, column: Column<'a, S>) -> Column<'a, S> {
+    match column {
+        Column::Uint8(values) => Column::Uint8(lag_slice(alloc, values)),
+        Column::TinyInt(values) => Column::TinyInt(lag_slice(alloc, values)),
+        Column::SmallInt(values) => Column::SmallInt(lag_slice(alloc, values)),
+        Column::Int(values) => Column::Int(lag_slice(alloc, values)),
+        Column::BigInt(values) => Column::BigInt(lag_slice(alloc, values)),
+        Column::Int128(values) => Column::Int128(lag_slice(alloc, values)),
+        Column::Decimal75(precision, scale, values) => {
+            Column::Decimal75(precision, scale, lag_slice(alloc, values))
+        }
+        Column::Scalar(values) => Column::Scalar(lag_slice(alloc, values)),
+        Column::Boolean(_)
+        | Column::VarChar(_)
+        | Column::VarBinary(_)
+        | Column::TimestampTZ(_, _, _) => {
+            unreachable!("Lag expressions are validated to be numeric on construction")
+        }
+    }
+}
+
+impl ProofExpr for LagExpr {
+    fn data_type(&self) -> ColumnType {
+        self.from_expr.data_type()
+    }
+
+    fn first_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Column<'a, S>> {
+        let from_column = self
+            .from_expr
+            .first_round_evaluate(builder, alloc, table, params)?;
+        let from_values: &'a [S] = alloc.alloc_slice_copy(&from_column.to_scalar());
+        builder.request_post_result_challenges(2);
+        first_round_evaluate_shift(builder, alloc, from_values);
+        Ok(lag_column(alloc, from_column))
+    }
+
+    #[tracing::instrument(name = "LagExpr::final_round_evaluate", level = "debug", skip_all)]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Column<'a, S>> {
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+        let from_column = self
+            .from_expr
+            .final_round_evaluate(builder, alloc, table, params)?;
+        let from_values: &'a [S] = alloc.alloc_slice_copy(&from_column.to_scalar());
+        let shifted_values = final_round_evaluate_shift(builder, alloc, alpha, beta, from_values);
+        let lagged_values: &'a [S] = lag_slice(alloc, from_values);
+        builder.produce_intermediate_mle(lagged_values);
+        let chi_n = alloc.alloc_slice_fill_copy(table.num_rows(), true);
+
+        // subpolynomial: y - chi_n * s
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![
+                (S::one(), vec![Box::new(lagged_values)]),
+                (
+                    -S::one(),
+                    vec![Box::new(chi_n as &[_]), Box::new(shifted_values)],
+                ),
+            ],
+        );
+        Ok(lag_column(alloc, from_column))
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut impl VerificationBuilder<S>,
+        accessor: &IndexMap<Ident, S>,
+        chi_eval: S,
+        params: &[LiteralValue],
+    ) -> Result<S, ProofError> {
+        let alpha = builder.try_consume_post_result_challenge()?;
+        let beta = builder.try_consume_post_result_challenge()?;
+        let from_eval = self
+            .from_expr
+            .verifier_evaluate(builder, accessor, chi_eval, params)?;
+        let (shifted_eval, _) = verify_shift(builder, alpha, beta, from_eval, chi_eval)?;
+        let lagged_eval = builder.try_consume_final_round_mle_evaluation()?;
+
+        // subpolynomial: y - chi_n * s
+        builder.try_produce_sumcheck_subpolynomial_evaluation(
+            SumcheckSubpolynomialType::Identity,
+            lagged_eval - chi_eval * shifted_eval,
+            2,
+        )?;
+        Ok(lagged_eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.from_expr.get_column_references(columns);
+    }
+}
@@ -0,0 +1,159 @@
+use super::{DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::{bigint, decimal75, owned_table, varchar},
+            table_utility::{borrowed_bigint, table},
+            Column, ColumnType, LiteralValue, OwnedTableTestAccessor, TableRef, TableTestAccessor,
+        },
+        math::decimal::Precision,
+    },
+    sql::{
+        proof::{exercise_verification, FirstRoundBuilder, VerifiableQueryResult},
+        proof_exprs::test_utility::{aliased_plan, column, const_bool, gt, lag, subtract},
+        proof_plans::test_utility::{column_field, filter, projection, table_exec},
+        AnalyzeError,
+    },
+};
+use blitzar::proof::InnerProductProof;
+use bumpalo::Bump;
+
+#[test]
+fn we_can_compute_the_correct_output_of_a_lag_expr_using_first_round_evaluate() {
+    let alloc = Bump::new();
+    let data = table([borrowed_bigint("a", [5, -3, i64::MAX, i64::MIN, 0], &alloc)]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        TableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data.clone(), 0, ());
+    let lag_expr: DynProofExpr = lag(column(&t, "a", &accessor));
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
+    let res = lag_expr
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
+        .unwrap();
+    let expected_res = Column::BigInt(&[0, 5, -3, i64::MAX, i64::MIN]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_lag_expr_over_a_bigint_column() {
+    let data = owned_table([bigint("a", [5, -3, i64::MAX, i64::MIN, 0])]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(lag(column(&t, "a", &accessor)), "lag_a")],
+        table_exec(t.clone(), vec![column_field("a", ColumnType::BigInt)]),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([bigint("lag_a", [0, 5, -3, i64::MAX, i64::MIN])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_differences_between_consecutive_rows() {
+    let data = owned_table([
+        bigint("a", [10, 12, 11, 20, 20, 25]),
+        decimal75("b", 5, 2, [100, 150, 125, -50, 0, 300]),
+    ]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    // SELECT a - LAG(a) AS delta_a, b - LAG(b) AS delta_b FROM t
+    let ast = filter(
+        vec![
+            aliased_plan(
+                subtract(column(&t, "a", &accessor), lag(column(&t, "a", &accessor))),
+                "delta_a",
+            ),
+            aliased_plan(
+                subtract(column(&t, "b", &accessor), lag(column(&t, "b", &accessor))),
+                "delta_b",
+            ),
+        ],
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::BigInt),
+                column_field("b", ColumnType::Decimal75(Precision::new(5).unwrap(), 2)),
+            ],
+        ),
+        const_bool(true),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([
+        decimal75("delta_a", 20, 0, [10, 2, -1, 9, 0, 5]),
+        decimal75("delta_b", 6, 2, [100, 50, -25, -175, 50, 300]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_lag_expr_in_a_filter_predicate() {
+    let data = owned_table([bigint("a", [10, 12, 11, 20, 20, 25])]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    // SELECT a FROM t WHERE a > LAG(a)
+    let ast = filter(
+        vec![aliased_plan(column(&t, "a", &accessor), "a")],
+        table_exec(t.clone(), vec![column_field("a", ColumnType::BigInt)]),
+        gt(column(&t, "a", &accessor), lag(column(&t, "a", &accessor))),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([bigint("a", [10, 12, 20, 25])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_lag_expr_on_an_empty_table() {
+    let data = owned_table([bigint("a", [0_i64; 0])]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    let ast = projection(
+        vec![aliased_plan(lag(column(&t, "a", &accessor)), "lag_a")],
+        table_exec(t.clone(), vec![column_field("a", ColumnType::BigInt)]),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([bigint("lag_a", [0_i64; 0])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_lag_non_numeric_expressions() {
+    let t = TableRef::new("sxt", "t");
+    let data = owned_table([varchar("a", ["x"])]);
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_lag(column(&t, "a", &accessor)),
+        Err(AnalyzeError::InvalidDataType {
+            expr_type: ColumnType::VarChar
+        })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_lag(DynProofExpr::new_literal(LiteralValue::Boolean(true))),
+        Err(AnalyzeError::InvalidDataType {
+            expr_type: ColumnType::Boolean
+        })
+    ));
+}
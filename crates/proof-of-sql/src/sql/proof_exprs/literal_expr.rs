@@ -6,7 +6,7 @@ use crate::{
         proof::{PlaceholderResult, ProofError},
         scalar::Scalar,
     },
-    sql::proof::{FinalRoundBuilder, VerificationBuilder},
+    sql::proof::{FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder},
     utils::log,
 };
 use bumpalo::Bump;
@@ -49,6 +49,7 @@ impl ProofExpr for LiteralExpr {
     #[tracing::instrument(name = "LiteralExpr::first_round_evaluate", level = "debug", skip_all)]
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        _builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         _params: &[LiteralValue],
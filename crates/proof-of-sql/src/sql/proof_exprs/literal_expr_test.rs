@@ -9,7 +9,7 @@ use crate::{
     },
     proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, FirstRoundBuilder, VerifiableQueryResult},
         proof_exprs::test_utility::*,
         proof_plans::test_utility::*,
     },
@@ -144,8 +144,9 @@ fn we_can_compute_the_correct_output_of_a_literal_expr_using_first_round_evaluat
     let data: Table<Curve25519Scalar> =
         table([borrowed_bigint("a", [123_i64, 456, 789, 1011], &alloc)]);
     let literal_expr: DynProofExpr = const_bool(true);
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
     let res = literal_expr
-        .first_round_evaluate(&alloc, &data, &[])
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
         .unwrap();
     let expected_res = Column::Boolean(&[true, true, true, true]);
     assert_eq!(res, expected_res);
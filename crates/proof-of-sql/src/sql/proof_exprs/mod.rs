@@ -22,6 +22,8 @@ mod multiply_expr_test;
 
 mod dyn_proof_expr;
 pub use dyn_proof_expr::DynProofExpr;
+#[cfg(test)]
+mod dyn_proof_expr_test;
 
 mod literal_expr;
 pub(crate) use literal_expr::LiteralExpr;
@@ -59,7 +61,9 @@ pub(crate) use numerical_util::{add_subtract_columns, multiply_columns};
 pub(crate) use numerical_util::{divide_columns, modulo_columns};
 
 mod equals_expr;
-pub(crate) use equals_expr::EqualsExpr;
+pub(crate) use equals_expr::{
+    final_round_evaluate_equals_zero, verifier_evaluate_equals_zero, EqualsExpr,
+};
 #[cfg(all(test, feature = "blitzar"))]
 mod equals_expr_test;
 
@@ -83,3 +87,18 @@ mod scaling_cast_expr;
 pub(crate) use scaling_cast_expr::ScalingCastExpr;
 #[cfg(all(test, feature = "blitzar"))]
 mod scaling_cast_expr_test;
+
+mod round_expr;
+pub(crate) use round_expr::{CeilExpr, FloorExpr, RoundExpr};
+#[cfg(all(test, feature = "blitzar"))]
+mod round_expr_test;
+
+mod abs_expr;
+pub(crate) use abs_expr::AbsExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod abs_expr_test;
+
+mod lag_expr;
+pub(crate) use lag_expr::LagExpr;
+#[cfg(all(test, feature = "blitzar"))]
+mod lag_expr_test;
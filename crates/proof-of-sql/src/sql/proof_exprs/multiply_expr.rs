@@ -7,7 +7,9 @@ use crate::{
         scalar::Scalar,
     },
     sql::{
-        proof::{FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+        proof::{
+            FinalRoundBuilder, FirstRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder,
+        },
         proof_exprs::multiply_columns,
         AnalyzeError, AnalyzeResult,
     },
@@ -57,12 +59,17 @@ impl ProofExpr for MultiplyExpr {
 
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         params: &[LiteralValue],
     ) -> PlaceholderResult<Column<'a, S>> {
-        let lhs_column: Column<'a, S> = self.lhs.first_round_evaluate(alloc, table, params)?;
-        let rhs_column: Column<'a, S> = self.rhs.first_round_evaluate(alloc, table, params)?;
+        let lhs_column: Column<'a, S> = self
+            .lhs
+            .first_round_evaluate(builder, alloc, table, params)?;
+        let rhs_column: Column<'a, S> = self
+            .rhs
+            .first_round_evaluate(builder, alloc, table, params)?;
         let res = multiply_columns(&lhs_column, &rhs_column, alloc);
         Ok(Column::Decimal75(self.precision(), self.scale(), res))
     }
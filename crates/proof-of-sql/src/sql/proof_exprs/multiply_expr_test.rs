@@ -9,7 +9,7 @@ use crate::{
     },
     proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, FirstRoundBuilder, VerifiableQueryResult},
         proof_exprs::{multiply_expr::MultiplyExpr, test_utility::*, DynProofExpr, ProofExpr},
         proof_plans::{test_utility::*, DynProofPlan},
         AnalyzeError,
@@ -282,8 +282,9 @@ fn we_can_compute_the_correct_output_of_a_multiply_expr_using_first_round_evalua
             const_decimal75(2, 1, 15),
         ),
     );
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
     let res = arithmetic_expr
-        .first_round_evaluate(&alloc, &data, &[])
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
         .unwrap();
     let expected_res = borrowed_decimal75("f", 18, 1, [0_i64, 5, 75, 25], &alloc).1;
     assert_eq!(res, expected_res);
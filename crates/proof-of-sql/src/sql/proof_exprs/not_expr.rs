@@ -7,7 +7,7 @@ use crate::{
         scalar::Scalar,
     },
     sql::{
-        proof::{FinalRoundBuilder, VerificationBuilder},
+        proof::{FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder},
         AnalyzeError, AnalyzeResult,
     },
     utils::log,
@@ -46,13 +46,16 @@ impl ProofExpr for NotExpr {
     #[tracing::instrument(name = "NotExpr::first_round_evaluate", level = "debug", skip_all)]
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         params: &[LiteralValue],
     ) -> PlaceholderResult<Column<'a, S>> {
         log::log_memory_usage("Start");
 
-        let expr_column: Column<'a, S> = self.expr.first_round_evaluate(alloc, table, params)?;
+        let expr_column: Column<'a, S> = self
+            .expr
+            .first_round_evaluate(builder, alloc, table, params)?;
         let expr = expr_column.as_boolean().expect("expr is not boolean");
         let res = Column::Boolean(alloc.alloc_slice_fill_with(expr.len(), |i| !expr[i]));
 
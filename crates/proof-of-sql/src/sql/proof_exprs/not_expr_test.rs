@@ -8,7 +8,7 @@ use crate::{
         scalar::test_scalar::TestScalar,
     },
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, FirstRoundBuilder, VerifiableQueryResult},
         proof_exprs::{not_expr::NotExpr, test_utility::*, DynProofExpr, ProofExpr},
         proof_plans::test_utility::*,
         AnalyzeError,
@@ -143,7 +143,10 @@ fn we_can_compute_the_correct_output_of_a_not_expr_using_first_round_evaluate()
     let t = TableRef::new("sxt", "t");
     accessor.add_table(t.clone(), data.clone(), 0);
     let not_expr: DynProofExpr = not(equal(column(&t, "b", &accessor), const_int128(1)));
-    let res = not_expr.first_round_evaluate(&alloc, &data, &[]).unwrap();
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
+    let res = not_expr
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
+        .unwrap();
     let expected_res = Column::Boolean(&[true, false]);
     assert_eq!(res, expected_res);
 }
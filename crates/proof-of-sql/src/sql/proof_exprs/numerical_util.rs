@@ -14,6 +14,10 @@ use num_traits::{NumCast, PrimInt};
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefMutIterator, ParallelIterator};
 
 /// Add or subtract two columns together.
+///
+/// Columns carry no null bitmap, as nulls are rejected when data is loaded
+/// (see `ArrowArrayToColumnConversionError::ArrayContainsNulls`),
+/// so there is no null propagation to perform here.
 /// # Panics
 /// Panics if: `lhs` and `rhs` are not of the same length
 #[tracing::instrument(level = "debug", skip_all)]
@@ -59,6 +63,8 @@ pub(crate) fn add_subtract_columns<'a, S: Scalar>(
 }
 
 /// Multiply two columns together.
+///
+/// As with [`add_subtract_columns`], operands are never null.
 /// # Panics
 /// Panics if: `lhs` and `rhs` are not of the same length.
 #[tracing::instrument(level = "debug", skip_all)]
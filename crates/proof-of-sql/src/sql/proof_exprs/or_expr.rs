@@ -7,7 +7,9 @@ use crate::{
         scalar::Scalar,
     },
     sql::{
-        proof::{FinalRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder},
+        proof::{
+            FinalRoundBuilder, FirstRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder,
+        },
         AnalyzeError, AnalyzeResult,
     },
     utils::log,
@@ -18,6 +20,8 @@ use serde::{Deserialize, Serialize};
 use sqlparser::ast::Ident;
 
 /// Provable logical OR expression
+///
+/// Boolean columns are never null, so SQL three-valued logic reduces to ordinary boolean OR.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OrExpr {
     lhs: Box<DynProofExpr>,
@@ -56,14 +60,19 @@ impl ProofExpr for OrExpr {
     #[tracing::instrument(name = "OrExpr::first_round_evaluate", level = "debug", skip_all)]
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         params: &[LiteralValue],
     ) -> PlaceholderResult<Column<'a, S>> {
         log::log_memory_usage("Start");
 
-        let lhs_column: Column<'a, S> = self.lhs.first_round_evaluate(alloc, table, params)?;
-        let rhs_column: Column<'a, S> = self.rhs.first_round_evaluate(alloc, table, params)?;
+        let lhs_column: Column<'a, S> = self
+            .lhs
+            .first_round_evaluate(builder, alloc, table, params)?;
+        let rhs_column: Column<'a, S> = self
+            .rhs
+            .first_round_evaluate(builder, alloc, table, params)?;
         let lhs = lhs_column.as_boolean().expect("lhs is not boolean");
         let rhs = rhs_column.as_boolean().expect("rhs is not boolean");
         let result = Column::Boolean(first_round_evaluate_or(table.num_rows(), alloc, lhs, rhs));
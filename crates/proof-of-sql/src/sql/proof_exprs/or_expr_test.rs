@@ -7,7 +7,7 @@ use crate::{
         },
     },
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, FirstRoundBuilder, VerifiableQueryResult},
         proof_exprs::{or_expr::OrExpr, test_utility::*, DynProofExpr, ProofExpr},
         proof_plans::test_utility::*,
         AnalyzeError,
@@ -232,7 +232,10 @@ fn we_can_compute_the_correct_output_of_an_or_expr_using_first_round_evaluate()
         equal(column(&t, "b", &accessor), const_int128(1)),
         equal(column(&t, "d", &accessor), const_varchar("g")),
     );
-    let res = and_expr.first_round_evaluate(&alloc, &data, &[]).unwrap();
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
+    let res = and_expr
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
+        .unwrap();
     let expected_res = Column::Boolean(&[false, true, true, true]);
     assert_eq!(res, expected_res);
 }
@@ -6,7 +6,7 @@ use crate::{
         proof::{PlaceholderError, PlaceholderResult, ProofError},
         scalar::Scalar,
     },
-    sql::proof::{FinalRoundBuilder, VerificationBuilder},
+    sql::proof::{FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder},
     utils::log,
 };
 use bumpalo::Bump;
@@ -64,7 +64,7 @@ impl PlaceholderExpr {
     /// Note that this function will return an error if
     /// 1. The placeholder id is out of bounds
     /// 2. The placeholder type does not match the type of the value in `params`
-    fn interpolate<'a>(
+    pub(crate) fn interpolate<'a>(
         &self,
         params: &'a [LiteralValue],
     ) -> Result<&'a LiteralValue, PlaceholderError> {
@@ -98,6 +98,7 @@ impl ProofExpr for PlaceholderExpr {
     )]
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        _builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         params: &[LiteralValue],
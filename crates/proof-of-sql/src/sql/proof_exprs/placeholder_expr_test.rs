@@ -10,7 +10,7 @@ use crate::{
     },
     proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::{
-        proof::{QueryError, VerifiableQueryResult},
+        proof::{FirstRoundBuilder, QueryError, VerifiableQueryResult},
         proof_exprs::test_utility::*,
         proof_plans::test_utility::*,
     },
@@ -171,8 +171,14 @@ fn we_can_compute_the_correct_output_of_a_placeholder_expr_using_first_round_eva
         table([borrowed_bigint("a", [123_i64, 456, 789, 1011], &alloc)]);
     let placeholder_expr: DynProofExpr =
         DynProofExpr::try_new_placeholder(1, ColumnType::BigInt).unwrap();
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
     let res = placeholder_expr
-        .first_round_evaluate(&alloc, &data, &[LiteralValue::BigInt(504_i64)])
+        .first_round_evaluate(
+            first_round_builder,
+            &alloc,
+            &data,
+            &[LiteralValue::BigInt(504_i64)],
+        )
         .unwrap();
     let expected_res = Column::BigInt(&[504, 504, 504, 504]);
     assert_eq!(res, expected_res);
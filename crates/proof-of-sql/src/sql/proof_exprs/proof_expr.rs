@@ -6,7 +6,7 @@ use crate::{
         proof::{PlaceholderResult, ProofError},
         scalar::Scalar,
     },
-    sql::proof::{FinalRoundBuilder, VerificationBuilder},
+    sql::proof::{FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder},
 };
 use bumpalo::Bump;
 use core::fmt::Debug;
@@ -21,8 +21,11 @@ pub trait ProofExpr: Debug + Send + Sync {
     /// This returns the result of evaluating the expression on the given table, and returns
     /// a column of values. This result slice is guaranteed to have length `table_length`.
     /// Implementations must ensure that the returned slice has length `table_length`.
+    /// Any intermediate columns the expression needs to commit to before the post-result
+    /// challenges are drawn are added to the [`FirstRoundBuilder`].
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         params: &[LiteralValue],
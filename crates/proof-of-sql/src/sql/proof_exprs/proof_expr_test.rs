@@ -1,7 +1,10 @@
 use super::{test_utility::*, DynProofExpr, ProofExpr};
-use crate::base::{
-    commitment::InnerProductProof,
-    database::{table_utility::*, Column, TableRef, TableTestAccessor, TestAccessor},
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{table_utility::*, Column, TableRef, TableTestAccessor, TestAccessor},
+    },
+    sql::proof::FirstRoundBuilder,
 };
 use bumpalo::Bump;
 
@@ -41,7 +44,10 @@ fn we_can_compute_the_correct_result_of_a_complex_bool_expr_using_first_round_ev
         ),
         not(equal(column(&t, "c", &accessor), const_int128(3))),
     );
-    let res = bool_expr.first_round_evaluate(&alloc, &data, &[]).unwrap();
+    let first_round_builder = &mut FirstRoundBuilder::new(data.num_rows());
+    let res = bool_expr
+        .first_round_evaluate(first_round_builder, &alloc, &data, &[])
+        .unwrap();
     let expected_res = Column::Boolean(&[
         false, true, false, true, false, true, false, true, false, true, false, true, false, true,
         false, false, false,
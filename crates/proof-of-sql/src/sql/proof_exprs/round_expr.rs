@@ -0,0 +1,392 @@
+use super::{DecimalProofExpr, DynProofExpr, ProofExpr};
+use crate::{
+    base::{
+        database::{Column, ColumnRef, ColumnType, LiteralValue, Table},
+        if_rayon,
+        map::{IndexMap, IndexSet},
+        math::decimal::{DecimalError, Precision, MAX_SUPPORTED_PRECISION},
+        proof::{PlaceholderResult, ProofError},
+        scalar::{Scalar, ScalarExt},
+    },
+    sql::{
+        proof::{FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder},
+        proof_gadgets::{final_round_evaluate_sign, verifier_evaluate_sign},
+        AnalyzeError, AnalyzeResult,
+    },
+};
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
+use bnum::types::U256;
+use bumpalo::Bump;
+use core::{fmt::Debug, marker::PhantomData};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::Ident;
+
+/// Provable ROUND expression for decimals
+///
+/// Rounds a decimal expression to a smaller scale like SQL `ROUND`, i.e. ties are rounded away
+/// from zero (`2.5 -> 3`, `-2.5 -> -3`).
+///
+/// If `x` is the input at scale `s`, `y` the output at scale `t` and `f = 10^(s - t)`,
+/// then `y` is the unique integer with `-f/2 <= x - f * y < f/2` if `x` is non-negative and
+/// `-f/2 < x - f * y <= f/2` otherwise. The prover commits to `y` and proves both bounds on
+/// the remainder `x - f * y` with the sign gadget, using the proven sign of `x` to pick them.
+///
+/// The remainder bounds alone hold modulo the field order, where any remainder in range is
+/// reached by some `y`. So `y` is also proven to fit into the bits of the output precision.
+/// Then `x - f * y - remainder` is far smaller than the field order and, being zero modulo it,
+/// is zero as an integer, which makes `y` the correctly rounded integer.
+pub type RoundExpr = RoundingExpr<HalfAwayFromZero>;
+
+/// Provable FLOOR expression for decimals
+///
+/// Rounds towards negative infinity (`2.7 -> 2`, `-2.3 -> -3`), i.e. `0 <= x - f * y < f`.
+pub type FloorExpr = RoundingExpr<Floor>;
+
+/// Provable CEIL expression for decimals
+///
+/// Rounds towards positive infinity (`2.3 -> 3`, `-2.7 -> -2`), i.e. `-f < x - f * y <= 0`.
+pub type CeilExpr = RoundingExpr<Ceil>;
+
+/// How a [`RoundingExpr`] rounds a decimal to a smaller scale
+pub trait RoundingMode: Debug + Clone + PartialEq {
+    /// Whether rounding commutes with negation, i.e. `round(-x) = -round(x)`
+    ///
+    /// The remainder of a negative `x` is then bounded by the negated bounds,
+    /// i.e. `-upper < remainder <= lower`.
+    const IS_ODD: bool = false;
+
+    /// Rounds `value / factor` to an integer
+    fn round<S: Scalar>(value: S, factor: U256) -> S;
+
+    /// Returns `(lower, upper)` such that the remainder `x - factor * y` of a correctly
+    /// rounded `y` is exactly characterized by `-lower <= remainder < upper`
+    ///
+    /// If [`Self::IS_ODD`] is set, these are the bounds for non-negative `x` only.
+    fn remainder_bounds(factor: U256) -> (U256, U256);
+}
+
+/// Round half away from zero, see [`RoundExpr`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HalfAwayFromZero;
+
+/// Round towards negative infinity, see [`FloorExpr`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Floor;
+
+/// Round towards positive infinity, see [`CeilExpr`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ceil;
+
+impl RoundingMode for HalfAwayFromZero {
+    const IS_ODD: bool = true;
+
+    fn round<S: Scalar>(value: S, factor: U256) -> S {
+        let half = factor >> 1;
+        if value > S::MAX_SIGNED {
+            -S::from_wrapping(((-value).into_u256_wrapping() + half) / factor)
+        } else {
+            S::from_wrapping((value.into_u256_wrapping() + half) / factor)
+        }
+    }
+
+    fn remainder_bounds(factor: U256) -> (U256, U256) {
+        (factor >> 1, factor >> 1)
+    }
+}
+
+impl RoundingMode for Floor {
+    fn round<S: Scalar>(value: S, factor: U256) -> S {
+        if value > S::MAX_SIGNED {
+            -S::from_wrapping(div_ceil((-value).into_u256_wrapping(), factor))
+        } else {
+            S::from_wrapping(value.into_u256_wrapping() / factor)
+        }
+    }
+
+    fn remainder_bounds(factor: U256) -> (U256, U256) {
+        (U256::ZERO, factor)
+    }
+}
+
+impl RoundingMode for Ceil {
+    fn round<S: Scalar>(value: S, factor: U256) -> S {
+        if value > S::MAX_SIGNED {
+            -S::from_wrapping((-value).into_u256_wrapping() / factor)
+        } else {
+            S::from_wrapping(div_ceil(value.into_u256_wrapping(), factor))
+        }
+    }
+
+    fn remainder_bounds(factor: U256) -> (U256, U256) {
+        (factor - U256::ONE, U256::ONE)
+    }
+}
+
+/// Provable expression rounding a decimal to a smaller scale, see [`RoundExpr`],
+/// [`FloorExpr`] and [`CeilExpr`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoundingExpr<M> {
+    from_expr: Box<DynProofExpr>,
+    scale: i8,
+    #[serde(skip)]
+    mode: PhantomData<M>,
+}
+
+impl<M: RoundingMode> RoundingExpr<M> {
+    /// Creates a new expression rounding `from_expr` to `scale` digits after the decimal point
+    pub fn try_new(from_expr: Box<DynProofExpr>, scale: i8) -> AnalyzeResult<Self> {
+        let from_datatype = from_expr.data_type();
+        let ColumnType::Decimal75(_, from_scale) = from_datatype else {
+            return Err(AnalyzeError::InvalidDataType {
+                expr_type: from_datatype,
+            });
+        };
+        let scale_reduction = i16::from(from_scale) - i16::from(scale);
+        if scale_reduction <= 0 || scale_reduction > i16::from(MAX_SUPPORTED_PRECISION) {
+            return Err(DecimalError::InvalidScale {
+                scale: scale.to_string(),
+            }
+            .into());
+        }
+        Ok(Self {
+            from_expr,
+            scale,
+            mode: PhantomData,
+        })
+    }
+
+    /// Returns the from expression
+    pub fn get_from_expr(&self) -> &DynProofExpr {
+        &self.from_expr
+    }
+
+    /// Returns the scale the expression is rounded to
+    pub fn scale(&self) -> i8 {
+        self.scale
+    }
+
+    /// Returns the number of decimal digits removed by the rounding
+    fn scale_reduction(&self) -> u8 {
+        let from_scale = self
+            .from_expr
+            .data_type()
+            .scale()
+            .expect("Input of a round expression is a decimal");
+        u8::try_from(i16::from(from_scale) - i16::from(self.scale))
+            .expect("Scale reduction is validated on construction")
+    }
+
+    /// Returns the rounding factor `10^(from_scale - scale)`
+    fn factor(&self) -> U256 {
+        U256::TEN.pow(u32::from(self.scale_reduction()))
+    }
+
+    /// Returns the bounds on the remainder `x - factor * y` of a non-negative `x` as scalars,
+    /// together with how much they shift by for a negative `x`
+    ///
+    /// For a negative `x` of an odd rounding mode, `-upper < remainder <= lower` is the same as
+    /// `-(upper - 1) <= remainder < lower + 1`. So `lower` shifts by `upper - 1 - lower` and
+    /// `upper` by `lower + 1 - upper`.
+    fn remainder_bounds<S: Scalar>(&self) -> ((S, S), (S, S)) {
+        let (lower, upper) = M::remainder_bounds(self.factor());
+        let (lower, upper) = (S::from_wrapping(lower), S::from_wrapping(upper));
+        let shifts = if M::IS_ODD {
+            (upper - S::ONE - lower, lower + S::ONE - upper)
+        } else {
+            (S::ZERO, S::ZERO)
+        };
+        ((lower, upper), shifts)
+    }
+
+    /// Returns the number of bits of a signed integer which holds every rounded value
+    ///
+    /// The input has at most `precision - 1` integer digits at the output scale, plus a possible
+    /// carry, so a rounded value is at most `10^(precision - 1)` in absolute value.
+    fn rounded_value_bits(&self) -> u8 {
+        let max_abs = U256::TEN.pow(u32::from(DecimalProofExpr::precision(self).value() - 1));
+        u8::try_from(max_abs.bits() + 1).expect("A decimal of precision 75 has fewer than 256 bits")
+    }
+
+    /// Commits to `rounded` and proves that it is `from_values` rounded, see [`RoundExpr`]
+    pub(super) fn final_round_evaluate_rounding<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        from_values: &[S],
+        rounded: &'a [S],
+    ) {
+        builder.produce_intermediate_mle(rounded);
+        final_round_evaluate_sign(builder, alloc, rounded);
+
+        // The bounds of an odd rounding mode depend on the sign of x
+        let is_negative = if M::IS_ODD {
+            final_round_evaluate_sign(builder, alloc, alloc.alloc_slice_copy(from_values));
+            from_values
+                .iter()
+                .map(|&value| {
+                    if value > S::MAX_SIGNED {
+                        S::ONE
+                    } else {
+                        S::ZERO
+                    }
+                })
+                .collect()
+        } else {
+            vec![S::ZERO; from_values.len()]
+        };
+
+        // remainder = x - factor * y must satisfy -lower <= remainder < upper
+        let factor = S::from_wrapping(self.factor());
+        let ((lower, upper), (lower_shift, upper_shift)) = self.remainder_bounds::<S>();
+        let remainder_plus_lower = alloc.alloc_slice_fill_with(from_values.len(), |i| {
+            from_values[i] - factor * rounded[i] + lower + lower_shift * is_negative[i]
+        });
+        let remainder_minus_upper = alloc.alloc_slice_fill_with(from_values.len(), |i| {
+            from_values[i] - factor * rounded[i] - upper - upper_shift * is_negative[i]
+        });
+        final_round_evaluate_sign(builder, alloc, remainder_plus_lower);
+        final_round_evaluate_sign(builder, alloc, remainder_minus_upper);
+    }
+
+    /// Rounds each element of `from_column` and returns the resulting decimal column
+    fn round_column<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        from_column: Column<'a, S>,
+    ) -> Column<'a, S> {
+        let factor = self.factor();
+        let values = from_column.to_scalar();
+        let rounded = if_rayon!(values.par_iter(), values.iter())
+            .map(|&value| M::round(value, factor))
+            .collect::<Vec<_>>();
+        Column::Decimal75(
+            DecimalProofExpr::precision(self),
+            self.scale,
+            alloc.alloc_slice_copy(&rounded),
+        )
+    }
+}
+
+/// Returns the type of rounding an expression of type `from_type` to `scale`,
+/// or `None` if `from_type` is not a decimal with a larger scale
+pub(crate) fn try_round_type(from_type: ColumnType, scale: i8) -> Option<ColumnType> {
+    let ColumnType::Decimal75(from_precision, from_scale) = from_type else {
+        return None;
+    };
+    let scale_reduction = u8::try_from(i16::from(from_scale) - i16::from(scale))
+        .ok()
+        .filter(|reduction| (1..=MAX_SUPPORTED_PRECISION).contains(reduction))?;
+    let precision = (from_precision.value() + 1)
+        .saturating_sub(scale_reduction)
+        .clamp(1, MAX_SUPPORTED_PRECISION);
+    Some(ColumnType::Decimal75(
+        Precision::new(precision).expect("Precision is clamped to a valid range"),
+        scale,
+    ))
+}
+
+/// Returns `ceil(value / factor)` for an unsigned `value`
+fn div_ceil(value: U256, factor: U256) -> U256 {
+    (value + factor - U256::ONE) / factor
+}
+
+impl<M: RoundingMode> ProofExpr for RoundingExpr<M> {
+    /// Rounding may carry into a new integer digit, e.g. `9.99 -> 10.0`,
+    /// so the precision drops by the scale reduction but gains one digit.
+    fn data_type(&self) -> ColumnType {
+        try_round_type(self.from_expr.data_type(), self.scale)
+            .expect("Round expressions are validated on construction")
+    }
+
+    fn first_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Column<'a, S>> {
+        let from_column = self
+            .from_expr
+            .first_round_evaluate(builder, alloc, table, params)?;
+        Ok(self.round_column(alloc, from_column))
+    }
+
+    #[tracing::instrument(name = "RoundingExpr::final_round_evaluate", level = "debug", skip_all)]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table: &Table<'a, S>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Column<'a, S>> {
+        let from_column = self
+            .from_expr
+            .final_round_evaluate(builder, alloc, table, params)?;
+        let from_values = from_column.to_scalar();
+        let result = self.round_column(alloc, from_column);
+        let rounded = result
+            .as_decimal75()
+            .expect("Result of a round expression is a decimal");
+        self.final_round_evaluate_rounding(builder, alloc, &from_values, rounded);
+        Ok(result)
+    }
+
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut impl VerificationBuilder<S>,
+        accessor: &IndexMap<Ident, S>,
+        chi_eval: S,
+        params: &[LiteralValue],
+    ) -> Result<S, ProofError> {
+        let from_eval = self
+            .from_expr
+            .verifier_evaluate(builder, accessor, chi_eval, params)?;
+        let rounded_eval = builder.try_consume_final_round_mle_evaluation()?;
+        // y fits into the bits of the output precision
+        verifier_evaluate_sign(
+            builder,
+            rounded_eval,
+            chi_eval,
+            Some(self.rounded_value_bits()),
+        )?;
+
+        // The bounds of an odd rounding mode depend on the sign of x
+        let is_negative_eval = if M::IS_ODD {
+            verifier_evaluate_sign(builder, from_eval, chi_eval, None)?
+        } else {
+            S::ZERO
+        };
+
+        let factor = S::from_wrapping(self.factor());
+        let ((lower, upper), (lower_shift, upper_shift)) = self.remainder_bounds::<S>();
+        let remainder_eval = from_eval - factor * rounded_eval;
+        // remainder + lower >= 0
+        let lower_sign_eval = verifier_evaluate_sign(
+            builder,
+            remainder_eval + lower * chi_eval + lower_shift * is_negative_eval,
+            chi_eval,
+            None,
+        )?;
+        // remainder - upper < 0
+        let upper_sign_eval = verifier_evaluate_sign(
+            builder,
+            remainder_eval - upper * chi_eval - upper_shift * is_negative_eval,
+            chi_eval,
+            None,
+        )?;
+        if lower_sign_eval != S::ZERO || upper_sign_eval != chi_eval {
+            return Err(ProofError::VerificationError {
+                error: "rounding remainder is out of range",
+            });
+        }
+        Ok(rounded_eval)
+    }
+
+    fn get_column_references(&self, columns: &mut IndexSet<ColumnRef>) {
+        self.from_expr.get_column_references(columns);
+    }
+}
+
+impl<M: RoundingMode> DecimalProofExpr for RoundingExpr<M> {}
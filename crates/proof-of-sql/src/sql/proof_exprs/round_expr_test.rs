@@ -0,0 +1,437 @@
+use super::{
+    round_expr::{Ceil, Floor, HalfAwayFromZero, RoundingExpr, RoundingMode},
+    DynProofExpr, ProofExpr,
+};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::{bigint, decimal75, owned_table},
+            Column, ColumnField, ColumnRef, ColumnType, LiteralValue, OwnedTableTestAccessor,
+            Table, TableEvaluation, TableOptions, TableRef,
+        },
+        map::{IndexMap, IndexSet},
+        math::decimal::{DecimalError, Precision},
+        proof::{PlaceholderResult, ProofError},
+        scalar::{Scalar, ScalarExt},
+    },
+    sql::{
+        proof::{
+            exercise_verification, FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+            QueryError, VerifiableQueryResult, VerificationBuilder,
+        },
+        proof_exprs::{
+            test_utility::{aliased_plan, ceil, column, const_bool, floor, round},
+            LiteralExpr,
+        },
+        proof_plans::{
+            test_utility::{column_field, filter, table_exec},
+            DynProofPlan,
+        },
+        AnalyzeError,
+    },
+};
+use blitzar::proof::InnerProductProof;
+use bnum::types::U256;
+use bumpalo::Bump;
+use core::marker::PhantomData;
+use num_traits::Inv;
+use serde::Serialize;
+use sqlparser::ast::Ident;
+
+/// Projects `expr` over `input` like a `ProjectionExec`, but forges the rounded values by
+/// rounding with `F` instead of `M`.
+///
+/// If `shift_first_row` is set, the rounded value of the first row is also forged by adding
+/// `1 / factor` to it. This shifts the remainder `x - factor * y` of that row by one, keeping it
+/// in range, so only the bound on `y` itself can catch the forgery.
+#[derive(Debug, Serialize)]
+#[serde(bound = "")]
+struct DishonestRoundingExec<M, F> {
+    expr: RoundingExpr<M>,
+    #[serde(skip)]
+    factor: U256,
+    #[serde(skip)]
+    shift_first_row: bool,
+    input: DynProofPlan,
+    #[serde(skip)]
+    forged_mode: PhantomData<F>,
+}
+
+impl<M: RoundingMode, F: RoundingMode> DishonestRoundingExec<M, F> {
+    fn forged_rounding<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        from_column: Column<'a, S>,
+    ) -> &'a [S] {
+        let from_values = from_column.to_scalar();
+        let factor_inv = S::from_wrapping(self.factor).inv().unwrap();
+        alloc.alloc_slice_fill_with(from_values.len(), |i| {
+            let rounded = F::round(from_values[i], self.factor);
+            if i == 0 && self.shift_first_row {
+                rounded + factor_inv
+            } else {
+                rounded
+            }
+        })
+    }
+
+    fn result_table<'a, S: Scalar>(&self, rounded: &'a [S]) -> Table<'a, S> {
+        let ColumnType::Decimal75(precision, scale) = self.expr.data_type() else {
+            panic!("Result of a round expression is a decimal");
+        };
+        Table::try_from_iter_with_options(
+            [(
+                Ident::new("r"),
+                Column::Decimal75(precision, scale, rounded),
+            )],
+            TableOptions::new(Some(rounded.len())),
+        )
+        .unwrap()
+    }
+}
+
+impl<M: RoundingMode + Send + Sync, F: RoundingMode + Send + Sync> ProofPlan
+    for DishonestRoundingExec<M, F>
+{
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut impl VerificationBuilder<S>,
+        accessor: &IndexMap<TableRef, IndexMap<Ident, S>>,
+        chi_eval_map: &IndexMap<TableRef, (S, usize)>,
+        params: &[LiteralValue],
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        let input_eval = self
+            .input
+            .verifier_evaluate(builder, accessor, chi_eval_map, params)?;
+        let input_accessor: IndexMap<_, _> = self
+            .input
+            .get_column_result_fields()
+            .iter()
+            .map(ColumnField::name)
+            .zip(input_eval.column_evals().iter().copied())
+            .collect();
+        let rounded_eval =
+            self.expr
+                .verifier_evaluate(builder, &input_accessor, input_eval.chi_eval(), params)?;
+        Ok(TableEvaluation::new(vec![rounded_eval], input_eval.chi()))
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        vec![ColumnField::new(Ident::new("r"), self.expr.data_type())]
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        self.input.get_column_references()
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        self.input.get_table_references()
+    }
+}
+
+impl<M: RoundingMode, F: RoundingMode> ProverEvaluate for DishonestRoundingExec<M, F> {
+    fn first_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        let input = self
+            .input
+            .first_round_evaluate(builder, alloc, table_map, params)?;
+        let from_column = self
+            .expr
+            .get_from_expr()
+            .first_round_evaluate(builder, alloc, &input, params)?;
+        Ok(self.result_table(self.forged_rounding(alloc, from_column)))
+    }
+
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        let input = self
+            .input
+            .final_round_evaluate(builder, alloc, table_map, params)?;
+        let from_column = self
+            .expr
+            .get_from_expr()
+            .final_round_evaluate(builder, alloc, &input, params)?;
+        let rounded = self.forged_rounding(alloc, from_column);
+        self.expr
+            .final_round_evaluate_rounding(builder, alloc, &from_column.to_scalar(), rounded);
+        Ok(self.result_table(rounded))
+    }
+}
+
+/// Every remainder of the first row, `1.234 - y`, stays in range after it is shifted by one
+fn we_cannot_verify_forged_rounded_values<M: RoundingMode + Send + Sync>() {
+    let data = owned_table([decimal75("a", 6, 3, [1234, -1235, 1500])]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    let plan = DishonestRoundingExec::<M, M> {
+        expr: RoundingExpr::try_new(Box::new(column(&t, "a", &accessor)), 0).unwrap(),
+        factor: U256::TEN.pow(3),
+        shift_first_row: true,
+        input: table_exec(
+            t.clone(),
+            vec![column_field(
+                "a",
+                ColumnType::Decimal75(Precision::new(6).unwrap(), 3),
+            )],
+        ),
+        forged_mode: PhantomData,
+    };
+    let verifiable_res =
+        VerifiableQueryResult::<InnerProductProof>::new(&plan, &accessor, &(), &[]).unwrap();
+    assert!(matches!(
+        verifiable_res.verify(&plan, &accessor, &(), &[]),
+        Err(QueryError::ProofError { .. })
+    ));
+}
+
+#[test]
+fn we_can_prove_round_expr_at_several_scales() {
+    let data = owned_table([decimal75(
+        "a",
+        6,
+        3,
+        [1234, 1235, -1235, 1499, -1500, 9999, 0, -1],
+    )]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(round(column(&t, "a", &accessor), 2), "a_2"),
+            aliased_plan(round(column(&t, "a", &accessor), 0), "a_0"),
+            aliased_plan(round(column(&t, "a", &accessor), -1), "a_neg_1"),
+        ],
+        table_exec(
+            t.clone(),
+            vec![column_field(
+                "a",
+                ColumnType::Decimal75(Precision::new(6).unwrap(), 3),
+            )],
+        ),
+        DynProofExpr::Literal(LiteralExpr::new(LiteralValue::Boolean(true))),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([
+        // 9.999 rounds up into a new integer digit
+        // Ties are rounded away from zero
+        decimal75("a_2", 6, 2, [123, 124, -124, 150, -150, 1000, 0, 0]),
+        decimal75("a_0", 4, 0, [1, 1, -1, 1, -2, 10, 0, 0]),
+        decimal75("a_neg_1", 3, -1, [0, 0, 0, 0, 0, 1, 0, 0]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_floor_and_ceil_exprs_at_several_scales() {
+    let data = owned_table([decimal75(
+        "a",
+        6,
+        3,
+        [1234, 1235, -1235, 1499, -1500, 9999, 0, -1],
+    )]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    let ast = filter(
+        vec![
+            aliased_plan(floor(column(&t, "a", &accessor), 2), "floor_2"),
+            aliased_plan(floor(column(&t, "a", &accessor), 0), "floor_0"),
+            aliased_plan(floor(column(&t, "a", &accessor), -1), "floor_neg_1"),
+            aliased_plan(ceil(column(&t, "a", &accessor), 2), "ceil_2"),
+            aliased_plan(ceil(column(&t, "a", &accessor), 0), "ceil_0"),
+            aliased_plan(ceil(column(&t, "a", &accessor), -1), "ceil_neg_1"),
+        ],
+        table_exec(
+            t.clone(),
+            vec![column_field(
+                "a",
+                ColumnType::Decimal75(Precision::new(6).unwrap(), 3),
+            )],
+        ),
+        DynProofExpr::Literal(LiteralExpr::new(LiteralValue::Boolean(true))),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([
+        decimal75("floor_2", 6, 2, [123, 123, -124, 149, -150, 999, 0, -1]),
+        decimal75("floor_0", 4, 0, [1, 1, -2, 1, -2, 9, 0, -1]),
+        decimal75("floor_neg_1", 3, -1, [0, 0, -1, 0, -1, 0, 0, -1]),
+        // 9.999 rounds up into a new integer digit
+        decimal75("ceil_2", 6, 2, [124, 124, -123, 150, -150, 1000, 0, 0]),
+        decimal75("ceil_0", 4, 0, [2, 2, -1, 2, -1, 10, 0, 0]),
+        decimal75("ceil_neg_1", 3, -1, [1, 1, 0, 1, 0, 1, 0, 0]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_round_expr_in_a_filter_predicate() {
+    let data = owned_table([
+        decimal75("a", 5, 2, [149, 150, 250, -250, 351]),
+        bigint("b", [1, 2, 3, 4, 5]),
+    ]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    let ast = filter(
+        vec![aliased_plan(column(&t, "b", &accessor), "b")],
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::Decimal75(Precision::new(5).unwrap(), 2)),
+                column_field("b", ColumnType::BigInt),
+            ],
+        ),
+        DynProofExpr::try_new_equals(
+            round(column(&t, "a", &accessor), 0),
+            DynProofExpr::new_literal(LiteralValue::Decimal75(
+                Precision::new(4).unwrap(),
+                0,
+                2.into(),
+            )),
+        )
+        .unwrap(),
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &t);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([bigint("b", [2])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_compute_the_data_type_of_a_round_expr() {
+    let t = TableRef::new("sxt", "t");
+    let data = owned_table([decimal75("a", 75, 10, [0]), decimal75("b", 3, 2, [0])]);
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    assert_eq!(
+        round(column(&t, "a", &accessor), 9).data_type(),
+        ColumnType::Decimal75(Precision::new(75).unwrap(), 9)
+    );
+    assert_eq!(
+        round(column(&t, "a", &accessor), -60).data_type(),
+        ColumnType::Decimal75(Precision::new(6).unwrap(), -60)
+    );
+    assert_eq!(
+        round(column(&t, "b", &accessor), -2).data_type(),
+        ColumnType::Decimal75(Precision::new(1).unwrap(), -2)
+    );
+}
+
+#[test]
+fn we_cannot_round_non_decimal_expressions_or_to_a_larger_scale() {
+    let t = TableRef::new("sxt", "t");
+    let data = owned_table([decimal75("a", 6, 3, [0]), bigint("b", [0])]);
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    assert!(matches!(
+        DynProofExpr::try_new_round(column(&t, "b", &accessor), 0),
+        Err(AnalyzeError::InvalidDataType {
+            expr_type: ColumnType::BigInt
+        })
+    ));
+    assert!(matches!(
+        DynProofExpr::try_new_round(const_bool(true), 0),
+        Err(AnalyzeError::InvalidDataType {
+            expr_type: ColumnType::Boolean
+        })
+    ));
+    for scale in [3, 4, -73] {
+        assert!(matches!(
+            DynProofExpr::try_new_round(column(&t, "a", &accessor), scale),
+            Err(AnalyzeError::DecimalConversionError {
+                source: DecimalError::InvalidScale { .. }
+            })
+        ));
+    }
+}
+
+#[test]
+fn we_cannot_floor_or_ceil_non_decimal_expressions_or_to_a_larger_scale() {
+    let t = TableRef::new("sxt", "t");
+    let data = owned_table([decimal75("a", 6, 3, [0]), bigint("b", [0])]);
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    for try_new in [DynProofExpr::try_new_floor, DynProofExpr::try_new_ceil] {
+        assert!(matches!(
+            try_new(column(&t, "b", &accessor), 0),
+            Err(AnalyzeError::InvalidDataType {
+                expr_type: ColumnType::BigInt
+            })
+        ));
+        assert!(matches!(
+            try_new(column(&t, "a", &accessor), 3),
+            Err(AnalyzeError::DecimalConversionError {
+                source: DecimalError::InvalidScale { .. }
+            })
+        ));
+    }
+}
+
+#[test]
+fn we_cannot_verify_a_forged_round_expr_result() {
+    we_cannot_verify_forged_rounded_values::<HalfAwayFromZero>();
+}
+
+/// Rounding `-1.5` towards positive infinity keeps the remainder within `[-0.5, 0.5]`,
+/// so only the sign of the input decides that the tie has to be rounded away from zero
+#[test]
+fn we_cannot_verify_a_round_expr_result_with_negative_ties_rounded_up() {
+    let data = owned_table([decimal75("a", 6, 3, [-1500, 1500, -1499])]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    let plan = DishonestRoundingExec::<HalfAwayFromZero, Ceil> {
+        expr: RoundingExpr::try_new(Box::new(column(&t, "a", &accessor)), 0).unwrap(),
+        factor: U256::TEN.pow(3),
+        shift_first_row: false,
+        input: table_exec(
+            t.clone(),
+            vec![column_field(
+                "a",
+                ColumnType::Decimal75(Precision::new(6).unwrap(), 3),
+            )],
+        ),
+        forged_mode: PhantomData,
+    };
+    let verifiable_res =
+        VerifiableQueryResult::<InnerProductProof>::new(&plan, &accessor, &(), &[]).unwrap();
+    assert!(matches!(
+        verifiable_res.verify(&plan, &accessor, &(), &[]),
+        Err(QueryError::ProofError { .. })
+    ));
+}
+
+#[test]
+fn we_cannot_verify_a_forged_floor_expr_result() {
+    we_cannot_verify_forged_rounded_values::<Floor>();
+}
+
+#[test]
+fn we_cannot_verify_a_forged_ceil_expr_result() {
+    we_cannot_verify_forged_rounded_values::<Ceil>();
+}
@@ -10,7 +10,7 @@ use crate::{
         scalar::Scalar,
     },
     sql::{
-        proof::{FinalRoundBuilder, VerificationBuilder},
+        proof::{FinalRoundBuilder, FirstRoundBuilder, VerificationBuilder},
         AnalyzeError, AnalyzeResult,
     },
 };
@@ -65,11 +65,14 @@ impl ProofExpr for ScalingCastExpr {
 
     fn first_round_evaluate<'a, S: Scalar>(
         &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
         alloc: &'a Bump,
         table: &Table<'a, S>,
         params: &[LiteralValue],
     ) -> PlaceholderResult<Column<'a, S>> {
-        let uncasted_result = self.from_expr.first_round_evaluate(alloc, table, params)?;
+        let uncasted_result = self
+            .from_expr
+            .first_round_evaluate(builder, alloc, table, params)?;
         Ok(cast_column_with_scaling(
             alloc,
             uncasted_result,
@@ -106,6 +106,26 @@ pub fn scaling_cast(left: DynProofExpr, right: ColumnType) -> DynProofExpr {
     DynProofExpr::try_new_scaling_cast(left, right).unwrap()
 }
 
+pub fn round(expr: DynProofExpr, scale: i8) -> DynProofExpr {
+    DynProofExpr::try_new_round(expr, scale).unwrap()
+}
+
+pub fn abs(expr: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_abs(expr).unwrap()
+}
+
+pub fn lag(expr: DynProofExpr) -> DynProofExpr {
+    DynProofExpr::try_new_lag(expr).unwrap()
+}
+
+pub fn floor(expr: DynProofExpr, scale: i8) -> DynProofExpr {
+    DynProofExpr::try_new_floor(expr, scale).unwrap()
+}
+
+pub fn ceil(expr: DynProofExpr, scale: i8) -> DynProofExpr {
+    DynProofExpr::try_new_ceil(expr, scale).unwrap()
+}
+
 pub fn const_bool(val: bool) -> DynProofExpr {
     DynProofExpr::new_literal(LiteralValue::Boolean(val))
 }
@@ -6,7 +6,6 @@ pub(crate) use filter_base::{final_round_evaluate_filter, verify_evaluate_filter
 pub(crate) mod fold_log_expr;
 mod membership_check;
 mod monotonic;
-#[cfg_attr(not(test), expect(dead_code))]
 mod permutation_check;
 mod shift;
 pub(crate) use membership_check::{
@@ -15,18 +14,18 @@ pub(crate) use membership_check::{
 };
 #[cfg(test)]
 mod membership_check_test;
-#[expect(unused_imports)]
-use permutation_check::{final_round_evaluate_permutation_check, verify_permutation_check};
+pub(crate) use permutation_check::{
+    final_round_evaluate_permutation_check, verify_permutation_check,
+};
 #[cfg(test)]
 mod permutation_check_test;
-use shift::{final_round_evaluate_shift, first_round_evaluate_shift, verify_shift};
+pub(crate) use shift::{final_round_evaluate_shift, first_round_evaluate_shift, verify_shift};
 mod sign_expr;
 pub(crate) use sign_expr::{
     final_round_evaluate_sign, first_round_evaluate_sign, verifier_evaluate_sign,
 };
 #[cfg(feature = "blitzar")]
-#[cfg_attr(not(test), expect(dead_code))]
-mod range_check;
+pub mod range_check;
 #[cfg(all(test, feature = "blitzar"))]
 mod range_check_test;
 #[cfg(all(test, feature = "blitzar"))]
@@ -17,19 +17,40 @@
 //!   and the modular multiplicative inverse of this sum is computed, forming a new matrix of logarithmic derivatives.
 //!   This matrix is key to constructing range constraints.
 //!
+//! ## Bounded Ranges:
+//! [`prove_range`] and [`verify_range`] prove that every value of a column lies within `[min, max]`.
+//! They range check both `column - min` and `max - column` to `[0, 2^248)`. The two sum to
+//! `max - min`, which is far below the order of the field, so neither of them can have wrapped around.
+//! For decimals and timestamps, the bounds are in units of the scale and the time unit respectively.
+//! Each bounded range check uses two post-result challenges and 62 intermediate word columns.
+//!
 //! ## Optimization Opportunities:
 //! * Batch Inversion: Inversions of large vectors are computationally expensive
 //! * Parallelization: Single-threaded execution of these operations is a performance bottleneck
 use crate::{
-    base::{proof::ProofSizeMismatch, scalar::Scalar, slice_ops},
+    base::{
+        commitment::CommitmentEvaluationProof,
+        database::{
+            ColumnField, ColumnRef, ColumnType, CommitmentAccessor, DataAccessor, LiteralValue,
+            Table, TableEvaluation, TableOptions, TableRef,
+        },
+        map::{indexset, IndexMap, IndexSet},
+        proof::{PlaceholderResult, ProofError, ProofSizeMismatch},
+        scalar::Scalar,
+        slice_ops,
+    },
     sql::proof::{
-        FinalRoundBuilder, FirstRoundBuilder, SumcheckSubpolynomialType, VerificationBuilder,
+        FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate, QueryError,
+        SumcheckSubpolynomialType, VerifiableQueryResult, VerificationBuilder,
     },
 };
 use alloc::{boxed::Box, vec, vec::Vec};
 use bumpalo::Bump;
 use bytemuck::cast_slice;
 use core::iter::repeat_with;
+use serde::Serialize;
+use snafu::Snafu;
+use sqlparser::ast::Ident;
 use tracing::{span, Level};
 
 #[tracing::instrument(name = "range check first round evaluate", level = "debug", skip_all)]
@@ -229,14 +250,14 @@ fn get_logarithmic_derivative_from_rho_256_logarithmic_derivative<'a, S: Scalar>
 
 /// Verify that the prover claim is correct.
 ///
-/// # Panics
+/// # Errors
 ///
-/// if a column contains values outside of the selected range.
+/// Returns [`ProofError::VerificationError`] if a column contains values outside of the selected range.
 pub(crate) fn verifier_evaluate_range_check<S: Scalar>(
     builder: &mut impl VerificationBuilder<S>,
     input_column_eval: S,
     chi_n_eval: S,
-) -> Result<(), ProofSizeMismatch> {
+) -> Result<(), ProofError> {
     // Retrieve the post-result challenge α
     let alpha = builder.try_consume_post_result_challenge()?;
     let chi_256_eval = builder.try_consume_chi_evaluation()?.0;
@@ -279,10 +300,11 @@ pub(crate) fn verifier_evaluate_range_check<S: Scalar>(
 
     // Ensure the sum of the scalars (interpreted in base 256) matches
     // the claimed input_column_eval. If not, the column is out of range.
-    assert_eq!(
-        word_eval_weighted_sum, input_column_eval,
-        "Range check failed, column contains values outside of the selected range"
-    );
+    if word_eval_weighted_sum != input_column_eval {
+        return Err(ProofError::VerificationError {
+            error: "Range check failed, column contains values outside of the selected range",
+        });
+    }
 
     // Retrieve eval of (0..256)
     let rho_256_eval = builder
@@ -316,6 +338,257 @@ pub(crate) fn verifier_evaluate_range_check<S: Scalar>(
     Ok(())
 }
 
+/// Returns `column_data - min` and `max - column_data`, which are range checked to prove that
+/// `column_data` lies within `[min, max]`
+fn bounded_range_check_columns<'a, S: Scalar>(
+    alloc: &'a Bump,
+    column_data: &[impl Copy + Into<S>],
+    min: S,
+    max: S,
+) -> (&'a [S], &'a [S]) {
+    let above_min = alloc.alloc_slice_fill_iter(
+        column_data
+            .iter()
+            .map(|&value| Into::<S>::into(value) - min),
+    );
+    let below_max = alloc.alloc_slice_fill_iter(
+        column_data
+            .iter()
+            .map(|&value| max - Into::<S>::into(value)),
+    );
+    (above_min, below_max)
+}
+
+/// First round of proving that every value of `column_data` lies within `[min, max]`
+///
+/// The caller must request two post-result challenges.
+pub(crate) fn first_round_evaluate_bounded_range_check<'a, S: Scalar + 'a>(
+    builder: &mut FirstRoundBuilder<'a, S>,
+    column_data: &[impl Copy + Into<S>],
+    min: S,
+    max: S,
+    alloc: &'a Bump,
+) {
+    let (above_min, below_max) = bounded_range_check_columns(alloc, column_data, min, max);
+    first_round_evaluate_range_check(builder, above_min, alloc);
+    first_round_evaluate_range_check(builder, below_max, alloc);
+}
+
+/// Final round of proving that every value of `column_data` lies within `[min, max]`
+pub(crate) fn final_round_evaluate_bounded_range_check<'a, S: Scalar + 'a>(
+    builder: &mut FinalRoundBuilder<'a, S>,
+    column_data: &[impl Copy + Into<S>],
+    min: S,
+    max: S,
+    alloc: &'a Bump,
+) {
+    let (above_min, below_max) = bounded_range_check_columns(alloc, column_data, min, max);
+    final_round_evaluate_range_check(builder, above_min, alloc);
+    final_round_evaluate_range_check(builder, below_max, alloc);
+}
+
+/// Verify that every value of the column evaluating to `column_eval` lies within `[min, max]`
+///
+/// This is only sound if `max - min` is less than `2^248`.
+///
+/// # Errors
+///
+/// Returns [`ProofError::VerificationError`] if the column contains values outside of `[min, max]`.
+pub(crate) fn verifier_evaluate_bounded_range_check<S: Scalar>(
+    builder: &mut impl VerificationBuilder<S>,
+    column_eval: S,
+    chi_n_eval: S,
+    min: S,
+    max: S,
+) -> Result<(), ProofError> {
+    verifier_evaluate_range_check(builder, column_eval - min * chi_n_eval, chi_n_eval)?;
+    verifier_evaluate_range_check(builder, max * chi_n_eval - column_eval, chi_n_eval)
+}
+
+/// Errors that can occur when proving or verifying the range of a column
+#[derive(Snafu, Debug)]
+pub enum RangeCheckError {
+    /// The minimum of the range is greater than its maximum
+    #[snafu(display("invalid range: the minimum {min} is greater than the maximum {max}"))]
+    InvalidBounds {
+        /// The minimum of the range
+        min: i128,
+        /// The maximum of the range
+        max: i128,
+    },
+    /// The column is not of an integer, decimal or timestamp type
+    #[snafu(display("range checks are not supported for columns of type {column_type}"))]
+    UnsupportedColumnType {
+        /// The type of the column
+        column_type: ColumnType,
+    },
+    /// The proof could not be created or failed to verify, e.g. because the column has values
+    /// outside of the range
+    #[snafu(transparent)]
+    Query {
+        /// The underlying source error
+        source: QueryError,
+    },
+}
+
+/// Plan proving that every value of `column` lies within `[min, max]`
+///
+/// The plan has no result columns. The bounds are part of the plan, so they are bound to the proof.
+#[derive(Debug, Serialize)]
+struct BoundedRangeCheckPlan {
+    column: ColumnRef,
+    min: i128,
+    max: i128,
+}
+
+impl BoundedRangeCheckPlan {
+    fn try_new(column: ColumnRef, min: i128, max: i128) -> Result<Self, RangeCheckError> {
+        if min > max {
+            return Err(RangeCheckError::InvalidBounds { min, max });
+        }
+        match column.column_type() {
+            ColumnType::Uint8
+            | ColumnType::TinyInt
+            | ColumnType::SmallInt
+            | ColumnType::Int
+            | ColumnType::BigInt
+            | ColumnType::Int128
+            | ColumnType::Decimal75(_, _)
+            | ColumnType::TimestampTZ(_, _) => Ok(Self { column, min, max }),
+            column_type => Err(RangeCheckError::UnsupportedColumnType {
+                column_type: *column_type,
+            }),
+        }
+    }
+
+    /// Returns the values of the column along with an empty result table of the same length
+    fn column_values<'a, S: Scalar>(
+        &self,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+    ) -> (Vec<S>, Table<'a, S>) {
+        let table = &table_map[&self.column.table_ref()];
+        let values = table.inner_table()[&self.column.column_id()].to_scalar();
+        let result =
+            Table::try_new_with_options(IndexMap::default(), TableOptions::new(Some(values.len())))
+                .expect("A table without columns is always valid");
+        (values, result)
+    }
+}
+
+impl ProverEvaluate for BoundedRangeCheckPlan {
+    fn first_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        _params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        builder.request_post_result_challenges(2);
+        let (values, result) = self.column_values(table_map);
+        first_round_evaluate_bounded_range_check(
+            builder,
+            &values,
+            S::from(self.min),
+            S::from(self.max),
+            alloc,
+        );
+        Ok(result)
+    }
+
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        _params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        let (values, result) = self.column_values(table_map);
+        final_round_evaluate_bounded_range_check(
+            builder,
+            &values,
+            S::from(self.min),
+            S::from(self.max),
+            alloc,
+        );
+        Ok(result)
+    }
+}
+
+impl ProofPlan for BoundedRangeCheckPlan {
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut impl VerificationBuilder<S>,
+        accessor: &IndexMap<TableRef, IndexMap<Ident, S>>,
+        chi_eval_map: &IndexMap<TableRef, (S, usize)>,
+        _params: &[LiteralValue],
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        let table_ref = self.column.table_ref();
+        let column_eval = accessor[&table_ref][&self.column.column_id()];
+        let chi_eval = chi_eval_map[&table_ref];
+        verifier_evaluate_bounded_range_check(
+            builder,
+            column_eval,
+            chi_eval.0,
+            S::from(self.min),
+            S::from(self.max),
+        )?;
+        Ok(TableEvaluation::new(Vec::new(), chi_eval))
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        Vec::new()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        indexset! {self.column.clone()}
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        indexset! {self.column.table_ref()}
+    }
+}
+
+/// Prove that every value of `column` lies within `[min, max]`
+///
+/// The column must be of an integer, decimal or timestamp type. The bounds are compared to the
+/// raw values, so for decimals they are in units of the scale and for timestamps in the time unit.
+/// The proof is verified with [`verify_range`] using the same column and bounds.
+///
+/// # Errors
+///
+/// Returns [`RangeCheckError::InvalidBounds`] if `min > max` and
+/// [`RangeCheckError::UnsupportedColumnType`] if the column type can't be range checked.
+pub fn prove_range<CP: CommitmentEvaluationProof>(
+    column: ColumnRef,
+    min: i128,
+    max: i128,
+    accessor: &impl DataAccessor<CP::Scalar>,
+    setup: &CP::ProverPublicSetup<'_>,
+) -> Result<VerifiableQueryResult<CP>, RangeCheckError> {
+    let plan = BoundedRangeCheckPlan::try_new(column, min, max)?;
+    VerifiableQueryResult::new(&plan, accessor, setup, &[])
+        .map_err(|error| QueryError::from(ProofError::from(error)).into())
+}
+
+/// Verify a proof created by [`prove_range`] that every value of `column` lies within `[min, max]`
+///
+/// # Errors
+///
+/// Returns [`RangeCheckError::Query`] if the proof is invalid, in particular if the column has
+/// values outside of `[min, max]`, and the same errors as [`prove_range`] for invalid arguments.
+pub fn verify_range<CP: CommitmentEvaluationProof>(
+    proof: VerifiableQueryResult<CP>,
+    column: ColumnRef,
+    min: i128,
+    max: i128,
+    accessor: &impl CommitmentAccessor<CP::Commitment>,
+    setup: &CP::VerifierPublicSetup<'_>,
+) -> Result<(), RangeCheckError> {
+    let plan = BoundedRangeCheckPlan::try_new(column, min, max)?;
+    proof.verify(&plan, accessor, setup, &[])?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
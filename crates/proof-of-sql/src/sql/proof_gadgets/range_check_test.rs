@@ -1,6 +1,6 @@
 use super::range_check::{
-    final_round_evaluate_range_check, first_round_evaluate_range_check,
-    verifier_evaluate_range_check,
+    final_round_evaluate_range_check, first_round_evaluate_range_check, prove_range,
+    verifier_evaluate_range_check, verify_range, RangeCheckError,
 };
 use crate::{
     base::{
@@ -188,11 +188,14 @@ mod tests {
             posql_time::{PoSQLTimeUnit, PoSQLTimeZone},
         },
         proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
-        sql::proof::VerifiableQueryResult,
+        sql::proof::{QueryError, VerifiableQueryResult},
     };
     use blitzar::proof::InnerProductProof;
     use num_bigint::BigUint;
 
+    const OUT_OF_RANGE_ERROR: &str =
+        "Range check failed, column contains values outside of the selected range";
+
     fn check_range(
         table_name: TableRef,
         col_name: &str,
@@ -264,9 +267,6 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "Range check failed, column contains values outside of the selected range"
-    )]
     fn we_cannot_successfully_verify_invalid_range() {
         let data = owned_table([scalar("a", -2..254)]);
         let t = TableRef::new("sxt", "t");
@@ -277,7 +277,14 @@ mod tests {
         };
         let verifiable_res =
             VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &(), &[]).unwrap();
-        let _ = verifiable_res.verify(&ast, &accessor, &(), &[]);
+        assert!(matches!(
+            verifiable_res.verify(&ast, &accessor, &(), &[]),
+            Err(QueryError::ProofError {
+                source: ProofError::VerificationError {
+                    error: OUT_OF_RANGE_ERROR
+                }
+            })
+        ));
     }
 
     #[test]
@@ -358,9 +365,6 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(
-        expected = "Range check failed, column contains values outside of the selected range"
-    )]
     fn we_cannot_prove_a_range_check_equal_to_range_boundary() {
         // 2^248
         let big_uint = BigUint::from(2u8).pow(248);
@@ -387,6 +391,99 @@ mod tests {
         };
         let verifiable_res =
             VerifiableQueryResult::<InnerProductProof>::new(&ast, &accessor, &(), &[]).unwrap();
-        verifiable_res.verify(&ast, &accessor, &(), &[]).unwrap();
+        assert!(matches!(
+            verifiable_res.verify(&ast, &accessor, &(), &[]),
+            Err(QueryError::ProofError {
+                source: ProofError::VerificationError {
+                    error: OUT_OF_RANGE_ERROR
+                }
+            })
+        ));
+    }
+
+    #[test]
+    fn we_can_prove_and_verify_that_columns_are_within_a_range() {
+        let data = owned_table([
+            bigint("amount", [0_i64, 1, 50, 99, 100]),
+            decimal75("price", 10, 2, [-150_i64, 0, 1999, 1999, 5]),
+            uint8("u", [7_u8; 5]),
+        ]);
+        let t = TableRef::new("sxt", "t");
+        let accessor =
+            OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+        let check = |column: &str, column_type: ColumnType, min: i128, max: i128| {
+            let column = ColumnRef::new(t.clone(), column.into(), column_type);
+            let proof = prove_range(column.clone(), min, max, &accessor, &()).unwrap();
+            verify_range(proof, column, min, max, &accessor, &()).unwrap();
+        };
+        check("amount", ColumnType::BigInt, 0, 100);
+        check("amount", ColumnType::BigInt, -5, i128::MAX);
+        check(
+            "price",
+            ColumnType::Decimal75(Precision::new(10).unwrap(), 2),
+            -150,
+            1999,
+        );
+        check("u", ColumnType::Uint8, 7, 7);
+    }
+
+    #[test]
+    fn we_cannot_verify_that_columns_with_values_out_of_range_are_within_the_range() {
+        let data = owned_table([
+            bigint("amount", [0_i64, 1, 50, 101]),
+            bigint("b", [-1_i64, 0, 1, 2]),
+        ]);
+        let t = TableRef::new("sxt", "t");
+        let accessor =
+            OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+        let amount = ColumnRef::new(t.clone(), "amount".into(), ColumnType::BigInt);
+
+        // Above the maximum
+        let proof = prove_range(amount.clone(), 0, 100, &accessor, &()).unwrap();
+        assert!(matches!(
+            verify_range(proof, amount.clone(), 0, 100, &accessor, &()),
+            Err(RangeCheckError::Query {
+                source: QueryError::ProofError { .. }
+            })
+        ));
+
+        // Below the minimum
+        let b = ColumnRef::new(t, "b".into(), ColumnType::BigInt);
+        let proof = prove_range(b.clone(), 0, 100, &accessor, &()).unwrap();
+        assert!(verify_range(proof, b, 0, 100, &accessor, &()).is_err());
+
+        // A proof can't be verified against a narrower range than it was created for
+        let proof = prove_range(amount.clone(), 0, 200, &accessor, &()).unwrap();
+        assert!(verify_range(proof, amount, 0, 150, &accessor, &()).is_err());
+    }
+
+    #[test]
+    fn we_cannot_prove_ranges_with_invalid_bounds_or_column_types() {
+        let data = owned_table([bigint("a", [1_i64]), varchar("b", ["x"])]);
+        let t = TableRef::new("sxt", "t");
+        let accessor =
+            OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+        assert!(matches!(
+            prove_range(
+                ColumnRef::new(t.clone(), "a".into(), ColumnType::BigInt),
+                2,
+                1,
+                &accessor,
+                &()
+            ),
+            Err(RangeCheckError::InvalidBounds { min: 2, max: 1 })
+        ));
+        assert!(matches!(
+            prove_range(
+                ColumnRef::new(t, "b".into(), ColumnType::VarChar),
+                0,
+                1,
+                &accessor,
+                &()
+            ),
+            Err(RangeCheckError::UnsupportedColumnType {
+                column_type: ColumnType::VarChar
+            })
+        ));
     }
 }
@@ -3,8 +3,8 @@ use crate::{
     base::{
         database::{
             group_by_util::{aggregate_columns, AggregatedColumns},
-            Column, ColumnField, ColumnRef, ColumnType, LiteralValue, Table, TableEvaluation,
-            TableRef,
+            try_sum_column_type, Column, ColumnField, ColumnRef, ColumnType, LiteralValue, Table,
+            TableEvaluation, TableRef,
         },
         map::{IndexMap, IndexSet},
         proof::{PlaceholderResult, ProofError},
@@ -244,7 +244,11 @@ impl ProofPlan for AggregateExec {
                 ColumnField::new(aliased_expr.alias.clone(), aliased_expr.expr.data_type())
             })
             .chain(self.sum_expr.iter().map(|aliased_expr| {
-                ColumnField::new(aliased_expr.alias.clone(), aliased_expr.expr.data_type())
+                let data_type = aliased_expr.expr.data_type();
+                ColumnField::new(
+                    aliased_expr.alias.clone(),
+                    try_sum_column_type(data_type).unwrap_or(data_type),
+                )
             }))
             .chain(iter::once(ColumnField::new(
                 self.count_alias.clone(),
@@ -290,14 +294,14 @@ impl ProverEvaluate for AggregateExec {
             .map(|aliased_expr| -> PlaceholderResult<Column<'a, S>> {
                 aliased_expr
                     .expr
-                    .first_round_evaluate(alloc, &input, params)
+                    .first_round_evaluate(builder, alloc, &input, params)
             })
             .collect::<PlaceholderResult<Vec<_>>>()?;
         // End compute g_in_star
 
         let selection_column: Column<'a, S> = self
             .where_clause
-            .first_round_evaluate(alloc, &input, params)?;
+            .first_round_evaluate(builder, alloc, &input, params)?;
         let selection = selection_column
             .as_boolean()
             .expect("selection is not boolean");
@@ -309,7 +313,7 @@ impl ProverEvaluate for AggregateExec {
             .map(|aliased_expr| -> PlaceholderResult<Column<'a, S>> {
                 aliased_expr
                     .expr
-                    .first_round_evaluate(alloc, &input, params)
+                    .first_round_evaluate(builder, alloc, &input, params)
             })
             .collect::<PlaceholderResult<Vec<_>>>()?;
         // End compute sum_in_fold
@@ -9,7 +9,7 @@ use crate::{
     },
     proof_primitive::inner_product::curve_25519_scalar::Curve25519Scalar,
     sql::{
-        proof::{exercise_verification, VerifiableQueryResult},
+        proof::{exercise_verification, ProofPlan, VerifiableQueryResult},
         proof_exprs::test_utility::*,
         proof_plans::{AggregateExec, AggregateExecError},
     },
@@ -87,6 +87,44 @@ fn we_can_prove_a_simple_aggregate_with_bigint_columns() {
     assert_eq!(res, expected);
 }
 
+/// `select a, sum(c) as sum_c, count(*) as __count__ from sxt.t group by a` where `c` is a boolean
+#[test]
+fn we_can_prove_an_aggregate_summing_a_boolean_column_as_a_count_of_trues() {
+    let data = owned_table([
+        bigint("a", [1, 2, 2, 1, 2]),
+        boolean("c", [true, false, true, true, true]),
+    ]);
+    let t = TableRef::new("sxt", "t");
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t.clone(), data, 0);
+    let expr = aggregate(
+        cols_expr_plan(&t, &["a"], &accessor),
+        vec![sum_expr(column(&t, "c", &accessor), "sum_c")],
+        "__count__",
+        table_exec(
+            t.clone(),
+            vec![
+                ColumnField::new("a".into(), ColumnType::BigInt),
+                ColumnField::new("c".into(), ColumnType::Boolean),
+            ],
+        ),
+        const_bool(true),
+    );
+    assert_eq!(
+        expr.get_column_result_fields()[1],
+        ColumnField::new("sum_c".into(), ColumnType::BigInt)
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &(), &[]).unwrap();
+    exercise_verification(&res, &expr, &accessor, &t);
+    let res = res.verify(&expr, &accessor, &(), &[]).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [1, 2]),
+        bigint("sum_c", [2, 2]),
+        bigint("__count__", [2, 3]),
+    ]);
+    assert_eq!(res, expected);
+}
+
 /// `select a, sum(c * 2 + 1) as sum_c, count(*) as __count__ from sxt.t where b = 99 group by a`
 #[test]
 fn we_can_prove_an_aggregate_with_bigint_columns() {
@@ -0,0 +1,390 @@
+use super::DynProofPlan;
+use crate::{
+    base::{
+        database::{
+            apply_column_to_indexes, Column, ColumnField, ColumnRef, LiteralValue, Table,
+            TableEvaluation, TableOptions, TableRef,
+        },
+        map::{IndexMap, IndexSet},
+        proof::{PlaceholderResult, ProofError},
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{
+            FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate, VerificationBuilder,
+        },
+        proof_gadgets::{
+            final_round_evaluate_membership_check, final_round_evaluate_monotonic,
+            first_round_evaluate_membership_check, first_round_evaluate_monotonic,
+            verify_membership_check, verify_monotonic,
+        },
+        AnalyzeError, AnalyzeResult,
+    },
+    utils::log,
+};
+use alloc::{boxed::Box, vec::Vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::Ident;
+
+/// `ProofPlan` for queries of the form
+/// ```ignore
+///     <ProofPlan> CROSS JOIN <ProofPlan>
+/// ```
+///
+/// If the left input has `n` rows and the right input has `m` rows, row `k` of the result is row
+/// `i_k = k / m` of the left input followed by row `j_k = k % m` of the right input.
+/// 1. The prover commits to the index columns `i` and `j`.
+/// 2. A membership check of the rows of `(i, L')` in the rows of `(rho_L, L)` proves that the left
+///    part `L'` of the result is `L` looked up by `i`, and in particular that `0 <= i_k < n`.
+///    The right part of the result is proven the same way with `j`.
+/// 3. `m * i + j` is proven to be strictly increasing. Since its `n * m` values lie in `[0, n * m)`,
+///    this proves `m * i_k + j_k = k`, i.e. every pair of rows appears exactly once and in order.
+///
+/// The verifier rejects results with more than `max_output_rows` rows.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct CrossJoinExec {
+    pub(super) left: Box<DynProofPlan>,
+    pub(super) right: Box<DynProofPlan>,
+    pub(super) max_output_rows: usize,
+}
+
+impl CrossJoinExec {
+    /// The default maximum number of rows of the result
+    pub const DEFAULT_MAX_OUTPUT_ROWS: usize = 1 << 20;
+
+    /// Create a new `CrossJoinExec` whose result may have at most `max_output_rows` rows
+    ///
+    /// # Errors
+    /// Returns [`AnalyzeError::DuplicateColumnName`] if the inputs have a column name in common.
+    pub fn try_new(
+        left: Box<DynProofPlan>,
+        right: Box<DynProofPlan>,
+        max_output_rows: usize,
+    ) -> AnalyzeResult<Self> {
+        let left_names = left
+            .get_column_result_fields()
+            .into_iter()
+            .map(|field| field.name())
+            .collect::<IndexSet<_>>();
+        if let Some(field) = right
+            .get_column_result_fields()
+            .into_iter()
+            .find(|field| left_names.contains(&field.name()))
+        {
+            return Err(AnalyzeError::DuplicateColumnName {
+                name: field.name().value,
+            });
+        }
+        Ok(Self {
+            left,
+            right,
+            max_output_rows,
+        })
+    }
+
+    pub(crate) fn left_plan(&self) -> &DynProofPlan {
+        &self.left
+    }
+
+    pub(crate) fn right_plan(&self) -> &DynProofPlan {
+        &self.right
+    }
+
+    /// Get the maximum number of rows of the result
+    #[must_use]
+    pub fn max_output_rows(&self) -> usize {
+        self.max_output_rows
+    }
+}
+
+/// Returns the index columns `i` and `j` with `i_k = k / m` and `j_k = k % m`
+/// along with the same indexes as `usize`s
+fn cross_join_indexes(
+    alloc: &Bump,
+    num_rows_left: usize,
+    num_rows_right: usize,
+) -> (&[i128], &[i128], Vec<usize>, Vec<usize>) {
+    let (left_indexes, right_indexes): (Vec<usize>, Vec<usize>) = (0..num_rows_left)
+        .flat_map(|i| (0..num_rows_right).map(move |j| (i, j)))
+        .unzip();
+    let i = alloc.alloc_slice_fill_with(left_indexes.len(), |k| left_indexes[k] as i128);
+    let j = alloc.alloc_slice_fill_with(right_indexes.len(), |k| right_indexes[k] as i128);
+    (i, j, left_indexes, right_indexes)
+}
+
+/// Returns the columns of `table` looked up by `indexes`
+fn columns_at_indexes<'a, S: Scalar>(
+    alloc: &'a Bump,
+    table: &Table<'a, S>,
+    indexes: &[usize],
+) -> Vec<Column<'a, S>> {
+    table
+        .columns()
+        .map(|column| {
+            apply_column_to_indexes(column, alloc, indexes)
+                .expect("Indexes can not be out of bounds")
+        })
+        .collect()
+}
+
+/// Returns the rows `0..num_rows` as scalars
+fn row_numbers<S: Scalar>(alloc: &Bump, num_rows: usize) -> &[S] {
+    alloc.alloc_slice_fill_with(num_rows, |k| S::from(k as u64))
+}
+
+impl ProofPlan for CrossJoinExec
+where
+    CrossJoinExec: ProverEvaluate,
+{
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut impl VerificationBuilder<S>,
+        accessor: &IndexMap<TableRef, IndexMap<Ident, S>>,
+        chi_eval_map: &IndexMap<TableRef, (S, usize)>,
+        params: &[LiteralValue],
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        let left_eval = self
+            .left
+            .verifier_evaluate(builder, accessor, chi_eval_map, params)?;
+        let right_eval = self
+            .right
+            .verifier_evaluate(builder, accessor, chi_eval_map, params)?;
+        let num_rows_right = right_eval.chi().1;
+        let output_length = left_eval
+            .chi()
+            .1
+            .checked_mul(num_rows_right)
+            .filter(|&length| length <= self.max_output_rows)
+            .ok_or(ProofError::VerificationError {
+                error: "cross join result exceeds the maximum number of rows",
+            })?;
+        let output_chi = builder.try_consume_chi_evaluation()?;
+        if output_chi.1 != output_length {
+            return Err(ProofError::VerificationError {
+                error: "cross join result length does not match the inputs",
+            });
+        }
+        let alpha = builder.try_consume_post_result_challenge()?;
+        let beta = builder.try_consume_post_result_challenge()?;
+        let num_columns_left = left_eval.column_evals().len();
+        let output_column_evals = builder.try_consume_first_round_mle_evaluations(
+            num_columns_left + right_eval.column_evals().len(),
+        )?;
+        let left_rho_eval = builder.try_consume_rho_evaluation()?;
+        let right_rho_eval = builder.try_consume_rho_evaluation()?;
+        let i_eval = builder.try_consume_first_round_mle_evaluation()?;
+        let j_eval = builder.try_consume_first_round_mle_evaluation()?;
+
+        // 3. `m * i + j` is strictly increasing
+        let row_number_eval = S::from(num_rows_right as u64) * i_eval + j_eval;
+        verify_monotonic::<S, true, true>(builder, alpha, beta, row_number_eval, output_chi.0)?;
+
+        // 2. The left and right parts of the result are looked up by `i` and `j`
+        let (left_output_evals, right_output_evals) =
+            output_column_evals.split_at(num_columns_left);
+        verify_membership_check(
+            builder,
+            alpha,
+            beta,
+            left_eval.chi_eval(),
+            output_chi.0,
+            &[&[left_rho_eval], left_eval.column_evals()].concat(),
+            &[&[i_eval], left_output_evals].concat(),
+        )?;
+        verify_membership_check(
+            builder,
+            alpha,
+            beta,
+            right_eval.chi_eval(),
+            output_chi.0,
+            &[&[right_rho_eval], right_eval.column_evals()].concat(),
+            &[&[j_eval], right_output_evals].concat(),
+        )?;
+        Ok(TableEvaluation::new(output_column_evals, output_chi))
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        self.left
+            .get_column_result_fields()
+            .into_iter()
+            .chain(self.right.get_column_result_fields())
+            .collect()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        self.left
+            .get_column_references()
+            .into_iter()
+            .chain(self.right.get_column_references())
+            .collect()
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        self.left
+            .get_table_references()
+            .into_iter()
+            .chain(self.right.get_table_references())
+            .collect()
+    }
+}
+
+impl ProverEvaluate for CrossJoinExec {
+    #[tracing::instrument(
+        name = "CrossJoinExec::first_round_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn first_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        log::log_memory_usage("Start");
+
+        let left = self
+            .left
+            .first_round_evaluate(builder, alloc, table_map, params)?;
+        let right = self
+            .right
+            .first_round_evaluate(builder, alloc, table_map, params)?;
+        let num_rows_left = left.num_rows();
+        let num_rows_right = right.num_rows();
+        let (i, j, left_indexes, right_indexes) =
+            cross_join_indexes(alloc, num_rows_left, num_rows_right);
+        let output_length = left_indexes.len();
+        let left_output_columns = columns_at_indexes(alloc, &left, &left_indexes);
+        let right_output_columns = columns_at_indexes(alloc, &right, &right_indexes);
+        for column in left_output_columns.iter().chain(&right_output_columns) {
+            builder.produce_intermediate_mle(*column);
+        }
+        builder.produce_chi_evaluation_length(output_length);
+        builder.request_post_result_challenges(2);
+        builder.produce_rho_evaluation_length(num_rows_left);
+        builder.produce_rho_evaluation_length(num_rows_right);
+        builder.produce_intermediate_mle(i);
+        builder.produce_intermediate_mle(j);
+
+        // 3. `m * i + j` is strictly increasing
+        first_round_evaluate_monotonic(builder, alloc, row_numbers::<S>(alloc, output_length));
+
+        // 2. The left and right parts of the result are looked up by `i` and `j`
+        first_round_evaluate_membership_check(
+            builder,
+            alloc,
+            &[Column::rho(num_rows_left, alloc)]
+                .into_iter()
+                .chain(left.columns().copied())
+                .collect::<Vec<_>>(),
+            &[&[Column::Int128(i)], left_output_columns.as_slice()].concat(),
+        );
+        first_round_evaluate_membership_check(
+            builder,
+            alloc,
+            &[Column::rho(num_rows_right, alloc)]
+                .into_iter()
+                .chain(right.columns().copied())
+                .collect::<Vec<_>>(),
+            &[&[Column::Int128(j)], right_output_columns.as_slice()].concat(),
+        );
+
+        let res = Table::try_from_iter_with_options(
+            self.get_column_result_fields()
+                .into_iter()
+                .map(|field| field.name())
+                .zip(left_output_columns.into_iter().chain(right_output_columns)),
+            TableOptions::new(Some(output_length)),
+        )
+        .expect("Failed to create table from iterator");
+
+        log::log_memory_usage("End");
+
+        Ok(res)
+    }
+
+    #[tracing::instrument(
+        name = "CrossJoinExec::final_round_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        log::log_memory_usage("Start");
+
+        let left = self
+            .left
+            .final_round_evaluate(builder, alloc, table_map, params)?;
+        let right = self
+            .right
+            .final_round_evaluate(builder, alloc, table_map, params)?;
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+        let num_rows_left = left.num_rows();
+        let num_rows_right = right.num_rows();
+        let (i, j, left_indexes, right_indexes) =
+            cross_join_indexes(alloc, num_rows_left, num_rows_right);
+        let output_length = left_indexes.len();
+        let left_output_columns = columns_at_indexes(alloc, &left, &left_indexes);
+        let right_output_columns = columns_at_indexes(alloc, &right, &right_indexes);
+        let chi_left = alloc.alloc_slice_fill_copy(num_rows_left, true);
+        let chi_right = alloc.alloc_slice_fill_copy(num_rows_right, true);
+        let chi_output = alloc.alloc_slice_fill_copy(output_length, true);
+
+        // 3. `m * i + j` is strictly increasing
+        final_round_evaluate_monotonic::<S, true, true>(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            row_numbers::<S>(alloc, output_length),
+        );
+
+        // 2. The left and right parts of the result are looked up by `i` and `j`
+        final_round_evaluate_membership_check(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            chi_left,
+            chi_output,
+            &[Column::rho(num_rows_left, alloc)]
+                .into_iter()
+                .chain(left.columns().copied())
+                .collect::<Vec<_>>(),
+            &[&[Column::Int128(i)], left_output_columns.as_slice()].concat(),
+        );
+        final_round_evaluate_membership_check(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            chi_right,
+            chi_output,
+            &[Column::rho(num_rows_right, alloc)]
+                .into_iter()
+                .chain(right.columns().copied())
+                .collect::<Vec<_>>(),
+            &[&[Column::Int128(j)], right_output_columns.as_slice()].concat(),
+        );
+
+        let res = Table::try_from_iter_with_options(
+            self.get_column_result_fields()
+                .into_iter()
+                .map(|field| field.name())
+                .zip(left_output_columns.into_iter().chain(right_output_columns)),
+            TableOptions::new(Some(output_length)),
+        )
+        .expect("Failed to create table from iterator");
+
+        log::log_memory_usage("End");
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,139 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::{
+        database::{
+            owned_table_utility::*, table_utility::*, ColumnType, TableRef, TableTestAccessor,
+            TestAccessor,
+        },
+        proof::ProofError,
+    },
+    sql::{
+        proof::{exercise_verification, QueryError, VerifiableQueryResult},
+        AnalyzeError,
+    },
+};
+use blitzar::proof::InnerProductProof;
+use bumpalo::Bump;
+
+fn cats_plan(table_ref: &TableRef) -> DynProofPlan {
+    table_exec(
+        table_ref.clone(),
+        vec![
+            column_field("id", ColumnType::BigInt),
+            column_field("name", ColumnType::VarChar),
+        ],
+    )
+}
+
+fn toys_plan(table_ref: &TableRef) -> DynProofPlan {
+    table_exec(
+        table_ref.clone(),
+        vec![
+            column_field("toy", ColumnType::VarChar),
+            column_field("price", ColumnType::Int),
+        ],
+    )
+}
+
+fn accessor_with_cats_and_toys<'a>(
+    alloc: &'a Bump,
+    cats: &TableRef,
+    toys: &TableRef,
+    num_toys: usize,
+) -> TableTestAccessor<'a, InnerProductProof> {
+    let mut accessor = TableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        cats.clone(),
+        table([
+            borrowed_bigint("id", [1_i64, 2, 3], alloc),
+            borrowed_varchar("name", ["Chloe", "Margaret", "Prudence"], alloc),
+        ]),
+        0,
+    );
+    accessor.add_table(
+        toys.clone(),
+        table([
+            borrowed_varchar("toy", ["ball", "mouse"].into_iter().take(num_toys), alloc),
+            borrowed_int("price", [5, 3].into_iter().take(num_toys), alloc),
+        ]),
+        0,
+    );
+    accessor
+}
+
+#[test]
+fn we_can_prove_and_get_the_correct_result_from_a_cross_join() {
+    let alloc = Bump::new();
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let toys: TableRef = "sxt.toys".parse().unwrap();
+    let accessor = accessor_with_cats_and_toys(&alloc, &cats, &toys, 2);
+    let ast = cross_join(cats_plan(&cats), toys_plan(&toys));
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &cats);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([
+        bigint("id", [1_i64, 1, 2, 2, 3, 3]),
+        varchar(
+            "name",
+            [
+                "Chloe", "Chloe", "Margaret", "Margaret", "Prudence", "Prudence",
+            ],
+        ),
+        varchar("toy", ["ball", "mouse", "ball", "mouse", "ball", "mouse"]),
+        int("price", [5, 3, 5, 3, 5, 3]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_cross_join_with_an_empty_input() {
+    let alloc = Bump::new();
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let toys: TableRef = "sxt.toys".parse().unwrap();
+    let accessor = accessor_with_cats_and_toys(&alloc, &cats, &toys, 0);
+    let expected_res = owned_table([
+        bigint("id", [0_i64; 0]),
+        varchar("name", [""; 0]),
+        varchar("toy", [""; 0]),
+        int("price", [0; 0]),
+    ]);
+    let ast = cross_join(cats_plan(&cats), toys_plan(&toys));
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &cats);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_verify_a_cross_join_whose_result_exceeds_the_maximum_number_of_rows() {
+    let alloc = Bump::new();
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let toys: TableRef = "sxt.toys".parse().unwrap();
+    let accessor = accessor_with_cats_and_toys(&alloc, &cats, &toys, 2);
+    let ast = DynProofPlan::try_new_cross_join(cats_plan(&cats), toys_plan(&toys), 5).unwrap();
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    assert!(matches!(
+        verifiable_res.verify(&ast, &accessor, &(), &[]),
+        Err(QueryError::ProofError {
+            source: ProofError::VerificationError { .. }
+        })
+    ));
+}
+
+#[test]
+fn we_cannot_cross_join_inputs_with_a_common_column_name() {
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let kittens: TableRef = "sxt.kittens".parse().unwrap();
+    assert_eq!(
+        DynProofPlan::try_new_cross_join(cats_plan(&cats), cats_plan(&kittens), 10),
+        Err(AnalyzeError::DuplicateColumnName {
+            name: "id".to_string()
+        })
+    );
+}
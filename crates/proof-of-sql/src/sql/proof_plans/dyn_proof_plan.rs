@@ -1,12 +1,16 @@
 use super::{
-    AggregateExec, EmptyExec, FilterExec, GroupByExec, LegacyFilterExec, ProjectionExec, SliceExec,
-    SortMergeJoinExec, TableExec, UnionExec,
+    AggregateExec, CrossJoinExec, EmptyExec, FilterExec, GroupByExec, LegacyFilterExec,
+    NoMatchExec, ProjectionExec, RowNumberExec, SemiJoinExec, SliceExec, SortMergeJoinExec,
+    TableExec, UnionExec,
 };
 use crate::{
     base::{
-        database::{ColumnField, ColumnRef, LiteralValue, Table, TableEvaluation, TableRef},
+        database::{
+            ColumnField, ColumnRef, ColumnType, LiteralValue, MetadataAccessor, Table,
+            TableEvaluation, TableRef,
+        },
         map::{IndexMap, IndexSet},
-        proof::{PlaceholderResult, ProofError},
+        proof::{PlaceholderError, PlaceholderResult, ProofError},
         scalar::Scalar,
     },
     sql::{
@@ -17,8 +21,10 @@ use crate::{
         AnalyzeResult,
     },
 };
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
 use bumpalo::Bump;
+use core::fmt::{self, Display, Formatter};
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use sqlparser::ast::Ident;
 
@@ -88,6 +94,27 @@ pub enum DynProofPlan {
     ///     ON col1 = col2
     /// ```
     SortMergeJoin(SortMergeJoinExec),
+    /// `ProofPlan` proving that no row matches a predicate, for queries of the form
+    /// ```ignore
+    ///     SELECT NOT EXISTS (SELECT * FROM <input> WHERE <where_clause>)
+    /// ```
+    NoMatch(NoMatchExec),
+    /// `ProofPlan` for queries of the form
+    /// ```ignore
+    ///     SELECT * FROM <ProofPlan>
+    ///     WHERE col1 IN (SELECT col2 FROM <ProofPlan>)
+    /// ```
+    SemiJoin(SemiJoinExec),
+    /// `ProofPlan` for queries of the form
+    /// ```ignore
+    ///     <ProofPlan> CROSS JOIN <ProofPlan>
+    /// ```
+    CrossJoin(CrossJoinExec),
+    /// `ProofPlan` for queries of the form
+    /// ```ignore
+    ///     SELECT *, ROW_NUMBER() OVER (ORDER BY col) FROM <ProofPlan>
+    /// ```
+    RowNumber(RowNumberExec),
 }
 
 impl DynProofPlan {
@@ -175,6 +202,526 @@ impl DynProofPlan {
         ))
     }
 
+    /// Creates a new plan proving that no row of `input` matches `where_clause`.
+    #[must_use]
+    pub fn new_no_match(input: DynProofPlan, where_clause: DynProofExpr) -> Self {
+        Self::NoMatch(NoMatchExec::new(Box::new(input), where_clause))
+    }
+
+    /// Creates a new semi join plan.
+    pub fn try_new_semi_join(
+        left: DynProofPlan,
+        right: DynProofPlan,
+        left_join_column_index: usize,
+        right_join_column_index: usize,
+    ) -> AnalyzeResult<Self> {
+        SemiJoinExec::try_new(
+            Box::new(left),
+            Box::new(right),
+            left_join_column_index,
+            right_join_column_index,
+        )
+        .map(Self::SemiJoin)
+    }
+
+    /// Creates a new cross join plan whose result may have at most `max_output_rows` rows.
+    pub fn try_new_cross_join(
+        left: DynProofPlan,
+        right: DynProofPlan,
+        max_output_rows: usize,
+    ) -> AnalyzeResult<Self> {
+        CrossJoinExec::try_new(Box::new(left), Box::new(right), max_output_rows)
+            .map(Self::CrossJoin)
+    }
+
+    /// Creates a new plan numbering the rows of `input` ordered by the column at `order_by_index`.
+    pub fn try_new_row_number(
+        input: DynProofPlan,
+        order_by_index: usize,
+        ascending: bool,
+        alias: Ident,
+    ) -> AnalyzeResult<Self> {
+        RowNumberExec::try_new(Box::new(input), order_by_index, ascending, alias)
+            .map(Self::RowNumber)
+    }
+
+    /// Replaces every placeholder in the plan with a literal of its value in `params`.
+    ///
+    /// This allows a parameterized query to be planned once and bound for each execution.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PlaceholderError`] if
+    /// 1. A placeholder id is out of bounds of `params`
+    /// 2. The type of a placeholder does not match the type of its value in `params`
+    /// 3. There are more `params` than the placeholders require
+    pub fn try_bind_placeholders(&self, params: &[LiteralValue]) -> PlaceholderResult<Self> {
+        let mut num_params_required = 0;
+        let plan = self.try_bind_placeholders_impl(params, &mut num_params_required)?;
+        if num_params_required == params.len() {
+            Ok(plan)
+        } else {
+            Err(PlaceholderError::InvalidParamCount {
+                expected: num_params_required,
+                actual: params.len(),
+            })
+        }
+    }
+
+    fn try_bind_placeholders_impl(
+        &self,
+        params: &[LiteralValue],
+        num_params_required: &mut usize,
+    ) -> PlaceholderResult<Self> {
+        fn bind_aliased(
+            aliased_exprs: &[AliasedDynProofExpr],
+            params: &[LiteralValue],
+            num_params_required: &mut usize,
+        ) -> PlaceholderResult<Vec<AliasedDynProofExpr>> {
+            aliased_exprs
+                .iter()
+                .map(|aliased_expr| {
+                    Ok(AliasedDynProofExpr {
+                        expr: aliased_expr
+                            .expr
+                            .try_bind_placeholders(params, num_params_required)?,
+                        alias: aliased_expr.alias.clone(),
+                    })
+                })
+                .collect()
+        }
+        Ok(match self {
+            DynProofPlan::Empty(_) | DynProofPlan::Table(_) => self.clone(),
+            DynProofPlan::Projection(plan) => Self::new_projection(
+                bind_aliased(plan.aliased_results(), params, num_params_required)?,
+                plan.input()
+                    .try_bind_placeholders_impl(params, num_params_required)?,
+            ),
+            DynProofPlan::GroupBy(plan) => Self::GroupBy(GroupByExec {
+                group_by_exprs: plan.group_by_exprs.clone(),
+                sum_expr: bind_aliased(&plan.sum_expr, params, num_params_required)?,
+                count_alias: plan.count_alias.clone(),
+                table: plan.table.clone(),
+                where_clause: plan
+                    .where_clause
+                    .try_bind_placeholders(params, num_params_required)?,
+            }),
+            DynProofPlan::Aggregate(plan) => Self::try_new_aggregate(
+                bind_aliased(plan.group_by_exprs(), params, num_params_required)?,
+                bind_aliased(plan.sum_expr(), params, num_params_required)?,
+                plan.count_alias().clone(),
+                plan.input()
+                    .try_bind_placeholders_impl(params, num_params_required)?,
+                plan.where_clause()
+                    .try_bind_placeholders(params, num_params_required)?,
+            )
+            .expect("binding placeholders preserves the shape of aggregates"),
+            DynProofPlan::LegacyFilter(plan) => Self::new_legacy_filter(
+                bind_aliased(plan.aliased_results(), params, num_params_required)?,
+                plan.table().clone(),
+                plan.where_clause()
+                    .try_bind_placeholders(params, num_params_required)?,
+            ),
+            DynProofPlan::Filter(plan) => Self::new_filter(
+                bind_aliased(plan.aliased_results(), params, num_params_required)?,
+                plan.input()
+                    .try_bind_placeholders_impl(params, num_params_required)?,
+                plan.where_clause()
+                    .try_bind_placeholders(params, num_params_required)?,
+            ),
+            DynProofPlan::Slice(plan) => Self::new_slice(
+                plan.input()
+                    .try_bind_placeholders_impl(params, num_params_required)?,
+                plan.skip(),
+                plan.fetch(),
+            ),
+            DynProofPlan::Union(plan) => Self::Union(UnionExec {
+                inputs: plan
+                    .inputs
+                    .iter()
+                    .map(|input| input.try_bind_placeholders_impl(params, num_params_required))
+                    .collect::<PlaceholderResult<_>>()?,
+            }),
+            DynProofPlan::SortMergeJoin(plan) => Self::SortMergeJoin(SortMergeJoinExec {
+                left: Box::new(
+                    plan.left
+                        .try_bind_placeholders_impl(params, num_params_required)?,
+                ),
+                right: Box::new(
+                    plan.right
+                        .try_bind_placeholders_impl(params, num_params_required)?,
+                ),
+                left_join_column_indexes: plan.left_join_column_indexes.clone(),
+                right_join_column_indexes: plan.right_join_column_indexes.clone(),
+                result_idents: plan.result_idents.clone(),
+            }),
+            DynProofPlan::NoMatch(plan) => Self::new_no_match(
+                plan.input()
+                    .try_bind_placeholders_impl(params, num_params_required)?,
+                plan.where_clause()
+                    .try_bind_placeholders(params, num_params_required)?,
+            ),
+            DynProofPlan::SemiJoin(plan) => Self::SemiJoin(SemiJoinExec {
+                left: Box::new(
+                    plan.left
+                        .try_bind_placeholders_impl(params, num_params_required)?,
+                ),
+                right: Box::new(
+                    plan.right
+                        .try_bind_placeholders_impl(params, num_params_required)?,
+                ),
+                left_join_column_index: plan.left_join_column_index,
+                right_join_column_index: plan.right_join_column_index,
+            }),
+            DynProofPlan::CrossJoin(plan) => Self::CrossJoin(CrossJoinExec {
+                left: Box::new(
+                    plan.left
+                        .try_bind_placeholders_impl(params, num_params_required)?,
+                ),
+                right: Box::new(
+                    plan.right
+                        .try_bind_placeholders_impl(params, num_params_required)?,
+                ),
+                max_output_rows: plan.max_output_rows,
+            }),
+            DynProofPlan::RowNumber(plan) => Self::RowNumber(RowNumberExec {
+                input: Box::new(
+                    plan.input
+                        .try_bind_placeholders_impl(params, num_params_required)?,
+                ),
+                order_by_index: plan.order_by_index,
+                ascending: plan.ascending,
+                alias: plan.alias.clone(),
+            }),
+        })
+    }
+
+    /// Returns an upper bound on the length of the largest table the plan materializes
+    ///
+    /// The bound is computed from the table lengths in `accessor` only:
+    /// filters, projections, slices and aggregations never grow their input,
+    /// a union is bounded by the sum of its inputs and a join by the product.
+    /// Callers can use it to pick a large enough setup, e.g. `max_nu` for Dory.
+    #[must_use]
+    pub fn estimated_table_length_bound(&self, accessor: &impl MetadataAccessor) -> usize {
+        match self {
+            DynProofPlan::Empty(_) => 1,
+            DynProofPlan::Table(plan) => accessor.get_length(plan.table_ref()),
+            DynProofPlan::LegacyFilter(plan) => accessor.get_length(&plan.table().table_ref),
+            DynProofPlan::GroupBy(plan) => accessor.get_length(&plan.table().table_ref),
+            DynProofPlan::Projection(plan) => plan.input().estimated_table_length_bound(accessor),
+            DynProofPlan::Filter(plan) => plan.input().estimated_table_length_bound(accessor),
+            DynProofPlan::Slice(plan) => plan.input().estimated_table_length_bound(accessor),
+            DynProofPlan::RowNumber(plan) => plan.input().estimated_table_length_bound(accessor),
+            // These always return at least one row, even for empty inputs
+            DynProofPlan::Aggregate(plan) => {
+                plan.input().estimated_table_length_bound(accessor).max(1)
+            }
+            DynProofPlan::NoMatch(plan) => {
+                plan.input().estimated_table_length_bound(accessor).max(1)
+            }
+            DynProofPlan::Union(plan) => plan
+                .inputs
+                .iter()
+                .map(|input| input.estimated_table_length_bound(accessor))
+                .fold(0, usize::saturating_add),
+            DynProofPlan::SortMergeJoin(plan) => {
+                let left = plan.left.estimated_table_length_bound(accessor);
+                let right = plan.right.estimated_table_length_bound(accessor);
+                left.max(right).max(left.saturating_mul(right))
+            }
+            // The union of the join columns is bounded by the sum of the inputs
+            DynProofPlan::SemiJoin(plan) => plan
+                .left
+                .estimated_table_length_bound(accessor)
+                .saturating_add(plan.right.estimated_table_length_bound(accessor)),
+            DynProofPlan::CrossJoin(plan) => {
+                let left = plan.left.estimated_table_length_bound(accessor);
+                let right = plan.right.estimated_table_length_bound(accessor);
+                left.max(right).max(left.saturating_mul(right))
+            }
+        }
+    }
+
+    /// Returns an equivalent plan which is cheaper to prove
+    ///
+    /// The following rewrites are applied bottom up:
+    /// 1. Adjacent [`SliceExec`]s are collapsed into one. An outer `LIMIT f2 OFFSET s2` over an
+    ///    inner `LIMIT f1 OFFSET s1` selects the same rows as a single
+    ///    `LIMIT min(f1 - s2, f2) OFFSET s1 + s2`, where a missing fetch is unbounded.
+    /// 2. A [`ProjectionExec`] directly over a [`TableExec`] which selects every column of the
+    ///    table in order without renaming any is replaced by the [`TableExec`].
+    #[must_use]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "Simplifying only rewrites slices and projections, which preserves the input schema"
+    )]
+    pub fn simplify(&self) -> Self {
+        match self {
+            DynProofPlan::Empty(_)
+            | DynProofPlan::Table(_)
+            | DynProofPlan::LegacyFilter(_)
+            | DynProofPlan::GroupBy(_) => self.clone(),
+            DynProofPlan::Projection(plan) => match plan.input().simplify() {
+                DynProofPlan::Table(table)
+                    if is_identity_projection(plan.aliased_results(), &table) =>
+                {
+                    Self::Table(table)
+                }
+                input => Self::new_projection(plan.aliased_results().to_vec(), input),
+            },
+            DynProofPlan::Aggregate(plan) => Self::try_new_aggregate(
+                plan.group_by_exprs().to_vec(),
+                plan.sum_expr().to_vec(),
+                plan.count_alias().clone(),
+                plan.input().simplify(),
+                plan.where_clause().clone(),
+            )
+            .expect("simplifying preserves the schema of the input"),
+            DynProofPlan::Filter(plan) => Self::new_filter(
+                plan.aliased_results().to_vec(),
+                plan.input().simplify(),
+                plan.where_clause().clone(),
+            ),
+            DynProofPlan::Slice(plan) => match plan.input().simplify() {
+                // `LIMIT ALL` without an offset selects every row
+                input if plan.skip() == 0 && plan.fetch().is_none() => input,
+                DynProofPlan::Slice(inner) => {
+                    let remaining = inner.fetch().map(|fetch| fetch.saturating_sub(plan.skip()));
+                    let fetch = match (remaining, plan.fetch()) {
+                        (Some(remaining), Some(fetch)) => Some(remaining.min(fetch)),
+                        (remaining, None) => remaining,
+                        (None, fetch) => fetch,
+                    };
+                    Self::Slice(SliceExec::new(
+                        inner.input,
+                        inner.skip.saturating_add(plan.skip()),
+                        fetch,
+                    ))
+                }
+                input => Self::new_slice(input, plan.skip(), plan.fetch()),
+            },
+            DynProofPlan::Union(plan) => Self::Union(UnionExec {
+                inputs: plan.inputs.iter().map(Self::simplify).collect(),
+            }),
+            DynProofPlan::SortMergeJoin(plan) => Self::SortMergeJoin(SortMergeJoinExec {
+                left: Box::new(plan.left.simplify()),
+                right: Box::new(plan.right.simplify()),
+                left_join_column_indexes: plan.left_join_column_indexes.clone(),
+                right_join_column_indexes: plan.right_join_column_indexes.clone(),
+                result_idents: plan.result_idents.clone(),
+            }),
+            DynProofPlan::NoMatch(plan) => {
+                Self::new_no_match(plan.input().simplify(), plan.where_clause().clone())
+            }
+            DynProofPlan::SemiJoin(plan) => Self::SemiJoin(SemiJoinExec {
+                left: Box::new(plan.left.simplify()),
+                right: Box::new(plan.right.simplify()),
+                left_join_column_index: plan.left_join_column_index,
+                right_join_column_index: plan.right_join_column_index,
+            }),
+            DynProofPlan::CrossJoin(plan) => Self::CrossJoin(CrossJoinExec {
+                left: Box::new(plan.left.simplify()),
+                right: Box::new(plan.right.simplify()),
+                max_output_rows: plan.max_output_rows,
+            }),
+            DynProofPlan::RowNumber(plan) => Self::RowNumber(RowNumberExec {
+                input: Box::new(plan.input.simplify()),
+                order_by_index: plan.order_by_index,
+                ascending: plan.ascending,
+                alias: plan.alias.clone(),
+            }),
+        }
+    }
+
+    /// Returns the plan with every reference to table `from` replaced by a reference to table `to`
+    ///
+    /// This rewrites table scans as well as the column references in expressions,
+    /// recursing through all inputs. References to other tables are left unchanged.
+    #[must_use]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "Renaming a table preserves the types of columns"
+    )]
+    pub fn rename_table(&self, from: &TableRef, to: &TableRef) -> Self {
+        let rename_ref = |table_ref: &TableRef| {
+            if table_ref == from {
+                to.clone()
+            } else {
+                table_ref.clone()
+            }
+        };
+        let rename_aliased = |aliased_exprs: &[AliasedDynProofExpr]| {
+            aliased_exprs
+                .iter()
+                .map(|aliased_expr| AliasedDynProofExpr {
+                    expr: aliased_expr.expr.rename_table(from, to),
+                    alias: aliased_expr.alias.clone(),
+                })
+                .collect::<Vec<_>>()
+        };
+        match self {
+            DynProofPlan::Empty(_) => self.clone(),
+            DynProofPlan::Table(plan) => {
+                Self::new_table(rename_ref(plan.table_ref()), plan.schema().to_vec())
+            }
+            DynProofPlan::Projection(plan) => Self::new_projection(
+                rename_aliased(plan.aliased_results()),
+                plan.input().rename_table(from, to),
+            ),
+            DynProofPlan::GroupBy(plan) => Self::GroupBy(GroupByExec {
+                group_by_exprs: plan
+                    .group_by_exprs
+                    .iter()
+                    .map(|expr| expr.rename_table(from, to))
+                    .collect(),
+                sum_expr: rename_aliased(&plan.sum_expr),
+                count_alias: plan.count_alias.clone(),
+                table: TableExpr {
+                    table_ref: rename_ref(&plan.table.table_ref),
+                },
+                where_clause: plan.where_clause.rename_table(from, to),
+            }),
+            DynProofPlan::Aggregate(plan) => Self::try_new_aggregate(
+                rename_aliased(plan.group_by_exprs()),
+                rename_aliased(plan.sum_expr()),
+                plan.count_alias().clone(),
+                plan.input().rename_table(from, to),
+                plan.where_clause().rename_table(from, to),
+            )
+            .expect("renaming a table preserves the shape of aggregates"),
+            DynProofPlan::LegacyFilter(plan) => Self::new_legacy_filter(
+                rename_aliased(plan.aliased_results()),
+                TableExpr {
+                    table_ref: rename_ref(&plan.table().table_ref),
+                },
+                plan.where_clause().rename_table(from, to),
+            ),
+            DynProofPlan::Filter(plan) => Self::new_filter(
+                rename_aliased(plan.aliased_results()),
+                plan.input().rename_table(from, to),
+                plan.where_clause().rename_table(from, to),
+            ),
+            DynProofPlan::Slice(plan) => Self::new_slice(
+                plan.input().rename_table(from, to),
+                plan.skip(),
+                plan.fetch(),
+            ),
+            DynProofPlan::Union(plan) => Self::Union(UnionExec {
+                inputs: plan
+                    .inputs
+                    .iter()
+                    .map(|input| input.rename_table(from, to))
+                    .collect(),
+            }),
+            DynProofPlan::SortMergeJoin(plan) => Self::SortMergeJoin(SortMergeJoinExec {
+                left: Box::new(plan.left.rename_table(from, to)),
+                right: Box::new(plan.right.rename_table(from, to)),
+                left_join_column_indexes: plan.left_join_column_indexes.clone(),
+                right_join_column_indexes: plan.right_join_column_indexes.clone(),
+                result_idents: plan.result_idents.clone(),
+            }),
+            DynProofPlan::NoMatch(plan) => Self::new_no_match(
+                plan.input().rename_table(from, to),
+                plan.where_clause().rename_table(from, to),
+            ),
+            DynProofPlan::SemiJoin(plan) => Self::SemiJoin(SemiJoinExec {
+                left: Box::new(plan.left.rename_table(from, to)),
+                right: Box::new(plan.right.rename_table(from, to)),
+                left_join_column_index: plan.left_join_column_index,
+                right_join_column_index: plan.right_join_column_index,
+            }),
+            DynProofPlan::CrossJoin(plan) => Self::CrossJoin(CrossJoinExec {
+                left: Box::new(plan.left.rename_table(from, to)),
+                right: Box::new(plan.right.rename_table(from, to)),
+                max_output_rows: plan.max_output_rows,
+            }),
+            DynProofPlan::RowNumber(plan) => Self::RowNumber(RowNumberExec {
+                input: Box::new(plan.input.rename_table(from, to)),
+                order_by_index: plan.order_by_index,
+                ascending: plan.ascending,
+                alias: plan.alias.clone(),
+            }),
+        }
+    }
+
+    /// Returns every column the plan reads from the accessor when it is proven
+    ///
+    /// Callers can use it to fetch only these columns from storage before proving.
+    /// Note that a [`TableExec`] reads every column of its schema,
+    /// while plans reading tables directly, such as [`LegacyFilterExec`], only read the columns their expressions use.
+    #[must_use]
+    pub fn referenced_columns(&self) -> IndexSet<ColumnRef> {
+        self.get_column_references()
+    }
+
+    /// Returns the zero-based index and type of every placeholder in the plan,
+    /// including repeated occurrences.
+    #[must_use]
+    pub fn get_placeholders(&self) -> Vec<(usize, ColumnType)> {
+        let mut placeholders = Vec::new();
+        self.collect_placeholders(&mut placeholders);
+        placeholders
+    }
+
+    fn collect_placeholders(&self, placeholders: &mut Vec<(usize, ColumnType)>) {
+        let (inputs, exprs): (Vec<&DynProofPlan>, Vec<&DynProofExpr>) = match self {
+            DynProofPlan::Empty(_) | DynProofPlan::Table(_) => (vec![], vec![]),
+            DynProofPlan::Projection(plan) => (
+                vec![plan.input()],
+                plan.aliased_results().iter().map(|e| &e.expr).collect(),
+            ),
+            DynProofPlan::GroupBy(plan) => (
+                vec![],
+                plan.sum_expr()
+                    .iter()
+                    .map(|e| &e.expr)
+                    .chain([plan.where_clause()])
+                    .collect(),
+            ),
+            DynProofPlan::Aggregate(plan) => (
+                vec![plan.input()],
+                plan.group_by_exprs()
+                    .iter()
+                    .chain(plan.sum_expr())
+                    .map(|e| &e.expr)
+                    .chain([plan.where_clause()])
+                    .collect(),
+            ),
+            DynProofPlan::LegacyFilter(plan) => (
+                vec![],
+                plan.aliased_results()
+                    .iter()
+                    .map(|e| &e.expr)
+                    .chain([plan.where_clause()])
+                    .collect(),
+            ),
+            DynProofPlan::Filter(plan) => (
+                vec![plan.input()],
+                plan.aliased_results()
+                    .iter()
+                    .map(|e| &e.expr)
+                    .chain([plan.where_clause()])
+                    .collect(),
+            ),
+            DynProofPlan::Slice(plan) => (vec![plan.input()], vec![]),
+            DynProofPlan::RowNumber(plan) => (vec![plan.input()], vec![]),
+            DynProofPlan::Union(plan) => (plan.input_plans().iter().collect(), vec![]),
+            DynProofPlan::SortMergeJoin(plan) => {
+                (vec![plan.left_plan(), plan.right_plan()], vec![])
+            }
+            DynProofPlan::NoMatch(plan) => (vec![plan.input()], vec![plan.where_clause()]),
+            DynProofPlan::SemiJoin(plan) => (vec![plan.left_plan(), plan.right_plan()], vec![]),
+            DynProofPlan::CrossJoin(plan) => (vec![plan.left_plan(), plan.right_plan()], vec![]),
+        };
+        for input in inputs {
+            input.collect_placeholders(placeholders);
+        }
+        for expr in exprs {
+            expr.collect_placeholders(placeholders);
+        }
+    }
+
     /// Returns the resulting column fields of the plan as column references
     pub(crate) fn get_column_result_fields_as_references(&self) -> IndexSet<ColumnRef> {
         self.get_column_result_fields()
@@ -182,4 +729,201 @@ impl DynProofPlan {
             .map(|f| ColumnRef::new(TableRef::from_names(None, ""), f.name(), f.data_type()))
             .collect()
     }
+
+    /// Writes the plan as a tree with one node per line, indenting the node by `depth` levels
+    /// and each of its inputs by one more level
+    fn fmt_tree(&self, f: &mut Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(f, "{:indent$}", "", indent = 2 * depth)?;
+        let inputs: Vec<&DynProofPlan> = match self {
+            DynProofPlan::Empty(_) => {
+                write!(f, "Empty")?;
+                vec![]
+            }
+            DynProofPlan::Table(table) => {
+                write!(
+                    f,
+                    "TableScan {} [{}]",
+                    table.table_ref(),
+                    table
+                        .schema()
+                        .iter()
+                        .map(|field| field.name().value)
+                        .join(", ")
+                )?;
+                vec![]
+            }
+            DynProofPlan::Projection(projection) => {
+                write!(
+                    f,
+                    "Projection [{}]",
+                    projection.aliased_results().iter().join(", ")
+                )?;
+                vec![projection.input()]
+            }
+            DynProofPlan::GroupBy(group_by) => {
+                write!(
+                    f,
+                    "GroupBy [{}] [{}] FROM {} WHERE {}",
+                    group_by
+                        .group_by_exprs()
+                        .iter()
+                        .map(|column| column.column_id().value)
+                        .join(", "),
+                    display_aggregates(group_by.sum_expr(), group_by.count_alias()),
+                    group_by.table().table_ref,
+                    group_by.where_clause()
+                )?;
+                vec![]
+            }
+            DynProofPlan::Aggregate(aggregate) => {
+                write!(
+                    f,
+                    "Aggregate [{}] [{}] WHERE {}",
+                    aggregate.group_by_exprs().iter().join(", "),
+                    display_aggregates(aggregate.sum_expr(), aggregate.count_alias()),
+                    aggregate.where_clause()
+                )?;
+                vec![aggregate.input()]
+            }
+            DynProofPlan::LegacyFilter(filter) => {
+                write!(
+                    f,
+                    "LegacyFilter [{}] FROM {} WHERE {}",
+                    filter.aliased_results().iter().join(", "),
+                    filter.table().table_ref,
+                    filter.where_clause()
+                )?;
+                vec![]
+            }
+            DynProofPlan::Filter(filter) => {
+                write!(
+                    f,
+                    "Filter [{}] WHERE {}",
+                    filter.aliased_results().iter().join(", "),
+                    filter.where_clause()
+                )?;
+                vec![filter.input()]
+            }
+            DynProofPlan::Slice(slice) => {
+                write!(f, "Slice OFFSET {}", slice.skip())?;
+                if let Some(fetch) = slice.fetch() {
+                    write!(f, " LIMIT {fetch}")?;
+                }
+                vec![slice.input()]
+            }
+            DynProofPlan::Union(union) => {
+                write!(f, "Union")?;
+                union.input_plans().iter().collect()
+            }
+            DynProofPlan::SortMergeJoin(join) => {
+                let left_fields = join.left_plan().get_column_result_fields();
+                let right_fields = join.right_plan().get_column_result_fields();
+                write!(
+                    f,
+                    "SortMergeJoin [{}] ON {}",
+                    join.result_idents()
+                        .iter()
+                        .map(|ident| &ident.value)
+                        .join(", "),
+                    join.left_join_column_indexes()
+                        .iter()
+                        .zip(join.right_join_column_indexes())
+                        .map(|(&left, &right)| format!(
+                            "({} = {})",
+                            left_fields[left].name().value,
+                            right_fields[right].name().value
+                        ))
+                        .join(" AND ")
+                )?;
+                vec![join.left_plan(), join.right_plan()]
+            }
+            DynProofPlan::NoMatch(no_match) => {
+                write!(f, "NoMatch WHERE {}", no_match.where_clause())?;
+                vec![no_match.input()]
+            }
+            DynProofPlan::SemiJoin(join) => {
+                write!(
+                    f,
+                    "SemiJoin ON ({} = {})",
+                    join.left_plan().get_column_result_fields()[join.left_join_column_index()]
+                        .name()
+                        .value,
+                    join.right_plan().get_column_result_fields()[join.right_join_column_index()]
+                        .name()
+                        .value
+                )?;
+                vec![join.left_plan(), join.right_plan()]
+            }
+            DynProofPlan::CrossJoin(join) => {
+                write!(f, "CrossJoin")?;
+                vec![join.left_plan(), join.right_plan()]
+            }
+            DynProofPlan::RowNumber(row_number) => {
+                write!(
+                    f,
+                    "RowNumber {} ORDER BY {} {}",
+                    row_number.alias().value,
+                    row_number.input().get_column_result_fields()[row_number.order_by_index()]
+                        .name()
+                        .value,
+                    if row_number.ascending() {
+                        "ASC"
+                    } else {
+                        "DESC"
+                    }
+                )?;
+                vec![row_number.input()]
+            }
+        };
+        for input in inputs {
+            writeln!(f)?;
+            input.fmt_tree(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether `aliased_results` selects every column of `table` in order without renaming any
+fn is_identity_projection(aliased_results: &[AliasedDynProofExpr], table: &TableExec) -> bool {
+    aliased_results.len() == table.schema().len()
+        && aliased_results
+            .iter()
+            .zip(table.schema())
+            .all(|(aliased_expr, field)| match &aliased_expr.expr {
+                DynProofExpr::Column(column) => {
+                    aliased_expr.alias == field.name()
+                        && *column.column_ref()
+                            == ColumnRef::new(
+                                table.table_ref().clone(),
+                                field.name(),
+                                field.data_type(),
+                            )
+                }
+                _ => false,
+            })
+}
+
+/// Display the sums and the count of a grouping, e.g. `SUM(a) AS sum_a, COUNT(*) AS count`
+fn display_aggregates(sum_exprs: &[AliasedDynProofExpr], count_alias: &Ident) -> String {
+    sum_exprs
+        .iter()
+        .map(|sum_expr| format!("SUM({}) AS {}", sum_expr.expr, sum_expr.alias.value))
+        .chain(core::iter::once(format!(
+            "COUNT(*) AS {}",
+            count_alias.value
+        )))
+        .join(", ")
+}
+
+/// Display the plan as an EXPLAIN-style tree, with each input indented below the plan using it
+///
+/// For example
+/// ```text
+/// Filter [a, (b + 1) AS c] WHERE (a = 5)
+///   TableScan sxt.t [a, b]
+/// ```
+impl Display for DynProofPlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_tree(f, 0)
+    }
 }
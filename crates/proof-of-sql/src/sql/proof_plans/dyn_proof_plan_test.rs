@@ -0,0 +1,508 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::{
+        commitment::naive_evaluation_proof::NaiveEvaluationProof,
+        database::{
+            owned_table_utility::*, ColumnRef, ColumnType, OwnedTableTestAccessor, TableRef,
+            TestAccessor,
+        },
+        map::IndexSet,
+    },
+    sql::{
+        proof::{ProofPlan, VerifiableQueryResult},
+        proof_exprs::{test_utility::*, ColumnExpr, DynProofExpr},
+    },
+};
+
+fn accessor_with_tables(
+    lengths: &[(&TableRef, usize)],
+) -> OwnedTableTestAccessor<'static, NaiveEvaluationProof> {
+    let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_empty_with_setup(());
+    for (table_ref, length) in lengths {
+        let ids = (0..i64::try_from(*length).unwrap()).collect::<Vec<_>>();
+        accessor.add_table((*table_ref).clone(), owned_table([bigint("id", ids)]), 0);
+    }
+    accessor
+}
+
+fn table_plan(table_ref: &TableRef) -> DynProofPlan {
+    table_exec(
+        table_ref.clone(),
+        vec![column_field("id", ColumnType::BigInt)],
+    )
+}
+
+#[test]
+fn we_can_bound_the_table_length_of_a_filter_by_its_input() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_tables(&[(&t, 5)]);
+    let plan = filter(
+        vec![aliased_plan(column(&t, "id", &accessor), "id")],
+        table_plan(&t),
+        equal(column(&t, "id", &accessor), const_bigint(3)),
+    );
+    assert_eq!(plan.estimated_table_length_bound(&accessor), 5);
+    assert_eq!(empty_exec().estimated_table_length_bound(&accessor), 1);
+}
+
+#[test]
+fn we_can_bound_the_table_length_of_a_union_by_the_sum_of_its_inputs() {
+    let t = TableRef::new("sxt", "t");
+    let u = TableRef::new("sxt", "u");
+    let accessor = accessor_with_tables(&[(&t, 5), (&u, 3)]);
+    let plan = union_exec(vec![table_plan(&t), table_plan(&u), table_plan(&t)]);
+    assert_eq!(plan.estimated_table_length_bound(&accessor), 13);
+}
+
+#[test]
+fn we_can_bound_the_table_length_of_a_join_by_the_product_of_its_inputs() {
+    let t = TableRef::new("sxt", "t");
+    let u = TableRef::new("sxt", "u");
+    let e = TableRef::new("sxt", "e");
+    let accessor = accessor_with_tables(&[(&t, 5), (&u, 3), (&e, 0)]);
+    let plan = sort_merge_join(
+        table_plan(&t),
+        table_plan(&u),
+        vec![0],
+        vec![0],
+        vec!["id".into()],
+    );
+    assert_eq!(plan.estimated_table_length_bound(&accessor), 15);
+
+    // Joining with an empty table still materializes the other input
+    let plan = sort_merge_join(
+        table_plan(&t),
+        table_plan(&e),
+        vec![0],
+        vec![0],
+        vec!["id".into()],
+    );
+    assert_eq!(plan.estimated_table_length_bound(&accessor), 5);
+}
+
+#[test]
+fn we_can_get_the_referenced_columns_of_a_filter() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_tables(&[(&t, 5)]);
+    let a = ColumnRef::new(t.clone(), "a".into(), ColumnType::BigInt);
+    let b = ColumnRef::new(t.clone(), "b".into(), ColumnType::BigInt);
+    let id = ColumnRef::new(t.clone(), "id".into(), ColumnType::BigInt);
+
+    // A `TableExec` reads its whole schema
+    let plan = filter(
+        vec![aliased_plan(column(&t, "id", &accessor), "id")],
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("id", ColumnType::BigInt),
+                column_field("a", ColumnType::BigInt),
+            ],
+        ),
+        equal(column(&t, "id", &accessor), const_bigint(3)),
+    );
+    assert_eq!(
+        plan.referenced_columns(),
+        IndexSet::from_iter([id.clone(), a])
+    );
+
+    // A `LegacyFilterExec` only reads the columns of its expressions
+    let plan = legacy_filter(
+        vec![aliased_plan(DynProofExpr::new_column(b.clone()), "b")],
+        tab(&t),
+        equal(column(&t, "id", &accessor), const_bigint(3)),
+    );
+    assert_eq!(plan.referenced_columns(), IndexSet::from_iter([b, id]));
+}
+
+#[test]
+fn we_can_get_the_referenced_columns_of_a_group_by() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_tables(&[(&t, 5)]);
+    let a = ColumnRef::new(t.clone(), "a".into(), ColumnType::BigInt);
+    let b = ColumnRef::new(t.clone(), "b".into(), ColumnType::BigInt);
+    let id = ColumnRef::new(t.clone(), "id".into(), ColumnType::BigInt);
+    let plan = group_by(
+        vec![ColumnExpr::new(a.clone())],
+        vec![sum_expr(DynProofExpr::new_column(b.clone()), "sum_b")],
+        "count",
+        tab(&t),
+        equal(column(&t, "id", &accessor), const_bigint(3)),
+    );
+    assert_eq!(plan.referenced_columns(), IndexSet::from_iter([a, b, id]));
+}
+
+#[test]
+fn we_can_get_the_referenced_columns_of_a_join() {
+    let t = TableRef::new("sxt", "t");
+    let u = TableRef::new("sxt", "u");
+    let plan = sort_merge_join(
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("id", ColumnType::BigInt),
+                column_field("a", ColumnType::VarChar),
+            ],
+        ),
+        table_exec(
+            u.clone(),
+            vec![
+                column_field("id", ColumnType::BigInt),
+                column_field("b", ColumnType::Boolean),
+            ],
+        ),
+        vec![0],
+        vec![0],
+        vec!["id".into(), "a".into(), "b".into()],
+    );
+    assert_eq!(
+        plan.referenced_columns(),
+        IndexSet::from_iter([
+            ColumnRef::new(t.clone(), "id".into(), ColumnType::BigInt),
+            ColumnRef::new(t, "a".into(), ColumnType::VarChar),
+            ColumnRef::new(u.clone(), "id".into(), ColumnType::BigInt),
+            ColumnRef::new(u, "b".into(), ColumnType::Boolean),
+        ])
+    );
+}
+
+#[test]
+fn we_can_collapse_nested_slices_into_one() {
+    let t = TableRef::new("sxt", "t");
+    let plan = slice_exec(
+        slice_exec(slice_exec(table_plan(&t), 1, None), 2, Some(5)),
+        1,
+        Some(2),
+    );
+    assert_eq!(plan.simplify(), slice_exec(table_plan(&t), 4, Some(2)));
+
+    // Slices below other plans are collapsed as well
+    let accessor = accessor_with_tables(&[(&t, 5)]);
+    let plan = filter(
+        vec![aliased_plan(column(&t, "id", &accessor), "id")],
+        slice_exec(slice_exec(table_plan(&t), 0, Some(3)), 0, Some(3)),
+        equal(column(&t, "id", &accessor), const_bigint(3)),
+    );
+    let expected = filter(
+        vec![aliased_plan(column(&t, "id", &accessor), "id")],
+        slice_exec(table_plan(&t), 0, Some(3)),
+        equal(column(&t, "id", &accessor), const_bigint(3)),
+    );
+    assert_eq!(plan.simplify(), expected);
+
+    // A single slice is left as is
+    let plan = slice_exec(table_plan(&t), 2, None);
+    assert_eq!(plan.simplify(), plan);
+}
+
+#[test]
+fn collapsing_nested_slices_preserves_the_query_result() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_tables(&[(&t, 6)]);
+    let fetches = [None, Some(0), Some(1), Some(3), Some(7)];
+    for inner_skip in 0..4 {
+        for inner_fetch in fetches {
+            for outer_skip in 0..4 {
+                for outer_fetch in fetches {
+                    let plan = slice_exec(
+                        slice_exec(table_plan(&t), inner_skip, inner_fetch),
+                        outer_skip,
+                        outer_fetch,
+                    );
+                    let simplified = plan.simplify();
+                    let selects_every_row = inner_skip == 0
+                        && inner_fetch.is_none()
+                        && outer_skip == 0
+                        && outer_fetch.is_none();
+                    assert!(match &simplified {
+                        DynProofPlan::Slice(slice) =>
+                            !selects_every_row && matches!(slice.input(), DynProofPlan::Table(_)),
+                        DynProofPlan::Table(_) => selects_every_row,
+                        _ => false,
+                    });
+                    let expected = VerifiableQueryResult::new(&plan, &accessor, &(), &[])
+                        .unwrap()
+                        .verify(&plan, &accessor, &(), &[])
+                        .unwrap()
+                        .table;
+                    let actual = VerifiableQueryResult::new(&simplified, &accessor, &(), &[])
+                        .unwrap()
+                        .verify(&simplified, &accessor, &(), &[])
+                        .unwrap()
+                        .table;
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn we_can_elide_slices_which_select_every_row() {
+    let t = TableRef::new("sxt", "t");
+    // LIMIT ALL
+    assert_eq!(
+        slice_exec(table_plan(&t), 0, None).simplify(),
+        table_plan(&t)
+    );
+    assert_eq!(
+        slice_exec(slice_exec(table_plan(&t), 0, None), 0, None).simplify(),
+        table_plan(&t)
+    );
+    assert_eq!(
+        slice_exec(slice_exec(table_plan(&t), 0, None), 1, Some(2)).simplify(),
+        slice_exec(table_plan(&t), 1, Some(2))
+    );
+    // LIMIT 0 and OFFSET without LIMIT are kept
+    assert_eq!(
+        slice_exec(table_plan(&t), 0, Some(0)).simplify(),
+        slice_exec(table_plan(&t), 0, Some(0))
+    );
+    assert_eq!(
+        slice_exec(table_plan(&t), 1, None).simplify(),
+        slice_exec(table_plan(&t), 1, None)
+    );
+}
+
+#[test]
+fn we_can_simplify_and_prove_slices_with_huge_skips_and_fetches() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_tables(&[(&t, 6)]);
+    let plan = slice_exec(
+        slice_exec(table_plan(&t), usize::MAX - 1, Some(usize::MAX)),
+        usize::MAX,
+        Some(usize::MAX),
+    );
+    assert_eq!(
+        plan.simplify(),
+        slice_exec(table_plan(&t), usize::MAX, Some(0))
+    );
+    for (plan, expected) in [
+        (plan.clone(), owned_table([bigint("id", [0_i64; 0])])),
+        (plan.simplify(), owned_table([bigint("id", [0_i64; 0])])),
+        (
+            slice_exec(table_plan(&t), 4, Some(usize::MAX)),
+            owned_table([bigint("id", [4_i64, 5])]),
+        ),
+        (
+            slice_exec(table_plan(&t), usize::MAX, None),
+            owned_table([bigint("id", [0_i64; 0])]),
+        ),
+    ] {
+        let actual = VerifiableQueryResult::new(&plan, &accessor, &(), &[])
+            .unwrap()
+            .verify(&plan, &accessor, &(), &[])
+            .unwrap()
+            .table;
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn we_can_remove_identity_projections_over_tables() {
+    let t = TableRef::new("sxt", "t");
+    let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t.clone(),
+        owned_table([bigint("a", [1_i64, 2]), varchar("b", ["x", "y"])]),
+        0,
+    );
+    let table = table_exec(
+        t.clone(),
+        vec![
+            column_field("a", ColumnType::BigInt),
+            column_field("b", ColumnType::VarChar),
+        ],
+    );
+
+    let plan = projection(cols_expr_plan(&t, &["a", "b"], &accessor), table.clone());
+    assert_eq!(plan.simplify(), table);
+
+    // Identity projections are removed below other plans as well
+    let plan = slice_exec(plan, 1, None);
+    assert_eq!(plan.simplify(), slice_exec(table.clone(), 1, None));
+
+    // Reordering, dropping or renaming columns is preserved
+    for aliased_results in [
+        cols_expr_plan(&t, &["b", "a"], &accessor),
+        cols_expr_plan(&t, &["a"], &accessor),
+        vec![
+            aliased_plan(column(&t, "a", &accessor), "a"),
+            aliased_plan(column(&t, "b", &accessor), "c"),
+        ],
+    ] {
+        let plan = projection(aliased_results, table.clone());
+        assert_eq!(plan.simplify(), plan);
+    }
+}
+
+fn accessor_with_abc_table(t: &TableRef) -> OwnedTableTestAccessor<'static, NaiveEvaluationProof> {
+    let mut accessor = OwnedTableTestAccessor::<NaiveEvaluationProof>::new_empty_with_setup(());
+    accessor.add_table(
+        t.clone(),
+        owned_table([
+            bigint("a", [1, 2, 3]),
+            bigint("b", [4, 5, 6]),
+            varchar("c", ["x", "y", "x"]),
+        ]),
+        0,
+    );
+    accessor
+}
+
+fn abc_table_plan(t: &TableRef) -> DynProofPlan {
+    table_exec(
+        t.clone(),
+        vec![
+            column_field("a", ColumnType::BigInt),
+            column_field("b", ColumnType::BigInt),
+            column_field("c", ColumnType::VarChar),
+        ],
+    )
+}
+
+#[test]
+fn we_can_display_a_filter_over_a_projection_over_a_table() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_abc_table(&t);
+    let d = DynProofExpr::new_column(ColumnRef::new(t.clone(), "d".into(), ColumnType::BigInt));
+    let plan = filter(
+        vec![
+            aliased_plan(column(&t, "a", &accessor), "a"),
+            aliased_plan(d.clone(), "d"),
+        ],
+        projection(
+            vec![
+                aliased_plan(column(&t, "a", &accessor), "a"),
+                aliased_plan(add(column(&t, "b", &accessor), const_bigint(1)), "d"),
+            ],
+            abc_table_plan(&t),
+        ),
+        and(
+            gt(d, const_bigint(2)),
+            lte(column(&t, "a", &accessor), const_bigint(10)),
+        ),
+    );
+    assert_eq!(
+        plan.to_string(),
+        [
+            "Filter [a, d] WHERE ((d > 2) AND NOT (a > 10))",
+            "  Projection [a, (b + 1) AS d]",
+            "    TableScan sxt.t [a, b, c]",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn we_can_display_a_group_by() {
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_abc_table(&t);
+    let plan = group_by(
+        cols_expr(&t, &["c"], &accessor),
+        vec![sum_expr(column(&t, "a", &accessor), "sum_a")],
+        "__count__",
+        tab(&t),
+        equal(column(&t, "c", &accessor), const_varchar("x")),
+    );
+    assert_eq!(
+        plan.to_string(),
+        "GroupBy [c] [SUM(a) AS sum_a, COUNT(*) AS __count__] FROM sxt.t WHERE (c = 'x')"
+    );
+}
+
+#[test]
+fn we_can_display_plans_with_several_inputs() {
+    let t = TableRef::new("sxt", "t");
+    let plan = union_exec(vec![
+        slice_exec(abc_table_plan(&t), 1, Some(2)),
+        slice_exec(abc_table_plan(&t), 0, None),
+    ]);
+    assert_eq!(
+        plan.to_string(),
+        [
+            "Union",
+            "  Slice OFFSET 1 LIMIT 2",
+            "    TableScan sxt.t [a, b, c]",
+            "  Slice OFFSET 0",
+            "    TableScan sxt.t [a, b, c]",
+        ]
+        .join("\n")
+    );
+}
+
+fn bigint_column(table_ref: &TableRef, name: &str) -> DynProofExpr {
+    DynProofExpr::new_column(ColumnRef::new(
+        table_ref.clone(),
+        name.into(),
+        ColumnType::BigInt,
+    ))
+}
+
+fn filter_over_table_plan(t: &TableRef) -> DynProofPlan {
+    filter(
+        vec![
+            aliased_plan(bigint_column(t, "a"), "a"),
+            aliased_plan(
+                add(bigint_column(t, "a"), bigint_column(t, "b")),
+                "a_plus_b",
+            ),
+        ],
+        table_exec(
+            t.clone(),
+            vec![
+                column_field("a", ColumnType::BigInt),
+                column_field("b", ColumnType::BigInt),
+            ],
+        ),
+        gt(bigint_column(t, "b"), const_bigint(5)),
+    )
+}
+
+#[test]
+fn we_can_rename_the_table_of_a_filter_over_a_table() {
+    let t = TableRef::new("sxt", "t");
+    let u = TableRef::new("sxt", "u");
+    let plan = filter_over_table_plan(&t);
+    let renamed = plan.rename_table(&t, &u);
+    assert_eq!(renamed, filter_over_table_plan(&u));
+    assert!(renamed
+        .referenced_columns()
+        .iter()
+        .all(|column_ref| column_ref.table_ref() == u));
+    assert_eq!(renamed.rename_table(&u, &t), plan);
+}
+
+#[test]
+fn renaming_a_table_not_in_the_plan_leaves_it_unchanged() {
+    let t = TableRef::new("sxt", "t");
+    let plan = filter_over_table_plan(&t);
+    assert_eq!(
+        plan.rename_table(&TableRef::new("sxt", "v"), &TableRef::new("sxt", "u")),
+        plan
+    );
+}
+
+#[test]
+fn we_only_rename_the_matching_side_of_a_join() {
+    let t = TableRef::new("sxt", "t");
+    let s = TableRef::new("sxt", "s");
+    let u = TableRef::new("sxt", "u");
+    let join_plan = |left: &TableRef| {
+        sort_merge_join(
+            filter_over_table_plan(left),
+            table_exec(
+                s.clone(),
+                vec![
+                    column_field("a", ColumnType::BigInt),
+                    column_field("c", ColumnType::BigInt),
+                ],
+            ),
+            vec![0],
+            vec![0],
+            vec!["a".into(), "a_plus_b".into(), "c".into()],
+        )
+    };
+    let renamed = join_plan(&t).rename_table(&t, &u);
+    assert_eq!(renamed, join_plan(&u));
+    assert_eq!(renamed.get_table_references(), IndexSet::from_iter([u, s]));
+}
@@ -168,7 +168,7 @@ impl ProverEvaluate for FilterExec {
         // 1. selection
         let selection_column: Column<'a, S> = self
             .where_clause
-            .first_round_evaluate(alloc, &input, params)?;
+            .first_round_evaluate(builder, alloc, &input, params)?;
         let selection = selection_column
             .as_boolean()
             .expect("selection is not boolean");
@@ -181,7 +181,7 @@ impl ProverEvaluate for FilterExec {
             .map(|aliased_expr| -> PlaceholderResult<Column<'a, S>> {
                 aliased_expr
                     .expr
-                    .first_round_evaluate(alloc, &input, params)
+                    .first_round_evaluate(builder, alloc, &input, params)
             })
             .collect::<PlaceholderResult<Vec<_>>>()?;
 
@@ -3,8 +3,8 @@ use crate::{
     base::{
         database::{
             group_by_util::{aggregate_columns, AggregatedColumns},
-            Column, ColumnField, ColumnRef, ColumnType, LiteralValue, Table, TableEvaluation,
-            TableRef,
+            try_sum_column_type, Column, ColumnField, ColumnRef, ColumnType, LiteralValue, Table,
+            TableEvaluation, TableRef,
         },
         map::{IndexMap, IndexSet},
         proof::{PlaceholderResult, ProofError},
@@ -43,6 +43,11 @@ use tracing::{span, Level};
 /// ```
 ///
 /// Note: if `group_by_exprs` is empty, then the query is equivalent to removing the `GROUP BY` clause.
+///
+/// The output is always sorted by the group key in ascending order, so callers can rely on the
+/// order without a separate sort. This comes at no extra cost: uniqueness of the groups is proven
+/// by showing the group key column is strictly increasing, which also proves that it is sorted.
+/// Without group keys the output is a single row.
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct GroupByExec {
     pub(super) group_by_exprs: Vec<ColumnExpr>,
@@ -213,7 +218,11 @@ impl ProofPlan for GroupByExec {
             .iter()
             .map(|col| col.get_column_field())
             .chain(self.sum_expr.iter().map(|aliased_expr| {
-                ColumnField::new(aliased_expr.alias.clone(), aliased_expr.expr.data_type())
+                let data_type = aliased_expr.expr.data_type();
+                ColumnField::new(
+                    aliased_expr.alias.clone(),
+                    try_sum_column_type(data_type).unwrap_or(data_type),
+                )
             }))
             .chain(iter::once(ColumnField::new(
                 self.count_alias.clone(),
@@ -264,14 +273,14 @@ impl ProverEvaluate for GroupByExec {
             .group_by_exprs
             .iter()
             .map(|expr| -> PlaceholderResult<Column<'a, S>> {
-                expr.first_round_evaluate(alloc, table, params)
+                expr.first_round_evaluate(builder, alloc, table, params)
             })
             .collect::<PlaceholderResult<Vec<_>>>()?;
         // End compute g_in_star
 
         let selection_column: Column<'a, S> = self
             .where_clause
-            .first_round_evaluate(alloc, table, params)?;
+            .first_round_evaluate(builder, alloc, table, params)?;
         let selection = selection_column
             .as_boolean()
             .expect("selection is not boolean");
@@ -281,7 +290,9 @@ impl ProverEvaluate for GroupByExec {
             .sum_expr
             .iter()
             .map(|aliased_expr| -> PlaceholderResult<Column<'a, S>> {
-                aliased_expr.expr.first_round_evaluate(alloc, table, params)
+                aliased_expr
+                    .expr
+                    .first_round_evaluate(builder, alloc, table, params)
             })
             .collect::<PlaceholderResult<Vec<_>>>()?;
         // End compute sum_in_fold
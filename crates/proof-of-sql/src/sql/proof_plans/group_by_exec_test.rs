@@ -69,6 +69,63 @@ fn we_can_prove_a_simple_group_by_with_bigint_columns() {
     assert_eq!(res, expected);
 }
 
+/// `select a, sum(c) as sum_c, count(*) as __count__ from sxt.t group by a`
+#[test]
+fn we_can_prove_that_group_by_output_is_sorted_by_the_group_key() {
+    let data = owned_table([
+        bigint("a", [3, -7, 0, 3, 12, -7, -1, 0]),
+        bigint("c", [1, 2, 3, 4, 5, 6, 7, 8]),
+    ]);
+    let t = TableRef::new("sxt", "t");
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t.clone(), data, 0);
+    let expr = group_by(
+        cols_expr(&t, &["a"], &accessor),
+        vec![sum_expr(column(&t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(&t),
+        const_bool(true),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &(), &[]).unwrap();
+    exercise_verification(&res, &expr, &accessor, &t);
+    let res = res.verify(&expr, &accessor, &(), &[]).unwrap().table;
+    let expected = owned_table([
+        bigint("a", [-7, -1, 0, 3, 12]),
+        bigint("sum_c", [2 + 6, 7, 3 + 8, 1 + 4, 5]),
+        bigint("__count__", [2, 1, 2, 2, 1]),
+    ]);
+    assert_eq!(res, expected);
+}
+
+/// `select a, sum(c) as sum_c, count(*) as __count__ from sxt.t group by a` where `a` is a decimal
+#[test]
+fn we_can_prove_a_group_by_with_a_decimal_key() {
+    // `a` has scale 2, so e.g. 1.5 and 1.50 are both stored as 150 and land in the same group
+    let data = owned_table([
+        decimal75("a", 5, 2, [150, -25, 150, 1000, -25, -1000]),
+        bigint("c", [1, 2, 3, 4, 5, 6]),
+    ]);
+    let t = TableRef::new("sxt", "t");
+    let mut accessor = OwnedTableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t.clone(), data, 0);
+    let expr = group_by(
+        cols_expr(&t, &["a"], &accessor),
+        vec![sum_expr(column(&t, "c", &accessor), "sum_c")],
+        "__count__",
+        tab(&t),
+        const_bool(true),
+    );
+    let res = VerifiableQueryResult::new(&expr, &accessor, &(), &[]).unwrap();
+    exercise_verification(&res, &expr, &accessor, &t);
+    let res = res.verify(&expr, &accessor, &(), &[]).unwrap().table;
+    let expected = owned_table([
+        decimal75("a", 5, 2, [-1000, -25, 150, 1000]),
+        bigint("sum_c", [6, 2 + 5, 1 + 3, 4]),
+        bigint("__count__", [1, 2, 2, 1]),
+    ]);
+    assert_eq!(res, expected);
+}
+
 /// `select a, sum(c * 2 + 1) as sum_c, count(*) as __count__ from sxt.t where b = 99 group by a`
 #[test]
 fn we_can_prove_a_group_by_with_bigint_columns() {
@@ -50,7 +50,7 @@ impl ProverEvaluate for DishonestFilterExec {
         // 1. selection
         let selection_column: Column<'a, S> = self
             .where_clause()
-            .first_round_evaluate(alloc, table, params)?;
+            .first_round_evaluate(builder, alloc, table, params)?;
         let selection = selection_column
             .as_boolean()
             .expect("selection is not boolean");
@@ -60,7 +60,9 @@ impl ProverEvaluate for DishonestFilterExec {
             .aliased_results()
             .iter()
             .map(|aliased_expr| -> PlaceholderResult<Column<'a, S>> {
-                aliased_expr.expr.first_round_evaluate(alloc, table, params)
+                aliased_expr
+                    .expr
+                    .first_round_evaluate(builder, alloc, table, params)
             })
             .collect::<PlaceholderResult<Vec<_>>>()?;
         // Compute filtered_columns
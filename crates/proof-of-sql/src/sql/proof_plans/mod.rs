@@ -63,8 +63,35 @@ pub use sort_merge_join_exec::SortMergeJoinExec;
 #[cfg(all(test, feature = "blitzar"))]
 mod sort_merge_join_exec_test;
 
+mod semi_join_exec;
+pub use semi_join_exec::SemiJoinExec;
+#[cfg(all(test, feature = "blitzar"))]
+mod semi_join_exec_test;
+
+mod cross_join_exec;
+pub use cross_join_exec::CrossJoinExec;
+#[cfg(all(test, feature = "blitzar"))]
+mod cross_join_exec_test;
+
+mod row_number_exec;
+pub use row_number_exec::RowNumberExec;
+#[cfg(all(test, feature = "blitzar"))]
+mod row_number_exec_test;
+
+mod no_match_exec;
+pub(crate) use no_match_exec::NoMatchExec;
+#[cfg(all(test, feature = "blitzar"))]
+mod no_match_exec_test;
+
 mod dyn_proof_plan;
 pub use dyn_proof_plan::DynProofPlan;
+#[cfg(test)]
+mod dyn_proof_plan_test;
+
+mod versioned_proof_plan;
+pub use versioned_proof_plan::{VersionedProofPlan, VersionedProofPlanError};
+#[cfg(test)]
+mod versioned_proof_plan_test;
 
 #[cfg(test)]
 mod demo_mock_plan;
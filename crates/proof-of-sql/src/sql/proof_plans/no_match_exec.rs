@@ -0,0 +1,169 @@
+use super::DynProofPlan;
+use crate::{
+    base::{
+        database::{
+            ColumnField, ColumnRef, LiteralValue, Table, TableEvaluation, TableOptions, TableRef,
+        },
+        map::{IndexMap, IndexSet},
+        proof::{PlaceholderResult, ProofError},
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{
+            FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate,
+            SumcheckSubpolynomialType, VerificationBuilder,
+        },
+        proof_exprs::{DynProofExpr, ProofExpr},
+    },
+    utils::log,
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::Ident;
+
+/// Proves that no row of the input matches a predicate, e.g. for queries of the form
+/// ```ignore
+///     SELECT NOT EXISTS (SELECT * FROM <input> WHERE <where_clause>)
+/// ```
+///
+/// Rather than returning an empty filtered result, this constrains the selection
+/// column to be identically zero, which needs a single degree-one identity constraint.
+/// The result is a single row with no columns, as with [`super::EmptyExec`].
+/// If any row matches, no valid proof can be produced.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct NoMatchExec {
+    input: Box<DynProofPlan>,
+    where_clause: DynProofExpr,
+}
+
+impl NoMatchExec {
+    /// Creates a new no-match plan.
+    pub fn new(input: Box<DynProofPlan>, where_clause: DynProofExpr) -> Self {
+        Self {
+            input,
+            where_clause,
+        }
+    }
+
+    /// Get the input plan
+    pub fn input(&self) -> &DynProofPlan {
+        &self.input
+    }
+
+    /// Get the where clause expression
+    pub fn where_clause(&self) -> &DynProofExpr {
+        &self.where_clause
+    }
+
+    /// Returns a table with a single row and no columns
+    fn result_table<'a, S: Scalar>() -> Table<'a, S> {
+        Table::<'a, S>::try_new_with_options(IndexMap::default(), TableOptions::new(Some(1)))
+            .expect("an empty table with one row is valid")
+    }
+}
+
+impl ProofPlan for NoMatchExec {
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut impl VerificationBuilder<S>,
+        accessor: &IndexMap<TableRef, IndexMap<Ident, S>>,
+        chi_eval_map: &IndexMap<TableRef, (S, usize)>,
+        params: &[LiteralValue],
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        let input_eval = self
+            .input
+            .verifier_evaluate(builder, accessor, chi_eval_map, params)?;
+        let accessor = self
+            .input
+            .get_column_result_fields()
+            .iter()
+            .map(ColumnField::name)
+            .zip(input_eval.column_evals().iter().copied())
+            .collect::<IndexMap<_, _>>();
+
+        let selection_eval =
+            self.where_clause
+                .verifier_evaluate(builder, &accessor, input_eval.chi().0, params)?;
+
+        // subpolynomial: selection
+        builder.try_produce_sumcheck_subpolynomial_evaluation(
+            SumcheckSubpolynomialType::Identity,
+            selection_eval,
+            1,
+        )?;
+
+        Ok(TableEvaluation::new(
+            Vec::<S>::new(),
+            (builder.singleton_chi_evaluation(), 1),
+        ))
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        Vec::new()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        self.input.get_column_references()
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        self.input.get_table_references()
+    }
+}
+
+impl ProverEvaluate for NoMatchExec {
+    #[tracing::instrument(name = "NoMatchExec::first_round_evaluate", level = "debug", skip_all)]
+    fn first_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        log::log_memory_usage("Start");
+
+        let input = self
+            .input
+            .first_round_evaluate(builder, alloc, table_map, params)?;
+        // The selection is evaluated so that it can add its intermediate columns
+        self.where_clause
+            .first_round_evaluate(builder, alloc, &input, params)?;
+        let res = Self::result_table();
+
+        log::log_memory_usage("End");
+
+        Ok(res)
+    }
+
+    #[tracing::instrument(name = "NoMatchExec::final_round_evaluate", level = "debug", skip_all)]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        log::log_memory_usage("Start");
+
+        let input = self
+            .input
+            .final_round_evaluate(builder, alloc, table_map, params)?;
+        let selection = self
+            .where_clause
+            .final_round_evaluate(builder, alloc, &input, params)?
+            .as_boolean()
+            .expect("selection is not boolean");
+
+        // subpolynomial: selection
+        builder.produce_sumcheck_subpolynomial(
+            SumcheckSubpolynomialType::Identity,
+            vec![(S::one(), vec![Box::new(selection)])],
+        );
+        let res = Self::result_table();
+
+        log::log_memory_usage("End");
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,88 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::{
+        commitment::InnerProductProof,
+        database::{
+            table_utility::*, ColumnField, ColumnRef, ColumnType, OwnedTable, TableRef,
+            TableTestAccessor, TestAccessor,
+        },
+        map::IndexMap,
+    },
+    sql::{
+        proof::{ProofPlan, VerifiableQueryResult},
+        proof_exprs::test_utility::*,
+    },
+};
+use bumpalo::Bump;
+
+fn accessor_with_statuses<'a>(
+    alloc: &'a Bump,
+    t: &TableRef,
+    statuses: &[&'a str],
+) -> TableTestAccessor<'a, InnerProductProof> {
+    let data = table([
+        borrowed_bigint("id", vec![1; statuses.len()], alloc),
+        borrowed_varchar("status", statuses.iter().copied(), alloc),
+    ]);
+    let mut accessor = TableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(t.clone(), data, 0);
+    accessor
+}
+
+fn no_banned_rows_plan(
+    t: &TableRef,
+    accessor: &TableTestAccessor<'_, InnerProductProof>,
+) -> DynProofPlan {
+    DynProofPlan::new_no_match(
+        table_exec(
+            t.clone(),
+            vec![
+                ColumnField::new("id".into(), ColumnType::BigInt),
+                ColumnField::new("status".into(), ColumnType::VarChar),
+            ],
+        ),
+        equal(column(t, "status", accessor), const_varchar("banned")),
+    )
+}
+
+#[test]
+fn we_can_prove_that_no_row_matches_a_predicate() {
+    let alloc = Bump::new();
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_statuses(&alloc, &t, &["active", "inactive", "active"]);
+    let plan = no_banned_rows_plan(&t, &accessor);
+    assert!(plan.get_column_result_fields().is_empty());
+    assert_eq!(
+        plan.get_column_references(),
+        [
+            ColumnRef::new(t.clone(), "id".into(), ColumnType::BigInt),
+            ColumnRef::new(t.clone(), "status".into(), ColumnType::VarChar),
+        ]
+        .into_iter()
+        .collect()
+    );
+
+    let res = VerifiableQueryResult::new(&plan, &accessor, &(), &[]).unwrap();
+    let res = res.verify(&plan, &accessor, &(), &[]).unwrap().table;
+    assert_eq!(res, OwnedTable::try_new(IndexMap::default()).unwrap());
+}
+
+#[test]
+fn we_can_prove_that_no_row_of_an_empty_table_matches_a_predicate() {
+    let alloc = Bump::new();
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_statuses(&alloc, &t, &[]);
+    let plan = no_banned_rows_plan(&t, &accessor);
+    let res = VerifiableQueryResult::new(&plan, &accessor, &(), &[]).unwrap();
+    assert!(res.verify(&plan, &accessor, &(), &[]).is_ok());
+}
+
+#[test]
+fn we_cannot_prove_that_no_row_matches_a_predicate_if_some_row_does() {
+    let alloc = Bump::new();
+    let t = TableRef::new("sxt", "t");
+    let accessor = accessor_with_statuses(&alloc, &t, &["active", "banned", "active"]);
+    let plan = no_banned_rows_plan(&t, &accessor);
+    let res = VerifiableQueryResult::new(&plan, &accessor, &(), &[]).unwrap();
+    assert!(res.verify(&plan, &accessor, &(), &[]).is_err());
+}
@@ -133,7 +133,7 @@ impl ProverEvaluate for ProjectionExec {
                         aliased_expr.alias.clone(),
                         aliased_expr
                             .expr
-                            .first_round_evaluate(alloc, &input, params)?,
+                            .first_round_evaluate(builder, alloc, &input, params)?,
                     ))
                 },
             )
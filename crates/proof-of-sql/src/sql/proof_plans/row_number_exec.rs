@@ -0,0 +1,354 @@
+use super::{semi_join_exec::is_provably_ordered_column_type, DynProofPlan};
+use crate::{
+    base::{
+        database::{
+            apply_column_to_indexes, order_by_util::compare_indexes_by_columns, Column,
+            ColumnField, ColumnRef, ColumnType, LiteralValue, Table, TableEvaluation, TableOptions,
+            TableRef,
+        },
+        map::{IndexMap, IndexSet},
+        proof::{PlaceholderResult, ProofError},
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{
+            FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate, VerificationBuilder,
+        },
+        proof_gadgets::{
+            final_round_evaluate_monotonic, final_round_evaluate_permutation_check,
+            first_round_evaluate_monotonic, verify_monotonic, verify_permutation_check,
+        },
+        AnalyzeError, AnalyzeResult,
+    },
+    utils::log,
+};
+use alloc::{boxed::Box, vec::Vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::Ident;
+
+/// `ProofPlan` for queries of the form
+/// ```ignore
+///     SELECT *, ROW_NUMBER() OVER (ORDER BY <order_by_column> [ASC | DESC]) AS <alias>
+///     FROM <ProofPlan>
+/// ```
+///
+/// The result consists of the rows of the input sorted by the order by column, followed by
+/// the row number column `1, 2, ..., n`.
+/// 1. A permutation check proves that the rows of the result are a permutation of the input rows.
+/// 2. The order by column of the result is proven to be monotonic, so the rows are sorted.
+/// 3. The row number column is `rho + chi`. It is proven to be strictly increasing,
+///    which rules out a claimed `rho` shorter than the result.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct RowNumberExec {
+    pub(super) input: Box<DynProofPlan>,
+    pub(super) order_by_index: usize,
+    pub(super) ascending: bool,
+    pub(super) alias: Ident,
+}
+
+impl RowNumberExec {
+    /// Create a new `RowNumberExec` numbering the rows of `input` ordered by the column at
+    /// `order_by_index`, with the row numbers in a column named `alias`
+    ///
+    /// # Errors
+    /// Returns [`AnalyzeError::InvalidDataType`] if the order by column can not be proven to be
+    /// sorted, e.g. a `VARCHAR` column, and [`AnalyzeError::DuplicateColumnName`] if `alias` is
+    /// already a column of the input.
+    ///
+    /// # Panics
+    /// Panics if `order_by_index` is out of bounds.
+    pub fn try_new(
+        input: Box<DynProofPlan>,
+        order_by_index: usize,
+        ascending: bool,
+        alias: Ident,
+    ) -> AnalyzeResult<Self> {
+        let input_fields = input.get_column_result_fields();
+        let order_by_type = input_fields[order_by_index].data_type();
+        if !is_provably_ordered_column_type(order_by_type) {
+            return Err(AnalyzeError::InvalidDataType {
+                expr_type: order_by_type,
+            });
+        }
+        if input_fields.iter().any(|field| field.name() == alias) {
+            return Err(AnalyzeError::DuplicateColumnName { name: alias.value });
+        }
+        Ok(Self {
+            input,
+            order_by_index,
+            ascending,
+            alias,
+        })
+    }
+
+    pub(crate) fn input(&self) -> &DynProofPlan {
+        &self.input
+    }
+
+    /// Get the index of the order by column in the input
+    #[must_use]
+    pub fn order_by_index(&self) -> usize {
+        self.order_by_index
+    }
+
+    /// Whether the rows are numbered in ascending order of the order by column
+    #[must_use]
+    pub fn ascending(&self) -> bool {
+        self.ascending
+    }
+
+    /// Get the name of the row number column
+    #[must_use]
+    pub fn alias(&self) -> &Ident {
+        &self.alias
+    }
+
+    /// Returns the input columns sorted by the order by column
+    fn sorted_columns<'a, S: Scalar>(
+        &self,
+        alloc: &'a Bump,
+        input: &Table<'a, S>,
+    ) -> Vec<Column<'a, S>> {
+        let order_by = [*input
+            .column(self.order_by_index)
+            .expect("Indexes can not be out of bounds")];
+        let mut indexes = (0..input.num_rows()).collect::<Vec<_>>();
+        indexes.sort_by(|&i, &j| {
+            let ordering = compare_indexes_by_columns(&order_by, i, j);
+            if self.ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+        input
+            .columns()
+            .map(|column| {
+                apply_column_to_indexes(column, alloc, &indexes)
+                    .expect("Indexes can not be out of bounds")
+            })
+            .collect()
+    }
+}
+
+/// Returns the row numbers `1..=num_rows`
+#[expect(
+    clippy::cast_possible_wrap,
+    reason = "A table can not have more than i64::MAX rows"
+)]
+fn row_numbers(alloc: &Bump, num_rows: usize) -> &[i64] {
+    alloc.alloc_slice_fill_with(num_rows, |k| k as i64 + 1)
+}
+
+impl ProofPlan for RowNumberExec
+where
+    RowNumberExec: ProverEvaluate,
+{
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut impl VerificationBuilder<S>,
+        accessor: &IndexMap<TableRef, IndexMap<Ident, S>>,
+        chi_eval_map: &IndexMap<TableRef, (S, usize)>,
+        params: &[LiteralValue],
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        let input_eval = self
+            .input
+            .verifier_evaluate(builder, accessor, chi_eval_map, params)?;
+        let chi_eval = input_eval.chi_eval();
+        let alpha = builder.try_consume_post_result_challenge()?;
+        let beta = builder.try_consume_post_result_challenge()?;
+        let sorted_column_evals =
+            builder.try_consume_first_round_mle_evaluations(input_eval.column_evals().len())?;
+        let rho_eval = builder.try_consume_rho_evaluation()?;
+
+        // 2. The order by column is monotonic
+        let order_by_eval = sorted_column_evals[self.order_by_index];
+        if self.ascending {
+            verify_monotonic::<S, false, true>(builder, alpha, beta, order_by_eval, chi_eval)?;
+        } else {
+            verify_monotonic::<S, false, false>(builder, alpha, beta, order_by_eval, chi_eval)?;
+        }
+
+        // 3. The row numbers are strictly increasing
+        let row_number_eval = rho_eval + chi_eval;
+        verify_monotonic::<S, true, true>(builder, alpha, beta, row_number_eval, chi_eval)?;
+
+        // 1. The result is a permutation of the input
+        verify_permutation_check(
+            builder,
+            alpha,
+            beta,
+            chi_eval,
+            input_eval.column_evals(),
+            &sorted_column_evals,
+        )?;
+
+        Ok(TableEvaluation::new(
+            sorted_column_evals
+                .into_iter()
+                .chain([row_number_eval])
+                .collect(),
+            input_eval.chi(),
+        ))
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        self.input
+            .get_column_result_fields()
+            .into_iter()
+            .chain([ColumnField::new(self.alias.clone(), ColumnType::BigInt)])
+            .collect()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        self.input.get_column_references()
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        self.input.get_table_references()
+    }
+}
+
+impl ProverEvaluate for RowNumberExec {
+    #[tracing::instrument(
+        name = "RowNumberExec::first_round_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn first_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        log::log_memory_usage("Start");
+
+        let input = self
+            .input
+            .first_round_evaluate(builder, alloc, table_map, params)?;
+        let num_rows = input.num_rows();
+        let sorted_columns = self.sorted_columns(alloc, &input);
+        for column in &sorted_columns {
+            builder.produce_intermediate_mle(*column);
+        }
+        builder.request_post_result_challenges(2);
+        builder.produce_rho_evaluation_length(num_rows);
+        let row_numbers = row_numbers(alloc, num_rows);
+
+        // 2. The order by column is monotonic
+        first_round_evaluate_monotonic(
+            builder,
+            alloc,
+            alloc.alloc_slice_copy(&sorted_columns[self.order_by_index].to_scalar()),
+        );
+
+        // 3. The row numbers are strictly increasing
+        first_round_evaluate_monotonic(
+            builder,
+            alloc,
+            alloc.alloc_slice_copy(&Column::<S>::BigInt(row_numbers).to_scalar()),
+        );
+
+        let res = Table::try_from_iter_with_options(
+            self.get_column_result_fields()
+                .into_iter()
+                .map(|field| field.name())
+                .zip(
+                    sorted_columns
+                        .into_iter()
+                        .chain([Column::BigInt(row_numbers)]),
+                ),
+            TableOptions::new(Some(num_rows)),
+        )
+        .expect("Failed to create table from iterator");
+
+        log::log_memory_usage("End");
+
+        Ok(res)
+    }
+
+    #[tracing::instrument(
+        name = "RowNumberExec::final_round_evaluate",
+        level = "debug",
+        skip_all
+    )]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        log::log_memory_usage("Start");
+
+        let input = self
+            .input
+            .final_round_evaluate(builder, alloc, table_map, params)?;
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+        let num_rows = input.num_rows();
+        let sorted_columns = self.sorted_columns(alloc, &input);
+        let row_numbers = row_numbers(alloc, num_rows);
+        let chi = alloc.alloc_slice_fill_copy(num_rows, true);
+
+        // 2. The order by column is monotonic
+        let order_by_values =
+            alloc.alloc_slice_copy(&sorted_columns[self.order_by_index].to_scalar());
+        if self.ascending {
+            final_round_evaluate_monotonic::<S, false, true>(
+                builder,
+                alloc,
+                alpha,
+                beta,
+                order_by_values,
+            );
+        } else {
+            final_round_evaluate_monotonic::<S, false, false>(
+                builder,
+                alloc,
+                alpha,
+                beta,
+                order_by_values,
+            );
+        }
+
+        // 3. The row numbers are strictly increasing
+        final_round_evaluate_monotonic::<S, true, true>(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            alloc.alloc_slice_copy(&Column::<S>::BigInt(row_numbers).to_scalar()),
+        );
+
+        // 1. The result is a permutation of the input
+        final_round_evaluate_permutation_check(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            chi,
+            &input.columns().copied().collect::<Vec<_>>(),
+            &sorted_columns,
+        );
+
+        let res = Table::try_from_iter_with_options(
+            self.get_column_result_fields()
+                .into_iter()
+                .map(|field| field.name())
+                .zip(
+                    sorted_columns
+                        .into_iter()
+                        .chain([Column::BigInt(row_numbers)]),
+                ),
+            TableOptions::new(Some(num_rows)),
+        )
+        .expect("Failed to create table from iterator");
+
+        log::log_memory_usage("End");
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,139 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::database::{
+        owned_table_utility::*, table_utility::*, ColumnType, TableRef, TableTestAccessor,
+        TestAccessor,
+    },
+    sql::{
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::test_utility::*,
+        AnalyzeError,
+    },
+};
+use blitzar::proof::InnerProductProof;
+use bumpalo::Bump;
+
+fn cats_plan(table_ref: &TableRef) -> DynProofPlan {
+    table_exec(
+        table_ref.clone(),
+        vec![
+            column_field("id", ColumnType::BigInt),
+            column_field("name", ColumnType::VarChar),
+            column_field("age", ColumnType::Int),
+        ],
+    )
+}
+
+fn accessor_with_cats<'a>(
+    alloc: &'a Bump,
+    cats: &TableRef,
+) -> TableTestAccessor<'a, InnerProductProof> {
+    let mut accessor = TableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    accessor.add_table(
+        cats.clone(),
+        table([
+            borrowed_bigint("id", [1_i64, 2, 3, 4, 5], alloc),
+            borrowed_varchar(
+                "name",
+                ["Chloe", "Margaret", "Prudence", "Lucy", "Pepper"],
+                alloc,
+            ),
+            borrowed_int("age", [13, -2, 7, 13, 0], alloc),
+        ]),
+        0,
+    );
+    accessor
+}
+
+#[test]
+fn we_can_prove_and_get_the_correct_result_from_a_row_number() {
+    let alloc = Bump::new();
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let accessor = accessor_with_cats(&alloc, &cats);
+    // SELECT *, ROW_NUMBER() OVER (ORDER BY age) AS row_num FROM cats
+    let ast = row_number(cats_plan(&cats), 2, true, "row_num");
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &cats);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([
+        bigint("id", [2_i64, 5, 3, 1, 4]),
+        varchar("name", ["Margaret", "Pepper", "Prudence", "Chloe", "Lucy"]),
+        int("age", [-2, 0, 7, 13, 13]),
+        bigint("row_num", [1_i64, 2, 3, 4, 5]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_descending_row_number_over_a_filtered_input() {
+    let alloc = Bump::new();
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let accessor = accessor_with_cats(&alloc, &cats);
+    // SELECT id, ROW_NUMBER() OVER (ORDER BY id DESC) AS row_num FROM cats WHERE age > 0
+    let ast = row_number(
+        filter(
+            cols_expr_plan(&cats, &["id"], &accessor),
+            cats_plan(&cats),
+            gt(column(&cats, "age", &accessor), const_int(0)),
+        ),
+        0,
+        false,
+        "row_num",
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &cats);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([
+        bigint("id", [4_i64, 3, 1]),
+        bigint("row_num", [1_i64, 2, 3]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_row_number_over_an_empty_input() {
+    let alloc = Bump::new();
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let accessor = accessor_with_cats(&alloc, &cats);
+    let ast = row_number(
+        filter(
+            cols_expr_plan(&cats, &["id"], &accessor),
+            cats_plan(&cats),
+            gt(column(&cats, "age", &accessor), const_int(100)),
+        ),
+        0,
+        true,
+        "row_num",
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &cats);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([bigint("id", [0_i64; 0]), bigint("row_num", [0_i64; 0])]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_cannot_create_a_row_number_over_unordered_columns_or_with_a_duplicate_alias() {
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    assert_eq!(
+        DynProofPlan::try_new_row_number(cats_plan(&cats), 1, true, "row_num".into()),
+        Err(AnalyzeError::InvalidDataType {
+            expr_type: ColumnType::VarChar
+        })
+    );
+    assert_eq!(
+        DynProofPlan::try_new_row_number(cats_plan(&cats), 0, true, "age".into()),
+        Err(AnalyzeError::DuplicateColumnName {
+            name: "age".to_string()
+        })
+    );
+}
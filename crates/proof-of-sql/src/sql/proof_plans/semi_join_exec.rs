@@ -0,0 +1,416 @@
+use super::{fold_vals, DynProofPlan};
+use crate::{
+    base::{
+        database::{
+            filter_util::filter_columns, join_util::ordered_set_union, Column, ColumnField,
+            ColumnRef, ColumnType, LiteralValue, Table, TableEvaluation, TableOptions, TableRef,
+        },
+        map::{IndexMap, IndexSet},
+        proof::{PlaceholderResult, ProofError},
+        scalar::Scalar,
+    },
+    sql::{
+        proof::{
+            FinalRoundBuilder, FirstRoundBuilder, ProofPlan, ProverEvaluate, VerificationBuilder,
+        },
+        proof_exprs::{final_round_evaluate_equals_zero, verifier_evaluate_equals_zero},
+        proof_gadgets::{
+            final_round_evaluate_filter, final_round_evaluate_membership_check,
+            final_round_evaluate_monotonic, first_round_evaluate_membership_check,
+            first_round_evaluate_monotonic, verify_evaluate_filter, verify_membership_check,
+            verify_monotonic,
+        },
+        AnalyzeError, AnalyzeResult,
+    },
+    utils::log,
+};
+use alloc::{boxed::Box, collections::BTreeSet, string::ToString, vec::Vec};
+use bumpalo::Bump;
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::Ident;
+
+/// `ProofPlan` for queries of the form
+/// ```ignore
+///     SELECT * FROM <ProofPlan> WHERE col1 IN (SELECT col2 FROM <ProofPlan>)
+/// ```
+///
+/// The result consists of the rows of the left input whose join column value appears in the
+/// join column of the right input, in their original order.
+///
+/// Let `c_l` and `c_r` be the join columns and `u` the sorted union of their distinct values.
+/// 1. `u` is proven to be strictly increasing, so its values are distinct.
+/// 2. A membership check of `c_r` in `u` proves the multiplicities `w_r` of the values of `u` in `c_r`.
+/// 3. The indicator `e = (w_r != 0)` of the values of `u` appearing in `c_r` is proven with the
+///    equals-zero gadget.
+/// 4. A membership check of the rows of `(c_l, s)` in the rows of `(u, e)` proves that the
+///    selection `s` is `e` looked up by `c_l`, since the values of `u` are distinct.
+/// 5. The result is proven to be the rows of the left input selected by `s` with the filter gadget.
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
+pub struct SemiJoinExec {
+    pub(super) left: Box<DynProofPlan>,
+    pub(super) right: Box<DynProofPlan>,
+    pub(super) left_join_column_index: usize,
+    pub(super) right_join_column_index: usize,
+}
+
+/// Whether a column of type `column_type` can be proven to be sorted
+///
+/// The order of the values of these types agrees with the signed order of their scalars.
+pub(super) fn is_provably_ordered_column_type(column_type: ColumnType) -> bool {
+    matches!(
+        column_type,
+        ColumnType::Boolean
+            | ColumnType::Uint8
+            | ColumnType::TinyInt
+            | ColumnType::SmallInt
+            | ColumnType::Int
+            | ColumnType::BigInt
+            | ColumnType::Int128
+            | ColumnType::Decimal75(_, _)
+            | ColumnType::TimestampTZ(_, _)
+    )
+}
+
+impl SemiJoinExec {
+    /// Create a new `SemiJoinExec` keeping the rows of `left` whose value in the column at
+    /// `left_join_column_index` appears in the column of `right` at `right_join_column_index`
+    ///
+    /// # Errors
+    /// Returns [`AnalyzeError::DataTypeMismatch`] if the join columns have different types and
+    /// [`AnalyzeError::InvalidDataType`] if the join columns can not be compared in a proof,
+    /// e.g. `VARCHAR` columns.
+    ///
+    /// # Panics
+    /// Panics if one of the join column indexes is out of bounds.
+    pub fn try_new(
+        left: Box<DynProofPlan>,
+        right: Box<DynProofPlan>,
+        left_join_column_index: usize,
+        right_join_column_index: usize,
+    ) -> AnalyzeResult<Self> {
+        let left_type = left.get_column_result_fields()[left_join_column_index].data_type();
+        let right_type = right.get_column_result_fields()[right_join_column_index].data_type();
+        if left_type != right_type {
+            return Err(AnalyzeError::DataTypeMismatch {
+                left_type: left_type.to_string(),
+                right_type: right_type.to_string(),
+            });
+        }
+        if !is_provably_ordered_column_type(left_type) {
+            return Err(AnalyzeError::InvalidDataType {
+                expr_type: left_type,
+            });
+        }
+        Ok(Self {
+            left,
+            right,
+            left_join_column_index,
+            right_join_column_index,
+        })
+    }
+
+    pub(crate) fn left_plan(&self) -> &DynProofPlan {
+        &self.left
+    }
+
+    pub(crate) fn right_plan(&self) -> &DynProofPlan {
+        &self.right
+    }
+
+    pub(crate) fn left_join_column_index(&self) -> usize {
+        self.left_join_column_index
+    }
+
+    pub(crate) fn right_join_column_index(&self) -> usize {
+        self.right_join_column_index
+    }
+
+    /// Returns the join columns of the evaluated inputs
+    fn join_columns<'a, S: Scalar>(
+        &self,
+        left: &Table<'a, S>,
+        right: &Table<'a, S>,
+    ) -> (Column<'a, S>, Column<'a, S>) {
+        (
+            *left
+                .column(self.left_join_column_index)
+                .expect("Indexes can not be out of bounds"),
+            *right
+                .column(self.right_join_column_index)
+                .expect("Indexes can not be out of bounds"),
+        )
+    }
+}
+
+/// Returns the selection of the rows of `left_key` whose value appears in `right_key`
+/// along with the sorted union `u` of the distinct values of both
+fn semi_join_selection<'a, S: Scalar>(
+    alloc: &'a Bump,
+    left_key: Column<'a, S>,
+    right_key: Column<'a, S>,
+) -> (&'a [bool], Column<'a, S>) {
+    let right_values = right_key.to_scalar().into_iter().collect::<BTreeSet<_>>();
+    let left_values = left_key.to_scalar();
+    let selection = alloc.alloc_slice_fill_with(left_values.len(), |i| {
+        right_values.contains(&left_values[i])
+    });
+    let union = ordered_set_union(&[left_key], &[right_key], alloc)
+        .expect("Join columns have the same type")
+        .remove(0);
+    (selection, union)
+}
+
+/// Returns the indicator of the nonzero entries of `multiplicities`
+fn nonzero_indicator<'a>(alloc: &'a Bump, multiplicities: &[i128]) -> &'a [bool] {
+    alloc.alloc_slice_fill_with(multiplicities.len(), |i| multiplicities[i] != 0)
+}
+
+impl ProofPlan for SemiJoinExec
+where
+    SemiJoinExec: ProverEvaluate,
+{
+    fn verifier_evaluate<S: Scalar>(
+        &self,
+        builder: &mut impl VerificationBuilder<S>,
+        accessor: &IndexMap<TableRef, IndexMap<Ident, S>>,
+        chi_eval_map: &IndexMap<TableRef, (S, usize)>,
+        params: &[LiteralValue],
+    ) -> Result<TableEvaluation<S>, ProofError> {
+        let left_eval = self
+            .left
+            .verifier_evaluate(builder, accessor, chi_eval_map, params)?;
+        let right_eval = self
+            .right
+            .verifier_evaluate(builder, accessor, chi_eval_map, params)?;
+        let left_key_eval = *left_eval
+            .column_evals()
+            .get(self.left_join_column_index)
+            .ok_or(ProofError::VerificationError {
+                error: "Join column index out of bounds",
+            })?;
+        let right_key_eval = *right_eval
+            .column_evals()
+            .get(self.right_join_column_index)
+            .ok_or(ProofError::VerificationError {
+                error: "Join column index out of bounds",
+            })?;
+        let output_chi_eval = builder.try_consume_chi_evaluation()?;
+        let alpha = builder.try_consume_post_result_challenge()?;
+        let beta = builder.try_consume_post_result_challenge()?;
+        let filtered_columns_evals =
+            builder.try_consume_first_round_mle_evaluations(left_eval.column_evals().len())?;
+
+        // 1. The union of the join columns is strictly increasing
+        let union_chi_eval = builder.try_consume_chi_evaluation()?.0;
+        let union_eval = builder.try_consume_first_round_mle_evaluation()?;
+        verify_monotonic::<S, true, true>(builder, alpha, beta, union_eval, union_chi_eval)?;
+        let selection_eval = builder.try_consume_first_round_mle_evaluation()?;
+
+        // 2. Multiplicities of the values of the union in the right join column
+        let right_multiplicity_eval = verify_membership_check(
+            builder,
+            alpha,
+            beta,
+            union_chi_eval,
+            right_eval.chi_eval(),
+            &[union_eval],
+            &[right_key_eval],
+        )?;
+
+        // 3. Indicator of the values of the union appearing in the right join column
+        let not_in_right_eval =
+            verifier_evaluate_equals_zero(builder, right_multiplicity_eval, union_chi_eval)?;
+        let in_right_eval = union_chi_eval - not_in_right_eval;
+
+        // 4. The selection is the indicator looked up by the left join column
+        verify_membership_check(
+            builder,
+            alpha,
+            beta,
+            union_chi_eval,
+            left_eval.chi_eval(),
+            &[union_eval, in_right_eval],
+            &[left_key_eval, selection_eval],
+        )?;
+
+        // 5. The result is the left input filtered by the selection
+        let c_fold_eval = alpha * fold_vals(beta, left_eval.column_evals());
+        let d_fold_eval = alpha * fold_vals(beta, &filtered_columns_evals);
+        verify_evaluate_filter(
+            builder,
+            c_fold_eval,
+            d_fold_eval,
+            left_eval.chi_eval(),
+            output_chi_eval.0,
+            selection_eval,
+        )?;
+        Ok(TableEvaluation::new(
+            filtered_columns_evals,
+            output_chi_eval,
+        ))
+    }
+
+    fn get_column_result_fields(&self) -> Vec<ColumnField> {
+        self.left.get_column_result_fields()
+    }
+
+    fn get_column_references(&self) -> IndexSet<ColumnRef> {
+        self.left
+            .get_column_references()
+            .into_iter()
+            .chain(self.right.get_column_references())
+            .collect()
+    }
+
+    fn get_table_references(&self) -> IndexSet<TableRef> {
+        self.left
+            .get_table_references()
+            .into_iter()
+            .chain(self.right.get_table_references())
+            .collect()
+    }
+}
+
+impl ProverEvaluate for SemiJoinExec {
+    #[tracing::instrument(name = "SemiJoinExec::first_round_evaluate", level = "debug", skip_all)]
+    fn first_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FirstRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        log::log_memory_usage("Start");
+
+        let left = self
+            .left
+            .first_round_evaluate(builder, alloc, table_map, params)?;
+        let right = self
+            .right
+            .first_round_evaluate(builder, alloc, table_map, params)?;
+        let (left_key, right_key) = self.join_columns(&left, &right);
+        let (selection, union) = semi_join_selection(alloc, left_key, right_key);
+
+        let left_columns = left.columns().copied().collect::<Vec<_>>();
+        let (filtered_columns, output_length) = filter_columns(alloc, &left_columns, selection);
+        filtered_columns.iter().copied().for_each(|column| {
+            builder.produce_intermediate_mle(column);
+        });
+        builder.produce_chi_evaluation_length(output_length);
+        builder.request_post_result_challenges(2);
+
+        // 1. The union of the join columns is strictly increasing
+        builder.produce_chi_evaluation_length(union.len());
+        let union_values = alloc.alloc_slice_copy(&union.to_scalar());
+        builder.produce_intermediate_mle(union_values as &[_]);
+        first_round_evaluate_monotonic(builder, alloc, union_values);
+        builder.produce_intermediate_mle(selection);
+
+        // 2. Multiplicities of the values of the union in the right join column
+        let right_multiplicities =
+            first_round_evaluate_membership_check(builder, alloc, &[union], &[right_key]);
+
+        // 4. The selection is the indicator looked up by the left join column
+        let in_right = nonzero_indicator(alloc, right_multiplicities);
+        first_round_evaluate_membership_check(
+            builder,
+            alloc,
+            &[union, Column::Boolean(in_right)],
+            &[left_key, Column::Boolean(selection)],
+        );
+
+        let res = Table::try_from_iter_with_options(
+            left.inner_table().keys().cloned().zip(filtered_columns),
+            TableOptions::new(Some(output_length)),
+        )
+        .expect("Failed to create table from iterator");
+
+        log::log_memory_usage("End");
+
+        Ok(res)
+    }
+
+    #[tracing::instrument(name = "SemiJoinExec::final_round_evaluate", level = "debug", skip_all)]
+    fn final_round_evaluate<'a, S: Scalar>(
+        &self,
+        builder: &mut FinalRoundBuilder<'a, S>,
+        alloc: &'a Bump,
+        table_map: &IndexMap<TableRef, Table<'a, S>>,
+        params: &[LiteralValue],
+    ) -> PlaceholderResult<Table<'a, S>> {
+        log::log_memory_usage("Start");
+
+        let left = self
+            .left
+            .final_round_evaluate(builder, alloc, table_map, params)?;
+        let right = self
+            .right
+            .final_round_evaluate(builder, alloc, table_map, params)?;
+        let alpha = builder.consume_post_result_challenge();
+        let beta = builder.consume_post_result_challenge();
+        let (left_key, right_key) = self.join_columns(&left, &right);
+        let (selection, union) = semi_join_selection(alloc, left_key, right_key);
+        let num_rows_union = union.len();
+        let chi_left = alloc.alloc_slice_fill_copy(left.num_rows(), true);
+        let chi_right = alloc.alloc_slice_fill_copy(right.num_rows(), true);
+        let chi_union = alloc.alloc_slice_fill_copy(num_rows_union, true);
+
+        // 1. The union of the join columns is strictly increasing
+        let union_values = alloc.alloc_slice_copy(&union.to_scalar());
+        final_round_evaluate_monotonic::<S, true, true>(builder, alloc, alpha, beta, union_values);
+
+        // 2. Multiplicities of the values of the union in the right join column
+        let right_multiplicities = final_round_evaluate_membership_check(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            chi_union,
+            chi_right,
+            &[union],
+            &[right_key],
+        );
+
+        // 3. Indicator of the values of the union appearing in the right join column
+        let right_multiplicity_values: &[S] =
+            alloc.alloc_slice_fill_with(num_rows_union, |i| S::from(right_multiplicities[i]));
+        final_round_evaluate_equals_zero(num_rows_union, builder, alloc, right_multiplicity_values);
+        let in_right = nonzero_indicator(alloc, right_multiplicities);
+
+        // 4. The selection is the indicator looked up by the left join column
+        final_round_evaluate_membership_check(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            chi_union,
+            chi_left,
+            &[union, Column::Boolean(in_right)],
+            &[left_key, Column::Boolean(selection)],
+        );
+
+        // 5. The result is the left input filtered by the selection
+        let left_columns = left.columns().copied().collect::<Vec<_>>();
+        let (filtered_columns, output_length) = filter_columns(alloc, &left_columns, selection);
+        final_round_evaluate_filter::<S>(
+            builder,
+            alloc,
+            alpha,
+            beta,
+            &left_columns,
+            selection,
+            &filtered_columns,
+            left.num_rows(),
+            output_length,
+        );
+        let res = Table::try_from_iter_with_options(
+            left.inner_table().keys().cloned().zip(filtered_columns),
+            TableOptions::new(Some(output_length)),
+        )
+        .expect("Failed to create table from iterator");
+
+        log::log_memory_usage("End");
+
+        Ok(res)
+    }
+}
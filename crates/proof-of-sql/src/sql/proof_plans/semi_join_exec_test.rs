@@ -0,0 +1,185 @@
+use super::{test_utility::*, DynProofPlan};
+use crate::{
+    base::database::{
+        owned_table_utility::*, table_utility::*, ColumnType, TableRef, TableTestAccessor,
+        TestAccessor,
+    },
+    sql::{
+        proof::{exercise_verification, VerifiableQueryResult},
+        proof_exprs::test_utility::*,
+        AnalyzeError,
+    },
+};
+use blitzar::proof::InnerProductProof;
+use bumpalo::Bump;
+
+fn cats_plan(table_ref: &TableRef) -> DynProofPlan {
+    table_exec(
+        table_ref.clone(),
+        vec![
+            column_field("id", ColumnType::BigInt),
+            column_field("name", ColumnType::VarChar),
+        ],
+    )
+}
+
+fn owners_plan(table_ref: &TableRef) -> DynProofPlan {
+    table_exec(
+        table_ref.clone(),
+        vec![
+            column_field("cat_id", ColumnType::BigInt),
+            column_field("human", ColumnType::VarChar),
+        ],
+    )
+}
+
+#[test]
+fn we_can_prove_and_get_the_correct_result_from_a_semi_join() {
+    let alloc = Bump::new();
+    let mut accessor = TableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let owners: TableRef = "sxt.owners".parse().unwrap();
+    accessor.add_table(
+        cats.clone(),
+        table([
+            borrowed_bigint("id", [5_i64, 1, 2, 3, 4, 1], &alloc),
+            borrowed_varchar(
+                "name",
+                ["Pepper", "Chloe", "Margaret", "Prudence", "Lucy", "Chloe"],
+                &alloc,
+            ),
+        ]),
+        0,
+    );
+    accessor.add_table(
+        owners.clone(),
+        table([
+            borrowed_bigint("cat_id", [1_i64, 2, 98, 4, 1, 2, 7], &alloc),
+            borrowed_varchar(
+                "human",
+                ["Cassia", "Cassia", "Gretta", "Gretta", "Ian", "Ian", "Erik"],
+                &alloc,
+            ),
+        ]),
+        0,
+    );
+    // SELECT * FROM cats WHERE id IN (SELECT cat_id FROM owners)
+    let ast = semi_join(cats_plan(&cats), owners_plan(&owners), 0, 0);
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &cats);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([
+        bigint("id", [1_i64, 2, 4, 1]),
+        varchar("name", ["Chloe", "Margaret", "Lucy", "Chloe"]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_semi_join_over_a_filtered_input() {
+    let alloc = Bump::new();
+    let mut accessor = TableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let owners: TableRef = "sxt.owners".parse().unwrap();
+    accessor.add_table(
+        cats.clone(),
+        table([
+            borrowed_bigint("id", [1_i64, 2, 3], &alloc),
+            borrowed_varchar("name", ["Chloe", "Margaret", "Prudence"], &alloc),
+        ]),
+        0,
+    );
+    accessor.add_table(
+        owners.clone(),
+        table([
+            borrowed_bigint("cat_id", [1_i64, 2, 3], &alloc),
+            borrowed_varchar("human", ["Cassia", "Gretta", "Cassia"], &alloc),
+        ]),
+        0,
+    );
+    // SELECT * FROM cats WHERE id IN (SELECT cat_id FROM owners WHERE human = 'Cassia')
+    let ast = semi_join(
+        cats_plan(&cats),
+        filter(
+            cols_expr_plan(&owners, &["cat_id"], &accessor),
+            owners_plan(&owners),
+            equal(column(&owners, "human", &accessor), const_varchar("Cassia")),
+        ),
+        0,
+        0,
+    );
+    let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+    exercise_verification(&verifiable_res, &ast, &accessor, &cats);
+    let res = verifiable_res
+        .verify(&ast, &accessor, &(), &[])
+        .unwrap()
+        .table;
+    let expected_res = owned_table([
+        bigint("id", [1_i64, 3]),
+        varchar("name", ["Chloe", "Prudence"]),
+    ]);
+    assert_eq!(res, expected_res);
+}
+
+#[test]
+fn we_can_prove_a_semi_join_with_no_matches_or_empty_inputs() {
+    let alloc = Bump::new();
+    let mut accessor = TableTestAccessor::<InnerProductProof>::new_empty_with_setup(());
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let owners: TableRef = "sxt.owners".parse().unwrap();
+    let no_owners: TableRef = "sxt.no_owners".parse().unwrap();
+    accessor.add_table(
+        cats.clone(),
+        table([
+            borrowed_bigint("id", [1_i64, 2], &alloc),
+            borrowed_varchar("name", ["Chloe", "Margaret"], &alloc),
+        ]),
+        0,
+    );
+    accessor.add_table(
+        owners.clone(),
+        table([
+            borrowed_bigint("cat_id", [3_i64, 4], &alloc),
+            borrowed_varchar("human", ["Cassia", "Gretta"], &alloc),
+        ]),
+        0,
+    );
+    accessor.add_table(
+        no_owners.clone(),
+        table([
+            borrowed_bigint("cat_id", [0_i64; 0], &alloc),
+            borrowed_varchar("human", [""; 0], &alloc),
+        ]),
+        0,
+    );
+    let expected_res = owned_table([bigint("id", [0_i64; 0]), varchar("name", [""; 0])]);
+    for right in [&owners, &no_owners] {
+        let ast = semi_join(cats_plan(&cats), owners_plan(right), 0, 0);
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+        exercise_verification(&verifiable_res, &ast, &accessor, &cats);
+        let res = verifiable_res
+            .verify(&ast, &accessor, &(), &[])
+            .unwrap()
+            .table;
+        assert_eq!(res, expected_res);
+    }
+}
+
+#[test]
+fn we_cannot_create_a_semi_join_with_mismatched_or_unsupported_join_columns() {
+    let cats: TableRef = "sxt.cats".parse().unwrap();
+    let owners: TableRef = "sxt.owners".parse().unwrap();
+    assert!(matches!(
+        DynProofPlan::try_new_semi_join(cats_plan(&cats), owners_plan(&owners), 0, 1),
+        Err(AnalyzeError::DataTypeMismatch { .. })
+    ));
+    assert!(matches!(
+        DynProofPlan::try_new_semi_join(cats_plan(&cats), owners_plan(&owners), 1, 1),
+        Err(AnalyzeError::InvalidDataType {
+            expr_type: ColumnType::VarChar
+        })
+    ));
+}
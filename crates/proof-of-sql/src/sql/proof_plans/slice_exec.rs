@@ -90,7 +90,7 @@ where
         let (offset_chi_eval, offset) = builder.try_consume_chi_evaluation()?;
         let (max_chi_eval, max) = builder.try_consume_chi_evaluation()?;
 
-        if output_length != max - offset {
+        if max.checked_sub(offset) != Some(output_length) {
             return Err(ProofError::VerificationError {
                 error: "output length does not match selection length",
             });
@@ -101,9 +101,9 @@ where
             });
         }
         if max
-            != self
-                .fetch
-                .map_or(input_length, |f| (f + self.skip).min(input_length))
+            != self.fetch.map_or(input_length, |f| {
+                f.saturating_add(self.skip).min(input_length)
+            })
         {
             return Err(ProofError::VerificationError {
                 error: "max length does not match expected value",
@@ -169,7 +169,7 @@ impl ProverEvaluate for SliceExec {
         // The selected range is (offset_index, max_index]
         let offset_index = self.skip.min(input_length);
         let max_index = if let Some(fetch) = self.fetch {
-            (self.skip + fetch).min(input_length)
+            self.skip.saturating_add(fetch).min(input_length)
         } else {
             input_length
         };
@@ -89,6 +89,46 @@ fn we_can_prove_and_get_the_correct_empty_result_from_a_slice_exec() {
     assert_eq!(res, expected_res);
 }
 
+#[test]
+fn we_can_prove_a_slice_exec_with_a_skip_at_or_beyond_the_table_length() {
+    let data = owned_table([bigint("a", [1_i64, 2, 3]), varchar("b", ["1", "2", "3"])]);
+    let t = TableRef::new("sxt", "t");
+    let accessor =
+        OwnedTableTestAccessor::<InnerProductProof>::new_from_table(t.clone(), data, 0, ());
+    for (skip, fetch) in [
+        (3, None),
+        (4, None),
+        (usize::MAX, None),
+        (3, Some(1)),
+        (10, Some(usize::MAX)),
+        (1, Some(usize::MAX)),
+    ] {
+        let ast = slice_exec(
+            table_exec(
+                t.clone(),
+                vec![
+                    column_field("a", ColumnType::BigInt),
+                    column_field("b", ColumnType::VarChar),
+                ],
+            ),
+            skip,
+            fetch,
+        );
+        let verifiable_res = VerifiableQueryResult::new(&ast, &accessor, &(), &[]).unwrap();
+        exercise_verification(&verifiable_res, &ast, &accessor, &t);
+        let res = verifiable_res
+            .verify(&ast, &accessor, &(), &[])
+            .unwrap()
+            .table;
+        let expected_res = if skip == 1 {
+            owned_table([bigint("a", [2_i64, 3]), varchar("b", ["2", "3"])])
+        } else {
+            owned_table([bigint("a", [0_i64; 0]), varchar("b", [""; 0])])
+        };
+        assert_eq!(res, expected_res);
+    }
+}
+
 #[test]
 fn we_can_get_an_empty_result_from_a_slice_on_an_empty_table_using_first_round_evaluate() {
     let alloc = Bump::new();
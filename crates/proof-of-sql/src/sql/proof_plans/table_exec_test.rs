@@ -92,3 +92,20 @@ fn we_can_create_and_prove_a_table_exec() {
     ]);
     assert_eq!(res, expected);
 }
+
+#[test]
+fn table_exec_preserves_column_metadata_without_affecting_plan_equality() {
+    use crate::sql::proof::ProofPlan;
+
+    let table_ref = TableRef::new("namespace", "table_name");
+    let price = ColumnField::new("price".into(), ColumnType::BigInt);
+    let price_in_cents = price
+        .clone()
+        .with_metadata([("unit".to_string(), "cents".to_string())].into());
+    let plan = table_exec(table_ref.clone(), vec![price_in_cents]);
+    assert_eq!(
+        plan.get_column_result_fields()[0].metadata()["unit"],
+        "cents"
+    );
+    assert_eq!(plan, table_exec(table_ref, vec![price]));
+}
@@ -1,6 +1,6 @@
 use super::{
-    DynProofPlan, EmptyExec, GroupByExec, LegacyFilterExec, ProjectionExec, SliceExec,
-    SortMergeJoinExec, TableExec, UnionExec,
+    CrossJoinExec, DynProofPlan, EmptyExec, GroupByExec, LegacyFilterExec, ProjectionExec,
+    RowNumberExec, SemiJoinExec, SliceExec, SortMergeJoinExec, TableExec, UnionExec,
 };
 use crate::{
     base::database::{ColumnField, ColumnType, TableRef},
@@ -106,3 +106,42 @@ pub fn sort_merge_join(
         result_idents,
     ))
 }
+
+pub fn semi_join(
+    left: DynProofPlan,
+    right: DynProofPlan,
+    left_join_column_index: usize,
+    right_join_column_index: usize,
+) -> DynProofPlan {
+    DynProofPlan::SemiJoin(
+        SemiJoinExec::try_new(
+            Box::new(left),
+            Box::new(right),
+            left_join_column_index,
+            right_join_column_index,
+        )
+        .unwrap(),
+    )
+}
+
+pub fn cross_join(left: DynProofPlan, right: DynProofPlan) -> DynProofPlan {
+    DynProofPlan::CrossJoin(
+        CrossJoinExec::try_new(
+            Box::new(left),
+            Box::new(right),
+            CrossJoinExec::DEFAULT_MAX_OUTPUT_ROWS,
+        )
+        .unwrap(),
+    )
+}
+
+pub fn row_number(
+    input: DynProofPlan,
+    order_by_index: usize,
+    ascending: bool,
+    alias: &str,
+) -> DynProofPlan {
+    DynProofPlan::RowNumber(
+        RowNumberExec::try_new(Box::new(input), order_by_index, ascending, alias.into()).unwrap(),
+    )
+}
@@ -0,0 +1,114 @@
+use super::DynProofPlan;
+use crate::base::{try_standard_binary_deserialization, try_standard_binary_serialization};
+use alloc::vec::Vec;
+use bincode::error::{DecodeError, EncodeError};
+use snafu::Snafu;
+
+/// Errors that can occur when decoding a [`VersionedProofPlan`]
+#[derive(Snafu, Debug)]
+pub enum VersionedProofPlanError {
+    /// The version header is not one this library can decode
+    #[snafu(display(
+        "unsupported proof plan version {version}, expected a version between {} and {}",
+        VersionedProofPlan::MIN_SUPPORTED_VERSION,
+        VersionedProofPlan::CURRENT_VERSION
+    ))]
+    UnsupportedVersion {
+        /// The version found in the header
+        version: u16,
+    },
+    /// The version header or the plan could not be decoded
+    #[snafu(display("failed to decode proof plan: {error}"))]
+    Decode {
+        /// The underlying decode error
+        error: DecodeError,
+    },
+    /// There are bytes left over after the plan
+    #[snafu(display("found {count} trailing bytes after the proof plan"))]
+    TrailingBytes {
+        /// Number of bytes left over
+        count: usize,
+    },
+}
+
+/// A [`DynProofPlan`] tagged with the version of its serialization format
+///
+/// The encoded form is a big-endian `u16` version header followed by the plan in the standard
+/// binary serialization of that version.
+///
+/// Compatibility is handled as follows:
+/// - Backward: every version from [`Self::MIN_SUPPORTED_VERSION`] up to [`Self::CURRENT_VERSION`]
+///   is decoded into the current [`DynProofPlan`]. When the format of `DynProofPlan` changes,
+///   `CURRENT_VERSION` is bumped and the previous format gets its own arm in [`Self::try_decode`].
+/// - Forward: plans written by a newer version can't be interpreted and are rejected with
+///   [`VersionedProofPlanError::UnsupportedVersion`] rather than being misread.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionedProofPlan {
+    version: u16,
+    plan: DynProofPlan,
+}
+
+impl VersionedProofPlan {
+    /// The version used when encoding plans
+    pub const CURRENT_VERSION: u16 = 1;
+    /// The oldest version that can still be decoded
+    pub const MIN_SUPPORTED_VERSION: u16 = 1;
+
+    /// Wraps `plan` with the current version
+    #[must_use]
+    pub fn new(plan: DynProofPlan) -> Self {
+        Self {
+            version: Self::CURRENT_VERSION,
+            plan,
+        }
+    }
+
+    /// Get the version of the serialization format
+    #[must_use]
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Get a reference to the plan
+    #[must_use]
+    pub fn plan(&self) -> &DynProofPlan {
+        &self.plan
+    }
+
+    /// Get the plan
+    #[must_use]
+    pub fn into_plan(self) -> DynProofPlan {
+        self.plan
+    }
+
+    /// Encode the version header followed by the plan
+    pub fn try_encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let mut bytes = try_standard_binary_serialization(self.version)?;
+        bytes.extend(try_standard_binary_serialization(&self.plan)?);
+        Ok(bytes)
+    }
+
+    /// Decode a plan produced by [`Self::try_encode`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VersionedProofPlanError::UnsupportedVersion`] if the version header is outside of
+    /// the supported range, [`VersionedProofPlanError::Decode`] if the bytes are malformed
+    /// and [`VersionedProofPlanError::TrailingBytes`] if there is data after the plan.
+    pub fn try_decode(bytes: &[u8]) -> Result<Self, VersionedProofPlanError> {
+        let (version, header_length): (u16, _) = try_standard_binary_deserialization(bytes)
+            .map_err(|error| VersionedProofPlanError::Decode { error })?;
+        let body = &bytes[header_length..];
+        let (plan, plan_length): (DynProofPlan, _) = match version {
+            1 => try_standard_binary_deserialization(body)
+                .map_err(|error| VersionedProofPlanError::Decode { error })?,
+            _ => return Err(VersionedProofPlanError::UnsupportedVersion { version }),
+        };
+        if plan_length != body.len() {
+            return Err(VersionedProofPlanError::TrailingBytes {
+                count: body.len() - plan_length,
+            });
+        }
+        Ok(Self { version, plan })
+    }
+}
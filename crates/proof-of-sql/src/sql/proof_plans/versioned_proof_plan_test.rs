@@ -0,0 +1,78 @@
+use super::{test_utility::*, DynProofPlan, VersionedProofPlan, VersionedProofPlanError};
+use crate::{
+    base::database::{ColumnRef, ColumnType, TableRef},
+    sql::proof_exprs::{test_utility::*, DynProofExpr},
+};
+
+fn versioned_plan() -> VersionedProofPlan {
+    let t = TableRef::new("sxt", "t");
+    let a = DynProofExpr::new_column(ColumnRef::new(t.clone(), "a".into(), ColumnType::BigInt));
+    VersionedProofPlan::new(filter(
+        vec![aliased_plan(a.clone(), "a")],
+        table_exec(t, vec![column_field("a", ColumnType::BigInt)]),
+        equal(a, const_bigint(5)),
+    ))
+}
+
+#[test]
+fn we_can_encode_and_decode_a_versioned_plan() {
+    let plan = versioned_plan();
+    assert_eq!(plan.version(), VersionedProofPlan::CURRENT_VERSION);
+    let bytes = plan.try_encode().unwrap();
+    assert_eq!(
+        bytes[..2],
+        VersionedProofPlan::CURRENT_VERSION.to_be_bytes()
+    );
+    let decoded = VersionedProofPlan::try_decode(&bytes).unwrap();
+    assert_eq!(decoded, plan);
+    assert_eq!(decoded.into_plan(), plan.plan().clone());
+}
+
+#[test]
+fn we_cannot_decode_a_plan_with_a_newer_version() {
+    let mut bytes = versioned_plan().try_encode().unwrap();
+    bytes[1] += 1;
+    assert!(matches!(
+        VersionedProofPlan::try_decode(&bytes),
+        Err(VersionedProofPlanError::UnsupportedVersion { version })
+            if version == VersionedProofPlan::CURRENT_VERSION + 1
+    ));
+}
+
+#[test]
+fn we_cannot_decode_a_plan_with_a_version_older_than_the_minimum() {
+    let mut bytes = versioned_plan().try_encode().unwrap();
+    bytes[..2].copy_from_slice(&(VersionedProofPlan::MIN_SUPPORTED_VERSION - 1).to_be_bytes());
+    assert!(matches!(
+        VersionedProofPlan::try_decode(&bytes),
+        Err(VersionedProofPlanError::UnsupportedVersion { version: 0 })
+    ));
+}
+
+#[test]
+fn we_cannot_decode_a_truncated_or_padded_plan() {
+    let bytes = versioned_plan().try_encode().unwrap();
+    assert!(matches!(
+        VersionedProofPlan::try_decode(&bytes[..1]),
+        Err(VersionedProofPlanError::Decode { .. })
+    ));
+    assert!(matches!(
+        VersionedProofPlan::try_decode(&bytes[..bytes.len() - 1]),
+        Err(VersionedProofPlanError::Decode { .. })
+    ));
+    let mut padded = bytes;
+    padded.extend([0, 0, 0]);
+    assert!(matches!(
+        VersionedProofPlan::try_decode(&padded),
+        Err(VersionedProofPlanError::TrailingBytes { count: 3 })
+    ));
+}
+
+#[test]
+fn we_can_decode_the_plan_of_a_versioned_plan_as_a_plain_plan() {
+    let plan = versioned_plan();
+    let bytes = plan.try_encode().unwrap();
+    let (decoded, _): (DynProofPlan, _) =
+        crate::base::try_standard_binary_deserialization(&bytes[2..]).unwrap();
+    assert_eq!(&decoded, plan.plan());
+}
@@ -1,5 +1,8 @@
 use crate::{
-    base::{database::ColumnType, math::decimal::Precision},
+    base::{
+        database::{align_decimal_scales, ColumnType},
+        math::decimal::Precision,
+    },
     sql::{
         proof_exprs::{DynProofExpr, ProofExpr},
         AnalyzeError, AnalyzeResult,
@@ -39,7 +42,12 @@ fn decimal_scale_cast_expr(
 /// Scale cast one side so that both sides have the same scale
 ///
 /// We use this function so that binary ops for numeric types no longer
-/// need to keep track of scale
+/// need to keep track of scale. The common scale of numeric types is picked by
+/// [`align_decimal_scales`].
+#[expect(
+    clippy::missing_panics_doc,
+    reason = "The aligned type is always a decimal, which has a scale"
+)]
 pub fn scale_cast_binary_op(
     left_proof_expr: DynProofExpr,
     right_proof_expr: DynProofExpr,
@@ -48,26 +56,39 @@ pub fn scale_cast_binary_op(
     let right_type = right_proof_expr.data_type();
     let left_scale = left_type.scale().unwrap_or(0);
     let right_scale = right_type.scale().unwrap_or(0);
-    let scale = left_scale.max(right_scale);
     match left_scale.cmp(&right_scale) {
-        Ordering::Less => Ok((
-            if matches!(left_type, ColumnType::TimestampTZ(_, _)) {
-                DynProofExpr::try_new_scaling_cast(left_proof_expr, right_type)?
-            } else {
-                decimal_scale_cast_expr(left_proof_expr, left_scale, scale)?
-            },
-            right_proof_expr,
-        )),
-        Ordering::Greater => Ok((
-            left_proof_expr,
-            if matches!(right_type, ColumnType::TimestampTZ(_, _)) {
-                DynProofExpr::try_new_scaling_cast(right_proof_expr, left_type)?
-            } else {
-                decimal_scale_cast_expr(right_proof_expr, right_scale, scale)?
-            },
-        )),
-        Ordering::Equal => Ok((left_proof_expr, right_proof_expr)),
+        Ordering::Equal => return Ok((left_proof_expr, right_proof_expr)),
+        Ordering::Less if matches!(left_type, ColumnType::TimestampTZ(_, _)) => {
+            return Ok((
+                DynProofExpr::try_new_scaling_cast(left_proof_expr, right_type)?,
+                right_proof_expr,
+            ));
+        }
+        Ordering::Greater if matches!(right_type, ColumnType::TimestampTZ(_, _)) => {
+            return Ok((
+                left_proof_expr,
+                DynProofExpr::try_new_scaling_cast(right_proof_expr, left_type)?,
+            ));
+        }
+        Ordering::Less | Ordering::Greater => {}
     }
+    let (aligned_type, left_shift, right_shift) = align_decimal_scales(left_type, right_type)
+        .map_err(|_| AnalyzeError::DataTypeMismatch {
+            left_type: left_type.to_string(),
+            right_type: right_type.to_string(),
+        })?;
+    let scale = aligned_type.scale().expect("Decimals have scale");
+    let align = |proof_expr: DynProofExpr, from_scale: i8, shift: i8| {
+        if shift == 0 {
+            Ok(proof_expr)
+        } else {
+            decimal_scale_cast_expr(proof_expr, from_scale, scale)
+        }
+    };
+    Ok((
+        align(left_proof_expr, left_scale, left_shift)?,
+        align(right_proof_expr, right_scale, right_shift)?,
+    ))
 }
 
 #[cfg(test)]
@@ -228,6 +249,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn we_cannot_convert_scale_cast_binary_op_if_the_aligned_precision_overflows() {
+        let left = COLUMN3_DECIMAL_75_10();
+        let right = DynProofExpr::new_column(ColumnRef::new(
+            TableRef::from_names(Some("namespace"), "table_name"),
+            "column4".into(),
+            ColumnType::Decimal75(
+                Precision::new(20).expect("Precision is definitely valid"),
+                20,
+            ),
+        ));
+        assert!(matches!(
+            scale_cast_binary_op(left, right),
+            Err(AnalyzeError::DataTypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn we_cannot_convert_scale_cast_binary_op_with_non_numeric_types_of_differing_scales() {
+        assert!(matches!(
+            scale_cast_binary_op(COLUMN1_BOOLEAN(), COLUMN1_DECIMAL_10_5()),
+            Err(AnalyzeError::DataTypeMismatch { .. })
+        ));
+    }
+
     #[test]
     fn we_can_convert_scale_cast_binary_op_equal() {
         let left = COLUMN1_DECIMAL_10_5();